@@ -0,0 +1,17 @@
+/// @custom:storage-location erc7201:example.main
+struct MainStorage {
+    uint256 x;
+    uint256 y;
+}
+
+contract Example layout at 0x1000 {
+    // stored in slot 0x1000
+    uint64 counter;
+    // stored at the slot given by ERC-7201 for the namespace example.main
+    MainStorage main;
+
+    function inc() public {
+        counter += 1;
+        main.x = counter;
+    }
+}
@@ -0,0 +1,14 @@
+contract vault {
+    bool transient locked;
+
+    modifier nonReentrant() {
+        require(!locked, "reentrant call");
+        locked = true;
+        _;
+        locked = false;
+    }
+
+    function withdraw(address payable to, uint128 amount) public nonReentrant {
+        to.transfer(amount);
+    }
+}
@@ -0,0 +1,18 @@
+contract foo {
+    function bar() public {
+        other o = new other{refTime: 500_000_000, proofSize: 100_000}();
+
+        o.feh{
+            value: 102,
+            refTime: 100_000_000,
+            proofSize: 50_000,
+            storageDepositLimit: 1_000_000
+        }(102);
+    }
+}
+
+contract other {
+    function feh(uint32 x) public payable {
+        // ...
+    }
+}
@@ -0,0 +1,345 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Lossless concrete syntax tree.
+//!
+//! The parse tree in [`pt`] leaves out whitespace, and returns the comments separately. For tools
+//! like formatters and refactoring tools, [`crate::parse_cst`] returns a [`SyntaxTree`], which has
+//! the parse tree together with every token of the source. Each token carries the whitespace and
+//! comments around it as trivia, so the source can be reproduced byte for byte.
+//!
+//! Trivia is attached in the same way as most concrete syntax trees do: the trivia following a
+//! token up to and including the end of the line is trailing trivia of that token, and all other
+//! trivia is leading trivia of the next token. Any trivia after the last token is in
+//! [`SyntaxTree::end_trivia`].
+//!
+//! ```
+//! use solang_parser::pt::{CodeLocation, ContractPart, SourceUnitPart};
+//!
+//! let source = "contract c {\n    // the answer\n    uint x = 42; // constant?\n}\n";
+//! let tree = solang_parser::parse_cst(source, 0).unwrap();
+//!
+//! assert_eq!(tree.to_string(), source);
+//!
+//! let SourceUnitPart::ContractDefinition(contract) = &tree.source_unit.0[0] else {
+//!     unreachable!()
+//! };
+//! let ContractPart::VariableDefinition(var) = &contract.parts[0] else {
+//!     unreachable!()
+//! };
+//!
+//! let tokens = tree.tokens(&var.loc);
+//! assert_eq!(tokens[0].text, "uint");
+//! assert_eq!(tokens[0].leading_trivia[1].text, "// the answer");
+//! assert_eq!(tokens[3].text, "42");
+//!
+//! let semicolon = tree.token_at(var.loc.end()).unwrap();
+//! assert_eq!(semicolon.text, ";");
+//! assert_eq!(semicolon.trailing_trivia[1].text, "// constant?");
+//! ```
+
+use crate::lexer::{Lexer, Token};
+use crate::pt::{self, CodeLocation, Comment, Loc};
+use std::fmt;
+
+/// The parse tree of a source file, together with all of its tokens and trivia.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxTree<'input> {
+    /// The source the tree was parsed from.
+    pub source: &'input str,
+    /// The parse tree.
+    pub source_unit: pt::SourceUnit,
+    /// The tokens of the source, in source order.
+    pub tokens: Vec<SyntaxToken<'input>>,
+    /// The trivia after the last token.
+    pub end_trivia: Vec<Trivia<'input>>,
+}
+
+/// A token with its surrounding trivia.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxToken<'input> {
+    /// The token.
+    pub token: Token<'input>,
+    /// The location of the token, without trivia.
+    pub loc: Loc,
+    /// The source text of the token.
+    pub text: &'input str,
+    /// The trivia before the token.
+    pub leading_trivia: Vec<Trivia<'input>>,
+    /// The trivia after the token, up to and including the end of the line.
+    pub trailing_trivia: Vec<Trivia<'input>>,
+}
+
+/// Source text which is not a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trivia<'input> {
+    /// The kind of trivia.
+    pub kind: TriviaKind,
+    /// The location of the trivia.
+    pub loc: Loc,
+    /// The source text of the trivia.
+    pub text: &'input str,
+}
+
+/// The kind of [`Trivia`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriviaKind {
+    /// Spaces and tabs.
+    Whitespace,
+    /// A line ending; `\n`, `\r\n` or `\r`.
+    Newline,
+    /// `// ...`
+    LineComment,
+    /// `/* ... */`
+    BlockComment,
+    /// `/// ...`
+    DocLineComment,
+    /// `/** ... */`
+    DocBlockComment,
+    /// Text which the lexer skipped, like a byte order mark.
+    Skipped,
+}
+
+impl<'input> SyntaxTree<'input> {
+    /// Returns the tokens which are within `loc`. For a node of the parse tree, these are the
+    /// tokens the node was parsed from.
+    pub fn tokens(&self, loc: &Loc) -> &[SyntaxToken<'input>] {
+        let Loc::File(_, start, end) = *loc else {
+            return &[];
+        };
+
+        let first = self
+            .tokens
+            .partition_point(|token| token.loc.start() < start);
+        let last = self.tokens.partition_point(|token| token.loc.end() <= end);
+
+        if first < last {
+            &self.tokens[first..last]
+        } else {
+            &[]
+        }
+    }
+
+    /// Returns the token which contains the byte `offset`, if any.
+    pub fn token_at(&self, offset: usize) -> Option<&SyntaxToken<'input>> {
+        let index = self
+            .tokens
+            .partition_point(|token| token.loc.end() <= offset);
+
+        self.tokens
+            .get(index)
+            .filter(|token| token.loc.start() <= offset)
+    }
+
+    /// Returns the source text of `loc`, without the trivia before the first token and after the
+    /// last token.
+    pub fn text(&self, loc: &Loc) -> &'input str {
+        match *loc {
+            Loc::File(_, start, end) => &self.source[start..end],
+            _ => "",
+        }
+    }
+}
+
+impl fmt::Display for SyntaxTree<'_> {
+    /// Writes the source the tree was parsed from.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in &self.tokens {
+            token.fmt(f)?;
+        }
+
+        for trivia in &self.end_trivia {
+            f.write_str(trivia.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for SyntaxToken<'_> {
+    /// Writes the token with its trivia.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for trivia in &self.leading_trivia {
+            f.write_str(trivia.text)?;
+        }
+
+        f.write_str(self.text)?;
+
+        for trivia in &self.trailing_trivia {
+            f.write_str(trivia.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl CodeLocation for SyntaxToken<'_> {
+    fn loc(&self) -> Loc {
+        self.loc
+    }
+}
+
+impl CodeLocation for Trivia<'_> {
+    fn loc(&self) -> Loc {
+        self.loc
+    }
+}
+
+/// Lexes `source` again and attaches the trivia to the tokens. The source must have parsed
+/// without errors.
+pub(crate) fn build(source: &str, file_no: usize, source_unit: pt::SourceUnit) -> SyntaxTree<'_> {
+    let mut comments = Vec::new();
+    let mut errors = Vec::new();
+
+    // The lexer borrows the comments for as long as its tokens, so the tokens are moved over to
+    // the lifetime of the source.
+    let lexed: Vec<_> = Lexer::new(source, file_no, &mut comments, &mut errors)
+        .map(|(start, token, end)| (start, rebase(source, token), end))
+        .collect();
+
+    let mut builder = Builder {
+        source,
+        file_no,
+        offset: 0,
+        tokens: Vec::with_capacity(lexed.len()),
+        pending: Vec::new(),
+        trailing: false,
+    };
+
+    let mut comments = comments.into_iter().peekable();
+
+    for (start, token, end) in lexed {
+        while let Some(comment) = comments.next_if(|comment| comment.loc().start() < start) {
+            builder.comment(&comment);
+        }
+
+        builder.gap(start);
+
+        let leading_trivia = std::mem::take(&mut builder.pending);
+
+        builder.tokens.push(SyntaxToken {
+            token,
+            loc: Loc::File(file_no, start, end),
+            text: &source[start..end],
+            leading_trivia,
+            trailing_trivia: Vec::new(),
+        });
+
+        builder.offset = end;
+        builder.trailing = true;
+    }
+
+    for comment in comments {
+        builder.comment(&comment);
+    }
+
+    builder.gap(source.len());
+
+    SyntaxTree {
+        source,
+        source_unit,
+        tokens: builder.tokens,
+        end_trivia: builder.pending,
+    }
+}
+
+/// Returns `token` with its text borrowed from `source`. The text of a token is always a slice of
+/// the source it was lexed from.
+fn rebase<'input>(source: &'input str, token: Token<'_>) -> Token<'input> {
+    let slice = |text: &str| {
+        let start = text.as_ptr() as usize - source.as_ptr() as usize;
+
+        &source[start..start + text.len()]
+    };
+
+    match token {
+        Token::Identifier(id) => Token::Identifier(slice(id)),
+        Token::StringLiteral(unicode, s) => Token::StringLiteral(unicode, slice(s)),
+        Token::AddressLiteral(address) => Token::AddressLiteral(slice(address)),
+        Token::HexLiteral(hex) => Token::HexLiteral(slice(hex)),
+        Token::Number(integer, exp) => Token::Number(slice(integer), slice(exp)),
+        Token::RationalNumber(integer, fraction, exp) => {
+            Token::RationalNumber(slice(integer), slice(fraction), slice(exp))
+        }
+        Token::HexNumber(n) => Token::HexNumber(slice(n)),
+        Token::Annotation(name) => Token::Annotation(slice(name)),
+        // SAFETY: the remaining tokens have no text, so their lifetime can be anything. A token
+        // with text which is added to the lexer must be added above.
+        _ => unsafe { std::mem::transmute::<Token<'_>, Token<'input>>(token) },
+    }
+}
+
+struct Builder<'input> {
+    source: &'input str,
+    file_no: usize,
+    /// The end of the last token or trivia
+    offset: usize,
+    tokens: Vec<SyntaxToken<'input>>,
+    /// Leading trivia for the next token
+    pending: Vec<Trivia<'input>>,
+    /// Whether trivia is trailing trivia of the last token
+    trailing: bool,
+}
+
+impl<'input> Builder<'input> {
+    fn comment(&mut self, comment: &Comment) {
+        let Loc::File(_, start, end) = comment.loc() else {
+            unreachable!();
+        };
+
+        let kind = match comment {
+            Comment::Line(..) => TriviaKind::LineComment,
+            Comment::Block(..) => TriviaKind::BlockComment,
+            Comment::DocLine(..) => TriviaKind::DocLineComment,
+            Comment::DocBlock(..) => TriviaKind::DocBlockComment,
+        };
+
+        self.gap(start);
+        self.push(kind, start, end);
+    }
+
+    /// Adds the text between the last token or trivia and `end` as trivia.
+    fn gap(&mut self, end: usize) {
+        while self.offset < end {
+            let start = self.offset;
+            let rest = &self.source[start..end];
+
+            let (kind, len) = if rest.starts_with("\r\n") {
+                (TriviaKind::Newline, 2)
+            } else if rest.starts_with(['\n', '\r']) {
+                (TriviaKind::Newline, 1)
+            } else {
+                let whitespace = rest
+                    .find(|ch: char| !ch.is_whitespace() || ch == '\n' || ch == '\r')
+                    .unwrap_or(rest.len());
+
+                if whitespace > 0 {
+                    (TriviaKind::Whitespace, whitespace)
+                } else {
+                    let skipped = rest.find(char::is_whitespace).unwrap_or(rest.len());
+
+                    (TriviaKind::Skipped, skipped)
+                }
+            };
+
+            self.push(kind, start, start + len);
+        }
+    }
+
+    fn push(&mut self, kind: TriviaKind, start: usize, end: usize) {
+        let trivia = Trivia {
+            kind,
+            loc: Loc::File(self.file_no, start, end),
+            text: &self.source[start..end],
+        };
+
+        match self.tokens.last_mut() {
+            Some(token) if self.trailing => {
+                token.trailing_trivia.push(trivia);
+                self.trailing = kind != TriviaKind::Newline;
+            }
+            _ => self.pending.push(trivia),
+        }
+
+        self.offset = end;
+    }
+}
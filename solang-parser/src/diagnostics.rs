@@ -4,7 +4,7 @@
 
 use crate::pt;
 use crate::pt::Loc;
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 /// The level of a diagnostic.
 #[derive(Clone, Debug, Hash, PartialOrd, Ord, PartialEq, Eq)]
@@ -56,6 +56,75 @@ pub enum ErrorType {
     Warning,
 }
 
+impl ErrorType {
+    /// The code used for diagnostics of this type which have not been given a more specific code.
+    pub fn default_code(&self) -> Code {
+        match self {
+            ErrorType::None => Code::ERROR,
+            ErrorType::ParserError => Code::PARSER_ERROR,
+            ErrorType::SyntaxError => Code::SYNTAX_ERROR,
+            ErrorType::DeclarationError => Code::DECLARATION_ERROR,
+            ErrorType::CastError => Code::CAST_ERROR,
+            ErrorType::TypeError => Code::TYPE_ERROR,
+            ErrorType::Warning => Code::WARNING,
+        }
+    }
+}
+
+/// A stable code which identifies a kind of diagnostic, displayed as `S` followed by four digits,
+/// e.g. `S5001`.
+///
+/// Codes in the range `S1000`-`S4999` are used for errors, and codes from `S5000` onwards are
+/// used for warnings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Code(pub u16);
+
+impl Code {
+    /// Informational and debug messages.
+    pub const INFO: Code = Code(0);
+    /// Error without a specific error type.
+    pub const ERROR: Code = Code(1000);
+    /// Parser error.
+    pub const PARSER_ERROR: Code = Code(1001);
+    /// Syntax error.
+    pub const SYNTAX_ERROR: Code = Code(1002);
+    /// Declaration error.
+    pub const DECLARATION_ERROR: Code = Code(2000);
+    /// Type error.
+    pub const TYPE_ERROR: Code = Code(3000);
+    /// Cast error.
+    pub const CAST_ERROR: Code = Code(3100);
+    /// Warning without a more specific code.
+    pub const WARNING: Code = Code(5000);
+    /// Cast which may lose information.
+    pub const CAST_WARNING: Code = Code(5100);
+
+    /// Is this code in the warning range
+    pub fn is_warning(&self) -> bool {
+        self.0 >= Code::WARNING.0
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "S{:04}", self.0)
+    }
+}
+
+impl FromStr for Code {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s
+            .strip_prefix('S')
+            .or_else(|| s.strip_prefix('s'))
+            .filter(|digits| digits.len() == 4 && digits.chars().all(|c| c.is_ascii_digit()))
+            .ok_or_else(|| format!("'{s}' is not a diagnostic code, expected e.g. 'S5001'"))?;
+
+        Ok(Code(digits.parse().unwrap()))
+    }
+}
+
 /// A diagnostic note.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Note {
@@ -78,9 +147,29 @@ pub struct Diagnostic {
     pub message: String,
     /// Extra notes about the diagnostic.
     pub notes: Vec<Note>,
+    /// The stable code of the diagnostic.
+    pub code: Code,
 }
 
 impl Diagnostic {
+    /// Instantiate a new Diagnostic. The code is the default code for the error type; use
+    /// [`Diagnostic::with_code`] to give it a more specific one.
+    pub fn new(loc: Loc, level: Level, ty: ErrorType, message: String, notes: Vec<Note>) -> Self {
+        let code = match level {
+            Level::Debug | Level::Info => Code::INFO,
+            Level::Warning | Level::Error => ty.default_code(),
+        };
+
+        Diagnostic {
+            level,
+            ty,
+            loc,
+            message,
+            notes,
+            code,
+        }
+    }
+
     /// Instantiate a new Diagnostic with the given location and message at the debug level.
     pub fn debug(loc: Loc, message: String) -> Self {
         Diagnostic {
@@ -89,6 +178,7 @@ impl Diagnostic {
             loc,
             message,
             notes: Vec::new(),
+            code: Code::INFO,
         }
     }
 
@@ -100,6 +190,7 @@ impl Diagnostic {
             loc,
             message,
             notes: Vec::new(),
+            code: Code::INFO,
         }
     }
 
@@ -111,6 +202,7 @@ impl Diagnostic {
             loc,
             message,
             notes: Vec::new(),
+            code: Code::PARSER_ERROR,
         }
     }
 
@@ -122,6 +214,7 @@ impl Diagnostic {
             loc,
             message,
             notes: Vec::new(),
+            code: Code::SYNTAX_ERROR,
         }
     }
 
@@ -133,6 +226,7 @@ impl Diagnostic {
             loc,
             message,
             notes: Vec::new(),
+            code: Code::DECLARATION_ERROR,
         }
     }
 
@@ -144,6 +238,7 @@ impl Diagnostic {
             loc,
             message,
             notes: Vec::new(),
+            code: Code::CAST_ERROR,
         }
     }
 
@@ -158,6 +253,7 @@ impl Diagnostic {
                 loc: note_loc,
                 message: note,
             }],
+            code: Code::CAST_ERROR,
         }
     }
 
@@ -169,6 +265,7 @@ impl Diagnostic {
             loc,
             message,
             notes: Vec::new(),
+            code: Code::TYPE_ERROR,
         }
     }
 
@@ -180,6 +277,7 @@ impl Diagnostic {
             loc,
             message,
             notes: Vec::new(),
+            code: Code::CAST_WARNING,
         }
     }
 
@@ -191,6 +289,7 @@ impl Diagnostic {
             loc,
             message,
             notes: Vec::new(),
+            code: Code::WARNING,
         }
     }
 
@@ -205,6 +304,7 @@ impl Diagnostic {
                 loc: note_loc,
                 message: note,
             }],
+            code: Code::WARNING,
         }
     }
 
@@ -216,6 +316,7 @@ impl Diagnostic {
             loc,
             message,
             notes,
+            code: Code::WARNING,
         }
     }
 
@@ -230,6 +331,7 @@ impl Diagnostic {
                 loc: note_loc,
                 message: note,
             }],
+            code: Code::ERROR,
         }
     }
 
@@ -241,6 +343,13 @@ impl Diagnostic {
             loc,
             message,
             notes,
+            code: Code::ERROR,
         }
     }
+
+    /// Set a more specific code on this diagnostic.
+    pub fn with_code(mut self, code: Code) -> Self {
+        self.code = code;
+        self
+    }
 }
@@ -83,6 +83,12 @@ impl Display for pt::ContractDefinition {
             f.write_char(' ')?;
         }
 
+        if let Some(layout) = &self.layout {
+            f.write_str("layout at ")?;
+            layout.fmt(f)?;
+            f.write_char(' ')?;
+        }
+
         f.write_char('{')?;
         write_separated(&self.parts, f, " ")?;
         f.write_char('}')
@@ -1160,6 +1166,7 @@ impl Display for pt::VariableAttribute {
             Self::Visibility(vis) => vis.fmt(f),
             Self::Constant(_) => f.write_str("constant"),
             Self::Immutable(_) => f.write_str("immutable"),
+            Self::Transient(_) => f.write_str("transient"),
             Self::Override(_, idents) => {
                 f.write_str("override")?;
                 if !idents.is_empty() {
@@ -1827,8 +1834,16 @@ mod tests {
                 ty: pt::ContractTy::Contract(loc!()),
                 name: Some(id("name")),
                 base: vec![],
+                layout: None,
                 parts: vec![],
             } => "contract name {}",
+            pt::ContractDefinition {
+                ty: pt::ContractTy::Contract(loc!()),
+                name: Some(id("name")),
+                base: vec![],
+                layout: Some(expr!(SLOT)),
+                parts: vec![],
+            } => "contract name layout at SLOT {}",
             pt::ContractDefinition {
                 ty: pt::ContractTy::Contract(loc!()),
                 name: Some(id("name")),
@@ -1837,6 +1852,7 @@ mod tests {
                     name: idp!("base"),
                     args: None
                 }],
+                layout: None,
                 parts: vec![],
             } => "contract name base {}",
             pt::ContractDefinition {
@@ -1847,6 +1863,7 @@ mod tests {
                     name: idp!("base"),
                     args: Some(vec![])
                 }],
+                layout: None,
                 parts: vec![],
             } => "contract name base() {}",
             pt::ContractDefinition {
@@ -1857,6 +1874,7 @@ mod tests {
                     name: idp!("base"),
                     args: Some(vec![expr!(expr)])
                 }],
+                layout: None,
                 parts: vec![],
             } => "contract name base(expr) {}",
             pt::ContractDefinition {
@@ -1874,6 +1892,7 @@ mod tests {
                         args: None
                     },
                 ],
+                layout: None,
                 parts: vec![],
             } => "contract name base1 base2 {}",
 
@@ -2634,6 +2653,7 @@ mod tests {
             pt::VariableAttribute: {
                 pt::VariableAttribute::Constant(loc!()) => "constant",
                 pt::VariableAttribute::Immutable(loc!()) => "immutable",
+                pt::VariableAttribute::Transient(loc!()) => "transient",
 
                 pt::VariableAttribute::Override(loc!(), vec![]) => "override",
                 pt::VariableAttribute::Override(loc!(), vec![idp!["a", "b"]]) => "override(a.b)",
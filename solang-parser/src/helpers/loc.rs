@@ -433,6 +433,7 @@ impl_for_enums! {
         Self::Visibility(ref l, ..) => l.loc_opt().unwrap_or_default(),
         Self::Constant(l, ..)
         | Self::Immutable(l, ..)
+        | Self::Transient(l, ..)
         | Self::Override(l, ..) => l,
     }
 
@@ -482,6 +483,7 @@ impl_for_enums! {
         | Self::InvalidCharacterInHexLiteral(l, _)
         | Self::UnrecognisedToken(l, _)
         | Self::ExpectedFrom(l, _)
+        | Self::ExpectedLayoutAt(l, _)
         | Self::MissingExponent(l) => l,
     }
 }
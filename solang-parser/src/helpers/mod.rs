@@ -8,3 +8,9 @@ mod loc;
 pub use loc::{CodeLocation, OptionalCodeLocation};
 
 mod ord;
+
+pub mod visit;
+pub use visit::Visitor;
+
+pub mod visit_mut;
+pub use visit_mut::VisitorMut;
@@ -0,0 +1,1181 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Traversal of the parse tree.
+//!
+//! [`Visitor`] has a method for each kind of node in the parse tree. The default method visits the
+//! children of the node by calling the `walk_*` function of the same name, so an implementation
+//! only needs to override the methods for the nodes it is interested in. An overriding method can
+//! call the `walk_*` function to continue into the children of the node, or leave it out to skip
+//! them.
+//!
+//! ```
+//! use solang_parser::{helpers::{visit, Visitor}, pt::Expression};
+//!
+//! #[derive(Default)]
+//! struct CountCalls(usize);
+//!
+//! impl Visitor for CountCalls {
+//!     fn visit_expression(&mut self, expr: &Expression) {
+//!         if let Expression::FunctionCall(..) = expr {
+//!             self.0 += 1;
+//!         }
+//!
+//!         visit::walk_expression(self, expr);
+//!     }
+//! }
+//!
+//! let source = "contract c { function f() public { g(h()); } }";
+//! let (tree, comments) = solang_parser::parse(source, 0).unwrap();
+//!
+//! let mut counter = CountCalls::default();
+//! visit::walk_parse_tree(&mut counter, &tree, &comments);
+//! assert_eq!(counter.0, 2);
+//! ```
+//!
+//! Comments, including doc comments, are not part of the parse tree. [`crate::parse`] returns
+//! them separately, and [`walk_parse_tree`] visits them after the parse tree.
+//! [`doccomment::parse_doccomments`](crate::doccomment::parse_doccomments) can be used to find
+//! the doc comments of an item.
+//!
+//! See [`visit_mut`](super::visit_mut) for modifying the parse tree.
+
+// The matches below are exhaustive, so that a node which is added to the parse tree fails to
+// compile until it is walked here.
+
+use crate::pt::{
+    Annotation, Base, CatchClause, Comment, ContractDefinition, ContractPart, EnumDefinition,
+    ErrorDefinition, ErrorParameter, EventDefinition, EventParameter, Expression,
+    FunctionAttribute, FunctionDefinition, HexLiteral, Identifier, IdentifierPath, Import,
+    ImportPath, Mutability, NamedArgument, Parameter, ParameterList, PragmaDirective, SourceUnit,
+    SourceUnitPart, Statement, StorageLocation, StringLiteral, StructDefinition, Type,
+    TypeDefinition, Using, UsingFunction, UsingList, VariableAttribute, VariableDeclaration,
+    VariableDefinition, VersionComparator, Visibility, YulBlock, YulExpression, YulFor,
+    YulFunctionCall, YulFunctionDefinition, YulStatement, YulSwitch, YulSwitchOptions,
+    YulTypedIdentifier,
+};
+
+/// Visits the nodes of the parse tree. See the [module documentation](self).
+pub trait Visitor {
+    /// Visits a source unit.
+    fn visit_source_unit(&mut self, source_unit: &SourceUnit) {
+        walk_source_unit(self, source_unit);
+    }
+
+    /// Visits a source unit part.
+    fn visit_source_unit_part(&mut self, source_unit_part: &SourceUnitPart) {
+        walk_source_unit_part(self, source_unit_part);
+    }
+
+    /// Visits an import directive.
+    fn visit_import(&mut self, import: &Import) {
+        walk_import(self, import);
+    }
+
+    /// Visits an import path.
+    fn visit_import_path(&mut self, import_path: &ImportPath) {
+        walk_import_path(self, import_path);
+    }
+
+    /// Visits a pragma directive.
+    fn visit_pragma_directive(&mut self, pragma_directive: &PragmaDirective) {
+        walk_pragma_directive(self, pragma_directive);
+    }
+
+    /// Visits a version comparator.
+    fn visit_version_comparator(&mut self, version_comparator: &VersionComparator) {
+        walk_version_comparator(self, version_comparator);
+    }
+
+    /// Visits a contract definition.
+    fn visit_contract_definition(&mut self, contract_definition: &ContractDefinition) {
+        walk_contract_definition(self, contract_definition);
+    }
+
+    /// Visits a base contract or modifier invocation.
+    fn visit_base(&mut self, base: &Base) {
+        walk_base(self, base);
+    }
+
+    /// Visits a contract part.
+    fn visit_contract_part(&mut self, contract_part: &ContractPart) {
+        walk_contract_part(self, contract_part);
+    }
+
+    /// Visits a struct definition.
+    fn visit_struct_definition(&mut self, struct_definition: &StructDefinition) {
+        walk_struct_definition(self, struct_definition);
+    }
+
+    /// Visits an event definition.
+    fn visit_event_definition(&mut self, event_definition: &EventDefinition) {
+        walk_event_definition(self, event_definition);
+    }
+
+    /// Visits an event parameter.
+    fn visit_event_parameter(&mut self, event_parameter: &EventParameter) {
+        walk_event_parameter(self, event_parameter);
+    }
+
+    /// Visits an error definition.
+    fn visit_error_definition(&mut self, error_definition: &ErrorDefinition) {
+        walk_error_definition(self, error_definition);
+    }
+
+    /// Visits an error parameter.
+    fn visit_error_parameter(&mut self, error_parameter: &ErrorParameter) {
+        walk_error_parameter(self, error_parameter);
+    }
+
+    /// Visits an enum definition.
+    fn visit_enum_definition(&mut self, enum_definition: &EnumDefinition) {
+        walk_enum_definition(self, enum_definition);
+    }
+
+    /// Visits a state or constant variable definition.
+    fn visit_variable_definition(&mut self, variable_definition: &VariableDefinition) {
+        walk_variable_definition(self, variable_definition);
+    }
+
+    /// Visits a variable attribute.
+    fn visit_variable_attribute(&mut self, variable_attribute: &VariableAttribute) {
+        walk_variable_attribute(self, variable_attribute);
+    }
+
+    /// Visits a user defined value type definition.
+    fn visit_type_definition(&mut self, type_definition: &TypeDefinition) {
+        walk_type_definition(self, type_definition);
+    }
+
+    /// Visits an annotation.
+    fn visit_annotation(&mut self, annotation: &Annotation) {
+        walk_annotation(self, annotation);
+    }
+
+    /// Visits a using directive.
+    fn visit_using(&mut self, using: &Using) {
+        walk_using(self, using);
+    }
+
+    /// Visits a using list.
+    fn visit_using_list(&mut self, using_list: &UsingList) {
+        walk_using_list(self, using_list);
+    }
+
+    /// Visits a function in a using list.
+    fn visit_using_function(&mut self, using_function: &UsingFunction) {
+        walk_using_function(self, using_function);
+    }
+
+    /// Visits a function definition.
+    fn visit_function_definition(&mut self, function_definition: &FunctionDefinition) {
+        walk_function_definition(self, function_definition);
+    }
+
+    /// Visits a function attribute.
+    fn visit_function_attribute(&mut self, function_attribute: &FunctionAttribute) {
+        walk_function_attribute(self, function_attribute);
+    }
+
+    /// Visits a parameter list.
+    fn visit_parameter_list(&mut self, parameter_list: &ParameterList) {
+        walk_parameter_list(self, parameter_list);
+    }
+
+    /// Visits a parameter.
+    fn visit_parameter(&mut self, parameter: &Parameter) {
+        walk_parameter(self, parameter);
+    }
+
+    /// Visits a variable declaration.
+    fn visit_variable_declaration(&mut self, variable_declaration: &VariableDeclaration) {
+        walk_variable_declaration(self, variable_declaration);
+    }
+
+    /// Visits a visibility. This is a leaf node.
+    fn visit_visibility(&mut self, _visibility: &Visibility) {}
+
+    /// Visits a mutability. This is a leaf node.
+    fn visit_mutability(&mut self, _mutability: &Mutability) {}
+
+    /// Visits a storage location. This is a leaf node.
+    fn visit_storage_location(&mut self, _storage_location: &StorageLocation) {}
+
+    /// Visits a statement.
+    fn visit_statement(&mut self, statement: &Statement) {
+        walk_statement(self, statement);
+    }
+
+    /// Visits a catch clause.
+    fn visit_catch_clause(&mut self, catch_clause: &CatchClause) {
+        walk_catch_clause(self, catch_clause);
+    }
+
+    /// Visits an expression.
+    fn visit_expression(&mut self, expression: &Expression) {
+        walk_expression(self, expression);
+    }
+
+    /// Visits a type.
+    fn visit_type(&mut self, ty: &Type) {
+        walk_type(self, ty);
+    }
+
+    /// Visits a named argument.
+    fn visit_named_argument(&mut self, named_argument: &NamedArgument) {
+        walk_named_argument(self, named_argument);
+    }
+
+    /// Visits an identifier path.
+    fn visit_identifier_path(&mut self, identifier_path: &IdentifierPath) {
+        walk_identifier_path(self, identifier_path);
+    }
+
+    /// Visits an identifier. This is a leaf node.
+    fn visit_identifier(&mut self, _identifier: &Identifier) {}
+
+    /// Visits a string literal. This is a leaf node.
+    fn visit_string_literal(&mut self, _string_literal: &StringLiteral) {}
+
+    /// Visits a hex literal. This is a leaf node.
+    fn visit_hex_literal(&mut self, _hex_literal: &HexLiteral) {}
+
+    /// Visits a Yul block.
+    fn visit_yul_block(&mut self, yul_block: &YulBlock) {
+        walk_yul_block(self, yul_block);
+    }
+
+    /// Visits a Yul statement.
+    fn visit_yul_statement(&mut self, yul_statement: &YulStatement) {
+        walk_yul_statement(self, yul_statement);
+    }
+
+    /// Visits a Yul for loop.
+    fn visit_yul_for(&mut self, yul_for: &YulFor) {
+        walk_yul_for(self, yul_for);
+    }
+
+    /// Visits a Yul switch statement.
+    fn visit_yul_switch(&mut self, yul_switch: &YulSwitch) {
+        walk_yul_switch(self, yul_switch);
+    }
+
+    /// Visits a Yul switch case or default.
+    fn visit_yul_switch_options(&mut self, yul_switch_options: &YulSwitchOptions) {
+        walk_yul_switch_options(self, yul_switch_options);
+    }
+
+    /// Visits a Yul expression.
+    fn visit_yul_expression(&mut self, yul_expression: &YulExpression) {
+        walk_yul_expression(self, yul_expression);
+    }
+
+    /// Visits a Yul typed identifier.
+    fn visit_yul_typed_identifier(&mut self, yul_typed_identifier: &YulTypedIdentifier) {
+        walk_yul_typed_identifier(self, yul_typed_identifier);
+    }
+
+    /// Visits a Yul function definition.
+    fn visit_yul_function_definition(&mut self, yul_function_definition: &YulFunctionDefinition) {
+        walk_yul_function_definition(self, yul_function_definition);
+    }
+
+    /// Visits a Yul function call.
+    fn visit_yul_function_call(&mut self, yul_function_call: &YulFunctionCall) {
+        walk_yul_function_call(self, yul_function_call);
+    }
+
+    /// Visits a comment, which may be a doc comment. This is a leaf node.
+    fn visit_comment(&mut self, _comment: &Comment) {}
+}
+
+/// Visits the parse tree and then the comments, as returned by [`crate::parse`].
+pub fn walk_parse_tree<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    source_unit: &SourceUnit,
+    comments: &[Comment],
+) {
+    visitor.visit_source_unit(source_unit);
+
+    for comment in comments {
+        visitor.visit_comment(comment);
+    }
+}
+
+/// Walks the children of a source unit.
+pub fn walk_source_unit<V: Visitor + ?Sized>(visitor: &mut V, source_unit: &SourceUnit) {
+    for part in &source_unit.0 {
+        visitor.visit_source_unit_part(part);
+    }
+}
+
+/// Walks the children of a source unit part.
+pub fn walk_source_unit_part<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    source_unit_part: &SourceUnitPart,
+) {
+    match source_unit_part {
+        SourceUnitPart::PragmaDirective(pragma) => visitor.visit_pragma_directive(pragma),
+        SourceUnitPart::ImportDirective(import) => visitor.visit_import(import),
+        SourceUnitPart::ContractDefinition(contract) => visitor.visit_contract_definition(contract),
+        SourceUnitPart::EnumDefinition(def) => visitor.visit_enum_definition(def),
+        SourceUnitPart::StructDefinition(def) => visitor.visit_struct_definition(def),
+        SourceUnitPart::EventDefinition(def) => visitor.visit_event_definition(def),
+        SourceUnitPart::ErrorDefinition(def) => visitor.visit_error_definition(def),
+        SourceUnitPart::FunctionDefinition(def) => visitor.visit_function_definition(def),
+        SourceUnitPart::VariableDefinition(def) => visitor.visit_variable_definition(def),
+        SourceUnitPart::TypeDefinition(def) => visitor.visit_type_definition(def),
+        SourceUnitPart::Annotation(annotation) => visitor.visit_annotation(annotation),
+        SourceUnitPart::Using(using) => visitor.visit_using(using),
+        SourceUnitPart::StraySemicolon(_) => (),
+    }
+}
+
+/// Walks the children of an import directive.
+pub fn walk_import<V: Visitor + ?Sized>(visitor: &mut V, import: &Import) {
+    match import {
+        Import::Plain(path, _) => visitor.visit_import_path(path),
+        Import::GlobalSymbol(path, symbol, _) => {
+            visitor.visit_import_path(path);
+            visitor.visit_identifier(symbol);
+        }
+        Import::Rename(path, renames, _) => {
+            visitor.visit_import_path(path);
+
+            for (symbol, alias) in renames {
+                visitor.visit_identifier(symbol);
+
+                if let Some(alias) = alias {
+                    visitor.visit_identifier(alias);
+                }
+            }
+        }
+    }
+}
+
+/// Walks the children of an import path.
+pub fn walk_import_path<V: Visitor + ?Sized>(visitor: &mut V, import_path: &ImportPath) {
+    match import_path {
+        ImportPath::Filename(filename) => visitor.visit_string_literal(filename),
+        ImportPath::Path(path) => visitor.visit_identifier_path(path),
+    }
+}
+
+/// Walks the children of a pragma directive.
+pub fn walk_pragma_directive<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    pragma_directive: &PragmaDirective,
+) {
+    match pragma_directive {
+        PragmaDirective::Identifier(_, name, value) => {
+            if let Some(name) = name {
+                visitor.visit_identifier(name);
+            }
+
+            if let Some(value) = value {
+                visitor.visit_identifier(value);
+            }
+        }
+        PragmaDirective::StringLiteral(_, name, value) => {
+            visitor.visit_identifier(name);
+            visitor.visit_string_literal(value);
+        }
+        PragmaDirective::Version(_, name, comparators) => {
+            visitor.visit_identifier(name);
+
+            for comparator in comparators {
+                visitor.visit_version_comparator(comparator);
+            }
+        }
+    }
+}
+
+/// Walks the children of a version comparator.
+pub fn walk_version_comparator<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    version_comparator: &VersionComparator,
+) {
+    match version_comparator {
+        VersionComparator::Or { left, right, .. } => {
+            visitor.visit_version_comparator(left);
+            visitor.visit_version_comparator(right);
+        }
+        VersionComparator::Plain { .. }
+        | VersionComparator::Operator { .. }
+        | VersionComparator::Range { .. } => (),
+    }
+}
+
+/// Walks the children of a contract definition.
+pub fn walk_contract_definition<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    contract_definition: &ContractDefinition,
+) {
+    if let Some(name) = &contract_definition.name {
+        visitor.visit_identifier(name);
+    }
+
+    for base in &contract_definition.base {
+        visitor.visit_base(base);
+    }
+
+    if let Some(layout) = &contract_definition.layout {
+        visitor.visit_expression(layout);
+    }
+
+    for part in &contract_definition.parts {
+        visitor.visit_contract_part(part);
+    }
+}
+
+/// Walks the children of a base contract or modifier invocation.
+pub fn walk_base<V: Visitor + ?Sized>(visitor: &mut V, base: &Base) {
+    visitor.visit_identifier_path(&base.name);
+
+    if let Some(args) = &base.args {
+        for arg in args {
+            visitor.visit_expression(arg);
+        }
+    }
+}
+
+/// Walks the children of a contract part.
+pub fn walk_contract_part<V: Visitor + ?Sized>(visitor: &mut V, contract_part: &ContractPart) {
+    match contract_part {
+        ContractPart::StructDefinition(def) => visitor.visit_struct_definition(def),
+        ContractPart::EventDefinition(def) => visitor.visit_event_definition(def),
+        ContractPart::EnumDefinition(def) => visitor.visit_enum_definition(def),
+        ContractPart::ErrorDefinition(def) => visitor.visit_error_definition(def),
+        ContractPart::VariableDefinition(def) => visitor.visit_variable_definition(def),
+        ContractPart::FunctionDefinition(def) => visitor.visit_function_definition(def),
+        ContractPart::TypeDefinition(def) => visitor.visit_type_definition(def),
+        ContractPart::Annotation(annotation) => visitor.visit_annotation(annotation),
+        ContractPart::Using(using) => visitor.visit_using(using),
+        ContractPart::StraySemicolon(_) => (),
+    }
+}
+
+/// Walks the children of a struct definition.
+pub fn walk_struct_definition<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    struct_definition: &StructDefinition,
+) {
+    if let Some(name) = &struct_definition.name {
+        visitor.visit_identifier(name);
+    }
+
+    for field in &struct_definition.fields {
+        visitor.visit_variable_declaration(field);
+    }
+}
+
+/// Walks the children of an event definition.
+pub fn walk_event_definition<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    event_definition: &EventDefinition,
+) {
+    if let Some(name) = &event_definition.name {
+        visitor.visit_identifier(name);
+    }
+
+    for field in &event_definition.fields {
+        visitor.visit_event_parameter(field);
+    }
+}
+
+/// Walks the children of an event parameter.
+pub fn walk_event_parameter<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    event_parameter: &EventParameter,
+) {
+    visitor.visit_expression(&event_parameter.ty);
+
+    if let Some(name) = &event_parameter.name {
+        visitor.visit_identifier(name);
+    }
+}
+
+/// Walks the children of an error definition.
+pub fn walk_error_definition<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    error_definition: &ErrorDefinition,
+) {
+    visitor.visit_expression(&error_definition.keyword);
+
+    if let Some(name) = &error_definition.name {
+        visitor.visit_identifier(name);
+    }
+
+    for field in &error_definition.fields {
+        visitor.visit_error_parameter(field);
+    }
+}
+
+/// Walks the children of an error parameter.
+pub fn walk_error_parameter<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    error_parameter: &ErrorParameter,
+) {
+    visitor.visit_expression(&error_parameter.ty);
+
+    if let Some(name) = &error_parameter.name {
+        visitor.visit_identifier(name);
+    }
+}
+
+/// Walks the children of an enum definition.
+pub fn walk_enum_definition<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    enum_definition: &EnumDefinition,
+) {
+    if let Some(name) = &enum_definition.name {
+        visitor.visit_identifier(name);
+    }
+
+    for value in enum_definition.values.iter().flatten() {
+        visitor.visit_identifier(value);
+    }
+}
+
+/// Walks the children of a state or constant variable definition.
+pub fn walk_variable_definition<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    variable_definition: &VariableDefinition,
+) {
+    visitor.visit_expression(&variable_definition.ty);
+
+    for attr in &variable_definition.attrs {
+        visitor.visit_variable_attribute(attr);
+    }
+
+    if let Some(name) = &variable_definition.name {
+        visitor.visit_identifier(name);
+    }
+
+    if let Some(initializer) = &variable_definition.initializer {
+        visitor.visit_expression(initializer);
+    }
+}
+
+/// Walks the children of a variable attribute.
+pub fn walk_variable_attribute<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    variable_attribute: &VariableAttribute,
+) {
+    match variable_attribute {
+        VariableAttribute::Visibility(visibility) => visitor.visit_visibility(visibility),
+        VariableAttribute::Override(_, paths) => {
+            for path in paths {
+                visitor.visit_identifier_path(path);
+            }
+        }
+        VariableAttribute::Constant(_)
+        | VariableAttribute::Immutable(_)
+        | VariableAttribute::Transient(_) => (),
+    }
+}
+
+/// Walks the children of a user defined value type definition.
+pub fn walk_type_definition<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    type_definition: &TypeDefinition,
+) {
+    visitor.visit_identifier(&type_definition.name);
+    visitor.visit_expression(&type_definition.ty);
+}
+
+/// Walks the children of an annotation.
+pub fn walk_annotation<V: Visitor + ?Sized>(visitor: &mut V, annotation: &Annotation) {
+    visitor.visit_identifier(&annotation.id);
+
+    if let Some(value) = &annotation.value {
+        visitor.visit_expression(value);
+    }
+}
+
+/// Walks the children of a using directive.
+pub fn walk_using<V: Visitor + ?Sized>(visitor: &mut V, using: &Using) {
+    visitor.visit_using_list(&using.list);
+
+    if let Some(ty) = &using.ty {
+        visitor.visit_expression(ty);
+    }
+
+    if let Some(global) = &using.global {
+        visitor.visit_identifier(global);
+    }
+}
+
+/// Walks the children of a using list.
+pub fn walk_using_list<V: Visitor + ?Sized>(visitor: &mut V, using_list: &UsingList) {
+    match using_list {
+        UsingList::Library(path) => visitor.visit_identifier_path(path),
+        UsingList::Functions(functions) => {
+            for function in functions {
+                visitor.visit_using_function(function);
+            }
+        }
+        UsingList::Error => (),
+    }
+}
+
+/// Walks the children of a function in a using list.
+pub fn walk_using_function<V: Visitor + ?Sized>(visitor: &mut V, using_function: &UsingFunction) {
+    visitor.visit_identifier_path(&using_function.path);
+}
+
+/// Walks the children of a function definition.
+pub fn walk_function_definition<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    function_definition: &FunctionDefinition,
+) {
+    if let Some(name) = &function_definition.name {
+        visitor.visit_identifier(name);
+    }
+
+    visitor.visit_parameter_list(&function_definition.params);
+
+    for attr in &function_definition.attributes {
+        visitor.visit_function_attribute(attr);
+    }
+
+    visitor.visit_parameter_list(&function_definition.returns);
+
+    if let Some(body) = &function_definition.body {
+        visitor.visit_statement(body);
+    }
+}
+
+/// Walks the children of a function attribute.
+pub fn walk_function_attribute<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    function_attribute: &FunctionAttribute,
+) {
+    match function_attribute {
+        FunctionAttribute::Visibility(visibility) => visitor.visit_visibility(visibility),
+        FunctionAttribute::Mutability(mutability) => visitor.visit_mutability(mutability),
+        FunctionAttribute::Override(_, paths) => {
+            for path in paths {
+                visitor.visit_identifier_path(path);
+            }
+        }
+        FunctionAttribute::BaseOrModifier(_, base) => visitor.visit_base(base),
+        FunctionAttribute::Virtual(_)
+        | FunctionAttribute::Immutable(_)
+        | FunctionAttribute::Error(_) => (),
+    }
+}
+
+/// Walks the children of a parameter list.
+pub fn walk_parameter_list<V: Visitor + ?Sized>(visitor: &mut V, parameter_list: &ParameterList) {
+    for (_, param) in parameter_list {
+        if let Some(param) = param {
+            visitor.visit_parameter(param);
+        }
+    }
+}
+
+/// Walks the children of a parameter.
+pub fn walk_parameter<V: Visitor + ?Sized>(visitor: &mut V, parameter: &Parameter) {
+    if let Some(annotation) = &parameter.annotation {
+        visitor.visit_annotation(annotation);
+    }
+
+    visitor.visit_expression(&parameter.ty);
+
+    if let Some(storage) = &parameter.storage {
+        visitor.visit_storage_location(storage);
+    }
+
+    if let Some(name) = &parameter.name {
+        visitor.visit_identifier(name);
+    }
+}
+
+/// Walks the children of a variable declaration.
+pub fn walk_variable_declaration<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    variable_declaration: &VariableDeclaration,
+) {
+    visitor.visit_expression(&variable_declaration.ty);
+
+    if let Some(storage) = &variable_declaration.storage {
+        visitor.visit_storage_location(storage);
+    }
+
+    if let Some(name) = &variable_declaration.name {
+        visitor.visit_identifier(name);
+    }
+}
+
+/// Walks the children of a statement.
+pub fn walk_statement<V: Visitor + ?Sized>(visitor: &mut V, statement: &Statement) {
+    match statement {
+        Statement::Block { statements, .. } => {
+            for statement in statements {
+                visitor.visit_statement(statement);
+            }
+        }
+        Statement::Assembly {
+            dialect,
+            flags,
+            block,
+            ..
+        } => {
+            if let Some(dialect) = dialect {
+                visitor.visit_string_literal(dialect);
+            }
+
+            if let Some(flags) = flags {
+                for flag in flags {
+                    visitor.visit_string_literal(flag);
+                }
+            }
+
+            visitor.visit_yul_block(block);
+        }
+        Statement::Args(_, args) => {
+            for arg in args {
+                visitor.visit_named_argument(arg);
+            }
+        }
+        Statement::If(_, cond, then, otherwise) => {
+            visitor.visit_expression(cond);
+            visitor.visit_statement(then);
+
+            if let Some(otherwise) = otherwise {
+                visitor.visit_statement(otherwise);
+            }
+        }
+        Statement::While(_, cond, body) => {
+            visitor.visit_expression(cond);
+            visitor.visit_statement(body);
+        }
+        Statement::Expression(_, expr) => visitor.visit_expression(expr),
+        Statement::VariableDefinition(_, decl, initializer) => {
+            visitor.visit_variable_declaration(decl);
+
+            if let Some(initializer) = initializer {
+                visitor.visit_expression(initializer);
+            }
+        }
+        Statement::For(_, init, cond, next, body) => {
+            if let Some(init) = init {
+                visitor.visit_statement(init);
+            }
+
+            if let Some(cond) = cond {
+                visitor.visit_expression(cond);
+            }
+
+            if let Some(next) = next {
+                visitor.visit_expression(next);
+            }
+
+            if let Some(body) = body {
+                visitor.visit_statement(body);
+            }
+        }
+        Statement::DoWhile(_, body, cond) => {
+            visitor.visit_statement(body);
+            visitor.visit_expression(cond);
+        }
+        Statement::Return(_, expr) => {
+            if let Some(expr) = expr {
+                visitor.visit_expression(expr);
+            }
+        }
+        Statement::Revert(_, path, args) => {
+            if let Some(path) = path {
+                visitor.visit_identifier_path(path);
+            }
+
+            for arg in args {
+                visitor.visit_expression(arg);
+            }
+        }
+        Statement::RevertNamedArgs(_, path, args) => {
+            if let Some(path) = path {
+                visitor.visit_identifier_path(path);
+            }
+
+            for arg in args {
+                visitor.visit_named_argument(arg);
+            }
+        }
+        Statement::Emit(_, expr) => visitor.visit_expression(expr),
+        Statement::Try(_, expr, returns, clauses) => {
+            visitor.visit_expression(expr);
+
+            if let Some((params, body)) = returns {
+                visitor.visit_parameter_list(params);
+                visitor.visit_statement(body);
+            }
+
+            for clause in clauses {
+                visitor.visit_catch_clause(clause);
+            }
+        }
+        Statement::Continue(_) | Statement::Break(_) | Statement::Error(_) => (),
+    }
+}
+
+/// Walks the children of a catch clause.
+pub fn walk_catch_clause<V: Visitor + ?Sized>(visitor: &mut V, catch_clause: &CatchClause) {
+    match catch_clause {
+        CatchClause::Simple(_, param, body) => {
+            if let Some(param) = param {
+                visitor.visit_parameter(param);
+            }
+
+            visitor.visit_statement(body);
+        }
+        CatchClause::Named(_, name, param, body) => {
+            visitor.visit_identifier(name);
+            visitor.visit_parameter(param);
+            visitor.visit_statement(body);
+        }
+    }
+}
+
+/// Walks the children of an expression.
+pub fn walk_expression<V: Visitor + ?Sized>(visitor: &mut V, expression: &Expression) {
+    match expression {
+        Expression::PostIncrement(_, expr)
+        | Expression::PostDecrement(_, expr)
+        | Expression::New(_, expr)
+        | Expression::Parenthesis(_, expr)
+        | Expression::Not(_, expr)
+        | Expression::BitwiseNot(_, expr)
+        | Expression::Delete(_, expr)
+        | Expression::PreIncrement(_, expr)
+        | Expression::PreDecrement(_, expr)
+        | Expression::UnaryPlus(_, expr)
+        | Expression::Negate(_, expr) => visitor.visit_expression(expr),
+        Expression::ArraySubscript(_, array, index) => {
+            visitor.visit_expression(array);
+
+            if let Some(index) = index {
+                visitor.visit_expression(index);
+            }
+        }
+        Expression::ArraySlice(_, array, from, to) => {
+            visitor.visit_expression(array);
+
+            if let Some(from) = from {
+                visitor.visit_expression(from);
+            }
+
+            if let Some(to) = to {
+                visitor.visit_expression(to);
+            }
+        }
+        Expression::MemberAccess(_, expr, member) => {
+            visitor.visit_expression(expr);
+            visitor.visit_identifier(member);
+        }
+        Expression::FunctionCall(_, function, args) => {
+            visitor.visit_expression(function);
+
+            for arg in args {
+                visitor.visit_expression(arg);
+            }
+        }
+        Expression::FunctionCallBlock(_, function, block) => {
+            visitor.visit_expression(function);
+            visitor.visit_statement(block);
+        }
+        Expression::NamedFunctionCall(_, function, args) => {
+            visitor.visit_expression(function);
+
+            for arg in args {
+                visitor.visit_named_argument(arg);
+            }
+        }
+        Expression::Power(_, left, right)
+        | Expression::Multiply(_, left, right)
+        | Expression::Divide(_, left, right)
+        | Expression::Modulo(_, left, right)
+        | Expression::Add(_, left, right)
+        | Expression::Subtract(_, left, right)
+        | Expression::ShiftLeft(_, left, right)
+        | Expression::ShiftRight(_, left, right)
+        | Expression::BitwiseAnd(_, left, right)
+        | Expression::BitwiseXor(_, left, right)
+        | Expression::BitwiseOr(_, left, right)
+        | Expression::Less(_, left, right)
+        | Expression::More(_, left, right)
+        | Expression::LessEqual(_, left, right)
+        | Expression::MoreEqual(_, left, right)
+        | Expression::Equal(_, left, right)
+        | Expression::NotEqual(_, left, right)
+        | Expression::And(_, left, right)
+        | Expression::Or(_, left, right)
+        | Expression::Assign(_, left, right)
+        | Expression::AssignOr(_, left, right)
+        | Expression::AssignAnd(_, left, right)
+        | Expression::AssignXor(_, left, right)
+        | Expression::AssignShiftLeft(_, left, right)
+        | Expression::AssignShiftRight(_, left, right)
+        | Expression::AssignAdd(_, left, right)
+        | Expression::AssignSubtract(_, left, right)
+        | Expression::AssignMultiply(_, left, right)
+        | Expression::AssignDivide(_, left, right)
+        | Expression::AssignModulo(_, left, right) => {
+            visitor.visit_expression(left);
+            visitor.visit_expression(right);
+        }
+        Expression::ConditionalOperator(_, cond, left, right) => {
+            visitor.visit_expression(cond);
+            visitor.visit_expression(left);
+            visitor.visit_expression(right);
+        }
+        Expression::NumberLiteral(_, _, _, unit)
+        | Expression::RationalNumberLiteral(_, _, _, _, unit)
+        | Expression::HexNumberLiteral(_, _, unit) => {
+            if let Some(unit) = unit {
+                visitor.visit_identifier(unit);
+            }
+        }
+        Expression::StringLiteral(literals) => {
+            for literal in literals {
+                visitor.visit_string_literal(literal);
+            }
+        }
+        Expression::HexLiteral(literals) => {
+            for literal in literals {
+                visitor.visit_hex_literal(literal);
+            }
+        }
+        Expression::Type(_, ty) => visitor.visit_type(ty),
+        Expression::Variable(id) => visitor.visit_identifier(id),
+        Expression::List(_, params) => visitor.visit_parameter_list(params),
+        Expression::ArrayLiteral(_, exprs) => {
+            for expr in exprs {
+                visitor.visit_expression(expr);
+            }
+        }
+        Expression::BoolLiteral(..) | Expression::AddressLiteral(..) => (),
+    }
+}
+
+/// Walks the children of a type.
+pub fn walk_type<V: Visitor + ?Sized>(visitor: &mut V, ty: &Type) {
+    match ty {
+        Type::Mapping {
+            key,
+            key_name,
+            value,
+            value_name,
+            ..
+        } => {
+            visitor.visit_expression(key);
+
+            if let Some(key_name) = key_name {
+                visitor.visit_identifier(key_name);
+            }
+
+            visitor.visit_expression(value);
+
+            if let Some(value_name) = value_name {
+                visitor.visit_identifier(value_name);
+            }
+        }
+        Type::Function {
+            params,
+            attributes,
+            returns,
+        } => {
+            visitor.visit_parameter_list(params);
+
+            for attr in attributes {
+                visitor.visit_function_attribute(attr);
+            }
+
+            if let Some((params, attributes)) = returns {
+                visitor.visit_parameter_list(params);
+
+                for attr in attributes {
+                    visitor.visit_function_attribute(attr);
+                }
+            }
+        }
+        Type::Address
+        | Type::AddressPayable
+        | Type::Payable
+        | Type::Bool
+        | Type::String
+        | Type::Int(_)
+        | Type::Uint(_)
+        | Type::Bytes(_)
+        | Type::Rational
+        | Type::DynamicBytes => (),
+    }
+}
+
+/// Walks the children of a named argument.
+pub fn walk_named_argument<V: Visitor + ?Sized>(visitor: &mut V, named_argument: &NamedArgument) {
+    visitor.visit_identifier(&named_argument.name);
+    visitor.visit_expression(&named_argument.expr);
+}
+
+/// Walks the children of an identifier path.
+pub fn walk_identifier_path<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    identifier_path: &IdentifierPath,
+) {
+    for identifier in &identifier_path.identifiers {
+        visitor.visit_identifier(identifier);
+    }
+}
+
+/// Walks the children of a Yul block.
+pub fn walk_yul_block<V: Visitor + ?Sized>(visitor: &mut V, yul_block: &YulBlock) {
+    for statement in &yul_block.statements {
+        visitor.visit_yul_statement(statement);
+    }
+}
+
+/// Walks the children of a Yul statement.
+pub fn walk_yul_statement<V: Visitor + ?Sized>(visitor: &mut V, yul_statement: &YulStatement) {
+    match yul_statement {
+        YulStatement::Assign(_, left, right) => {
+            for expr in left {
+                visitor.visit_yul_expression(expr);
+            }
+
+            visitor.visit_yul_expression(right);
+        }
+        YulStatement::VariableDeclaration(_, ids, initializer) => {
+            for id in ids {
+                visitor.visit_yul_typed_identifier(id);
+            }
+
+            if let Some(initializer) = initializer {
+                visitor.visit_yul_expression(initializer);
+            }
+        }
+        YulStatement::If(_, cond, block) => {
+            visitor.visit_yul_expression(cond);
+            visitor.visit_yul_block(block);
+        }
+        YulStatement::For(yul_for) => visitor.visit_yul_for(yul_for),
+        YulStatement::Switch(switch) => visitor.visit_yul_switch(switch),
+        YulStatement::Block(block) => visitor.visit_yul_block(block),
+        YulStatement::FunctionDefinition(def) => visitor.visit_yul_function_definition(def),
+        YulStatement::FunctionCall(call) => visitor.visit_yul_function_call(call),
+        YulStatement::Leave(_)
+        | YulStatement::Break(_)
+        | YulStatement::Continue(_)
+        | YulStatement::Error(_) => (),
+    }
+}
+
+/// Walks the children of a Yul for loop.
+pub fn walk_yul_for<V: Visitor + ?Sized>(visitor: &mut V, yul_for: &YulFor) {
+    visitor.visit_yul_block(&yul_for.init_block);
+    visitor.visit_yul_expression(&yul_for.condition);
+    visitor.visit_yul_block(&yul_for.post_block);
+    visitor.visit_yul_block(&yul_for.execution_block);
+}
+
+/// Walks the children of a Yul switch statement.
+pub fn walk_yul_switch<V: Visitor + ?Sized>(visitor: &mut V, yul_switch: &YulSwitch) {
+    visitor.visit_yul_expression(&yul_switch.condition);
+
+    for case in &yul_switch.cases {
+        visitor.visit_yul_switch_options(case);
+    }
+
+    if let Some(default) = &yul_switch.default {
+        visitor.visit_yul_switch_options(default);
+    }
+}
+
+/// Walks the children of a Yul switch case or default.
+pub fn walk_yul_switch_options<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    yul_switch_options: &YulSwitchOptions,
+) {
+    match yul_switch_options {
+        YulSwitchOptions::Case(_, expr, block) => {
+            visitor.visit_yul_expression(expr);
+            visitor.visit_yul_block(block);
+        }
+        YulSwitchOptions::Default(_, block) => visitor.visit_yul_block(block),
+    }
+}
+
+/// Walks the children of a Yul expression.
+pub fn walk_yul_expression<V: Visitor + ?Sized>(visitor: &mut V, yul_expression: &YulExpression) {
+    match yul_expression {
+        YulExpression::BoolLiteral(_, _, ty)
+        | YulExpression::NumberLiteral(_, _, _, ty)
+        | YulExpression::HexNumberLiteral(_, _, ty) => {
+            if let Some(ty) = ty {
+                visitor.visit_identifier(ty);
+            }
+        }
+        YulExpression::HexStringLiteral(literal, ty) => {
+            visitor.visit_hex_literal(literal);
+
+            if let Some(ty) = ty {
+                visitor.visit_identifier(ty);
+            }
+        }
+        YulExpression::StringLiteral(literal, ty) => {
+            visitor.visit_string_literal(literal);
+
+            if let Some(ty) = ty {
+                visitor.visit_identifier(ty);
+            }
+        }
+        YulExpression::Variable(id) => visitor.visit_identifier(id),
+        YulExpression::FunctionCall(call) => visitor.visit_yul_function_call(call),
+        YulExpression::SuffixAccess(_, expr, member) => {
+            visitor.visit_yul_expression(expr);
+            visitor.visit_identifier(member);
+        }
+    }
+}
+
+/// Walks the children of a Yul typed identifier.
+pub fn walk_yul_typed_identifier<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    yul_typed_identifier: &YulTypedIdentifier,
+) {
+    visitor.visit_identifier(&yul_typed_identifier.id);
+
+    if let Some(ty) = &yul_typed_identifier.ty {
+        visitor.visit_identifier(ty);
+    }
+}
+
+/// Walks the children of a Yul function definition.
+pub fn walk_yul_function_definition<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    yul_function_definition: &YulFunctionDefinition,
+) {
+    visitor.visit_identifier(&yul_function_definition.id);
+
+    for param in &yul_function_definition.params {
+        visitor.visit_yul_typed_identifier(param);
+    }
+
+    for ret in &yul_function_definition.returns {
+        visitor.visit_yul_typed_identifier(ret);
+    }
+
+    visitor.visit_yul_block(&yul_function_definition.body);
+}
+
+/// Walks the children of a Yul function call.
+pub fn walk_yul_function_call<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    yul_function_call: &YulFunctionCall,
+) {
+    visitor.visit_identifier(&yul_function_call.id);
+
+    for arg in &yul_function_call.arguments {
+        visitor.visit_yul_expression(arg);
+    }
+}
@@ -10,6 +10,7 @@ use crate::pt::Loc;
 use diagnostics::Diagnostic;
 use lalrpop_util::ParseError;
 
+pub mod cst;
 pub mod diagnostics;
 pub mod doccomment;
 pub mod helpers;
@@ -64,6 +65,14 @@ pub fn parse(
     }
 }
 
+/// Parses a Solidity file into a lossless [`cst::SyntaxTree`], which has the parse tree together
+/// with every token and all whitespace and comments of the source.
+pub fn parse_cst(src: &str, file_no: usize) -> Result<cst::SyntaxTree<'_>, Vec<Diagnostic>> {
+    let (source_unit, _) = parse(src, file_no)?;
+
+    Ok(cst::build(src, file_no, source_unit))
+}
+
 /// Convert lalrop parser error to a Diagnostic
 fn parser_error_to_diagnostic(
     error: &ParseError<usize, Token, LexicalError>,
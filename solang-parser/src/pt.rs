@@ -849,6 +849,8 @@ pub struct ContractDefinition {
     pub name: Option<Identifier>,
     /// The list of inheritance specifiers.
     pub base: Vec<Base>,
+    /// The base slot of the storage layout, `layout at <expr>`.
+    pub layout: Option<Expression>,
     /// The list of contract parts.
     pub parts: Vec<ContractPart>,
 }
@@ -953,6 +955,9 @@ pub enum VariableAttribute {
     /// `immutable`
     Immutable(Loc),
 
+    /// `transient`
+    Transient(Loc),
+
     /// `ovveride(<1>,*)`
     Override(Loc, Vec<IdentifierPath>),
 }
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::diagnostics::{Diagnostic, ErrorType::ParserError, Level::Error};
+use crate::diagnostics::{
+    Code, Diagnostic, ErrorType, ErrorType::ParserError, Level, Level::Error,
+};
 use crate::lexer::Lexer;
 use crate::pt::*;
 use crate::solidity;
@@ -43,19 +45,19 @@ contract 9c {
         assert_eq!(
             errors,
             vec![
-                Diagnostic { loc: File(0, 17, 21), level: Error, ty: ParserError, message: "'frum' found where 'from' expected".to_string(), notes: vec![]},
-                Diagnostic { loc: File(0, 48, 49), level: Error, ty: ParserError, message: "unrecognised token ';', expected \"*\", \"<\", \"<=\", \"=\", \">\", \">=\", \"^\", \"~\", identifier, number, string".to_string(), notes: vec![] },
-                Diagnostic { loc: File(0, 62, 65), level: Error, ty: ParserError, message: r#"unrecognised token 'for', expected "(", ";", "=""#.to_string(), notes: vec![] },
-                Diagnostic { loc: File(0, 78, 79), level: Error, ty: ParserError, message: r#"unrecognised token '9', expected "case", "default", "leave", "revert", "switch", identifier"#.to_string(), notes: vec![] },
-                Diagnostic { loc: File(0, 95, 96), level: Error, ty: ParserError, message: "unrecognised token '0', expected \"(\", \"++\", \"--\", \".\", \"[\", \"case\", \"constant\", \"default\", \"external\", \"immutable\", \"internal\", \"leave\", \"override\", \"private\", \"public\", \"revert\", \"switch\", \"{\", identifier".to_string(), notes: vec![] },
-                Diagnostic { loc: File(0, 116, 123), level: Error, ty: ParserError, message: "unrecognised token 'uint256', expected \"++\", \"--\", \".\", \"[\", \"case\", \"default\", \"leave\", \"switch\", identifier".to_string(), notes: vec![] },
-                Diagnostic { loc: File(0, 403, 404), level: Error, ty: ParserError, message: "unrecognised token '3', expected \"(\", \"++\", \"--\", \".\", \"[\", \"case\", \"constant\", \"default\", \"external\", \"immutable\", \"internal\", \"leave\", \"override\", \"private\", \"public\", \"revert\", \"switch\", \"{\", identifier".to_string(), notes: vec![] },
-                Diagnostic { loc: File(0, 441, 442), level: Error, ty: ParserError, message: r#"unrecognised token '4', expected "(", "case", "default", "leave", "revert", "switch", identifier"#.to_string(), notes: vec![] },
-                Diagnostic { loc: File(0, 460, 461), level: Error, ty: ParserError, message: "unrecognised token '!', expected \";\", \"case\", \"constant\", \"default\", \"external\", \"immutable\", \"internal\", \"leave\", \"override\", \"payable\", \"private\", \"public\", \"pure\", \"return\", \"returns\", \"revert\", \"switch\", \"view\", \"virtual\", \"{\", identifier".to_string(), notes: vec![] },
-                Diagnostic { loc: File(0, 482, 483), level: Error, ty: ParserError, message: "unrecognised token '3', expected \"!=\", \"%\", \"%=\", \"&\", \"&&\", \"&=\", \"(\", \"*\", \"**\", \"*=\", \"+\", \"++\", \"+=\", \"-\", \"--\", \"-=\", \".\", \"/\", \"/=\", \";\", \"<\", \"<<\", \"<<=\", \"<=\", \"=\", \"==\", \">\", \">=\", \">>\", \">>=\", \"?\", \"[\", \"^\", \"^=\", \"calldata\", \"case\", \"default\", \"leave\", \"memory\", \"revert\", \"storage\", \"switch\", \"{\", \"|\", \"|=\", \"||\", identifier".to_string(), notes: vec![] },
-                Diagnostic { loc: File(0, 518, 522), level: Error, ty: ParserError, message: "unrecognised token 'uint256', expected \"!=\", \"%\", \"%=\", \"&\", \"&&\", \"&=\", \"*\", \"**\", \"*=\", \"+\", \"++\", \"+=\", \"-\", \"--\", \"-=\", \".\", \"/\", \"/=\", \";\", \"<\", \"<<\", \"<<=\", \"<=\", \"=\", \"==\", \">\", \">=\", \">>\", \">>=\", \"?\", \"[\", \"^\", \"^=\", \"case\", \"default\", \"leave\", \"switch\", \"|\", \"|=\", \"||\", identifier".to_string(), notes: vec![] },
-                Diagnostic { loc: File(0, 555, 556), level: Error, ty: ParserError, message: "unrecognised token '}', expected \"!\", \"(\", \"+\", \"++\", \"-\", \"--\", \"[\", \"address\", \"assembly\", \"bool\", \"break\", \"byte\", \"bytes\", \"case\", \"continue\", \"default\", \"delete\", \"do\", \"emit\", \"false\", \"for\", \"function\", \"if\", \"leave\", \"mapping\", \"new\", \"payable\", \"return\", \"revert\", \"string\", \"switch\", \"true\", \"try\", \"type\", \"unchecked\", \"while\", \"{\", \"~\", Bytes, Int, Uint, address, hexnumber, hexstring, identifier, number, rational, string".to_string(), notes: vec![] },
-                Diagnostic { loc: File(0, 557, 558), level: Error, ty: ParserError, message: "unrecognised token '}', expected \"(\", \";\", \"[\", \"abstract\", \"address\", \"bool\", \"byte\", \"bytes\", \"case\", \"contract\", \"default\", \"enum\", \"event\", \"false\", \"function\", \"import\", \"interface\", \"leave\", \"library\", \"mapping\", \"payable\", \"pragma\", \"string\", \"struct\", \"switch\", \"true\", \"type\", \"using\", Bytes, Int, Uint, address, annotation, hexnumber, hexstring, identifier, number, rational, string".to_string(), notes: vec![] }
+                Diagnostic { loc: File(0, 17, 21), level: Error, ty: ParserError, message: "'frum' found where 'from' expected".to_string(), notes: vec![], code: Code::PARSER_ERROR },
+                Diagnostic { loc: File(0, 48, 49), level: Error, ty: ParserError, message: "unrecognised token ';', expected \"*\", \"<\", \"<=\", \"=\", \">\", \">=\", \"^\", \"~\", identifier, number, string".to_string(), notes: vec![], code: Code::PARSER_ERROR },
+                Diagnostic { loc: File(0, 62, 65), level: Error, ty: ParserError, message: r#"unrecognised token 'for', expected "(", ";", "=""#.to_string(), notes: vec![], code: Code::PARSER_ERROR },
+                Diagnostic { loc: File(0, 78, 79), level: Error, ty: ParserError, message: r#"unrecognised token '9', expected "case", "default", "leave", "revert", "switch", "transient", identifier"#.to_string(), notes: vec![], code: Code::PARSER_ERROR },
+                Diagnostic { loc: File(0, 95, 96), level: Error, ty: ParserError, message: "unrecognised token '0', expected \"(\", \"++\", \"--\", \".\", \"[\", \"case\", \"constant\", \"default\", \"external\", \"immutable\", \"internal\", \"leave\", \"override\", \"private\", \"public\", \"revert\", \"switch\", \"transient\", \"{\", identifier".to_string(), notes: vec![], code: Code::PARSER_ERROR },
+                Diagnostic { loc: File(0, 116, 123), level: Error, ty: ParserError, message: "unrecognised token 'uint256', expected \"++\", \"--\", \".\", \"[\", \"case\", \"default\", \"leave\", \"switch\", \"transient\", identifier".to_string(), notes: vec![], code: Code::PARSER_ERROR },
+                Diagnostic { loc: File(0, 403, 404), level: Error, ty: ParserError, message: "unrecognised token '3', expected \"(\", \"++\", \"--\", \".\", \"[\", \"case\", \"constant\", \"default\", \"external\", \"immutable\", \"internal\", \"leave\", \"override\", \"private\", \"public\", \"revert\", \"switch\", \"transient\", \"{\", identifier".to_string(), notes: vec![], code: Code::PARSER_ERROR },
+                Diagnostic { loc: File(0, 441, 442), level: Error, ty: ParserError, message: r#"unrecognised token '4', expected "(", "case", "default", "leave", "revert", "switch", "transient", identifier"#.to_string(), notes: vec![], code: Code::PARSER_ERROR },
+                Diagnostic { loc: File(0, 460, 461), level: Error, ty: ParserError, message: "unrecognised token '!', expected \";\", \"case\", \"constant\", \"default\", \"external\", \"immutable\", \"internal\", \"leave\", \"override\", \"payable\", \"private\", \"public\", \"pure\", \"return\", \"returns\", \"revert\", \"switch\", \"transient\", \"view\", \"virtual\", \"{\", identifier".to_string(), notes: vec![], code: Code::PARSER_ERROR },
+                Diagnostic { loc: File(0, 482, 483), level: Error, ty: ParserError, message: "unrecognised token '3', expected \"!=\", \"%\", \"%=\", \"&\", \"&&\", \"&=\", \"(\", \"*\", \"**\", \"*=\", \"+\", \"++\", \"+=\", \"-\", \"--\", \"-=\", \".\", \"/\", \"/=\", \";\", \"<\", \"<<\", \"<<=\", \"<=\", \"=\", \"==\", \">\", \">=\", \">>\", \">>=\", \"?\", \"[\", \"^\", \"^=\", \"calldata\", \"case\", \"default\", \"leave\", \"memory\", \"revert\", \"storage\", \"switch\", \"transient\", \"{\", \"|\", \"|=\", \"||\", identifier".to_string(), notes: vec![], code: Code::PARSER_ERROR },
+                Diagnostic { loc: File(0, 518, 522), level: Error, ty: ParserError, message: "unrecognised token 'uint256', expected \"!=\", \"%\", \"%=\", \"&\", \"&&\", \"&=\", \"*\", \"**\", \"*=\", \"+\", \"++\", \"+=\", \"-\", \"--\", \"-=\", \".\", \"/\", \"/=\", \";\", \"<\", \"<<\", \"<<=\", \"<=\", \"=\", \"==\", \">\", \">=\", \">>\", \">>=\", \"?\", \"[\", \"^\", \"^=\", \"case\", \"default\", \"leave\", \"switch\", \"transient\", \"|\", \"|=\", \"||\", identifier".to_string(), notes: vec![], code: Code::PARSER_ERROR },
+                Diagnostic { loc: File(0, 555, 556), level: Error, ty: ParserError, message: "unrecognised token '}', expected \"!\", \"(\", \"+\", \"++\", \"-\", \"--\", \"[\", \"address\", \"assembly\", \"bool\", \"break\", \"byte\", \"bytes\", \"case\", \"continue\", \"default\", \"delete\", \"do\", \"emit\", \"false\", \"for\", \"function\", \"if\", \"leave\", \"mapping\", \"new\", \"payable\", \"return\", \"revert\", \"string\", \"switch\", \"transient\", \"true\", \"try\", \"type\", \"unchecked\", \"while\", \"{\", \"~\", Bytes, Int, Uint, address, hexnumber, hexstring, identifier, number, rational, string".to_string(), notes: vec![], code: Code::PARSER_ERROR },
+                Diagnostic { loc: File(0, 557, 558), level: Error, ty: ParserError, message: "unrecognised token '}', expected \"(\", \";\", \"[\", \"abstract\", \"address\", \"bool\", \"byte\", \"bytes\", \"case\", \"contract\", \"default\", \"enum\", \"event\", \"false\", \"function\", \"import\", \"interface\", \"leave\", \"library\", \"mapping\", \"payable\", \"pragma\", \"string\", \"struct\", \"switch\", \"transient\", \"true\", \"type\", \"using\", Bytes, Int, Uint, address, annotation, hexnumber, hexstring, identifier, number, rational, string".to_string(), notes: vec![], code: Code::PARSER_ERROR }
             ]
         )
     }
@@ -115,6 +117,7 @@ fn parse_test() {
                 name: "foo".to_string(),
             }),
             base: Vec::new(),
+            layout: None,
             parts: vec![
                 ContractPart::StructDefinition(Box::new(StructDefinition {
                     name: Some(Identifier {
@@ -470,6 +473,7 @@ fn parse_error_test() {
                 name: "TestToken".to_string(),
             }),
             base: vec![],
+            layout: None,
             parts: vec![
                 ContractPart::ErrorDefinition(Box::new(ErrorDefinition {
                     loc: Loc::File(0, 102, 120),
@@ -1028,6 +1032,7 @@ fn parse_revert_test() {
                 name: "TestToken".to_string(),
             }),
             base: vec![],
+            layout: None,
             parts: vec![
                 ContractPart::ErrorDefinition(Box::new(ErrorDefinition {
                     loc: Loc::File(0, 42, 59),
@@ -1122,6 +1127,7 @@ fn parse_user_defined_value_type() {
                 name: "TestToken".to_string(),
             }),
             base: vec![],
+            layout: None,
             parts: vec![ContractPart::TypeDefinition(Box::new(TypeDefinition {
                 loc: Loc::File(0, 75, 98),
                 name: Identifier {
@@ -1136,6 +1142,114 @@ fn parse_user_defined_value_type() {
     assert_eq!(actual_parse_tree, expected_parse_tree);
 }
 
+#[test]
+fn parse_storage_layout() {
+    let src = r#"
+        contract A layout at 0x1000 {}
+        contract B is A layout at (SLOT + 1) {}
+        contract C layout at SLOT is A, B {}
+        "#;
+
+    let (actual_parse_tree, _) = crate::parse(src, 0).unwrap();
+
+    let layouts: Vec<_> = actual_parse_tree
+        .0
+        .iter()
+        .map(|part| match part {
+            SourceUnitPart::ContractDefinition(contract) => {
+                (contract.base.len(), contract.layout.clone().unwrap())
+            }
+            _ => unreachable!(),
+        })
+        .collect();
+
+    assert_eq!(
+        layouts[0],
+        (
+            0,
+            Expression::HexNumberLiteral(Loc::File(0, 30, 36), "0x1000".into(), None)
+        )
+    );
+    assert!(matches!(layouts[1], (1, Expression::Parenthesis(..))));
+    assert!(matches!(layouts[2], (2, Expression::Variable(..))));
+
+    let src = "contract D layout on 1 {}";
+
+    let errors = crate::parse(src, 0).unwrap_err();
+
+    assert_eq!(
+        errors,
+        vec![Diagnostic {
+            loc: File(0, 18, 20),
+            level: Error,
+            ty: ParserError,
+            message: "'on' found where 'layout at' expected".to_string(),
+            notes: vec![],
+            code: Code::PARSER_ERROR,
+        }]
+    );
+}
+
+#[test]
+fn parse_transient_identifier() {
+    let src = r#"
+        contract C {
+            uint transient;
+            uint transient transient;
+            uint public transient x;
+            function transient(uint transient) public {
+                uint transient = transient + 1;
+            }
+        }"#;
+
+    let (actual_parse_tree, _) = crate::parse(src, 0).unwrap();
+
+    let SourceUnitPart::ContractDefinition(contract) = &actual_parse_tree.0[0] else {
+        unreachable!();
+    };
+
+    let variables: Vec<_> = contract
+        .parts
+        .iter()
+        .filter_map(|part| match part {
+            ContractPart::VariableDefinition(var) => Some((
+                var.attrs
+                    .iter()
+                    .map(|attr| matches!(attr, VariableAttribute::Transient(_)))
+                    .collect::<Vec<_>>(),
+                var.name.as_ref().unwrap().name.as_str(),
+            )),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(
+        variables,
+        vec![
+            (vec![], "transient"),
+            (vec![true], "transient"),
+            (vec![false, true], "x"),
+        ]
+    );
+
+    let ContractPart::FunctionDefinition(func) = &contract.parts[3] else {
+        unreachable!();
+    };
+
+    assert_eq!(func.name.as_ref().unwrap().name, "transient");
+    assert_eq!(
+        func.params[0]
+            .1
+            .as_ref()
+            .unwrap()
+            .name
+            .as_ref()
+            .unwrap()
+            .name,
+        "transient"
+    );
+}
+
 #[test]
 fn parse_no_parameters_yul_function() {
     let src = r#"
@@ -1294,6 +1408,7 @@ contract MyTest {
                 name: "MyTest".to_string(),
             }),
             base: vec![],
+            layout: None,
             parts: vec![ContractPart::FunctionDefinition(
                 FunctionDefinition {
                     loc_prototype: File(0, 23, 55),
@@ -1349,3 +1464,306 @@ fn loc_union() {
     second.union(&other_first);
     assert_eq!(second, Loc::File(1, 4, 24));
 }
+
+#[test]
+fn diagnostic_code() {
+    assert_eq!(Code(5001).to_string(), "S5001");
+    assert_eq!(Code::INFO.to_string(), "S0000");
+    assert_eq!("S5001".parse(), Ok(Code(5001)));
+    assert_eq!("s1002".parse(), Ok(Code::SYNTAX_ERROR));
+    assert!("5001".parse::<Code>().is_err());
+    assert!("S50011".parse::<Code>().is_err());
+    assert!(Code::CAST_WARNING.is_warning());
+    assert!(!Code::TYPE_ERROR.is_warning());
+
+    let diag = Diagnostic::warning(Loc::Implicit, "foo".into()).with_code(Code(5123));
+    assert_eq!(diag.code, Code(5123));
+    assert_eq!(
+        Diagnostic::decl_error(Loc::Implicit, "foo".into()).code,
+        Code::DECLARATION_ERROR
+    );
+    assert_eq!(ParserError.default_code(), Code::PARSER_ERROR);
+    assert_eq!(
+        Diagnostic::new(
+            Loc::Implicit,
+            Error,
+            ErrorType::CastError,
+            "foo".into(),
+            vec![]
+        )
+        .code,
+        Code::CAST_ERROR
+    );
+    assert_eq!(
+        Diagnostic::new(
+            Loc::Implicit,
+            Level::Info,
+            ErrorType::None,
+            "foo".into(),
+            vec![]
+        )
+        .code,
+        Code::INFO
+    );
+}
+
+#[test]
+fn visitor() {
+    use crate::helpers::{visit, visit_mut, Visitor, VisitorMut};
+    use std::collections::HashMap;
+
+    /// Counts the nodes which can be found in the debug output of the parse tree
+    #[derive(Default)]
+    struct Counter(HashMap<&'static str, usize>);
+
+    macro_rules! count {
+        ($($method:ident, $walk:ident, $ty:ident;)*) => {
+            impl Visitor for Counter {
+                $(
+                    fn $method(&mut self, node: &$ty) {
+                        *self.0.entry(stringify!($ty)).or_default() += 1;
+                        visit::$walk(self, node);
+                    }
+                )*
+
+                fn visit_identifier(&mut self, _: &Identifier) {
+                    *self.0.entry("Identifier").or_default() += 1;
+                }
+
+                fn visit_string_literal(&mut self, _: &StringLiteral) {
+                    *self.0.entry("StringLiteral").or_default() += 1;
+                }
+
+                fn visit_hex_literal(&mut self, _: &HexLiteral) {
+                    *self.0.entry("HexLiteral").or_default() += 1;
+                }
+
+                fn visit_comment(&mut self, _: &Comment) {
+                    *self.0.entry("Comment").or_default() += 1;
+                }
+            }
+        };
+    }
+
+    count!(
+        visit_identifier_path, walk_identifier_path, IdentifierPath;
+        visit_parameter, walk_parameter, Parameter;
+        visit_annotation, walk_annotation, Annotation;
+        visit_base, walk_base, Base;
+        visit_variable_declaration, walk_variable_declaration, VariableDeclaration;
+        visit_named_argument, walk_named_argument, NamedArgument;
+        visit_yul_block, walk_yul_block, YulBlock;
+        visit_yul_typed_identifier, walk_yul_typed_identifier, YulTypedIdentifier;
+        visit_yul_function_call, walk_yul_function_call, YulFunctionCall;
+    );
+
+    struct Rename;
+
+    impl VisitorMut for Rename {
+        fn visit_identifier(&mut self, id: &mut Identifier) {
+            id.name.push_str("_visited");
+        }
+    }
+
+    let source_delimiter = regex::Regex::new(r"====.*====").unwrap();
+    let renamed = regex::Regex::new(r#"name: "[^"]*_visited""#).unwrap();
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    let corpus = [
+        "../testdata/solidity/test/libsolidity/semanticTests",
+        "../testdata/solidity/test/libsolidity/syntaxTests",
+        "../tests/contract_testcases",
+    ]
+    .into_iter()
+    .flat_map(|dir| WalkDir::new(root.join(dir)))
+    .filter_map(Result::ok)
+    .filter(|entry| entry.file_name().to_string_lossy().ends_with(".sol"));
+
+    let mut parsed = 0;
+
+    for entry in corpus {
+        let Ok(source) = fs::read_to_string(entry.path()) else {
+            continue;
+        };
+
+        for part in source_delimiter.split(&source) {
+            let Ok((mut tree, comments)) = crate::parse(part, 0) else {
+                continue;
+            };
+
+            parsed += 1;
+
+            let debug = format!("{tree:?}");
+
+            let mut counter = Counter::default();
+            visit::walk_parse_tree(&mut counter, &tree, &comments);
+
+            for (ty, visited) in &counter.0 {
+                let expected = if *ty == "Comment" {
+                    comments.len()
+                } else {
+                    regex::Regex::new(&format!(r"\b{ty} \{{"))
+                        .unwrap()
+                        .find_iter(&debug)
+                        .count()
+                };
+
+                assert_eq!(*visited, expected, "{ty} in {}", entry.path().display());
+            }
+
+            let identifiers = counter.0.get("Identifier").copied().unwrap_or_default();
+
+            visit_mut::walk_parse_tree(&mut Rename, &mut tree, &mut comments.clone());
+
+            assert_eq!(
+                renamed.find_iter(&format!("{tree:?}")).count(),
+                identifiers,
+                "{}",
+                entry.path().display()
+            );
+        }
+    }
+
+    assert!(parsed > 0);
+}
+
+#[test]
+fn concrete_syntax_tree() {
+    use crate::cst::TriviaKind;
+    use crate::helpers::{visit, Visitor};
+    use crate::lexer::Token;
+
+    let src = "/// doc\ncontract c { // start\n    uint x = 1; /* one */\r\n\n    // two\n    uint y = 2;\n} \n";
+    let tree = crate::parse_cst(src, 0).unwrap();
+
+    assert_eq!(tree.to_string(), src);
+
+    fn trivia<'a>(trivia: &[crate::cst::Trivia<'a>]) -> Vec<(TriviaKind, &'a str)> {
+        trivia
+            .iter()
+            .map(|trivia| (trivia.kind, trivia.text))
+            .collect()
+    }
+
+    let contract = &tree.tokens[0];
+    assert_eq!(contract.token, Token::Contract);
+    assert_eq!(
+        trivia(&contract.leading_trivia),
+        [
+            (TriviaKind::DocLineComment, "/// doc"),
+            (TriviaKind::Newline, "\n")
+        ]
+    );
+
+    let brace = tree.token_at(src.find('{').unwrap()).unwrap();
+    assert_eq!(brace.token, Token::OpenCurlyBrace);
+    assert_eq!(
+        trivia(&brace.trailing_trivia),
+        [
+            (TriviaKind::Whitespace, " "),
+            (TriviaKind::LineComment, "// start"),
+            (TriviaKind::Newline, "\n")
+        ]
+    );
+
+    let semicolon = tree.token_at(src.find("1;").unwrap() + 1).unwrap();
+    assert_eq!(semicolon.token, Token::Semicolon);
+    assert_eq!(
+        trivia(&semicolon.trailing_trivia),
+        [
+            (TriviaKind::Whitespace, " "),
+            (TriviaKind::BlockComment, "/* one */"),
+            (TriviaKind::Newline, "\r\n")
+        ]
+    );
+
+    let y = tree.token_at(src.find("uint y").unwrap()).unwrap();
+    assert_eq!(
+        trivia(&y.leading_trivia),
+        [
+            (TriviaKind::Newline, "\n"),
+            (TriviaKind::Whitespace, "    "),
+            (TriviaKind::LineComment, "// two"),
+            (TriviaKind::Newline, "\n"),
+            (TriviaKind::Whitespace, "    ")
+        ]
+    );
+
+    assert!(tree.token_at(src.find(" x").unwrap()).is_none());
+    assert_eq!(
+        trivia(&tree.tokens.last().unwrap().trailing_trivia),
+        [(TriviaKind::Whitespace, " "), (TriviaKind::Newline, "\n")]
+    );
+    assert!(tree.end_trivia.is_empty());
+
+    let SourceUnitPart::ContractDefinition(c) = &tree.source_unit.0[0] else {
+        unreachable!();
+    };
+    let ContractPart::VariableDefinition(var) = &c.parts[1] else {
+        unreachable!();
+    };
+    let tokens = tree.tokens(&var.loc);
+    assert_eq!(
+        tokens.iter().map(|token| token.token).collect::<Vec<_>>(),
+        [
+            Token::Uint(256),
+            Token::Identifier("y"),
+            Token::Assign,
+            Token::Number("2", "")
+        ]
+    );
+    assert_eq!(tree.text(&var.loc), "uint y = 2");
+
+    /// Checks that every identifier in the parse tree maps to its token
+    struct Identifiers<'a, 'input>(&'a crate::cst::SyntaxTree<'input>, usize);
+
+    impl Visitor for Identifiers<'_, '_> {
+        fn visit_identifier(&mut self, id: &Identifier) {
+            let tokens = self.0.tokens(&id.loc);
+
+            assert!(
+                matches!(tokens, [token] if token.text == id.name
+                    || token.token == Token::Annotation(&id.name)),
+                "{id:?} {tokens:?}"
+            );
+
+            self.1 += 1;
+        }
+    }
+
+    let source_delimiter = regex::Regex::new(r"====.*====").unwrap();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    let corpus = [
+        "../testdata/solidity/test/libsolidity/semanticTests",
+        "../testdata/solidity/test/libsolidity/syntaxTests",
+        "../tests/contract_testcases",
+    ]
+    .into_iter()
+    .flat_map(|dir| WalkDir::new(root.join(dir)))
+    .filter_map(Result::ok)
+    .filter(|entry| entry.file_name().to_string_lossy().ends_with(".sol"));
+
+    let mut identifiers = 0;
+
+    for entry in corpus {
+        let Ok(source) = fs::read_to_string(entry.path()) else {
+            continue;
+        };
+
+        for part in source_delimiter.split(&source) {
+            let Ok(tree) = crate::parse_cst(part, 0) else {
+                continue;
+            };
+
+            assert_eq!(tree.to_string(), part, "{}", entry.path().display());
+
+            let mut visitor = Identifiers(&tree, 0);
+            visit::walk_source_unit(&mut visitor, &tree.source_unit);
+            identifiers += visitor.1;
+        }
+    }
+
+    assert!(identifiers > 0);
+}
@@ -447,6 +447,10 @@ impl TypeManager<'_> {
             Type::ExternalFunction { .. } => {
                 self.convert(&Type::Struct(StructType::ExternalFunction))
             }
+            Type::FunctionSelector => IdlType::Array(
+                Box::new(IdlType::U8),
+                self.namespace.target.selector_length() as usize,
+            ),
             Type::UserType(type_no) => self.convert(&self.namespace.user_types[*type_no].ty),
             _ => unreachable!("Type should not be in the IDL"),
         }
@@ -2117,6 +2117,45 @@ contract Test2 {
     );
 }
 
+#[test]
+fn external_function_type() {
+    let src = r#"
+contract caller {
+    function(uint64) external returns (uint64) public f;
+}
+    "#;
+
+    let mut ns = generate_namespace(src);
+    codegen(&mut ns, &Options::default());
+    let idl = generate_anchor_idl(0, &ns, "0.1.0");
+
+    assert_eq!(idl.instructions[1].name, "f");
+    assert_eq!(
+        idl.instructions[1].returns,
+        Some(IdlType::Defined("ExternalFunction".to_string()))
+    );
+
+    assert_eq!(idl.types.len(), 1);
+    assert_eq!(idl.types[0].name, "ExternalFunction");
+    assert_eq!(
+        idl.types[0].ty,
+        IdlTypeDefinitionTy::Struct {
+            fields: vec![
+                IdlField {
+                    name: "selector".to_string(),
+                    docs: None,
+                    ty: IdlType::Array(IdlType::U8.into(), 8),
+                },
+                IdlField {
+                    name: "address".to_string(),
+                    docs: None,
+                    ty: IdlType::PublicKey,
+                }
+            ]
+        }
+    );
+}
+
 fn idl_account(name: &str, is_mut: bool, is_signer: bool) -> IdlAccountItem {
     IdlAccountItem::IdlAccount(IdlAccount {
         name: name.to_string(),
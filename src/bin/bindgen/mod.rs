@@ -0,0 +1,433 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli::{imports_arg, target_arg, Bindgen};
+use base58::ToBase58;
+use convert_case::{Boundary, Case, Casing};
+use ink_env::hash::{Blake2x256, CryptoHash};
+use solang::{
+    abi::anchor::event_discriminator,
+    codegen::{
+        codegen,
+        revert::{SolidityError, ERROR_SELECTOR, PANIC_SELECTOR},
+        Options,
+    },
+    sema::{
+        ast::{ArrayLength, Function, Namespace, Parameter, StructType, Type},
+        tags::render,
+    },
+    Target,
+};
+use solang_parser::pt::FunctionTy;
+use std::{
+    fs::{create_dir_all, File},
+    io::Write,
+    path::PathBuf,
+    process::exit,
+};
+
+mod rust;
+mod typescript;
+
+/// This subcommand generates typed client bindings for the contracts in the Solidity files. The
+/// bindings encode the input of constructors and messages, and decode return values, events and
+/// errors. The interface of each contract is collected first, and then written out by the
+/// generator for the chosen language.
+pub fn bindgen(args: &Bindgen) {
+    let target = target_arg(&args.target);
+
+    if !matches!(target, Target::Solana | Target::Polkadot { .. }) {
+        eprintln!("error: bindings can only be generated for the solana and polkadot targets");
+        exit(1);
+    }
+
+    let mut resolver = imports_arg(&args.package);
+    let output = args.output_directory.clone().unwrap_or_else(|| ".".into());
+    let mut success = true;
+
+    for filename in &args.package.input {
+        let mut ns = solang::parse_and_resolve(filename.as_os_str(), &mut resolver, target);
+
+        // codegen adds the default constructor and the solana accounts
+        if !ns.diagnostics.any_errors() {
+            codegen(&mut ns, &Options::default());
+        }
+
+        ns.print_diagnostics(&resolver, args.verbose);
+
+        if ns.diagnostics.any_errors() {
+            success = false;
+            continue;
+        }
+
+        for contract_no in 0..ns.contracts.len() {
+            let contract = &ns.contracts[contract_no];
+
+            // contracts that were imported are generated when the file they are in is given
+            if !contract.instantiable || ns.top_file_no() != contract.loc.file_no() {
+                continue;
+            }
+
+            if let Some(names) = &args.package.contracts {
+                if !names.contains(&contract.id.name) {
+                    continue;
+                }
+            }
+
+            let interface = Interface::new(contract_no, &ns);
+
+            let (ext, code) = match args.language.as_str() {
+                "rust" => ("rs", rust::generate(&interface, &ns)),
+                "typescript" => ("ts", typescript::generate(&interface, &ns)),
+                _ => unreachable!(),
+            };
+
+            let path = PathBuf::from(&output).join(format!("{}.{ext}", interface.name));
+
+            if args.verbose {
+                eprintln!("info: Saving bindings {}", path.display());
+            }
+
+            if let Err(err) = create_dir_all(&output)
+                .and_then(|_| File::create(&path))
+                .and_then(|mut file| file.write_all(code.as_bytes()))
+            {
+                eprintln!("{}: error: {}", path.display(), err);
+                exit(1);
+            }
+        }
+    }
+
+    if !success {
+        exit(1);
+    }
+}
+
+/// The externally visible interface of a contract
+pub(crate) struct Interface {
+    pub name: String,
+    pub docs: String,
+    /// The program id from `@program_id`, in base58
+    pub program_id: Option<String>,
+    pub constructors: Vec<Call>,
+    pub messages: Vec<Call>,
+    pub events: Vec<Event>,
+    /// Custom errors; `Error(string)` and `Panic(uint256)` are in BUILTIN_ERRORS
+    pub errors: Vec<CustomError>,
+    /// Structs used by any of the above, as index into ns.structs
+    pub structs: Vec<usize>,
+    /// Enums used by any of the above, as index into ns.enums
+    pub enums: Vec<usize>,
+}
+
+/// A constructor or message
+pub(crate) struct Call {
+    pub name: String,
+    pub docs: String,
+    /// The selector on Polkadot, or the discriminator on Solana
+    pub selector: Vec<u8>,
+    pub params: Vec<Param>,
+    pub returns: Vec<Param>,
+    /// The accounts of the instruction on Solana
+    pub accounts: Vec<Account>,
+}
+
+pub(crate) struct Param {
+    pub name: String,
+    pub ty: Type,
+}
+
+pub(crate) struct Account {
+    pub name: String,
+    pub is_signer: bool,
+    pub is_writer: bool,
+}
+
+pub(crate) struct Event {
+    pub name: String,
+    pub docs: String,
+    /// The topic on Polkadot, or the discriminator on Solana. Anonymous events have neither
+    pub id: Option<Vec<u8>>,
+    pub fields: Vec<Param>,
+}
+
+pub(crate) struct CustomError {
+    pub name: String,
+    pub docs: String,
+    pub selector: [u8; 4],
+    pub fields: Vec<Param>,
+}
+
+/// The selectors of the builtin `Error(string)` and `Panic(uint256)` errors
+pub(crate) const BUILTIN_ERRORS: [(&str, [u8; 4]); 2] =
+    [("Error", ERROR_SELECTOR), ("Panic", PANIC_SELECTOR)];
+
+impl Interface {
+    fn new(contract_no: usize, ns: &Namespace) -> Self {
+        let contract = &ns.contracts[contract_no];
+
+        let mut interface = Interface {
+            name: contract.id.name.clone(),
+            docs: render(&contract.tags),
+            program_id: contract.program_id.as_ref().map(|id| id.to_base58()),
+            constructors: Vec::new(),
+            messages: Vec::new(),
+            events: Vec::new(),
+            errors: Vec::new(),
+            structs: Vec::new(),
+            enums: Vec::new(),
+        };
+
+        let constructors = contract
+            .functions
+            .iter()
+            .filter(|function_no| {
+                ns.functions[**function_no].is_constructor()
+                    && ns.function_externally_callable(contract_no, Some(**function_no))
+            })
+            .map(|function_no| &ns.functions[*function_no])
+            .chain(contract.default_constructor.as_ref().map(|(func, _)| func));
+
+        for func in constructors {
+            if let Some(mut call) = interface.call(func, contract_no, ns) {
+                // overloaded constructors have the same name
+                if interface.constructors.iter().any(|c| c.name == call.name) {
+                    call.name.clone_from(&func.mangled_name);
+                }
+                interface.constructors.push(call);
+            }
+        }
+
+        let messages = contract
+            .all_functions
+            .keys()
+            .filter(|function_no| ns.function_externally_callable(contract_no, Some(**function_no)))
+            .map(|function_no| &ns.functions[*function_no])
+            .filter(|func| func.ty == FunctionTy::Function);
+
+        for func in messages {
+            if let Some(call) = interface.call(func, contract_no, ns) {
+                interface.messages.push(call);
+            }
+        }
+
+        for event_no in &contract.emits_events {
+            let event = &ns.events[*event_no];
+
+            // events may be declared more than once in the inheritance tree
+            if interface.events.iter().any(|e| e.name == event.id.name) {
+                continue;
+            }
+
+            let Some(fields) = interface.params(&event.id.name, &event.fields, "field", ns) else {
+                continue;
+            };
+
+            let id = if ns.target == Target::Solana {
+                Some(event_discriminator(&event.id.name))
+            } else if event.anonymous {
+                None
+            } else {
+                let mut topic = [0; 32];
+                <Blake2x256 as CryptoHash>::hash(event.signature.as_bytes(), &mut topic);
+                Some(topic.to_vec())
+            };
+
+            interface.events.push(Event {
+                name: event.id.name.clone(),
+                docs: render(&event.tags),
+                id,
+                fields,
+            });
+        }
+
+        // Solana does not return the revert data to the caller, so errors cannot be decoded
+        if ns.target != Target::Solana {
+            for (error_no, error) in ns.errors.iter().enumerate() {
+                let selector = SolidityError::Custom {
+                    error_no,
+                    exprs: Vec::new(),
+                }
+                .selector(ns);
+
+                // errors may be declared more than once, and may be the same as the builtin ones
+                if interface.errors.iter().any(|e| e.selector == selector)
+                    || BUILTIN_ERRORS
+                        .iter()
+                        .any(|(_, builtin)| *builtin == selector)
+                {
+                    continue;
+                }
+
+                let Some(fields) = interface.params(&error.name, &error.fields, "field", ns) else {
+                    continue;
+                };
+
+                interface.errors.push(CustomError {
+                    name: error.name.clone(),
+                    docs: render(&error.tags),
+                    selector,
+                    fields,
+                });
+            }
+        }
+
+        interface
+    }
+
+    fn call(&mut self, func: &Function, contract_no: usize, ns: &Namespace) -> Option<Call> {
+        let name = if func.is_constructor() {
+            if func.id.name.is_empty() {
+                "new"
+            } else {
+                &func.id.name
+            }
+        } else if func.mangled_name_contracts.contains(&contract_no) {
+            &func.mangled_name
+        } else {
+            &func.id.name
+        };
+
+        let (structs, enums) = (self.structs.len(), self.enums.len());
+
+        let params = self.params(name, &func.params, "arg", ns)?;
+
+        let Some(returns) = self.params(name, &func.returns, "return", ns) else {
+            // forget the structs and enums which were only used by the parameters
+            self.structs.truncate(structs);
+            self.enums.truncate(enums);
+            return None;
+        };
+
+        let accounts = func
+            .solana_accounts
+            .borrow()
+            .iter()
+            .map(|(name, account)| Account {
+                name: name.clone(),
+                is_signer: account.is_signer,
+                is_writer: account.is_writer,
+            })
+            .collect();
+
+        Some(Call {
+            name: name.to_owned(),
+            docs: render(&func.tags),
+            selector: func.selector(ns, &contract_no),
+            params,
+            returns,
+            accounts,
+        })
+    }
+
+    /// Collect the parameters, naming the unnamed ones. If any of the types cannot be
+    /// represented in the bindings, warn and return None, so the item is left out.
+    fn params(
+        &mut self,
+        item: &str,
+        params: &[Parameter<Type>],
+        prefix: &str,
+        ns: &Namespace,
+    ) -> Option<Vec<Param>> {
+        let mut res = Vec::new();
+        let (structs, enums) = (self.structs.len(), self.enums.len());
+
+        for (no, param) in params.iter().enumerate() {
+            if !self.add_type(&param.ty, ns) {
+                // forget the structs and enums which were only used by this item
+                self.structs.truncate(structs);
+                self.enums.truncate(enums);
+
+                eprintln!(
+                    "warning: '{}' has type '{}' which is not supported in bindings, skipping",
+                    item,
+                    param.ty.to_string(ns)
+                );
+                return None;
+            }
+
+            let name = match param.name_as_str() {
+                "" => format!("{prefix}{no}"),
+                name => name.to_owned(),
+            };
+
+            res.push(Param {
+                name,
+                ty: param.ty.clone(),
+            });
+        }
+
+        Some(res)
+    }
+
+    /// Record the structs and enums used by the type, and check that it is supported
+    fn add_type(&mut self, ty: &Type, ns: &Namespace) -> bool {
+        match ty {
+            Type::Bool
+            | Type::Int(_)
+            | Type::Uint(_)
+            | Type::Bytes(_)
+            | Type::DynamicBytes
+            | Type::String
+            | Type::Address(_)
+            | Type::Contract(_) => true,
+            Type::Enum(enum_no) => {
+                if !self.enums.contains(enum_no) {
+                    self.enums.push(*enum_no);
+                }
+                true
+            }
+            Type::Struct(StructType::UserDefined(struct_no)) => {
+                if self.structs.contains(struct_no) {
+                    return true;
+                }
+
+                self.structs.push(*struct_no);
+
+                ns.structs[*struct_no]
+                    .fields
+                    .iter()
+                    .all(|field| !field.recursive && self.add_type(&field.ty, ns))
+            }
+            Type::Array(elem, dims) => {
+                dims.iter()
+                    .all(|dim| matches!(dim, ArrayLength::Fixed(_) | ArrayLength::Dynamic))
+                    && self.add_type(elem, ns)
+            }
+            Type::UserType(no) => self.add_type(&ns.user_types[*no].ty, ns),
+            Type::Slice(elem) => self.add_type(elem, ns),
+            _ => false,
+        }
+    }
+}
+
+/// Format bytes as a comma separated list of hex numbers
+pub(crate) fn hex_list(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("0x{b:02x}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The docs as comment lines with the given prefix
+pub(crate) fn doc_lines(docs: &str, prefix: &str) -> String {
+    docs.lines()
+        .map(|line| {
+            if line.is_empty() {
+                format!("{}\n", prefix.trim_end())
+            } else {
+                format!("{prefix}{line}\n")
+            }
+        })
+        .collect()
+}
+
+/// Change the case of a name. Digits do not start a new word, so `uint256` is kept together
+pub(crate) fn convert(name: &str, case: Case) -> String {
+    name.with_boundaries(&[
+        Boundary::Underscore,
+        Boundary::LowerUpper,
+        Boundary::Acronym,
+    ])
+    .to_case(case)
+}
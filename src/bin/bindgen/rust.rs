@@ -0,0 +1,577 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{convert, doc_lines, hex_list, Call, Interface, Param, BUILTIN_ERRORS};
+use convert_case::Case;
+use solang::{
+    sema::{
+        ast::{ArrayLength, Namespace, StructType, Type},
+        tags::render,
+    },
+    Target,
+};
+use std::fmt::Write;
+
+/// Generate a Rust module for the contract. On Polkadot the types are encoded with
+/// parity-scale-codec, and on Solana with borsh and the solana-program crate.
+pub(super) fn generate(interface: &Interface, ns: &Namespace) -> String {
+    let solana = ns.target == Target::Solana;
+    let mut out = String::new();
+
+    out.push_str(&doc_lines(&interface.docs, "//! "));
+    writeln!(
+        out,
+        "// Generated by `solang bindgen` from the contract `{}`. Do not edit.\n",
+        interface.name
+    )
+    .unwrap();
+
+    if solana {
+        out.push_str("use borsh::{BorshDeserialize, BorshSerialize};\n");
+        out.push_str("use solana_program::instruction::{AccountMeta, Instruction};\n");
+        out.push_str("use solana_program::pubkey::Pubkey;\n");
+
+        if let Some(program_id) = &interface.program_id {
+            writeln!(
+                out,
+                "\n/// The program id of the contract\npub const ID: Pubkey = solana_program::pubkey!(\"{program_id}\");"
+            )
+            .unwrap();
+        }
+    } else {
+        out.push_str("use parity_scale_codec::{Decode, Encode};\n");
+    }
+
+    let derive = if solana {
+        "BorshSerialize, BorshDeserialize"
+    } else {
+        "Encode, Decode"
+    };
+
+    for enum_no in &interface.enums {
+        let decl = &ns.enums[*enum_no];
+
+        writeln!(out).unwrap();
+        out.push_str(&doc_lines(&render(&decl.tags), "/// "));
+        writeln!(
+            out,
+            "#[derive(Debug, Clone, Copy, PartialEq, Eq, {derive})]\npub enum {} {{",
+            type_name(&decl.id.name)
+        )
+        .unwrap();
+
+        for value in decl.values.keys() {
+            writeln!(out, "    {},", type_name(value)).unwrap();
+        }
+
+        out.push_str("}\n");
+    }
+
+    for struct_no in &interface.structs {
+        let decl = &ns.structs[*struct_no];
+
+        writeln!(out).unwrap();
+        out.push_str(&doc_lines(&render(&decl.tags), "/// "));
+        writeln!(
+            out,
+            "#[derive(Debug, Clone, PartialEq, Eq, {derive})]\npub struct {} {{",
+            type_name(&decl.id.name)
+        )
+        .unwrap();
+
+        for field in &decl.fields {
+            writeln!(
+                out,
+                "    pub {}: {},",
+                ident(field.name_as_str()),
+                rust_type(&field.ty, ns)
+            )
+            .unwrap();
+        }
+
+        out.push_str("}\n");
+    }
+
+    if solana {
+        out.push_str(
+            "\n/// Instructions of the program. Each function returns the instruction to call it with.\n",
+        );
+        out.push_str("pub mod instructions {\n    use super::*;\n");
+
+        for call in interface.constructors.iter().chain(&interface.messages) {
+            instruction(&mut out, call, ns);
+        }
+
+        out.push_str("}\n");
+    } else {
+        out.push_str(
+            "\n/// Constructors of the contract. Each function returns the input data to instantiate the contract with.\n",
+        );
+        out.push_str("pub mod constructors {\n    #[allow(unused_imports)]\n    use super::*;\n");
+
+        for call in &interface.constructors {
+            message(&mut out, call, ns);
+        }
+
+        out.push_str("}\n");
+
+        out.push_str(
+            "\n/// Messages of the contract. Each function returns the input data to call the message with.\n",
+        );
+        out.push_str("pub mod messages {\n    #[allow(unused_imports)]\n    use super::*;\n");
+
+        for call in &interface.messages {
+            message(&mut out, call, ns);
+        }
+
+        out.push_str("}\n");
+    }
+
+    events(&mut out, interface, ns);
+
+    if !solana {
+        errors(&mut out, interface, ns);
+    }
+
+    out
+}
+
+/// Polkadot constructor or message: the input is the selector followed by the SCALE encoded
+/// arguments
+fn message(out: &mut String, call: &Call, ns: &Namespace) {
+    let selector = format!("{}_SELECTOR", const_name(&call.name));
+
+    writeln!(
+        out,
+        "\n    /// Selector of `{}`\n    pub const {selector}: [u8; 4] = [{}];\n",
+        call.name,
+        hex_list(&call.selector)
+    )
+    .unwrap();
+
+    out.push_str(&doc_lines(&call.docs, "    /// "));
+    writeln!(
+        out,
+        "    pub fn {}({}) -> Vec<u8> {{",
+        ident(&call.name),
+        params(&call.params, ns)
+    )
+    .unwrap();
+
+    if call.params.is_empty() {
+        writeln!(out, "        {selector}.to_vec()").unwrap();
+    } else {
+        writeln!(out, "        let mut input = {selector}.to_vec();").unwrap();
+
+        for param in &call.params {
+            writeln!(out, "        {}.encode_to(&mut input);", ident(&param.name)).unwrap();
+        }
+
+        out.push_str("        input\n");
+    }
+
+    out.push_str("    }\n");
+
+    if !call.returns.is_empty() {
+        writeln!(
+            out,
+            "\n    /// Decode the output of `{}`\n    pub fn decode_{}(mut output: &[u8]) -> Result<{}, parity_scale_codec::Error> {{\n        Decode::decode(&mut output)\n    }}",
+            call.name,
+            snake_case(&call.name),
+            returns(&call.returns, ns)
+        )
+        .unwrap();
+    }
+}
+
+/// Solana instruction: the data is the discriminator followed by the borsh encoded arguments
+fn instruction(out: &mut String, call: &Call, ns: &Namespace) {
+    let discriminator = format!("{}_DISCRIMINATOR", const_name(&call.name));
+    let accounts = format!("{}Accounts", type_name(&call.name));
+
+    writeln!(
+        out,
+        "\n    /// Accounts of `{}`\n    #[derive(Debug, Clone, Copy, PartialEq, Eq)]\n    pub struct {accounts} {{",
+        call.name
+    )
+    .unwrap();
+
+    for account in &call.accounts {
+        writeln!(out, "        pub {}: Pubkey,", ident(&account.name)).unwrap();
+    }
+
+    writeln!(
+        out,
+        "    }}\n\n    /// Discriminator of `{}`\n    pub const {discriminator}: [u8; 8] = [{}];\n",
+        call.name,
+        hex_list(&call.selector)
+    )
+    .unwrap();
+
+    out.push_str(&doc_lines(&call.docs, "    /// "));
+
+    let mut args = format!("program_id: &Pubkey, accounts: &{accounts}");
+
+    if !call.params.is_empty() {
+        write!(args, ", {}", params(&call.params, ns)).unwrap();
+    }
+
+    writeln!(
+        out,
+        "    pub fn {}({args}) -> Instruction {{",
+        ident(&call.name)
+    )
+    .unwrap();
+
+    if call.params.is_empty() {
+        writeln!(out, "        let data = {discriminator}.to_vec();").unwrap();
+    } else {
+        writeln!(out, "        let mut data = {discriminator}.to_vec();").unwrap();
+
+        for param in &call.params {
+            writeln!(
+                out,
+                "        {}.serialize(&mut data).unwrap();",
+                ident(&param.name)
+            )
+            .unwrap();
+        }
+    }
+
+    out.push_str(
+        "        Instruction {\n            program_id: *program_id,\n            accounts: vec![\n",
+    );
+
+    for account in &call.accounts {
+        writeln!(
+            out,
+            "                AccountMeta::{}(accounts.{}, {}),",
+            if account.is_writer {
+                "new"
+            } else {
+                "new_readonly"
+            },
+            ident(&account.name),
+            account.is_signer
+        )
+        .unwrap();
+    }
+
+    out.push_str("            ],\n            data,\n        }\n    }\n");
+
+    if !call.returns.is_empty() {
+        writeln!(
+            out,
+            "\n    /// Decode the return data of `{}`\n    pub fn decode_{}(return_data: &[u8]) -> std::io::Result<{}> {{\n        BorshDeserialize::try_from_slice(return_data)\n    }}",
+            call.name,
+            snake_case(&call.name),
+            returns(&call.returns, ns)
+        )
+        .unwrap();
+    }
+}
+
+/// A struct for each event, and a function which recognizes and decodes them. On Polkadot the
+/// first topic identifies the event and the data has all the fields. On Solana the data starts
+/// with the discriminator.
+fn events(out: &mut String, interface: &Interface, ns: &Namespace) {
+    if interface.events.is_empty() {
+        return;
+    }
+
+    let solana = ns.target == Target::Solana;
+
+    out.push_str("\n/// Events the contract can emit\npub mod events {\n    use super::*;\n");
+
+    for event in &interface.events {
+        let name = type_name(&event.name);
+
+        writeln!(out).unwrap();
+        out.push_str(&doc_lines(&event.docs, "    /// "));
+        writeln!(
+            out,
+            "    #[derive(Debug, Clone, PartialEq, Eq, {})]\n    pub struct {name} {{",
+            if solana { "BorshDeserialize" } else { "Decode" }
+        )
+        .unwrap();
+
+        for field in &event.fields {
+            writeln!(
+                out,
+                "        pub {}: {},",
+                ident(&field.name),
+                rust_type(&field.ty, ns)
+            )
+            .unwrap();
+        }
+
+        out.push_str("    }\n");
+
+        if let Some(id) = &event.id {
+            let (konst, len) = if solana {
+                ("DISCRIMINATOR", 8)
+            } else {
+                ("TOPIC", 32)
+            };
+
+            writeln!(
+                out,
+                "\n    impl {name} {{\n        pub const {konst}: [u8; {len}] = [{}];\n    }}",
+                hex_list(id)
+            )
+            .unwrap();
+        }
+    }
+
+    let decodable = interface
+        .events
+        .iter()
+        .filter(|event| event.id.is_some())
+        .collect::<Vec<_>>();
+
+    if decodable.is_empty() {
+        out.push_str("}\n");
+        return;
+    }
+
+    out.push_str(
+        "\n    /// All the events which can be decoded\n    #[derive(Debug, Clone, PartialEq, Eq)]\n    pub enum ContractEvent {\n",
+    );
+
+    for event in &decodable {
+        let name = type_name(&event.name);
+        writeln!(out, "        {name}({name}),").unwrap();
+    }
+
+    out.push_str("    }\n");
+
+    if solana {
+        out.push_str(
+            "\n    /// Decode the data of an event, as logged by the program\n    pub fn decode(data: &[u8]) -> Option<ContractEvent> {\n        if data.len() < 8 {\n            return None;\n        }\n\n        let (discriminator, data) = data.split_at(8);\n\n        match discriminator {\n",
+        );
+
+        for event in &decodable {
+            let name = type_name(&event.name);
+            writeln!(
+                out,
+                "            d if d == {name}::DISCRIMINATOR => {name}::try_from_slice(data).ok().map(ContractEvent::{name}),"
+            )
+            .unwrap();
+        }
+    } else {
+        out.push_str(
+            "\n    /// Decode an event from its topics and data. Anonymous events have no topic which\n    /// identifies them, so they are not recognized.\n    pub fn decode(topics: &[[u8; 32]], mut data: &[u8]) -> Option<ContractEvent> {\n        match topics.first()? {\n",
+        );
+
+        for event in &decodable {
+            let name = type_name(&event.name);
+            writeln!(
+                out,
+                "            topic if *topic == {name}::TOPIC => {name}::decode(&mut data).ok().map(ContractEvent::{name}),"
+            )
+            .unwrap();
+        }
+    }
+
+    out.push_str("            _ => None,\n        }\n    }\n}\n");
+}
+
+/// The errors a call can revert with, identified by the selector at the start of the revert data
+fn errors(out: &mut String, interface: &Interface, ns: &Namespace) {
+    out.push_str(
+        "\n/// Errors the contract can revert with\npub mod errors {\n    use super::*;\n\n    #[derive(Debug, Clone, PartialEq, Eq)]\n    pub enum Error {\n        /// `Error(string)`, from `revert(\"...\")` or `require(..., \"...\")`\n        Error(String),\n        /// `Panic(uint256)`, with the panic code\n        Panic([u8; 32]),\n",
+    );
+
+    for error in &interface.errors {
+        out.push_str(&doc_lines(&error.docs, "        /// "));
+        writeln!(out, "        {} {{", type_name(&error.name)).unwrap();
+
+        for field in &error.fields {
+            writeln!(
+                out,
+                "            {}: {},",
+                ident(&field.name),
+                rust_type(&field.ty, ns)
+            )
+            .unwrap();
+        }
+
+        out.push_str("        },\n");
+    }
+
+    out.push_str(
+        "    }\n\n    /// Decode the revert data of a call\n    pub fn decode(data: &[u8]) -> Option<Error> {\n        if data.len() < 4 {\n            return None;\n        }\n\n        let (selector, mut data) = data.split_at(4);\n\n        match selector {\n",
+    );
+
+    for (name, selector) in BUILTIN_ERRORS {
+        writeln!(
+            out,
+            "            [{}] => Some(Error::{name}(Decode::decode(&mut data).ok()?)),",
+            hex_list(&selector)
+        )
+        .unwrap();
+    }
+
+    for error in &interface.errors {
+        writeln!(
+            out,
+            "            [{}] => Some(Error::{} {{",
+            hex_list(&error.selector),
+            type_name(&error.name)
+        )
+        .unwrap();
+
+        for field in &error.fields {
+            writeln!(
+                out,
+                "                {}: Decode::decode(&mut data).ok()?,",
+                ident(&field.name)
+            )
+            .unwrap();
+        }
+
+        out.push_str("            }),\n");
+    }
+
+    out.push_str("            _ => None,\n        }\n    }\n}\n");
+}
+
+fn params(params: &[Param], ns: &Namespace) -> String {
+    params
+        .iter()
+        .map(|param| format!("{}: {}", ident(&param.name), rust_type(&param.ty, ns)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A single return value is returned as is, multiple as a tuple
+fn returns(returns: &[Param], ns: &Namespace) -> String {
+    if returns.len() == 1 {
+        rust_type(&returns[0].ty, ns)
+    } else {
+        format!(
+            "({})",
+            returns
+                .iter()
+                .map(|ret| rust_type(&ret.ty, ns))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// The Rust type which has the same encoding as the Solidity type. Integers are encoded with
+/// the next power of two width, and those without a Rust type are little endian byte arrays.
+fn rust_type(ty: &Type, ns: &Namespace) -> String {
+    match ty {
+        Type::Bool => "bool".into(),
+        Type::Int(width) | Type::Uint(width) if width.next_power_of_two() > 128 => {
+            format!("[u8; {}]", width.next_power_of_two() / 8)
+        }
+        Type::Int(width) => format!("i{}", width.next_power_of_two().max(8)),
+        Type::Uint(width) => format!("u{}", width.next_power_of_two().max(8)),
+        Type::Address(_) | Type::Contract(_) => {
+            if ns.target == Target::Solana {
+                "Pubkey".into()
+            } else {
+                format!("[u8; {}]", ns.address_length)
+            }
+        }
+        Type::Bytes(n) => format!("[u8; {n}]"),
+        Type::String => "String".into(),
+        Type::DynamicBytes => "Vec<u8>".into(),
+        Type::Enum(enum_no) => type_name(&ns.enums[*enum_no].id.name),
+        Type::Struct(StructType::UserDefined(struct_no)) => {
+            type_name(&ns.structs[*struct_no].id.name)
+        }
+        Type::Array(elem, dims) => dims.iter().fold(rust_type(elem, ns), |ty, dim| match dim {
+            ArrayLength::Fixed(len) => format!("[{ty}; {len}]"),
+            _ => format!("Vec<{ty}>"),
+        }),
+        Type::UserType(no) => rust_type(&ns.user_types[*no].ty, ns),
+        Type::Slice(elem) => format!("Vec<{}>", rust_type(elem, ns)),
+        _ => unreachable!(),
+    }
+}
+
+/// Names of functions, fields and parameters. Keywords and the names used by the generated
+/// code get an underscore appended.
+fn ident(name: &str) -> String {
+    const RESERVED: &[&str] = &[
+        "abstract",
+        "as",
+        "async",
+        "await",
+        "become",
+        "box",
+        "break",
+        "const",
+        "continue",
+        "crate",
+        "do",
+        "dyn",
+        "else",
+        "enum",
+        "extern",
+        "false",
+        "final",
+        "fn",
+        "for",
+        "if",
+        "impl",
+        "in",
+        "let",
+        "loop",
+        "macro",
+        "match",
+        "mod",
+        "move",
+        "mut",
+        "override",
+        "priv",
+        "pub",
+        "ref",
+        "return",
+        "self",
+        "static",
+        "struct",
+        "super",
+        "trait",
+        "true",
+        "try",
+        "type",
+        "typeof",
+        "unsafe",
+        "unsized",
+        "use",
+        "virtual",
+        "where",
+        "while",
+        "yield",
+        "input",
+        "output",
+        "data",
+        "accounts",
+        "program_id",
+        "return_data",
+    ];
+
+    let name = snake_case(name);
+
+    if RESERVED.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    convert(name, Case::Snake)
+}
+
+fn const_name(name: &str) -> String {
+    convert(name, Case::UpperSnake)
+}
+
+fn type_name(name: &str) -> String {
+    convert(name, Case::Pascal)
+}
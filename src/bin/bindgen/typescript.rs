@@ -0,0 +1,802 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{convert, doc_lines, hex_list, Call, Interface, Param, BUILTIN_ERRORS};
+use convert_case::Case;
+use solang::{
+    sema::{
+        ast::{ArrayLength, Namespace, StructType, Type},
+        tags::render,
+    },
+    Target,
+};
+use std::fmt::Write;
+
+/// Encoding and decoding of the basic types, shared by both targets
+const CODEC: &str = r#"class Writer {
+  private buf: number[];
+
+  constructor(prefix: Uint8Array) {
+    this.buf = Array.from(prefix);
+  }
+
+  bool(value: boolean) {
+    this.buf.push(value ? 1 : 0);
+  }
+
+  int(value: number | bigint, size: number) {
+    let v = BigInt.asUintN(size * 8, BigInt(value));
+    for (let i = 0; i < size; i++) {
+      this.buf.push(Number(v & 0xffn));
+      v >>= 8n;
+    }
+  }
+
+  fixed(value: Uint8Array, size: number) {
+    if (value.length !== size) {
+      throw new Error(`expected ${size} bytes, got ${value.length}`);
+    }
+    value.forEach((b) => this.buf.push(b));
+  }
+
+  bytes(value: Uint8Array) {
+    this.length(value.length);
+    this.fixed(value, value.length);
+  }
+
+  string(value: string) {
+    this.bytes(new TextEncoder().encode(value));
+  }
+
+  vec<T>(values: T[], f: (value: T) => void) {
+    this.length(values.length);
+    values.forEach(f);
+  }
+
+  array<T>(values: T[], size: number, f: (value: T) => void) {
+    if (values.length !== size) {
+      throw new Error(`expected ${size} elements, got ${values.length}`);
+    }
+    values.forEach(f);
+  }
+
+  finish(): Uint8Array {
+    return Uint8Array.from(this.buf);
+  }
+"#;
+
+const CODEC_READER: &str = r#"}
+
+class Reader {
+  private offset = 0;
+
+  constructor(private data: Uint8Array) {}
+
+  private take(size: number): Uint8Array {
+    if (this.offset + size > this.data.length) {
+      throw new Error('unexpected end of data');
+    }
+    const bytes = this.data.slice(this.offset, this.offset + size);
+    this.offset += size;
+    return bytes;
+  }
+
+  bool(): boolean {
+    return this.take(1)[0] !== 0;
+  }
+
+  uint(size: number): bigint {
+    const bytes = this.take(size);
+    let v = 0n;
+    for (let i = size - 1; i >= 0; i--) {
+      v = (v << 8n) | BigInt(bytes[i]);
+    }
+    return v;
+  }
+
+  int(size: number): bigint {
+    return BigInt.asIntN(size * 8, this.uint(size));
+  }
+
+  fixed(size: number): Uint8Array {
+    return this.take(size);
+  }
+
+  bytes(): Uint8Array {
+    return this.take(this.length());
+  }
+
+  string(): string {
+    return new TextDecoder().decode(this.bytes());
+  }
+
+  vec<T>(f: () => T): T[] {
+    return this.array(this.length(), f);
+  }
+
+  array<T>(size: number, f: () => T): T[] {
+    const values: T[] = [];
+    for (let i = 0; i < size; i++) {
+      values.push(f());
+    }
+    return values;
+  }
+"#;
+
+const EQUAL: &str = r#"
+function equal(a: Uint8Array, b: Uint8Array): boolean {
+  return a.length === b.length && a.every((v, i) => v === b[i]);
+}
+"#;
+
+/// SCALE encodes lengths as compact integers
+const SCALE_LENGTH_WRITER: &str = r#"
+  length(len: number) {
+    if (len < 1 << 6) {
+      this.int(len << 2, 1);
+    } else if (len < 1 << 14) {
+      this.int((len << 2) | 1, 2);
+    } else if (len < 1 << 30) {
+      this.int((len << 2) | 2, 4);
+    } else {
+      throw new Error(`length ${len} too large`);
+    }
+  }
+"#;
+
+const SCALE_LENGTH_READER: &str = r#"
+  length(): number {
+    switch (this.data[this.offset] & 3) {
+      case 0:
+        return Number(this.uint(1) >> 2n);
+      case 1:
+        return Number(this.uint(2) >> 2n);
+      case 2:
+        return Number(this.uint(4) >> 2n);
+      default:
+        return Number(this.uint(Number(this.uint(1) >> 2n) + 4));
+    }
+  }
+}
+"#;
+
+/// Borsh encodes lengths as 32 bit integers
+const BORSH_LENGTH_WRITER: &str = r#"
+  length(len: number) {
+    this.int(len, 4);
+  }
+"#;
+
+const BORSH_LENGTH_READER: &str = r#"
+  length(): number {
+    return Number(this.uint(4));
+  }
+}
+"#;
+
+/// Generate a self-contained TypeScript module for the contract. On Polkadot the functions
+/// return the input data for calls, and on Solana they return a `TransactionInstruction`
+/// from `@solana/web3.js`.
+pub(super) fn generate(interface: &Interface, ns: &Namespace) -> String {
+    let solana = ns.target == Target::Solana;
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "// Generated by `solang bindgen` from the contract `{}`. Do not edit.\n",
+        interface.name
+    )
+    .unwrap();
+
+    out.push_str(&doc_comment(&interface.docs));
+
+    if solana {
+        out.push_str("import { PublicKey, TransactionInstruction } from '@solana/web3.js';\n\n");
+    }
+
+    out.push_str(CODEC);
+    out.push_str(if solana {
+        BORSH_LENGTH_WRITER
+    } else {
+        SCALE_LENGTH_WRITER
+    });
+    out.push_str(CODEC_READER);
+    out.push_str(if solana {
+        BORSH_LENGTH_READER
+    } else {
+        SCALE_LENGTH_READER
+    });
+    out.push_str(EQUAL);
+
+    if let Some(program_id) = &interface.program_id {
+        writeln!(
+            out,
+            "\n/** The program id of the contract */\nexport const PROGRAM_ID = new PublicKey('{program_id}');"
+        )
+        .unwrap();
+    }
+
+    for enum_no in &interface.enums {
+        let decl = &ns.enums[*enum_no];
+
+        writeln!(out).unwrap();
+        out.push_str(&doc_comment(&render(&decl.tags)));
+        writeln!(out, "export enum {} {{", type_name(&decl.id.name)).unwrap();
+
+        for value in decl.values.keys() {
+            writeln!(out, "  {},", type_name(value)).unwrap();
+        }
+
+        out.push_str("}\n");
+    }
+
+    for struct_no in &interface.structs {
+        let decl = &ns.structs[*struct_no];
+        let name = type_name(&decl.id.name);
+
+        writeln!(out).unwrap();
+        out.push_str(&doc_comment(&render(&decl.tags)));
+        writeln!(out, "export interface {name} {{").unwrap();
+
+        for field in &decl.fields {
+            writeln!(
+                out,
+                "  {}: {};",
+                ident(field.name_as_str()),
+                ts_type(&field.ty, ns)
+            )
+            .unwrap();
+        }
+
+        writeln!(out, "}}\n\nfunction write{name}(w: Writer, v: {name}) {{").unwrap();
+
+        for field in &decl.fields {
+            let value = format!("v.{}", ident(field.name_as_str()));
+            writeln!(out, "  {};", encode(&field.ty, &value, 0, ns)).unwrap();
+        }
+
+        writeln!(
+            out,
+            "}}\n\nfunction read{name}(r: Reader): {name} {{\n  return {{"
+        )
+        .unwrap();
+
+        for field in &decl.fields {
+            writeln!(
+                out,
+                "    {}: {},",
+                ident(field.name_as_str()),
+                decode(&field.ty, 0, ns)
+            )
+            .unwrap();
+        }
+
+        out.push_str("  };\n}\n");
+    }
+
+    for call in interface.constructors.iter().chain(&interface.messages) {
+        if solana {
+            instruction(&mut out, call, ns);
+        } else {
+            message(&mut out, call, ns);
+        }
+
+        if !call.returns.is_empty() {
+            let returns = if call.returns.len() == 1 {
+                decode(&call.returns[0].ty, 0, ns)
+            } else {
+                format!(
+                    "[{}]",
+                    call.returns
+                        .iter()
+                        .map(|ret| decode(&ret.ty, 0, ns))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            };
+
+            writeln!(
+                out,
+                "\n/** Decode the {} of `{}` */\nexport function decode{}(output: Uint8Array): {} {{\n  const r = new Reader(output);\n  return {returns};\n}}",
+                if solana { "return data" } else { "output" },
+                call.name,
+                type_name(&call.name),
+                return_type(&call.returns, ns)
+            )
+            .unwrap();
+        }
+    }
+
+    events(&mut out, interface, ns);
+
+    if !solana {
+        errors(&mut out, interface, ns);
+    }
+
+    out
+}
+
+/// Polkadot constructor or message: the input is the selector followed by the SCALE encoded
+/// arguments
+fn message(out: &mut String, call: &Call, ns: &Namespace) {
+    let selector = format!("{}_SELECTOR", const_name(&call.name));
+
+    writeln!(
+        out,
+        "\n/** Selector of `{}` */\nexport const {selector} = Uint8Array.from([{}]);\n",
+        call.name,
+        hex_list(&call.selector)
+    )
+    .unwrap();
+
+    out.push_str(&doc_comment(&call.docs));
+    writeln!(
+        out,
+        "export function encode{}({}): Uint8Array {{\n  const w = new Writer({selector});",
+        type_name(&call.name),
+        params(&call.params, ns)
+    )
+    .unwrap();
+
+    for param in &call.params {
+        writeln!(out, "  {};", encode(&param.ty, &ident(&param.name), 0, ns)).unwrap();
+    }
+
+    out.push_str("  return w.finish();\n}\n");
+}
+
+/// Solana instruction: the data is the discriminator followed by the borsh encoded arguments
+fn instruction(out: &mut String, call: &Call, ns: &Namespace) {
+    let discriminator = format!("{}_DISCRIMINATOR", const_name(&call.name));
+    let accounts = format!("{}Accounts", type_name(&call.name));
+
+    writeln!(
+        out,
+        "\n/** Accounts of `{}` */\nexport interface {accounts} {{",
+        call.name
+    )
+    .unwrap();
+
+    for account in &call.accounts {
+        writeln!(out, "  {}: PublicKey;", ident(&account.name)).unwrap();
+    }
+
+    writeln!(
+        out,
+        "}}\n\n/** Discriminator of `{}` */\nexport const {discriminator} = Uint8Array.from([{}]);\n",
+        call.name,
+        hex_list(&call.selector)
+    )
+    .unwrap();
+
+    out.push_str(&doc_comment(&call.docs));
+
+    let mut args = format!("programId: PublicKey, accounts: {accounts}");
+
+    if !call.params.is_empty() {
+        write!(args, ", {}", params(&call.params, ns)).unwrap();
+    }
+
+    writeln!(
+        out,
+        "export function {}({args}): TransactionInstruction {{\n  const w = new Writer({discriminator});",
+        ident(&call.name),
+    )
+    .unwrap();
+
+    for param in &call.params {
+        writeln!(out, "  {};", encode(&param.ty, &ident(&param.name), 0, ns)).unwrap();
+    }
+
+    out.push_str("  return new TransactionInstruction({\n    programId,\n    keys: [\n");
+
+    for account in &call.accounts {
+        writeln!(
+            out,
+            "      {{ pubkey: accounts.{}, isSigner: {}, isWritable: {} }},",
+            ident(&account.name),
+            account.is_signer,
+            account.is_writer
+        )
+        .unwrap();
+    }
+
+    out.push_str("    ],\n    data: Buffer.from(w.finish()),\n  });\n}\n");
+}
+
+/// An interface for each event, and a function which recognizes and decodes them. On Polkadot
+/// the first topic identifies the event and the data has all the fields. On Solana the data
+/// starts with the discriminator.
+fn events(out: &mut String, interface: &Interface, ns: &Namespace) {
+    let solana = ns.target == Target::Solana;
+
+    for event in &interface.events {
+        let name = type_name(&event.name);
+
+        writeln!(out).unwrap();
+        out.push_str(&doc_comment(&event.docs));
+        writeln!(out, "export interface {name}Event {{").unwrap();
+
+        for field in &event.fields {
+            writeln!(out, "  {}: {};", ident(&field.name), ts_type(&field.ty, ns)).unwrap();
+        }
+
+        out.push_str("}\n");
+
+        if let Some(id) = &event.id {
+            writeln!(
+                out,
+                "\nexport const {}_{} = Uint8Array.from([{}]);",
+                const_name(&event.name),
+                if solana { "DISCRIMINATOR" } else { "TOPIC" },
+                hex_list(id)
+            )
+            .unwrap();
+        }
+    }
+
+    let decodable = interface
+        .events
+        .iter()
+        .filter(|event| event.id.is_some())
+        .collect::<Vec<_>>();
+
+    if decodable.is_empty() {
+        return;
+    }
+
+    writeln!(
+        out,
+        "\n/** All the events which can be decoded */\nexport type ContractEvent =\n{};",
+        decodable
+            .iter()
+            .map(|event| {
+                let name = type_name(&event.name);
+                format!("  | {{ name: '{name}'; args: {name}Event }}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    )
+    .unwrap();
+
+    if solana {
+        out.push_str(
+            "\n/** Decode the data of an event, as logged by the program */\nexport function decodeEvent(data: Uint8Array): ContractEvent | undefined {\n  const discriminator = data.slice(0, 8);\n  const r = new Reader(data.slice(8));\n",
+        );
+    } else {
+        out.push_str(
+            "\n/**\n * Decode an event from its topics and data. Anonymous events have no topic which\n * identifies them, so they are not recognized.\n */\nexport function decodeEvent(topics: Uint8Array[], data: Uint8Array): ContractEvent | undefined {\n  if (topics.length === 0) {\n    return undefined;\n  }\n  const r = new Reader(data);\n",
+        );
+    }
+
+    for event in &decodable {
+        let name = type_name(&event.name);
+
+        writeln!(
+            out,
+            "  if (equal({}, {}_{})) {{\n    return {{\n      name: '{name}',\n      args: {{",
+            if solana { "discriminator" } else { "topics[0]" },
+            const_name(&event.name),
+            if solana { "DISCRIMINATOR" } else { "TOPIC" },
+        )
+        .unwrap();
+
+        for field in &event.fields {
+            writeln!(
+                out,
+                "        {}: {},",
+                ident(&field.name),
+                decode(&field.ty, 0, ns)
+            )
+            .unwrap();
+        }
+
+        out.push_str("      },\n    };\n  }\n");
+    }
+
+    out.push_str("  return undefined;\n}\n");
+}
+
+/// The errors a call can revert with, identified by the selector at the start of the revert data
+fn errors(out: &mut String, interface: &Interface, ns: &Namespace) {
+    let mut variants = vec![
+        "  | { name: 'Error'; args: { message: string } }".to_owned(),
+        "  | { name: 'Panic'; args: { code: bigint } }".to_owned(),
+    ];
+
+    for error in &interface.errors {
+        variants.push(format!(
+            "  | {{ name: '{}'; args: {{ {} }} }}",
+            type_name(&error.name),
+            error
+                .fields
+                .iter()
+                .map(|field| format!("{}: {}", ident(&field.name), ts_type(&field.ty, ns)))
+                .collect::<Vec<_>>()
+                .join("; ")
+        ));
+    }
+
+    writeln!(
+        out,
+        "\n/** Errors the contract can revert with */\nexport type ContractError =\n{};",
+        variants.join("\n")
+    )
+    .unwrap();
+
+    out.push_str(
+        "\n/** Decode the revert data of a call */\nexport function decodeError(data: Uint8Array): ContractError | undefined {\n  const selector = data.slice(0, 4);\n  const r = new Reader(data.slice(4));\n",
+    );
+
+    let builtin = BUILTIN_ERRORS
+        .iter()
+        .zip(["message: r.string()", "code: r.uint(32)"])
+        .map(|((name, selector), args)| (name.to_string(), selector, args.to_owned()));
+
+    let custom = interface.errors.iter().map(|error| {
+        (
+            type_name(&error.name),
+            &error.selector,
+            error
+                .fields
+                .iter()
+                .map(|field| format!("{}: {}", ident(&field.name), decode(&field.ty, 0, ns)))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    });
+
+    for (name, selector, args) in builtin.chain(custom) {
+        writeln!(
+            out,
+            "  if (equal(selector, Uint8Array.from([{}]))) {{\n    return {{ name: '{name}', args: {{ {args} }} }};\n  }}",
+            hex_list(selector)
+        )
+        .unwrap();
+    }
+
+    out.push_str("  return undefined;\n}\n");
+}
+
+/// The docs as a JSDoc comment, or nothing if there are no docs
+fn doc_comment(docs: &str) -> String {
+    if docs.is_empty() {
+        String::new()
+    } else {
+        format!("/**\n{} */\n", doc_lines(docs, " * "))
+    }
+}
+
+fn params(params: &[Param], ns: &Namespace) -> String {
+    params
+        .iter()
+        .map(|param| format!("{}: {}", ident(&param.name), ts_type(&param.ty, ns)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A single return value is returned as is, multiple as a tuple
+fn return_type(returns: &[Param], ns: &Namespace) -> String {
+    if returns.len() == 1 {
+        ts_type(&returns[0].ty, ns)
+    } else {
+        format!(
+            "[{}]",
+            returns
+                .iter()
+                .map(|ret| ts_type(&ret.ty, ns))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Integers up to 32 bits are numbers, wider ones are bigints
+fn int_size(width: u16) -> (u16, bool) {
+    let size = width.next_power_of_two().max(8) / 8;
+
+    (size, size > 4)
+}
+
+fn ts_type(ty: &Type, ns: &Namespace) -> String {
+    match ty {
+        Type::Bool => "boolean".into(),
+        Type::Int(width) | Type::Uint(width) => {
+            if int_size(*width).1 {
+                "bigint".into()
+            } else {
+                "number".into()
+            }
+        }
+        Type::Address(_) | Type::Contract(_) => {
+            if ns.target == Target::Solana {
+                "PublicKey".into()
+            } else {
+                "Uint8Array".into()
+            }
+        }
+        Type::Bytes(_) | Type::DynamicBytes => "Uint8Array".into(),
+        Type::String => "string".into(),
+        Type::Enum(enum_no) => type_name(&ns.enums[*enum_no].id.name),
+        Type::Struct(StructType::UserDefined(struct_no)) => {
+            type_name(&ns.structs[*struct_no].id.name)
+        }
+        Type::Array(elem, dims) => format!("{}{}", ts_type(elem, ns), "[]".repeat(dims.len())),
+        Type::UserType(no) => ts_type(&ns.user_types[*no].ty, ns),
+        Type::Slice(elem) => format!("{}[]", ts_type(elem, ns)),
+        _ => unreachable!(),
+    }
+}
+
+/// Expression which writes `value` of the type to the Writer `w`. Array elements are named by
+/// the depth of the array, so nested arrays do not shadow each other.
+fn encode(ty: &Type, value: &str, depth: usize, ns: &Namespace) -> String {
+    match ty {
+        Type::Bool => format!("w.bool({value})"),
+        Type::Int(width) | Type::Uint(width) => format!("w.int({value}, {})", int_size(*width).0),
+        Type::Address(_) | Type::Contract(_) => {
+            if ns.target == Target::Solana {
+                format!("w.fixed({value}.toBytes(), 32)")
+            } else {
+                format!("w.fixed({value}, {})", ns.address_length)
+            }
+        }
+        Type::Bytes(n) => format!("w.fixed({value}, {n})"),
+        Type::DynamicBytes => format!("w.bytes({value})"),
+        Type::String => format!("w.string({value})"),
+        Type::Enum(_) => format!("w.int({value}, 1)"),
+        Type::Struct(StructType::UserDefined(struct_no)) => format!(
+            "write{}(w, {value})",
+            type_name(&ns.structs[*struct_no].id.name)
+        ),
+        Type::Array(elem, dims) => {
+            let elem_ty = if dims.len() == 1 {
+                elem.as_ref().clone()
+            } else {
+                Type::Array(elem.clone(), dims[..dims.len() - 1].to_vec())
+            };
+            let e = format!("e{depth}");
+            let inner = encode(&elem_ty, &e, depth + 1, ns);
+
+            match dims.last().unwrap() {
+                ArrayLength::Fixed(len) => format!("w.array({value}, {len}, ({e}) => {inner})"),
+                _ => format!("w.vec({value}, ({e}) => {inner})"),
+            }
+        }
+        Type::UserType(no) => encode(&ns.user_types[*no].ty, value, depth, ns),
+        Type::Slice(elem) => {
+            let e = format!("e{depth}");
+            format!(
+                "w.vec({value}, ({e}) => {})",
+                encode(elem, &e, depth + 1, ns)
+            )
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Expression which reads a value of the type from the Reader `r`
+fn decode(ty: &Type, depth: usize, ns: &Namespace) -> String {
+    match ty {
+        Type::Bool => "r.bool()".into(),
+        Type::Int(width) | Type::Uint(width) => {
+            let (size, bigint) = int_size(*width);
+            let read = if matches!(ty, Type::Int(_)) {
+                "int"
+            } else {
+                "uint"
+            };
+
+            if bigint {
+                format!("r.{read}({size})")
+            } else {
+                format!("Number(r.{read}({size}))")
+            }
+        }
+        Type::Address(_) | Type::Contract(_) => {
+            if ns.target == Target::Solana {
+                "new PublicKey(r.fixed(32))".into()
+            } else {
+                format!("r.fixed({})", ns.address_length)
+            }
+        }
+        Type::Bytes(n) => format!("r.fixed({n})"),
+        Type::DynamicBytes => "r.bytes()".into(),
+        Type::String => "r.string()".into(),
+        Type::Enum(enum_no) => format!(
+            "Number(r.uint(1)) as {}",
+            type_name(&ns.enums[*enum_no].id.name)
+        ),
+        Type::Struct(StructType::UserDefined(struct_no)) => {
+            format!("read{}(r)", type_name(&ns.structs[*struct_no].id.name))
+        }
+        Type::Array(elem, dims) => {
+            let elem_ty = if dims.len() == 1 {
+                elem.as_ref().clone()
+            } else {
+                Type::Array(elem.clone(), dims[..dims.len() - 1].to_vec())
+            };
+            let inner = decode(&elem_ty, depth + 1, ns);
+
+            match dims.last().unwrap() {
+                ArrayLength::Fixed(len) => format!("r.array({len}, () => {inner})"),
+                _ => format!("r.vec(() => {inner})"),
+            }
+        }
+        Type::UserType(no) => decode(&ns.user_types[*no].ty, depth, ns),
+        Type::Slice(elem) => format!("r.vec(() => {})", decode(elem, depth + 1, ns)),
+        _ => unreachable!(),
+    }
+}
+
+/// Names of functions, fields and parameters. Reserved words and the names used by the
+/// generated code get an underscore appended.
+fn ident(name: &str) -> String {
+    const RESERVED: &[&str] = &[
+        "await",
+        "break",
+        "case",
+        "catch",
+        "class",
+        "const",
+        "continue",
+        "debugger",
+        "default",
+        "delete",
+        "do",
+        "else",
+        "enum",
+        "export",
+        "extends",
+        "false",
+        "finally",
+        "for",
+        "function",
+        "if",
+        "implements",
+        "import",
+        "in",
+        "instanceof",
+        "interface",
+        "let",
+        "new",
+        "null",
+        "package",
+        "private",
+        "protected",
+        "public",
+        "return",
+        "static",
+        "super",
+        "switch",
+        "this",
+        "throw",
+        "true",
+        "try",
+        "typeof",
+        "var",
+        "void",
+        "while",
+        "with",
+        "yield",
+        "w",
+        "r",
+        "accounts",
+        "programId",
+    ];
+
+    let name = convert(name, Case::Camel);
+
+    if RESERVED.contains(&name.as_str()) {
+        format!("{name}_")
+    } else {
+        name
+    }
+}
+
+fn const_name(name: &str) -> String {
+    convert(name, Case::UpperSnake)
+}
+
+fn type_name(name: &str) -> String {
+    convert(name, Case::Pascal)
+}
@@ -14,6 +14,10 @@ use serde::Deserialize;
 use solang::{
     codegen::{OptimizationLevel, Options},
     file_resolver::FileResolver,
+    sema::{
+        ast::Code,
+        diagnostics::{CodeLevel, DiagnosticsConfig},
+    },
     Target,
 };
 use std::{ffi::OsString, path::PathBuf, process::exit};
@@ -34,6 +38,15 @@ pub enum Commands {
     #[command(about = "Generate documention for contracts using doc comments")]
     Doc(Doc),
 
+    #[command(about = "Check Solidity source files for common security issues")]
+    Check(Check),
+
+    #[command(about = "Check whether a new storage layout is compatible with an old one")]
+    LayoutDiff(LayoutDiff),
+
+    #[command(about = "Print the Solidity source locations of offsets in a compiled contract")]
+    Symbolize(Symbolize),
+
     #[command(about = "Print shell completion for various shells to STDOUT")]
     ShellComplete(ShellComplete),
 
@@ -46,6 +59,13 @@ pub enum Commands {
 
     #[command(about = "Create a new Solang project")]
     New(New),
+
+    #[command(about = "Generate typed Rust or TypeScript client bindings for contracts")]
+    Bindgen(Bindgen),
+
+    #[cfg(feature = "fmt")]
+    #[command(about = "Format Solidity source files")]
+    Fmt(Fmt),
 }
 
 #[derive(Args)]
@@ -76,6 +96,52 @@ pub struct LanguageServerCommand {
 
     #[arg(name = "IMPORTMAP", help = "Map directory to search for solidity files [format: map=path]",value_parser = ValueParser::new(parse_import_map), action = ArgAction::Append, long = "importmap", short = 'm', num_args = 1)]
     pub import_map: Option<Vec<(String, PathBuf)>>,
+
+    #[clap(flatten)]
+    pub diagnostics: DiagnosticLevels,
+}
+
+#[derive(Args)]
+pub struct LayoutDiff {
+    #[arg(name = "OLD", help = "Storage layout of the deployed contract", required = true, value_parser = ValueParser::path_buf(), num_args = 1)]
+    pub old: PathBuf,
+
+    #[arg(name = "NEW", help = "Storage layout of the upgraded contract", required = true, value_parser = ValueParser::path_buf(), num_args = 1)]
+    pub new: PathBuf,
+}
+
+#[cfg(feature = "fmt")]
+#[derive(Args)]
+pub struct Fmt {
+    #[arg(name = "INPUT", help = "Solidity files to format", value_parser = ValueParser::path_buf(), num_args = 1.., required = true)]
+    pub input: Vec<PathBuf>,
+
+    #[arg(name = "IMPORTPATH", help = "Directory to search for solidity files", value_parser = ValueParser::path_buf(), action = ArgAction::Append, long = "importpath", short = 'I', num_args = 1)]
+    pub import_path: Option<Vec<PathBuf>>,
+
+    #[arg(name = "IMPORTMAP", help = "Map directory to search for solidity files [format: map=path]",value_parser = ValueParser::new(parse_import_map), action = ArgAction::Append, long = "importmap", short = 'm', num_args = 1)]
+    pub import_map: Option<Vec<(String, PathBuf)>>,
+
+    #[arg(name = "CHECK", help = "Do not write the files, and fail if any file is not formatted", long = "check", action = ArgAction::SetTrue)]
+    pub check: bool,
+
+    #[arg(name = "DIFF", help = "Print the changes formatting would make; implies --check", long = "diff", action = ArgAction::SetTrue)]
+    pub diff: bool,
+
+    #[arg(name = "CONFFILE", help = "Take formatting options from the [fmt] section of the configuration file", long = "config-file", value_parser = ValueParser::os_string(), num_args = 1, default_value = "solang.toml")]
+    pub configuration_file: OsString,
+
+    #[arg(name = "VERBOSE" ,help = "show debug messages", short = 'v', action = ArgAction::SetTrue, long = "verbose")]
+    pub verbose: bool,
+}
+
+#[derive(Args)]
+pub struct Symbolize {
+    #[arg(name = "ARTIFACT", help = "Contract compiled with -g, or its source map", required = true, value_parser = ValueParser::path_buf(), num_args = 1)]
+    pub artifact: PathBuf,
+
+    #[arg(name = "OFFSET", help = "Offset in the Wasm module, or instruction index in the Solana program, in decimal or 0x hex", required = true, num_args = 1..)]
+    pub offsets: Vec<String>,
 }
 
 #[derive(Args)]
@@ -99,6 +165,42 @@ pub struct Doc {
     pub output_directory: Option<OsString>,
 }
 
+#[derive(Args)]
+pub struct Bindgen {
+    #[clap(flatten)]
+    pub package: DocPackage,
+
+    #[clap(flatten)]
+    pub target: TargetArg,
+
+    #[arg(name = "LANGUAGE", help = "Language of the bindings", long = "lang", required = true, num_args = 1, value_parser = ["rust", "typescript"])]
+    pub language: String,
+
+    #[arg(name = "VERBOSE" ,help = "show debug messages", short = 'v', action = ArgAction::SetTrue, long = "verbose")]
+    pub verbose: bool,
+
+    #[arg(name = "OUTPUT",help = "output directory", short = 'o', long = "output", num_args = 1, value_parser = ValueParser::os_string())]
+    pub output_directory: Option<OsString>,
+}
+
+#[derive(Args)]
+pub struct Check {
+    #[clap(flatten)]
+    pub package: DocPackage,
+
+    #[clap(flatten)]
+    pub target: TargetArg,
+
+    #[clap(flatten)]
+    pub diagnostics: DiagnosticLevels,
+
+    #[arg(name = "DIAGNOSTICS-FORMAT", help = "Format for errors and warnings; sarif is written to stdout", long = "diagnostics-format", num_args = 1, value_parser = ["text", "sarif"])]
+    pub diagnostics_format: Option<String>,
+
+    #[arg(name = "VERBOSE" ,help = "show debug messages", short = 'v', action = ArgAction::SetTrue, long = "verbose")]
+    pub verbose: bool,
+}
+
 #[derive(Args, Deserialize, Debug, PartialEq)]
 pub struct Compile {
     #[arg(name = "CONFFILE", help = "Take arguments from configuration file", long = "config-file", value_parser = ValueParser::os_string(), num_args = 0..=1, default_value = "solang.toml")]
@@ -126,6 +228,10 @@ pub struct Compile {
     #[clap(flatten)]
     #[serde(default = "Optimizations::default")]
     pub optimizations: Optimizations,
+
+    #[clap(flatten)]
+    #[serde(default = "DiagnosticLevels::default")]
+    pub diagnostics: DiagnosticLevels,
 }
 
 impl Compile {
@@ -178,6 +284,10 @@ impl Compile {
                 "VERBOSE" => {
                     self.compiler_output.verbose = *matches.get_one::<bool>("VERBOSE").unwrap()
                 }
+                "DIAGNOSTICS-FORMAT" => {
+                    self.compiler_output.diagnostics_format =
+                        matches.get_one::<String>("DIAGNOSTICS-FORMAT").cloned()
+                }
 
                 // DebugFeatures args
                 "NOLOGRUNTIMEERRORS" => {
@@ -217,6 +327,10 @@ impl Compile {
                         .get_one::<bool>("COMMONSUBEXPRESSIONELIMINATION")
                         .unwrap()
                 }
+                "INLINE" => self.optimizations.inline = *matches.get_one::<bool>("INLINE").unwrap(),
+                "DEADCODE" => {
+                    self.optimizations.dead_code = *matches.get_one::<bool>("DEADCODE").unwrap()
+                }
                 "OPT" => self.optimizations.opt_level = matches.get_one::<String>("OPT").cloned(),
 
                 "TARGET" => self.target_arg.name = matches.get_one::<String>("TARGET").cloned(),
@@ -228,6 +342,19 @@ impl Compile {
                     self.target_arg.value_length = matches.get_one::<u64>("VALUE_LENGTH").copied()
                 }
 
+                // DiagnosticLevels args
+                "ALLOW" | "WARN" | "DENY" => {
+                    let level = match id.as_str() {
+                        "ALLOW" => CodeLevel::Allow,
+                        "WARN" => CodeLevel::Warn,
+                        _ => CodeLevel::Deny,
+                    };
+
+                    for code in matches.get_many::<Code>(id.as_str()).unwrap() {
+                        self.diagnostics.set(*code, level);
+                    }
+                }
+
                 _ => {}
             }
         }
@@ -238,7 +365,7 @@ impl Compile {
 
 #[derive(Args, Deserialize, Default, Debug, PartialEq)]
 pub struct CompilerOutput {
-    #[arg(name = "EMIT", help = "Emit compiler state at early stage", long = "emit", num_args = 1, value_parser = ["ast-dot", "cfg", "llvm-ir", "llvm-bc", "object", "asm"])]
+    #[arg(name = "EMIT", help = "Emit compiler state at early stage", long = "emit", num_args = 1, value_parser = ["ast-dot", "ast-json", "cfg", "llvm-ir", "llvm-bc", "object", "asm", "storage-layout"])]
     #[serde(deserialize_with = "deserialize_emit", default)]
     pub emit: Option<String>,
 
@@ -246,6 +373,10 @@ pub struct CompilerOutput {
     #[serde(default)]
     pub std_json_output: bool,
 
+    #[arg(name = "DIAGNOSTICS-FORMAT", help = "Format for errors and warnings; sarif is written to stdout", long = "diagnostics-format", num_args = 1, value_parser = ["text", "sarif"], conflicts_with = "STD-JSON")]
+    #[serde(default, deserialize_with = "deserialize_diagnostics_format")]
+    pub diagnostics_format: Option<String>,
+
     #[arg(name = "OUTPUT",help = "output directory", short = 'o', long = "output", num_args = 1, value_parser =ValueParser::string())]
     #[serde(default)]
     pub output_directory: Option<String>,
@@ -261,7 +392,7 @@ pub struct CompilerOutput {
 
 #[derive(Args)]
 pub struct TargetArg {
-    #[arg(name = "TARGET",required= true, long = "target", value_parser = ["solana", "polkadot", "evm"], help = "Target to build for [possible values: solana, polkadot]", num_args = 1, hide_possible_values = true)]
+    #[arg(name = "TARGET",required= true, long = "target", value_parser = ["solana", "polkadot", "polkadot-revive", "evm"], help = "Target to build for [possible values: solana, polkadot, polkadot-revive]", num_args = 1, hide_possible_values = true)]
     pub name: String,
 
     #[arg(name = "ADDRESS_LENGTH", help = "Address length on the Polkadot Parachain", long = "address-length", num_args = 1, value_parser = value_parser!(u64).range(4..1024))]
@@ -273,7 +404,7 @@ pub struct TargetArg {
 
 #[derive(Args, Deserialize, Debug, PartialEq)]
 pub struct CompileTargetArg {
-    #[arg(name = "TARGET", long = "target", value_parser = ["solana", "polkadot", "evm", "soroban"], help = "Target to build for [possible values: solana, polkadot]", num_args = 1, hide_possible_values = true)]
+    #[arg(name = "TARGET", long = "target", value_parser = ["solana", "polkadot", "polkadot-revive", "evm", "soroban"], help = "Target to build for [possible values: solana, polkadot, polkadot-revive]", num_args = 1, hide_possible_values = true)]
     pub name: Option<String>,
 
     #[arg(name = "ADDRESS_LENGTH", help = "Address length on the Polkadot Parachain", long = "address-length", num_args = 1, value_parser = value_parser!(u64).range(4..1024))]
@@ -378,6 +509,14 @@ pub struct Optimizations {
     )]
     pub common_subexpression_elimination: bool,
 
+    #[arg(name = "INLINE", help = "Disable function inlining", long = "no-inline", action = ArgAction::SetFalse, display_order = 6)]
+    #[serde(default = "default_true", rename(deserialize = "inline"))]
+    pub inline: bool,
+
+    #[arg(name = "DEADCODE", help = "Disable dead code elimination", long = "no-dead-code", action = ArgAction::SetFalse, display_order = 7)]
+    #[serde(default = "default_true", rename(deserialize = "dead-code"))]
+    pub dead_code: bool,
+
     #[arg(name = "OPT", help = "Set llvm optimizer level ", short = 'O', default_value = "default", value_parser = ["none", "less", "default", "aggressive"], num_args = 1)]
     #[serde(rename(deserialize = "llvm-IR-optimization-level"))]
     pub opt_level: Option<String>,
@@ -393,6 +532,37 @@ pub struct Optimizations {
     pub wasm_opt_passes: Option<OptimizationPasses>,
 }
 
+#[derive(Args, Deserialize, Default, Debug, PartialEq)]
+pub struct DiagnosticLevels {
+    #[arg(name = "ALLOW", help = "Do not report warnings with this code, e.g. S5001", short = 'A', long = "allow", value_parser = ValueParser::new(parse_code), value_delimiter = ',', action = ArgAction::Append, num_args = 1)]
+    #[serde(default, deserialize_with = "deserialize_codes")]
+    pub allow: Vec<Code>,
+
+    #[arg(name = "WARN", help = "Report warnings with this code as warnings, overriding the configuration file", short = 'W', long = "warn", value_parser = ValueParser::new(parse_code), value_delimiter = ',', action = ArgAction::Append, num_args = 1)]
+    #[serde(default, deserialize_with = "deserialize_codes")]
+    pub warn: Vec<Code>,
+
+    #[arg(name = "DENY", help = "Report warnings with this code as errors", short = 'D', long = "deny", value_parser = ValueParser::new(parse_code), value_delimiter = ',', action = ArgAction::Append, num_args = 1)]
+    #[serde(default, deserialize_with = "deserialize_codes")]
+    pub deny: Vec<Code>,
+}
+
+impl DiagnosticLevels {
+    /// Set the level for a code, removing it from the other levels. This is used for codes
+    /// given on the command line, which take precedence over the configuration file.
+    fn set(&mut self, code: Code, level: CodeLevel) {
+        self.allow.retain(|c| *c != code);
+        self.warn.retain(|c| *c != code);
+        self.deny.retain(|c| *c != code);
+
+        match level {
+            CodeLevel::Allow => self.allow.push(code),
+            CodeLevel::Warn => self.warn.push(code),
+            CodeLevel::Deny => self.deny.push(code),
+        }
+    }
+}
+
 pub trait TargetArgTrait {
     fn get_name(&self) -> &String;
     fn get_address_length(&self) -> &Option<u64>;
@@ -435,7 +605,7 @@ impl TargetArgTrait for CompileTargetArg {
 pub(crate) fn target_arg<T: TargetArgTrait>(target_arg: &T) -> Target {
     let target_name = target_arg.get_name();
 
-    if target_name == "solana" || target_name == "evm" {
+    if target_name == "solana" || target_name == "evm" || target_name == "polkadot-revive" {
         if target_arg.get_address_length().is_some() {
             eprintln!("error: address length cannot be modified except for polkadot target");
             exit(1);
@@ -447,12 +617,20 @@ pub(crate) fn target_arg<T: TargetArgTrait>(target_arg: &T) -> Target {
         }
     }
 
+    if target_name == "polkadot-revive" && !cfg!(feature = "polkavm") {
+        eprintln!(
+            "error: polkadot-revive target requires solang to be built with the polkavm feature"
+        );
+        exit(1);
+    }
+
     let target = match target_name.as_str() {
         "solana" => solang::Target::Solana,
         "polkadot" => solang::Target::Polkadot {
             address_length: target_arg.get_address_length().unwrap_or(32) as usize,
             value_length: target_arg.get_value_length().unwrap_or(16) as usize,
         },
+        "polkadot-revive" => solang::Target::PolkadotRevive,
         "evm" => solang::Target::EVM,
         "soroban" => solang::Target::Soroban,
         _ => unreachable!(),
@@ -505,6 +683,21 @@ impl PackageTrait for DocPackage {
     }
 }
 
+#[cfg(feature = "fmt")]
+impl PackageTrait for Fmt {
+    fn get_input(&self) -> &Vec<PathBuf> {
+        &self.input
+    }
+
+    fn get_import_path(&self) -> &Option<Vec<PathBuf>> {
+        &self.import_path
+    }
+
+    fn get_import_map(&self) -> &Option<Vec<(String, PathBuf)>> {
+        &self.import_map
+    }
+}
+
 pub fn imports_arg<T: PackageTrait>(package: &T) -> FileResolver {
     let mut resolver = FileResolver::default();
 
@@ -564,6 +757,8 @@ pub fn options_arg(debug: &DebugFeatures, optimizations: &Optimizations) -> Opti
         strength_reduce: optimizations.strength_reduce,
         vector_to_slice: optimizations.vector_to_slice,
         common_subexpression_elimination: optimizations.common_subexpression_elimination,
+        inline: optimizations.inline,
+        dead_code: optimizations.dead_code,
         generate_debug_information: debug.generate_debug_info,
         opt_level,
         log_runtime_errors: debug.log_runtime_errors && !debug.release,
@@ -577,6 +772,29 @@ pub fn options_arg(debug: &DebugFeatures, optimizations: &Optimizations) -> Opti
     }
 }
 
+pub fn diagnostics_config_arg(levels: &DiagnosticLevels) -> DiagnosticsConfig {
+    let mut config = DiagnosticsConfig::default();
+
+    for (codes, level) in [
+        (&levels.allow, CodeLevel::Allow),
+        (&levels.warn, CodeLevel::Warn),
+        (&levels.deny, CodeLevel::Deny),
+    ] {
+        for code in codes {
+            if !code.is_warning() {
+                eprintln!(
+                    "error: {code} is not a warning code, only warnings can be allowed or denied"
+                );
+                exit(1);
+            }
+
+            config.set(*code, level);
+        }
+    }
+
+    config
+}
+
 // Parse the import map argument. This takes the form
 /// --import-map openzeppelin=/opt/openzeppelin-contracts/contract,
 /// and returns the name of the map and the path.
@@ -588,6 +806,10 @@ fn parse_import_map(map: &str) -> Result<(String, PathBuf), String> {
     }
 }
 
+fn parse_code(code: &str) -> Result<Code, String> {
+    code.parse()
+}
+
 fn parse_version(version: &str) -> Result<String, String> {
     match Version::parse(version) {
         Ok(version) => Ok(version.to_string()),
@@ -625,6 +847,18 @@ where
     }
 }
 
+fn deserialize_codes<'de, D>(deserializer: D) -> Result<Vec<Code>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let codes: Vec<String> = Vec::deserialize(deserializer)?;
+
+    codes
+        .iter()
+        .map(|code| code.parse().map_err(serde::de::Error::custom))
+        .collect()
+}
+
 fn deserialize_version<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -648,16 +882,29 @@ where
     match str {
         Some(value) => {
             match value.as_str() {
-                "ast-dot"|"cfg"|"llvm-ir"|"llvm-bc"|"object"|"asm" =>
+                "ast-dot"|"ast-json"|"cfg"|"llvm-ir"|"llvm-bc"|"object"|"asm"|"storage-layout" =>
                     Ok(Some(value))
                 ,
-                _ => Err(serde::de::Error::custom("Invalid option for `emit`. Valid options are: `ast-dot`, `cfg`, `llvm-ir`, `llvm-bc`, `object`, `asm`"))
+                _ => Err(serde::de::Error::custom("Invalid option for `emit`. Valid options are: `ast-dot`, `ast-json`, `cfg`, `llvm-ir`, `llvm-bc`, `object`, `asm`, `storage-layout`"))
             }
         }
         None => Ok(None),
     }
 }
 
+fn deserialize_diagnostics_format<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let str: Option<String> = Option::deserialize(deserializer)?;
+    match str.as_deref() {
+        Some("text" | "sarif") | None => Ok(str),
+        Some(_) => Err(serde::de::Error::custom(
+            "Invalid option for `diagnostics_format`. Valid options are: `text`, `sarif`",
+        )),
+    }
+}
+
 fn default_true() -> bool {
     true
 }
@@ -6,6 +6,8 @@ mod tests {
     use crate::{cli, options_arg, Cli, Commands};
     use clap::{CommandFactory, Parser};
     use solang::codegen::Options;
+    use solang::sema::ast::Code;
+    use solang::sema::diagnostics::CodeLevel;
     use std::path::PathBuf;
 
     #[test]
@@ -15,7 +17,7 @@ mod tests {
 
     #[test]
     fn parse_compile_options() {
-        let mut command: Vec<&str> = "solang compile flipper.sol --target polkadot --value-length=31 --address-length=33 --no-dead-storage --no-constant-folding --no-strength-reduce --no-vector-to-slice --no-cse -O aggressive".split(' ').collect();
+        let mut command: Vec<&str> = "solang compile flipper.sol --target polkadot --value-length=31 --address-length=33 --no-dead-storage --no-constant-folding --no-strength-reduce --no-vector-to-slice --no-cse --no-inline --no-dead-code -O aggressive".split(' ').collect();
         let mut cli = Cli::parse_from(command);
 
         if let Commands::Compile(compile_args) = cli.command {
@@ -27,6 +29,8 @@ mod tests {
             assert_eq!(compile_args.target_arg.address_length.unwrap(), 33_u64);
             assert_eq!(compile_args.target_arg.value_length.unwrap(), 31_u64);
             assert!(!compile_args.optimizations.common_subexpression_elimination,);
+            assert!(!compile_args.optimizations.inline);
+            assert!(!compile_args.optimizations.dead_code);
             assert!(!compile_args.optimizations.constant_folding);
             assert!(!compile_args.optimizations.dead_storage);
             assert!(!compile_args.optimizations.vector_to_slice);
@@ -111,11 +115,15 @@ mod tests {
         strength-reduce = false
         vector-to-slice = false
         common-subexpression-elimination = true
+        inline = false
+        dead-code = false
         llvm-IR-optimization-level = "aggressive""#;
 
         let opt: cli::Optimizations = toml::from_str(opt_toml).unwrap();
 
         assert!(opt.common_subexpression_elimination);
+        assert!(!opt.inline);
+        assert!(!opt.dead_code);
         assert!(!opt.dead_storage);
         assert!(!opt.constant_folding);
         assert!(!opt.strength_reduce);
@@ -154,6 +162,7 @@ mod tests {
         emit = "ast-dot"
         output_directory = "output"
         output_meta = "metadata"
+        diagnostics_format = "sarif"
         "#;
 
         let out: cli::CompilerOutput = toml::from_str(compiler_out).unwrap();
@@ -163,6 +172,7 @@ mod tests {
         assert_eq!(out.emit, Some("ast-dot".to_owned()));
         assert_eq!(out.output_directory, Some("output".to_owned()));
         assert_eq!(out.output_meta, Some("metadata".to_owned()));
+        assert_eq!(out.diagnostics_format, Some("sarif".to_owned()));
 
         let default_out: cli::CompilerOutput = toml::from_str("").unwrap();
 
@@ -170,6 +180,68 @@ mod tests {
         assert!(!default_out.std_json_output);
     }
 
+    #[test]
+    fn parse_diagnostics_toml() {
+        let toml = r#"
+        allow = ["S5001", "S5003"]
+        deny = ["S5009"]"#;
+
+        let mut levels: cli::DiagnosticLevels = toml::from_str(toml).unwrap();
+
+        assert_eq!(levels.allow, vec![Code(5001), Code(5003)]);
+        assert!(levels.warn.is_empty());
+        assert_eq!(levels.deny, vec![Code(5009)]);
+
+        let config = cli::diagnostics_config_arg(&levels);
+
+        assert_eq!(config.level(Code(5001)), Some(CodeLevel::Allow));
+        assert_eq!(config.level(Code(5009)), Some(CodeLevel::Deny));
+        assert_eq!(config.level(Code(5002)), None);
+
+        // codes on the command line take precedence
+        let command = "solang compile flipper.sol --target solana -W S5009".split(' ');
+        let matches = Cli::command().get_matches_from(command);
+        let mut compile: cli::Compile = toml::from_str(
+            r#"
+            [package]
+            [target]
+            [diagnostics]
+            deny = ["S5009"]"#,
+        )
+        .unwrap();
+        compile.overwrite_with_matches(matches.subcommand_matches("compile").unwrap());
+        levels = compile.diagnostics;
+
+        assert!(levels.deny.is_empty());
+        assert_eq!(levels.warn, vec![Code(5009)]);
+
+        let res: Result<cli::DiagnosticLevels, _> = toml::from_str(r#"allow = ["W5001"]"#);
+
+        match res {
+            Ok(_) => unreachable!(),
+            Err(error) => assert_eq!(
+                "'W5001' is not a diagnostic code, expected e.g. 'S5001'",
+                error.message()
+            ),
+        }
+    }
+
+    #[test]
+    fn language_server_diagnostics() {
+        let command = "solang language-server --target polkadot -A S5001 -D S5003".split(' ');
+        let cli = Cli::parse_from(command);
+
+        if let Commands::LanguageServer(server_args) = cli.command {
+            let config = cli::diagnostics_config_arg(&server_args.diagnostics);
+
+            assert_eq!(config.level(Code(5001)), Some(CodeLevel::Allow));
+            assert_eq!(config.level(Code(5003)), Some(CodeLevel::Deny));
+            assert_eq!(config.level(Code(5009)), None);
+        } else {
+            unreachable!();
+        }
+    }
+
     #[test]
     fn overwrite_with_matches() {
         let toml = include_str!("../../../examples/solana/solana_config.toml");
@@ -190,6 +262,7 @@ mod tests {
                 compiler_output: cli::CompilerOutput {
                     emit: None,
                     std_json_output: false,
+                    diagnostics_format: None,
                     output_directory: None,
                     output_meta: None,
                     verbose: false
@@ -211,14 +284,17 @@ mod tests {
                     strength_reduce: true,
                     vector_to_slice: true,
                     common_subexpression_elimination: true,
+                    inline: true,
+                    dead_code: true,
                     opt_level: Some("aggressive".to_owned()),
                     #[cfg(feature = "wasm_opt")]
                     wasm_opt_passes: None
-                }
+                },
+                diagnostics: cli::DiagnosticLevels::default()
             }
         );
 
-        let command = "solang compile flipper.sol sesa.sol --config-file solang.toml --contract-authors not_sesa --target polkadot --value-length=31 --address-length=33 --no-dead-storage --no-constant-folding --no-strength-reduce --no-vector-to-slice --no-cse -O aggressive".split(' ');
+        let command = "solang compile flipper.sol sesa.sol --config-file solang.toml --contract-authors not_sesa --target polkadot --value-length=31 --address-length=33 --no-dead-storage --no-constant-folding --no-strength-reduce --no-vector-to-slice --no-cse --no-inline --no-dead-code -O aggressive -D S5001,S5004 --allow S5003".split(' ');
 
         let matches = Cli::command().get_matches_from(command);
 
@@ -244,6 +320,7 @@ mod tests {
                 compiler_output: cli::CompilerOutput {
                     emit: None,
                     std_json_output: false,
+                    diagnostics_format: None,
                     output_directory: None,
                     output_meta: None,
                     verbose: false
@@ -265,9 +342,16 @@ mod tests {
                     strength_reduce: false,
                     vector_to_slice: false,
                     common_subexpression_elimination: false,
+                    inline: false,
+                    dead_code: false,
                     opt_level: Some("aggressive".to_owned()),
                     #[cfg(feature = "wasm_opt")]
                     wasm_opt_passes: None
+                },
+                diagnostics: cli::DiagnosticLevels {
+                    allow: vec![Code(5003)],
+                    warn: vec![],
+                    deny: vec![Code(5001), Code(5004)]
                 }
             }
         );
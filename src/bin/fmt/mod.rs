@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli::{imports_arg, Fmt};
+use diffy::DiffOptions;
+use forge_fmt::{format, parse, FormatterConfig};
+use std::{ffi::OsStr, fs, path::Path, process::exit};
+
+/// This subcommand formats Solidity files with the same formatter as the language server. With
+/// `--check` or `--diff`, the files are not written, and the exit code is non-zero if any file
+/// is not formatted.
+pub fn fmt(args: &Fmt) {
+    let mut resolver = imports_arg(args);
+    let config = formatter_config(&args.configuration_file);
+    let check = args.check || args.diff;
+    let mut success = true;
+
+    for filename in &args.input {
+        let file = match resolver.resolve_file(None, filename.as_os_str()) {
+            Ok(file) => file,
+            Err(err) => {
+                eprintln!("error: {err}");
+                success = false;
+                continue;
+            }
+        };
+
+        let formatted = match format_source(&file.contents, config.clone()) {
+            Ok(formatted) => formatted,
+            Err(errors) => {
+                for err in errors {
+                    eprintln!("{}: error: {}", filename.display(), err);
+                }
+                success = false;
+                continue;
+            }
+        };
+
+        if *formatted == *file.contents {
+            continue;
+        }
+
+        if check {
+            if args.diff {
+                let name = filename.display().to_string();
+                let patch = DiffOptions::new()
+                    .set_original_filename(format!("a/{name}"))
+                    .set_modified_filename(format!("b/{name}"))
+                    .create_patch(&file.contents, &formatted);
+
+                print!("{patch}");
+            }
+
+            eprintln!("{}: error: file is not formatted", filename.display());
+            success = false;
+        } else {
+            if args.verbose {
+                eprintln!("info: Formatting {}", file.full_path.display());
+            }
+
+            if let Err(err) = fs::write(&file.full_path, formatted) {
+                eprintln!("{}: error: {}", file.full_path.display(), err);
+                success = false;
+            }
+        }
+    }
+
+    if !success {
+        exit(1);
+    }
+}
+
+fn format_source(source: &str, config: FormatterConfig) -> Result<String, Vec<String>> {
+    let parsed = parse(source)
+        .map_err(|errors| errors.into_iter().map(|e| e.message).collect::<Vec<_>>())?;
+
+    let mut formatted = String::new();
+
+    format(&mut formatted, parsed, config).map_err(|err| vec![err.to_string()])?;
+
+    Ok(formatted)
+}
+
+/// The formatting options are the defaults of the language server, overridden by the `[fmt]`
+/// section of the configuration file. The options have the same names as in `foundry.toml`.
+fn formatter_config(path: &OsStr) -> FormatterConfig {
+    let config = FormatterConfig {
+        line_length: 80,
+        ..Default::default()
+    };
+
+    let path = Path::new(path);
+
+    if !path.exists() {
+        return config;
+    }
+
+    let error = |msg: String| -> ! {
+        eprintln!("{}: error: {}", path.display(), msg);
+        exit(1);
+    };
+
+    let toml_data = fs::read_to_string(path).unwrap_or_else(|err| error(err.to_string()));
+
+    let table: toml::Table =
+        toml::from_str(&toml_data).unwrap_or_else(|err| error(err.to_string()));
+
+    let Some(fmt) = table.get("fmt") else {
+        return config;
+    };
+
+    let Some(fmt) = fmt.as_table() else {
+        error("'fmt' should be a table".into());
+    };
+
+    let mut options = toml::Table::try_from(&config).unwrap();
+
+    for (name, value) in fmt {
+        if !options.contains_key(name) {
+            error(format!("unknown formatting option '{name}'"));
+        }
+
+        options.insert(name.clone(), value.clone());
+    }
+
+    options
+        .try_into()
+        .unwrap_or_else(|err| error(format!("invalid formatting options: {err}")))
+}
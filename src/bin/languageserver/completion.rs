@@ -0,0 +1,446 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Completion of NatSpec tags, annotations and import paths. These are found by looking at the
+//! text around the cursor rather than the namespace, since the file is usually incomplete
+//! while typing.
+
+use solang::{
+    sema::function_annotation::{function_annotations, parameter_annotations},
+    Target,
+};
+use solang_parser::pt::{FunctionTy, Visibility};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use tower_lsp::lsp_types::{CompletionItem, CompletionItemKind};
+
+/// What is being completed at a position in a file
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum Site<'a> {
+    /// The path in an import directive, as far as it has been typed
+    ImportPath(&'a str),
+    /// A tag in a doc comment
+    DocTag,
+    /// The name of a parameter after `@param`
+    DocParam,
+    /// The name of a base contract after `@inheritdoc`
+    DocBase,
+    /// An annotation on a function or contract
+    Annotation,
+    /// An annotation on a parameter, in a parameter list which starts on the line of the cursor
+    ParameterAnnotation,
+}
+
+/// The tags which can be used in the doc comment of a declaration, and their arguments
+#[derive(Debug, Clone, Default)]
+pub(super) struct DocItem {
+    /// Parameters or fields which can be named with `@param`, if the tag is permitted
+    pub params: Option<Vec<String>>,
+    /// Whether `@return` is permitted
+    pub returns: bool,
+    /// Base contracts which can be named with `@inheritdoc`
+    pub bases: Vec<String>,
+}
+
+/// Convert a position in the text of a file to a byte offset. The character of an LSP position
+/// is counted in UTF-16 code units.
+pub(super) fn offset(text: &str, line: usize, character: usize) -> Option<usize> {
+    let mut start = 0;
+
+    for (no, l) in text.split_inclusive('\n').enumerate() {
+        if no == line {
+            let mut units = 0;
+
+            for (byte, ch) in l.char_indices() {
+                if units == character {
+                    return Some(start + byte);
+                } else if units > character {
+                    // the position is inside a surrogate pair
+                    return None;
+                }
+                units += ch.len_utf16();
+            }
+
+            return (units == character).then_some(start + l.len());
+        }
+        start += l.len();
+    }
+
+    // the cursor may be on an empty last line
+    (text.split_inclusive('\n').count() == line && character == 0).then_some(start)
+}
+
+/// Find out whether the cursor is somewhere we can complete something other than code
+pub(super) fn site(text: &str, offset: usize) -> Option<Site<'_>> {
+    let before = text.get(..offset)?;
+    let line = &before[before.rfind('\n').map_or(0, |n| n + 1)..];
+    let trimmed = line.trim_start();
+
+    if trimmed
+        .strip_prefix("import")
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_whitespace() || c == '"' || c == '\''))
+    {
+        // the cursor is in the string if it is preceded by an odd number of quotes
+        if line.matches(['"', '\'']).count() % 2 == 1 {
+            let start = line.rfind(['"', '\''])? + 1;
+
+            return Some(Site::ImportPath(&line[start..]));
+        }
+
+        return None;
+    }
+
+    if trimmed.starts_with("///") || trimmed.starts_with("/**") || trimmed.starts_with('*') {
+        let mut words = line.rsplit(char::is_whitespace);
+
+        if words.next()?.starts_with('@') {
+            return Some(Site::DocTag);
+        }
+
+        return match words.find(|word| !word.is_empty()) {
+            Some("@param") => Some(Site::DocParam),
+            Some("@inheritdoc") => Some(Site::DocBase),
+            _ => None,
+        };
+    }
+
+    if let Some(name) = trimmed.strip_prefix('@') {
+        if name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Some(Site::Annotation);
+        }
+    }
+
+    let word = line
+        .rsplit(|c: char| c.is_whitespace() || c == '(' || c == ',')
+        .next()?;
+
+    if let Some(name) = word.strip_prefix('@') {
+        if name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && line.matches('(').count() > line.matches(')').count()
+        {
+            return Some(Site::ParameterAnnotation);
+        }
+    }
+
+    None
+}
+
+/// The tags which can be used in a doc comment for the item
+pub(super) fn doc_tags(item: &DocItem) -> Vec<CompletionItem> {
+    let mut tags = vec!["notice", "dev", "author", "title", "custom:"];
+
+    if item.params.is_some() {
+        tags.push("param");
+    }
+
+    if item.returns {
+        tags.push("return");
+    }
+
+    if !item.bases.is_empty() {
+        tags.push("inheritdoc");
+    }
+
+    tags.into_iter()
+        .map(|tag| CompletionItem {
+            label: tag.to_string(),
+            kind: Some(CompletionItemKind::KEYWORD),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Suggest the names of parameters or base contracts
+pub(super) fn names(names: Option<&[String]>, kind: CompletionItemKind) -> Vec<CompletionItem> {
+    names
+        .unwrap_or_default()
+        .iter()
+        .map(|name| CompletionItem {
+            label: name.clone(),
+            kind: Some(kind),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// The annotations which are permitted on the declaration following the line of the cursor
+pub(super) fn annotations(text: &str, offset: usize, target: Target) -> Vec<CompletionItem> {
+    let declaration = next_declaration(text, offset);
+    let has_body = declaration.ends_with('{');
+    let words: Vec<&str> = declaration
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    let ty = match words.first() {
+        Some(&"contract") if target == Target::Solana => {
+            return vec![annotation("program_id")];
+        }
+        Some(&"function") => FunctionTy::Function,
+        Some(&"constructor") => FunctionTy::Constructor,
+        Some(&"fallback") => FunctionTy::Fallback,
+        Some(&"receive") => FunctionTy::Receive,
+        _ => return Vec::new(),
+    };
+
+    let visibility = if words.contains(&"external") {
+        Visibility::External(None)
+    } else if words.contains(&"public") || ty == FunctionTy::Constructor {
+        Visibility::Public(None)
+    } else if words.contains(&"private") {
+        Visibility::Private(None)
+    } else {
+        Visibility::Internal(None)
+    };
+
+    function_annotations(ty, &visibility, has_body, target)
+        .into_iter()
+        .map(annotation)
+        .collect()
+}
+
+/// The annotations which are permitted on the parameters of the function declared on the line of
+/// the cursor
+pub(super) fn parameter_annotation_items(
+    text: &str,
+    offset: usize,
+    target: Target,
+) -> Vec<CompletionItem> {
+    let line = &text[text[..offset].rfind('\n').map_or(0, |n| n + 1)..offset];
+
+    let ty = match line
+        .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .find(|word| !word.is_empty())
+    {
+        Some("function") => FunctionTy::Function,
+        Some("constructor") => FunctionTy::Constructor,
+        _ => return Vec::new(),
+    };
+
+    parameter_annotations(ty, target)
+        .into_iter()
+        .map(annotation)
+        .collect()
+}
+
+fn annotation(name: &str) -> CompletionItem {
+    CompletionItem {
+        label: name.to_string(),
+        kind: Some(CompletionItemKind::PROPERTY),
+        ..Default::default()
+    }
+}
+
+/// The text of the declaration after the line of the offset, up to the start of its body or the
+/// terminating semicolon. Annotations, comments and empty lines before it are skipped.
+fn next_declaration(text: &str, offset: usize) -> &str {
+    let rest = &text[offset..];
+    let mut start = rest.find('\n').map_or(rest.len(), |n| n + 1);
+
+    for line in rest[start..].split_inclusive('\n') {
+        let trimmed = line.trim();
+
+        if !(trimmed.is_empty()
+            || trimmed.starts_with('@')
+            || trimmed.starts_with("//")
+            || trimmed.starts_with("/*")
+            || trimmed.starts_with('*'))
+        {
+            break;
+        }
+
+        start += line.len();
+    }
+
+    let rest = &rest[start..];
+
+    match rest.find(['{', ';']) {
+        Some(end) => rest[..=end].trim_start(),
+        None => rest.trim_start(),
+    }
+}
+
+/// The files and directories which can be imported, given the path typed so far. Relative paths
+/// are looked up from the directory of the file; other paths in the import paths and maps.
+pub(super) fn import_paths(
+    typed: &str,
+    file: &Path,
+    import_paths: &[PathBuf],
+    import_maps: &[(String, PathBuf)],
+) -> Vec<CompletionItem> {
+    // the directory part of the path; the rest is filtered by the client
+    let dir = &typed[..typed.rfind('/').map_or(0, |n| n + 1)];
+
+    let mut items = Vec::new();
+
+    if dir.starts_with("./") || dir.starts_with("../") {
+        if let Some(parent) = file.parent() {
+            list_dir(&parent.join(dir), &mut items);
+        }
+    } else {
+        for path in file
+            .parent()
+            .into_iter()
+            .chain(import_paths.iter().map(|p| p.as_path()))
+        {
+            list_dir(&path.join(dir), &mut items);
+        }
+
+        for (map, path) in import_maps {
+            if dir.is_empty() {
+                items.push(CompletionItem {
+                    label: format!("{map}/"),
+                    kind: Some(CompletionItemKind::MODULE),
+                    ..Default::default()
+                });
+            } else if let Some(rest) = dir.strip_prefix(map.as_str()) {
+                if let Some(rest) = rest.strip_prefix('/') {
+                    list_dir(&path.join(rest), &mut items);
+                }
+            }
+        }
+    }
+
+    items.sort_by(|a, b| a.label.cmp(&b.label));
+    items.dedup_by(|a, b| a.label == b.label);
+
+    items
+}
+
+/// Add the Solidity files and the directories in a directory
+fn list_dir(dir: &Path, items: &mut Vec<CompletionItem>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        if name.starts_with('.') {
+            continue;
+        }
+
+        if entry.path().is_dir() {
+            items.push(CompletionItem {
+                label: format!("{name}/"),
+                kind: Some(CompletionItemKind::FOLDER),
+                ..Default::default()
+            });
+        } else if name.ends_with(".sol") {
+            items.push(CompletionItem {
+                label: name,
+                kind: Some(CompletionItemKind::FILE),
+                ..Default::default()
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sites() {
+        let text = "import \"./lib/\";\n/// @param a\n/// @inh\n    @pay\ncontract c {}\n";
+
+        assert_eq!(site(text, 14), Some(Site::ImportPath("./lib/")));
+        assert_eq!(site(text, 6), None);
+        assert_eq!(site(text, 28), Some(Site::DocParam));
+        assert_eq!(site(text, 29), Some(Site::DocParam));
+        assert_eq!(site(text, 38), Some(Site::DocTag));
+        assert_eq!(site(text, 47), Some(Site::Annotation));
+        assert_eq!(site(text, 60), None);
+
+        let text = "constructor(@seed bytes a, @b";
+
+        assert_eq!(site(text, 13), Some(Site::ParameterAnnotation));
+        assert_eq!(site(text, text.len()), Some(Site::ParameterAnnotation));
+        assert_eq!(site(text, 22), None);
+
+        assert_eq!(offset(text, 1, 4), Some(21));
+        assert_eq!(offset(text, 5, 0), Some(text.len()));
+        assert_eq!(offset(text, 6, 0), None);
+
+        // positions are in UTF-16 code units
+        let text = "/// é 😀 @\n";
+
+        assert_eq!(offset(text, 0, 6), Some(7));
+        assert_eq!(offset(text, 0, 9), Some(12));
+        assert_eq!(offset(text, 0, 8), Some(11));
+        assert_eq!(offset(text, 0, 7), None);
+        assert_eq!(offset(text, 0, 11), Some(text.len()));
+        assert_eq!(offset(text, 0, 12), None);
+        assert_eq!(site(text, 13), Some(Site::DocTag));
+
+        // the import keyword must be followed by whitespace or a quote
+        let text = "import\"a/\";\nimports \"b/\";\nimportant 'c/';\n";
+
+        assert_eq!(site(text, 9), Some(Site::ImportPath("a/")));
+        assert_eq!(site(text, 23), None);
+        assert_eq!(site(text, 39), None);
+    }
+
+    #[test]
+    fn annotations_for_declaration() {
+        let labels = |text: &str, target| {
+            annotations(text, 0, target)
+                .into_iter()
+                .map(|item| item.label)
+                .collect::<Vec<_>>()
+        };
+
+        let text = "@\n@seed(\"a\")\n// comment\nconstructor(address a) {\n}";
+
+        assert_eq!(
+            labels(text, Target::Solana),
+            [
+                "account",
+                "signer",
+                "mutableAccount",
+                "mutableSigner",
+                "payer",
+                "seed",
+                "bump",
+                "space"
+            ]
+        );
+        assert_eq!(labels(text, Target::default_polkadot()), ["selector"]);
+        assert!(labels(text, Target::EVM).is_empty());
+
+        let text = "@\nfunction f() external returns (uint64) {}";
+
+        assert_eq!(
+            labels(text, Target::Solana),
+            [
+                "selector",
+                "account",
+                "signer",
+                "mutableAccount",
+                "mutableSigner"
+            ]
+        );
+
+        let text = "@\nfunction f() internal;";
+
+        assert!(labels(text, Target::Solana).is_empty());
+
+        let text = "@\ncontract c {}";
+
+        assert_eq!(labels(text, Target::Solana), ["program_id"]);
+
+        let labels = |text: &str, target| {
+            parameter_annotation_items(text, text.len(), target)
+                .into_iter()
+                .map(|item| item.label)
+                .collect::<Vec<_>>()
+        };
+
+        let text = "contract c {\n    constructor(@seed bytes a, @";
+
+        assert_eq!(labels(text, Target::Solana), ["seed", "bump", "space"]);
+        assert!(labels(text, Target::default_polkadot()).is_empty());
+        assert!(labels("function f(@", Target::Solana).is_empty());
+    }
+}
@@ -13,8 +13,10 @@ use solang::{
         ast::{self, RetrieveType, StructType, Type},
         builtin::{get_prototype, BUILTIN_FUNCTIONS, BUILTIN_METHODS, BUILTIN_VARIABLE},
         builtin_structs::BUILTIN_STRUCTS,
+        diagnostics::DiagnosticsConfig,
         symtable,
         tags::render,
+        Imports,
     },
     Target,
 };
@@ -22,7 +24,8 @@ use solang_parser::pt;
 use std::{
     collections::{HashMap, HashSet},
     ffi::OsString,
-    path::PathBuf,
+    future::Future,
+    path::{Path, PathBuf},
 };
 use tokio::sync::Mutex;
 use tower_lsp::{
@@ -32,24 +35,27 @@ use tower_lsp::{
             GotoDeclarationParams, GotoDeclarationResponse, GotoImplementationParams,
             GotoImplementationResponse, GotoTypeDefinitionParams, GotoTypeDefinitionResponse,
         },
-        CompletionContext, CompletionItem, CompletionOptions, CompletionParams, CompletionResponse,
-        CompletionTriggerKind, DeclarationCapability, Diagnostic, DiagnosticRelatedInformation,
-        DiagnosticSeverity, DidChangeConfigurationParams, DidChangeTextDocumentParams,
-        DidChangeWatchedFilesParams, DidChangeWorkspaceFoldersParams, DidCloseTextDocumentParams,
-        DidOpenTextDocumentParams, DidSaveTextDocumentParams, DocumentFormattingParams,
-        ExecuteCommandOptions, ExecuteCommandParams, GotoDefinitionParams, GotoDefinitionResponse,
-        Hover, HoverContents, HoverParams, HoverProviderCapability,
-        ImplementationProviderCapability, InitializeParams, InitializeResult, InitializedParams,
-        Location, MarkedString, MessageType, OneOf, Position, Range, ReferenceParams, RenameParams,
-        ServerCapabilities, SignatureHelpOptions, TextDocumentContentChangeEvent,
-        TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit,
-        TypeDefinitionProviderCapability, Url, WorkspaceEdit, WorkspaceFoldersServerCapabilities,
-        WorkspaceServerCapabilities,
+        CompletionContext, CompletionItem, CompletionItemKind, CompletionOptions, CompletionParams,
+        CompletionResponse, CompletionTriggerKind, DeclarationCapability, Diagnostic,
+        DiagnosticRelatedInformation, DiagnosticSeverity, DidChangeConfigurationParams,
+        DidChangeTextDocumentParams, DidChangeWatchedFilesParams, DidChangeWorkspaceFoldersParams,
+        DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
+        DocumentFormattingParams, ExecuteCommandOptions, ExecuteCommandParams,
+        GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverContents, HoverParams,
+        HoverProviderCapability, ImplementationProviderCapability, InitializeParams,
+        InitializeResult, InitializedParams, Location, MarkedString, MessageType, NumberOrString,
+        OneOf, Position, Range, ReferenceParams, RenameParams, ServerCapabilities,
+        SignatureHelpOptions, TextDocumentContentChangeEvent, TextDocumentSyncCapability,
+        TextDocumentSyncKind, TextEdit, TypeDefinitionProviderCapability, Url, WorkspaceEdit,
+        WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
     },
     Client, LanguageServer, LspService, Server,
 };
 
-use crate::cli::{target_arg, LanguageServerCommand};
+use crate::cli::{diagnostics_config_arg, target_arg, LanguageServerCommand};
+use completion::{DocItem, Site};
+
+mod completion;
 
 /// Represents the type of the code object that a reference points to
 /// Here "code object" refers to contracts, functions, structs, enums etc., that are defined and used within a namespace.
@@ -135,6 +141,9 @@ struct FileCache {
     references: Lapper<usize, DefinitionIndex>,
     scopes: Lapper<usize, Vec<(String, Option<DefinitionIndex>)>>,
     top_level_code_objects: HashMap<String, Option<DefinitionIndex>>,
+    /// The tags which can be used in the doc comments of declarations, by the start of the
+    /// declaration
+    doc_items: Vec<(usize, DocItem)>,
 }
 
 /// Stores information used by the language server to service requests (eg: `Go to Definitions`) received from the client.
@@ -194,8 +203,18 @@ pub struct SolangServer {
     target: Target,
     importpaths: Vec<PathBuf>,
     importmaps: Vec<(String, PathBuf)>,
+    /// Warnings to allow or deny, from the command line
+    diagnostics_config: DiagnosticsConfig,
     files: Mutex<Files>,
     global_cache: Mutex<GlobalCache>,
+    /// A resolver for each file which has been parsed. This caches the contents and parse trees of
+    /// the file and its imports, so that unchanged files are not read and parsed on every edit.
+    /// The namespace of the resolved imports is kept with it, so that the imports are only
+    /// resolved again when one of them has changed.
+    resolvers: Mutex<HashMap<PathBuf, (FileResolver, Option<Imports>)>>,
+    /// Incremented on every change of a file, so that results of an analysis which has been
+    /// superseded by a later edit can be discarded
+    versions: Mutex<HashMap<PathBuf, u64>>,
 }
 
 #[tokio::main(flavor = "current_thread")]
@@ -219,14 +238,18 @@ pub async fn start_server(language_args: &LanguageServerCommand) -> ! {
     let stdout = tokio::io::stdout();
 
     let target = target_arg(&language_args.target);
+    let diagnostics_config = diagnostics_config_arg(&language_args.diagnostics);
 
     let (service, socket) = LspService::new(|client| SolangServer {
         client,
         target,
         importpaths,
         importmaps,
+        diagnostics_config,
         files: Mutex::new(Default::default()),
         global_cache: Mutex::new(Default::default()),
+        resolvers: Mutex::new(Default::default()),
+        versions: Mutex::new(Default::default()),
     });
 
     Server::new(stdin, stdout, socket).serve(service).await;
@@ -235,88 +258,204 @@ pub async fn start_server(language_args: &LanguageServerCommand) -> ! {
 }
 
 impl SolangServer {
-    /// Parse file
+    /// Parse and resolve the file. The diagnostics and caches are updated twice: once resolving
+    /// is done, and again once codegen has found any further errors and warnings. Codegen runs in
+    /// the background, and its results are discarded if the file has been changed in the meantime.
+    /// If neither the file nor any of its imports have changed since the last time, the results
+    /// of the last time are still valid, and nothing is done.
     async fn parse_file(&self, uri: Url) {
-        let mut resolver = FileResolver::default();
+        let path = match uri.to_file_path() {
+            Ok(path) => path,
+            Err(_) => return,
+        };
+
+        let resolver = self.resolvers.lock().await.remove(&path);
+        let (mut resolver, imports) = resolver.unwrap_or_else(|| (self.new_resolver(&path), None));
+
         for (path, contents) in &self.files.lock().await.text_buffers {
             resolver.set_file_contents(path.to_str().unwrap(), contents.clone());
         }
-        if let Ok(path) = uri.to_file_path() {
-            let dir = path.parent().unwrap();
 
-            resolver.add_import_path(dir);
+        if !resolver.is_modified() {
+            // the version is not changed, so that codegen of the last analysis still publishes
+            self.resolvers
+                .lock()
+                .await
+                .insert(path, (resolver, imports));
+            return;
+        }
 
-            let mut diags = Vec::new();
+        let version = {
+            let mut versions = self.versions.lock().await;
+            let version = versions.entry(path.clone()).or_default();
+            *version += 1;
+            *version
+        };
 
-            for path in &self.importpaths {
-                resolver.add_import_path(path);
-            }
+        let filename = path.file_name().unwrap().to_owned();
+        let target = self.target;
 
-            for (map, path) in &self.importmaps {
-                resolver.add_import_map(OsString::from(map), PathBuf::from(path));
+        let (mut ns, mut resolver, imports) = tokio::task::spawn_blocking(move || {
+            // reuse the resolved imports if they have not changed, else resolve them again
+            if let Some(ns) = imports
+                .as_ref()
+                .and_then(|imports| imports.parse_and_resolve(&filename, &mut resolver))
+            {
+                return (ns, resolver, imports);
             }
 
-            let os_str = path.file_name().unwrap();
+            let imports = Imports::resolve(&filename, &mut resolver, target);
+
+            let ns = imports
+                .as_ref()
+                .and_then(|imports| imports.parse_and_resolve(&filename, &mut resolver))
+                .unwrap_or_else(|| parse_and_resolve(&filename, &mut resolver, target));
+
+            (ns, resolver, imports)
+        })
+        .await
+        .unwrap();
+
+        ns.diagnostics.apply_config(&self.diagnostics_config);
+
+        let stale = self.is_stale(&path, version).await;
 
-            let mut ns = parse_and_resolve(os_str, &mut resolver, self.target);
+        if !stale {
+            // the results for these files are about to be published
+            resolver.clear_modified();
+        }
+
+        self.resolvers
+            .lock()
+            .await
+            .insert(path.clone(), (resolver, imports));
+
+        if stale {
+            return;
+        }
 
-            // codegen all the contracts; some additional errors/warnings will be detected here
+        self.publish(uri.clone(), &ns).await;
+
+        if ns.diagnostics.any_errors() {
+            // codegen does not run if there are errors
+            return;
+        }
+
+        // codegen all the contracts; some additional errors/warnings will be detected here
+        let mut ns = tokio::task::spawn_blocking(move || {
             codegen(&mut ns, &Default::default());
 
-            diags.extend(ns.diagnostics.iter().filter_map(|diag| {
-                if diag.loc.file_no() != ns.top_file_no() {
-                    // The first file is the one we wanted to parse; others are imported
+            ns
+        })
+        .await
+        .unwrap();
+
+        ns.diagnostics.apply_config(&self.diagnostics_config);
+
+        if self.is_stale(&path, version).await {
+            return;
+        }
+
+        self.publish(uri, &ns).await;
+    }
+
+    /// Create a resolver for a file, which searches the directory of the file, and the import
+    /// paths and maps given on the command line
+    fn new_resolver(&self, path: &Path) -> FileResolver {
+        let mut resolver = FileResolver::default();
+
+        resolver.enable_parse_cache();
+
+        resolver.add_import_path(path.parent().unwrap());
+
+        for path in &self.importpaths {
+            resolver.add_import_path(path);
+        }
+
+        for (map, path) in &self.importmaps {
+            resolver.add_import_map(OsString::from(map), PathBuf::from(path));
+        }
+
+        resolver
+    }
+
+    /// Has the file been changed since the analysis with the given version was started
+    async fn is_stale(&self, path: &Path, version: u64) -> bool {
+        self.versions.lock().await.get(path) != Some(&version)
+    }
+
+    /// Publish the diagnostics for the file and update the caches used for requests. Everything
+    /// is taken from the namespace before the returned future runs, since the namespace cannot be
+    /// shared between threads.
+    fn publish<'a>(&'a self, uri: Url, ns: &ast::Namespace) -> impl Future<Output = ()> + 'a {
+        let mut diags = Vec::new();
+
+        diags.extend(ns.diagnostics.iter().filter_map(|diag| {
+            if diag.loc.file_no() != ns.top_file_no() {
+                // The first file is the one we wanted to parse; others are imported
+                return None;
+            }
+
+            let severity = match diag.level {
+                ast::Level::Info => Some(DiagnosticSeverity::INFORMATION),
+                ast::Level::Warning => Some(DiagnosticSeverity::WARNING),
+                ast::Level::Error => Some(DiagnosticSeverity::ERROR),
+                ast::Level::Debug => {
                     return None;
                 }
+            };
 
-                let severity = match diag.level {
-                    ast::Level::Info => Some(DiagnosticSeverity::INFORMATION),
-                    ast::Level::Warning => Some(DiagnosticSeverity::WARNING),
-                    ast::Level::Error => Some(DiagnosticSeverity::ERROR),
-                    ast::Level::Debug => {
-                        return None;
-                    }
-                };
+            let related_information = if diag.notes.is_empty() {
+                None
+            } else {
+                Some(
+                    diag.notes
+                        .iter()
+                        .map(|note| DiagnosticRelatedInformation {
+                            message: note.message.to_string(),
+                            location: Location {
+                                uri: Url::from_file_path(&ns.files[note.loc.file_no()].path)
+                                    .unwrap(),
+                                range: loc_to_range(&note.loc, &ns.files[ns.top_file_no()]),
+                            },
+                        })
+                        .collect(),
+                )
+            };
 
-                let related_information = if diag.notes.is_empty() {
-                    None
-                } else {
-                    Some(
-                        diag.notes
-                            .iter()
-                            .map(|note| DiagnosticRelatedInformation {
-                                message: note.message.to_string(),
-                                location: Location {
-                                    uri: Url::from_file_path(&ns.files[note.loc.file_no()].path)
-                                        .unwrap(),
-                                    range: loc_to_range(&note.loc, &ns.files[ns.top_file_no()]),
-                                },
-                            })
-                            .collect(),
-                    )
-                };
+            let range = loc_to_range(&diag.loc, &ns.files[ns.top_file_no()]);
 
-                let range = loc_to_range(&diag.loc, &ns.files[ns.top_file_no()]);
+            let code = if diag.level == ast::Level::Info {
+                None
+            } else {
+                Some(NumberOrString::String(diag.code.to_string()))
+            };
 
-                Some(Diagnostic {
-                    range,
-                    message: diag.message.to_string(),
-                    severity,
-                    related_information,
-                    ..Default::default()
-                })
-            }));
+            Some(Diagnostic {
+                range,
+                message: diag.message.to_string(),
+                severity,
+                code,
+                related_information,
+                ..Default::default()
+            })
+        }));
 
-            let res = self.client.publish_diagnostics(uri, diags, None);
+        let (file_caches, global_cache) = Builder::new(ns).build();
 
-            let (file_caches, global_cache) = Builder::new(&ns).build();
+        let file_caches: Vec<_> = ns
+            .files
+            .iter()
+            .zip(file_caches)
+            .filter(|(f, _)| f.cache_no.is_some())
+            .map(|(f, c)| (f.path.clone(), c))
+            .collect();
+
+        async move {
+            let res = self.client.publish_diagnostics(uri, diags, None);
 
             let mut files = self.files.lock().await;
-            for (f, c) in ns.files.iter().zip(file_caches.into_iter()) {
-                if f.cache_no.is_some() {
-                    files.caches.insert(f.path.clone(), c);
-                }
-            }
+            files.caches.extend(file_caches);
 
             let mut gc = self.global_cache.lock().await;
             gc.extend(global_cache);
@@ -363,6 +502,7 @@ struct Builder<'a> {
     references: Vec<(usize, ReferenceEntry)>,
     scopes: Vec<(usize, ScopeEntry)>,
     top_level_code_objects: Vec<(usize, (String, Option<DefinitionIndex>))>,
+    doc_items: Vec<(usize, (usize, DocItem))>,
 
     definitions: Definitions,
     types: Types,
@@ -380,6 +520,7 @@ impl<'a> Builder<'a> {
             references: Vec::new(),
             scopes: Vec::new(),
             top_level_code_objects: Vec::new(),
+            doc_items: Vec::new(),
 
             definitions: HashMap::new(),
             types: HashMap::new(),
@@ -1197,6 +1338,12 @@ impl<'a> Builder<'a> {
                 if let Some(gas) = &call_args.gas {
                     self.expression(gas, symtab);
                 }
+                if let Some(proof_size) = &call_args.proof_size {
+                    self.expression(proof_size, symtab);
+                }
+                if let Some(storage_deposit_limit) = &call_args.storage_deposit_limit {
+                    self.expression(storage_deposit_limit, symtab);
+                }
             }
             ast::Expression::ExternalFunctionCallRaw {
                 address,
@@ -1212,6 +1359,12 @@ impl<'a> Builder<'a> {
                 if let Some(gas) = &call_args.gas {
                     self.expression(gas, symtab);
                 }
+                if let Some(proof_size) = &call_args.proof_size {
+                    self.expression(proof_size, symtab);
+                }
+                if let Some(storage_deposit_limit) = &call_args.storage_deposit_limit {
+                    self.expression(storage_deposit_limit, symtab);
+                }
             }
             ast::Expression::Constructor {
                 args, call_args, ..
@@ -1219,6 +1372,12 @@ impl<'a> Builder<'a> {
                 if let Some(gas) = &call_args.gas {
                     self.expression(gas, symtab);
                 }
+                if let Some(proof_size) = &call_args.proof_size {
+                    self.expression(proof_size, symtab);
+                }
+                if let Some(storage_deposit_limit) = &call_args.storage_deposit_limit {
+                    self.expression(storage_deposit_limit, symtab);
+                }
                 for arg in args {
                     self.expression(arg, symtab);
                 }
@@ -1329,6 +1488,49 @@ impl<'a> Builder<'a> {
         }
     }
 
+    /// Record which tags can be used in the doc comment of a declaration. Functions and state
+    /// variables in a contract can inherit the documentation of a base contract.
+    fn doc_item(
+        &mut self,
+        loc: &pt::Loc,
+        params: Option<&[ast::Parameter<Type>]>,
+        returns: bool,
+        contract_no: Option<usize>,
+    ) {
+        if !matches!(loc, pt::Loc::File(..)) {
+            return;
+        }
+
+        let bases = contract_no
+            .map(|contract_no| {
+                self.ns
+                    .contract_bases(contract_no)
+                    .into_iter()
+                    .filter(|base_no| *base_no != contract_no)
+                    .map(|base_no| self.ns.contracts[base_no].id.name.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        self.doc_items.push((
+            loc.file_no(),
+            (
+                loc.start(),
+                DocItem {
+                    params: params.map(|params| {
+                        params
+                            .iter()
+                            .map(|param| param.name_as_str().to_string())
+                            .filter(|name| !name.is_empty())
+                            .collect()
+                    }),
+                    returns,
+                    bases,
+                },
+            ),
+        ));
+    }
+
     // Constructs struct fields and stores it in the lookup table.
     fn field(&mut self, id: usize, field_id: usize, field: &ast::Parameter<Type>) {
         if let Some(loc) = field.ty_loc {
@@ -1440,6 +1642,8 @@ impl<'a> Builder<'a> {
                     self.field(si, fi, field);
                 }
 
+                self.doc_item(&struct_decl.loc, Some(&struct_decl.fields), false, None);
+
                 let file_no = struct_decl.id.loc.file_no();
                 let file = &self.ns.files[file_no];
                 self.hovers.push((
@@ -1487,6 +1691,8 @@ impl<'a> Builder<'a> {
                 continue;
             }
 
+            self.doc_item(&func.loc, Some(&func.params), true, func.contract_no);
+
             if let Some(bump) = &func.annotations.bump {
                 self.expression(&bump.1, &func.symtable);
             }
@@ -1668,8 +1874,11 @@ impl<'a> Builder<'a> {
             for (i, variable) in contract.variables.iter().enumerate() {
                 let symtable = symtable::Symtable::default();
                 self.contract_variable(variable, &symtable, Some(ci), i);
+                self.doc_item(&variable.loc, None, false, Some(ci));
             }
 
+            self.doc_item(&contract.loc, None, false, None);
+
             let file_no = contract.loc.file_no();
             let file = &self.ns.files[file_no];
             self.hovers.push((
@@ -1846,6 +2055,8 @@ impl<'a> Builder<'a> {
                 self.field(ei, fi, field);
             }
 
+            self.doc_item(&event.loc, Some(&event.fields), false, None);
+
             let file_no = event.id.loc.file_no();
             let file = &self.ns.files[file_no];
             self.hovers.push((
@@ -1870,6 +2081,10 @@ impl<'a> Builder<'a> {
             }
         }
 
+        for error in &self.ns.errors {
+            self.doc_item(&error.loc, Some(&error.fields), false, None);
+        }
+
         for lookup in &mut self.hovers {
             if let Some(msg) =
                 self.ns
@@ -1987,6 +2202,13 @@ impl<'a> Builder<'a> {
                         code_object.1.clone()
                     })
                     .collect(),
+                doc_items: self
+                    .doc_items
+                    .iter()
+                    .filter(|item| item.0 == i)
+                    .map(|(_, item)| item.clone())
+                    .sorted_by_key(|(start, _)| *start)
+                    .collect(),
             })
             .collect();
 
@@ -2066,7 +2288,7 @@ impl LanguageServer for SolangServer {
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 completion_provider: Some(CompletionOptions {
                     resolve_provider: Some(false),
-                    trigger_characters: Some(vec![".".to_string()]),
+                    trigger_characters: Some([".", "@", "\"", "/"].map(String::from).to_vec()),
                     all_commit_characters: None,
                     work_done_progress_options: Default::default(),
                     completion_item: None,
@@ -2130,6 +2352,9 @@ impl LanguageServer for SolangServer {
     }
 
     async fn did_change_watched_files(&self, _: DidChangeWatchedFilesParams) {
+        // files on disk may have changed, so the resolvers must read them again
+        self.resolvers.lock().await.clear();
+
         self.client
             .log_message(MessageType::INFO, "watched files have changed!")
             .await;
@@ -2204,6 +2429,10 @@ impl LanguageServer for SolangServer {
             let mut files = self.files.lock().await;
             files.caches.remove(&path);
             files.text_buffers.remove(&path);
+
+            // other resolvers may have the contents of the editor rather than the file on disk
+            self.resolvers.lock().await.clear();
+            self.versions.lock().await.remove(&path);
         }
 
         self.client.publish_diagnostics(uri, vec![], None).await;
@@ -2216,6 +2445,9 @@ impl LanguageServer for SolangServer {
     ///       associated with the `.` which triggered the request.
     /// * All other cases where the request is raised by user typing characters other than `.`
     ///     - Here, we return a list of variables, structs, enums, contracts, functions etc. accessible from the current scope.
+    ///
+    /// Inside doc comments, annotations and import directives, NatSpec tags, annotations and
+    /// import paths are suggested instead.
     async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
         let uri = params.text_document_position.text_document.uri;
         let path = uri.to_file_path().map_err(|_| Error {
@@ -2238,6 +2470,53 @@ impl LanguageServer for SolangServer {
             )
             .unwrap();
 
+        // In doc comments, annotations and import directives, we complete from the text
+        // around the cursor since the file is not going to be valid Solidity while typing
+        if let Some(text_buf) = files.text_buffers.get(&path) {
+            let position = params.text_document_position.position;
+
+            if let Some(text_offset) =
+                completion::offset(text_buf, position.line as _, position.character as _)
+            {
+                if let Some(site) = completion::site(text_buf, text_offset) {
+                    // the declaration which follows the doc comment
+                    let doc_item = cache
+                        .doc_items
+                        .iter()
+                        .find(|(start, _)| *start >= offset)
+                        .map(|(_, item)| item);
+
+                    let suggestions = match site {
+                        Site::ImportPath(typed) => completion::import_paths(
+                            typed,
+                            &path,
+                            &self.importpaths,
+                            &self.importmaps,
+                        ),
+                        Site::DocTag => doc_item.map(completion::doc_tags).unwrap_or_default(),
+                        Site::DocParam => completion::names(
+                            doc_item.and_then(|item| item.params.as_deref()),
+                            CompletionItemKind::VARIABLE,
+                        ),
+                        Site::DocBase => completion::names(
+                            doc_item.map(|item| item.bases.as_slice()),
+                            CompletionItemKind::CLASS,
+                        ),
+                        Site::Annotation => {
+                            completion::annotations(text_buf, text_offset, self.target)
+                        }
+                        Site::ParameterAnnotation => completion::parameter_annotation_items(
+                            text_buf,
+                            text_offset,
+                            self.target,
+                        ),
+                    };
+
+                    return Ok(Some(CompletionResponse::Array(suggestions)));
+                }
+            }
+        }
+
         let builtin_functions = BUILTIN_FUNCTIONS
             .iter()
             .filter(|function| function.target.is_empty() || function.target.contains(&self.target))
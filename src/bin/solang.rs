@@ -10,8 +10,11 @@ use solang::{
     codegen::{codegen, Options},
     emit::Generate,
     file_resolver::FileResolver,
+    sarif::SarifLog,
     sema::{ast::Namespace, file::PathDisplay},
+    sourcemap::SourceMap,
     standard_json::{EwasmContract, JsonContract, JsonResult},
+    storage_layout::{self, StorageLayout},
 };
 use std::{
     collections::{HashMap, HashSet},
@@ -23,12 +26,15 @@ use std::{
 };
 
 use crate::cli::{
-    imports_arg, options_arg, target_arg, Cli, Commands, Compile, CompilerOutput, Doc, New,
-    ShellComplete,
+    diagnostics_config_arg, imports_arg, options_arg, target_arg, Check, Cli, Commands, Compile,
+    CompilerOutput, Doc, LayoutDiff, New, ShellComplete, Symbolize,
 };
 
+mod bindgen;
 mod cli;
 mod doc;
+#[cfg(feature = "fmt")]
+mod fmt;
 mod idl;
 #[cfg(feature = "language_server")]
 mod languageserver;
@@ -40,6 +46,7 @@ fn main() {
 
     match cli.command {
         Commands::Doc(doc_args) => doc(doc_args),
+        Commands::Check(check_args) => check(check_args),
         Commands::Compile(compile_args) => {
             // Read config from configuration file. If extra args exist, only overwrite the fields that the user explicitly provides.
             let config = if let Some(conf_file) = &compile_args.configuration_file {
@@ -58,11 +65,16 @@ fn main() {
             };
             compile(&config)
         }
+        Commands::LayoutDiff(layout_diff_args) => layout_diff(layout_diff_args),
+        Commands::Symbolize(symbolize_args) => symbolize(symbolize_args),
         Commands::ShellComplete(shell_args) => shell_complete(Cli::command(), shell_args),
         #[cfg(feature = "language_server")]
         Commands::LanguageServer(server_args) => languageserver::start_server(&server_args),
         Commands::Idl(idl_args) => idl::idl(&idl_args),
         Commands::New(new_arg) => new_command(new_arg),
+        Commands::Bindgen(bindgen_args) => bindgen::bindgen(&bindgen_args),
+        #[cfg(feature = "fmt")]
+        Commands::Fmt(fmt_args) => fmt::fmt(&fmt_args),
     }
 }
 
@@ -155,6 +167,137 @@ fn doc(doc_args: Doc) {
     }
 }
 
+fn check(check_args: Check) {
+    let target = target_arg(&check_args.target);
+    let mut resolver = imports_arg(&check_args.package);
+
+    let diagnostics_config = diagnostics_config_arg(&check_args.diagnostics);
+
+    let sarif = check_args.diagnostics_format.as_deref() == Some("sarif");
+
+    let mut sarif_results = Vec::new();
+    let mut errors = false;
+
+    for filename in &check_args.package.input {
+        let mut ns = solang::parse_and_resolve(filename.as_os_str(), &mut resolver, target);
+
+        // the reentrancy lint needs the control flow graphs
+        codegen(&mut ns, &Options::default());
+
+        solang::lint::check(&mut ns);
+
+        ns.diagnostics.apply_config(&diagnostics_config);
+
+        if sarif {
+            sarif_results.extend(ns.diagnostics_as_sarif(&resolver));
+        } else {
+            ns.print_diagnostics(&resolver, check_args.verbose);
+        }
+
+        if ns.diagnostics.any_errors() {
+            errors = true;
+        }
+    }
+
+    if sarif {
+        let log = SarifLog::new(sarif_results);
+        println!("{}", serde_json::to_string_pretty(&log).unwrap());
+    }
+
+    if errors {
+        exit(1);
+    }
+}
+
+fn read_storage_layout(path: &Path) -> StorageLayout {
+    let json = match fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(err) => {
+            eprintln!("{}: error: {}", path.display(), err);
+            exit(1);
+        }
+    };
+
+    match serde_json::from_str(&json) {
+        Ok(layout) => layout,
+        Err(err) => {
+            eprintln!(
+                "{}: error: not a valid storage layout: {}",
+                path.display(),
+                err
+            );
+            exit(1);
+        }
+    }
+}
+
+fn layout_diff(layout_diff_args: LayoutDiff) {
+    let old = read_storage_layout(&layout_diff_args.old);
+    let new = read_storage_layout(&layout_diff_args.new);
+
+    let incompatibilities = storage_layout::compare(&old, &new);
+
+    if incompatibilities.is_empty() {
+        eprintln!(
+            "info: storage layout of contract {} is compatible",
+            new.contract
+        );
+        return;
+    }
+
+    for incompatibility in &incompatibilities {
+        eprintln!("error: {incompatibility}");
+    }
+
+    exit(1);
+}
+
+fn symbolize(symbolize_args: Symbolize) {
+    let path = &symbolize_args.artifact;
+
+    let contents = match fs::read(path) {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("{}: error: {}", path.display(), err);
+            exit(1);
+        }
+    };
+
+    let source_map = if path.extension().is_some_and(|ext| ext == "json") {
+        serde_json::from_slice(&contents).map_err(|err| format!("not a valid source map: {err}"))
+    } else {
+        SourceMap::from_binary(&contents)
+    };
+
+    let source_map = match source_map {
+        Ok(source_map) => source_map,
+        Err(err) => {
+            eprintln!("{}: error: {}", path.display(), err);
+            exit(1);
+        }
+    };
+
+    for arg in &symbolize_args.offsets {
+        let offset = match arg.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16),
+            None => arg.parse(),
+        };
+
+        let Ok(offset) = offset else {
+            eprintln!("error: '{arg}' is not a valid offset");
+            exit(1);
+        };
+
+        match source_map.lookup(offset) {
+            Some(loc) if loc.column != 0 => {
+                println!("{arg}: {}:{}:{}", loc.file, loc.line, loc.column)
+            }
+            Some(loc) => println!("{arg}: {}:{}", loc.file, loc.line),
+            None => println!("{arg}: unknown"),
+        }
+    }
+}
+
 fn compile(compile_args: &Compile) {
     let target = target_arg(&compile_args.target_arg);
 
@@ -173,6 +316,8 @@ fn compile(compile_args: &Compile) {
 
     let opt = options_arg(&compile_args.debug_features, &compile_args.optimizations);
 
+    let diagnostics_config = diagnostics_config_arg(&compile_args.diagnostics);
+
     let mut namespaces = Vec::new();
 
     let mut errors = false;
@@ -201,10 +346,18 @@ fn compile(compile_args: &Compile) {
 
     let std_json = compile_args.compiler_output.std_json_output;
 
-    for ns in &namespaces {
+    let sarif = compile_args.compiler_output.diagnostics_format.as_deref() == Some("sarif");
+
+    let mut sarif_results = Vec::new();
+
+    for ns in &mut namespaces {
+        ns.diagnostics.apply_config(&diagnostics_config);
+
         if std_json {
             let mut out = ns.diagnostics_as_json(&resolver);
             json.errors.append(&mut out);
+        } else if sarif {
+            sarif_results.extend(ns.diagnostics_as_sarif(&resolver));
         } else {
             ns.print_diagnostics(&resolver, compile_args.compiler_output.verbose);
         }
@@ -214,7 +367,12 @@ fn compile(compile_args: &Compile) {
         }
     }
 
-    if let Some("ast-dot") = compile_args.compiler_output.emit.as_deref() {
+    if sarif {
+        let log = SarifLog::new(sarif_results);
+        println!("{}", serde_json::to_string_pretty(&log).unwrap());
+    }
+
+    if let Some("ast-dot" | "ast-json") = compile_args.compiler_output.emit.as_deref() {
         exit(0);
     }
 
@@ -342,6 +500,24 @@ fn process_file(
         }
     }
 
+    if let Some("ast-json") = compiler_output.emit.as_deref() {
+        let stem = filepath.file_stem().unwrap().to_string_lossy();
+        let json_filename = output_file(compiler_output, &stem, "ast.json", false);
+
+        if verbose {
+            eprintln!("info: Saving json ast {}", json_filename.display());
+        }
+
+        let json = serde_json::to_string_pretty(&ns.ast_json()).unwrap();
+
+        let mut file = create_file(&json_filename);
+
+        if let Err(err) = file.write_all(json.as_bytes()) {
+            eprintln!("{}: error: {}", json_filename.display(), err);
+            exit(1);
+        }
+    }
+
     ns
 }
 
@@ -389,6 +565,30 @@ fn contract_results(
         return;
     }
 
+    if let Some("storage-layout") = compiler_output.emit.as_deref() {
+        let layout_filename = output_file(
+            compiler_output,
+            &resolved_contract.id.name,
+            "layout.json",
+            false,
+        );
+
+        if verbose {
+            eprintln!(
+                "info: Saving storage layout {} for contract {}",
+                layout_filename.display(),
+                resolved_contract.id
+            );
+        }
+
+        let layout = StorageLayout::new(contract_no, ns);
+
+        let mut file = create_file(&layout_filename);
+        file.write_all(serde_json::to_string_pretty(&layout).unwrap().as_bytes())
+            .unwrap();
+        return;
+    }
+
     if verbose {
         if ns.target == solang::Target::Solana {
             eprintln!(
@@ -414,7 +614,10 @@ fn contract_results(
     let code = binary.code(Generate::Linked).expect("llvm build");
 
     #[cfg(feature = "wasm_opt")]
-    if let Some(level) = opt.wasm_opt.filter(|_| ns.target.is_polkadot() && verbose) {
+    if let Some(level) = opt
+        .wasm_opt
+        .filter(|_| ns.target.is_polkadot() && !ns.target.is_polkadot_revive() && verbose)
+    {
         eprintln!(
             "info: wasm-opt level '{}' for contract {}",
             level, resolved_contract.id
@@ -466,7 +669,38 @@ fn contract_results(
 
         let mut file = create_file(&meta_filename);
         file.write_all(metadata.as_bytes()).unwrap();
+
+        if opt.generate_debug_information
+            && (ns.target == solang::Target::Solana
+                || (ns.target.is_polkadot() && !ns.target.is_polkadot_revive()))
+        {
+            save_source_map(compiler_output, &binary.name, &code);
+        }
+    }
+}
+
+fn save_source_map(compiler_output: &CompilerOutput, name: &str, code: &[u8]) {
+    let source_map = match SourceMap::from_binary(code) {
+        Ok(source_map) => source_map,
+        Err(err) => {
+            eprintln!("warning: no source map for contract {name}: {err}");
+            return;
+        }
+    };
+
+    let map_filename = output_file(compiler_output, name, "sourcemap.json", false);
+
+    if compiler_output.verbose {
+        eprintln!(
+            "info: Saving source map {} for contract {}",
+            map_filename.display(),
+            name
+        );
     }
+
+    let mut file = create_file(&map_filename);
+    file.write_all(serde_json::to_string(&source_map).unwrap().as_bytes())
+        .unwrap();
 }
 
 fn save_intermediates(
@@ -555,7 +789,7 @@ fn save_intermediates(
             true
         }
         Some("cfg") => true,
-        Some("ast-dot") => true,
+        Some("ast-dot" | "ast-json") => true,
         _ => false,
     }
 }
@@ -2,7 +2,7 @@
 
 use super::statements::{statement, LoopScopes};
 use super::{
-    constant_folding, dead_storage,
+    constant_folding, dead_code, dead_storage,
     expression::expression,
     reaching_definitions, strength_reduce,
     vartable::{Vars, Vartable},
@@ -70,14 +70,20 @@ pub enum Instr {
         res: usize,
         ty: Type,
         storage: Expression,
+        transient: bool,
     },
     /// Clear storage at slot for ty (might span multiple slots)
-    ClearStorage { ty: Type, storage: Expression },
+    ClearStorage {
+        ty: Type,
+        storage: Expression,
+        transient: bool,
+    },
     /// Set storage value at slot
     SetStorage {
         ty: Type,
         value: Expression,
         storage: Expression,
+        transient: bool,
     },
     /// In storage slot, set the value at the offset
     SetStorageBytes {
@@ -123,6 +129,8 @@ pub enum Instr {
         encoded_args: Expression,
         value: Option<Expression>,
         gas: Expression,
+        proof_size: Option<Expression>,
+        storage_deposit_limit: Option<Expression>,
         salt: Option<Expression>,
         address: Option<Expression>,
         seeds: Option<Expression>,
@@ -140,6 +148,8 @@ pub enum Instr {
         payload: Expression,
         value: Expression,
         gas: Expression,
+        proof_size: Option<Expression>,
+        storage_deposit_limit: Option<Expression>,
         callty: CallTy,
         contract_function_no: Option<(usize, usize)>,
         flags: Option<Expression>,
@@ -280,6 +290,8 @@ impl Instr {
                 encoded_args,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
                 salt,
                 address,
                 accounts,
@@ -291,6 +303,14 @@ impl Instr {
                 }
                 gas.recurse(cx, f);
 
+                if let Some(expr) = proof_size {
+                    expr.recurse(cx, f);
+                }
+
+                if let Some(expr) = storage_deposit_limit {
+                    expr.recurse(cx, f);
+                }
+
                 if let Some(expr) = salt {
                     expr.recurse(cx, f);
                 }
@@ -309,6 +329,8 @@ impl Instr {
                 payload,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
                 ..
             } => {
                 if let Some(expr) = address {
@@ -317,6 +339,14 @@ impl Instr {
                 payload.recurse(cx, f);
                 value.recurse(cx, f);
                 gas.recurse(cx, f);
+
+                if let Some(expr) = proof_size {
+                    expr.recurse(cx, f);
+                }
+
+                if let Some(expr) = storage_deposit_limit {
+                    expr.recurse(cx, f);
+                }
             }
 
             Instr::ValueTransfer { address, value, .. } => {
@@ -1027,19 +1057,36 @@ impl ControlFlowGraph {
                 true_block,
                 false_block,
             ),
-            Instr::LoadStorage { ty, res, storage } => format!(
-                "%{} = load storage slot({}) ty:{}",
+            Instr::LoadStorage {
+                ty,
+                res,
+                storage,
+                transient,
+            } => format!(
+                "%{} = load {}storage slot({}) ty:{}",
                 self.vars[res].id.name,
+                if *transient { "transient " } else { "" },
                 self.expr_to_string(contract, ns, storage),
                 ty.to_string(ns),
             ),
-            Instr::ClearStorage { ty, storage } => format!(
-                "clear storage slot({}) ty:{}",
+            Instr::ClearStorage {
+                ty,
+                storage,
+                transient,
+            } => format!(
+                "clear {}storage slot({}) ty:{}",
+                if *transient { "transient " } else { "" },
                 self.expr_to_string(contract, ns, storage),
                 ty.to_string(ns),
             ),
-            Instr::SetStorage { ty, value, storage } => format!(
-                "store storage slot({}) ty:{} = {}",
+            Instr::SetStorage {
+                ty,
+                value,
+                storage,
+                transient,
+            } => format!(
+                "store {}storage slot({}) ty:{} = {}",
+                if *transient { "transient " } else { "" },
                 self.expr_to_string(contract, ns, storage),
                 ty.to_string(ns),
                 self.expr_to_string(contract, ns, value),
@@ -1180,10 +1227,13 @@ impl ControlFlowGraph {
                 gas,
                 callty,
                 contract_function_no,
-                flags, ..
+                flags,
+                proof_size,
+                storage_deposit_limit,
+                ..
             } => {
                 format!(
-                    "{} = external call::{} address:{} payload:{} value:{} gas:{} accounts:{} seeds:{} contract|function:{} flags:{}",
+                    "{} = external call::{} address:{} payload:{} value:{} gas:{} accounts:{} seeds:{} contract|function:{} flags:{} proof_size:{} storage_deposit_limit:{}",
                     match success {
                         Some(i) => format!("%{}", self.vars[i].id.name),
                         None => "_".to_string(),
@@ -1212,7 +1262,9 @@ impl ControlFlowGraph {
                     } else {
                         "_".to_string()
                     },
-                    flags.as_ref().map(|e| self.expr_to_string(contract, ns, e)).unwrap_or_default()
+                    flags.as_ref().map(|e| self.expr_to_string(contract, ns, e)).unwrap_or_default(),
+                    proof_size.as_ref().map(|e| self.expr_to_string(contract, ns, e)).unwrap_or_default(),
+                    storage_deposit_limit.as_ref().map(|e| self.expr_to_string(contract, ns, e)).unwrap_or_default()
                 )
             }
             Instr::ValueTransfer {
@@ -1247,9 +1299,11 @@ impl ControlFlowGraph {
                 address,seeds,
                 accounts,
                 constructor_no,
+                proof_size,
+                storage_deposit_limit,
                 loc:_
             } => format!(
-                "%{}, {} = constructor(no: {}) salt:{} value:{} gas:{} address:{} seeds:{} {} encoded buffer: {} accounts: {}",
+                "%{}, {} = constructor(no: {}) salt:{} value:{} gas:{} address:{} seeds:{} {} encoded buffer: {} accounts: {} proof_size:{} storage_deposit_limit:{}",
                 self.vars[res].id.name,
                 match success {
                     Some(i) => format!("%{}", self.vars[i].id.name),
@@ -1284,7 +1338,9 @@ impl ControlFlowGraph {
                     self.expr_to_string(contract, ns, accounts)
                 } else {
                     String::new()
-                }
+                },
+                proof_size.as_ref().map(|e| self.expr_to_string(contract, ns, e)).unwrap_or_default(),
+                storage_deposit_limit.as_ref().map(|e| self.expr_to_string(contract, ns, e)).unwrap_or_default()
             ),
             Instr::SelfDestruct { recipient } => format!(
                 "selfdestruct {}",
@@ -1566,7 +1622,10 @@ pub fn optimize_and_check_cfg(
     // constant folding generates diagnostics, so always run it. This means that the diagnostics
     // do not depend which passes are enabled. If the constant_folding is not enabled, run it
     // dry mode.
-    constant_folding::constant_folding(cfg, !opt.constant_folding, ns);
+    let constant_conditions = constant_folding::constant_folding(cfg, !opt.constant_folding, ns);
+    if let ASTFunction::SolidityFunction(func_no) = func_no {
+        dead_code::constant_conditions(func_no, &constant_conditions, ns);
+    }
     if opt.vector_to_slice {
         vector_to_slice::vector_to_slice(cfg, ns);
     }
@@ -1581,6 +1640,9 @@ pub fn optimize_and_check_cfg(
     if opt.common_subexpression_elimination && func_no != ASTFunction::None {
         common_sub_expression_elimination(cfg, ns);
     }
+    if opt.dead_code {
+        dead_code::dead_code(cfg);
+    }
 }
 
 /// Generate the CFG for a function. If function_no is None, generate the implicit default
@@ -2099,6 +2161,7 @@ impl Contract {
         if let Some(layout) = self
             .layout
             .iter()
+            .chain(self.transient_layout.iter())
             .find(|l| l.contract_no == var_contract_no && l.var_no == var_no)
         {
             Expression::NumberLiteral {
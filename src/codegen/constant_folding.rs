@@ -12,7 +12,7 @@ use num_traits::{ToPrimitive, Zero};
 use ripemd::Ripemd160;
 use sha2::{Digest, Sha256};
 use solang_parser::pt;
-use solang_parser::pt::Loc;
+use solang_parser::pt::{CodeLocation, Loc};
 use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Rem, Shl, Shr, Sub};
 use tiny_keccak::{Hasher, Keccak};
 
@@ -20,7 +20,15 @@ use tiny_keccak::{Hasher, Keccak};
 /// like divide by zero, so this function may add diagnostics to the namespace. If dry_run
 /// is true, then diagnostics are generated but the CFG is not modified. This
 ///
-pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Namespace) {
+/// The location and value of each branch condition which was found to be constant is returned,
+/// so that the caller can warn about it.
+pub fn constant_folding(
+    cfg: &mut ControlFlowGraph,
+    dry_run: bool,
+    ns: &mut Namespace,
+) -> Vec<(Loc, bool)> {
+    let mut constant_conditions = Vec::new();
+
     // for each block, instruction
     for block_no in 0..cfg.blocks.len() {
         let mut vars = cfg.blocks[block_no].defs.clone();
@@ -77,8 +85,16 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                     true_block,
                     false_block,
                 } => {
+                    let loc = cond.loc();
+                    let literal = matches!(cond, Expression::BoolLiteral { .. });
                     let (cond, _) = expression(cond, Some(&vars), cfg, ns);
 
+                    if let Expression::BoolLiteral { value, .. } = cond {
+                        if !literal {
+                            constant_conditions.push((loc, value));
+                        }
+                    }
+
                     if !dry_run {
                         if let Expression::BoolLiteral { value: cond, .. } = cond {
                             cfg.blocks[block_no].instr[instr_no] = Instr::Branch {
@@ -119,17 +135,27 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                         cfg.blocks[block_no].instr[instr_no] = Instr::Print { expr };
                     }
                 }
-                Instr::ClearStorage { ty, storage } => {
+                Instr::ClearStorage {
+                    ty,
+                    storage,
+                    transient,
+                } => {
                     let (storage, _) = expression(storage, Some(&vars), cfg, ns);
 
                     if !dry_run {
                         cfg.blocks[block_no].instr[instr_no] = Instr::ClearStorage {
                             ty: ty.clone(),
                             storage,
+                            transient: *transient,
                         };
                     }
                 }
-                Instr::SetStorage { ty, storage, value } => {
+                Instr::SetStorage {
+                    ty,
+                    storage,
+                    value,
+                    transient,
+                } => {
                     let (storage, _) = expression(storage, Some(&vars), cfg, ns);
                     let (value, _) = expression(value, Some(&vars), cfg, ns);
 
@@ -138,10 +164,16 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                             ty: ty.clone(),
                             storage,
                             value,
+                            transient: *transient,
                         };
                     }
                 }
-                Instr::LoadStorage { ty, storage, res } => {
+                Instr::LoadStorage {
+                    ty,
+                    storage,
+                    res,
+                    transient,
+                } => {
                     let (storage, _) = expression(storage, Some(&vars), cfg, ns);
 
                     if !dry_run {
@@ -149,6 +181,7 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                             ty: ty.clone(),
                             storage,
                             res: *res,
+                            transient: *transient,
                         };
                     }
                 }
@@ -224,6 +257,8 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                     encoded_args,
                     value,
                     gas,
+                    proof_size,
+                    storage_deposit_limit,
                     salt,
                     address,
                     seeds,
@@ -236,6 +271,12 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                         .as_ref()
                         .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
                     let gas = expression(gas, Some(&vars), cfg, ns).0;
+                    let proof_size = proof_size
+                        .as_ref()
+                        .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
+                    let storage_deposit_limit = storage_deposit_limit
+                        .as_ref()
+                        .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
                     let salt = salt
                         .as_ref()
                         .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
@@ -256,6 +297,8 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                             encoded_args,
                             value,
                             gas,
+                            proof_size,
+                            storage_deposit_limit,
                             salt,
                             address,
                             seeds,
@@ -276,6 +319,8 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                     seeds,
                     contract_function_no,
                     flags,
+                    proof_size,
+                    storage_deposit_limit,
                 } => {
                     let value = expression(value, Some(&vars), cfg, ns).0;
                     let gas = expression(gas, Some(&vars), cfg, ns).0;
@@ -290,6 +335,12 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                     let flags = flags
                         .as_ref()
                         .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
+                    let proof_size = proof_size
+                        .as_ref()
+                        .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
+                    let storage_deposit_limit = storage_deposit_limit
+                        .as_ref()
+                        .map(|expr| expression(expr, Some(&vars), cfg, ns).0);
 
                     if !dry_run {
                         cfg.blocks[block_no].instr[instr_no] = Instr::ExternalCall {
@@ -304,6 +355,8 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                             callty: callty.clone(),
                             contract_function_no: *contract_function_no,
                             flags,
+                            proof_size,
+                            storage_deposit_limit,
                         };
                     }
                 }
@@ -418,6 +471,8 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
             );
         }
     }
+
+    constant_conditions
 }
 
 /// Recursively walk the expression and fold any constant expressions or variables. This function returns the
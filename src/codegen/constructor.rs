@@ -41,6 +41,14 @@ pub(super) fn call_constructor(
     } else {
         default_gas(ns)
     };
+    let proof_size = call_args
+        .proof_size
+        .as_ref()
+        .map(|e| expression(e, cfg, callee_contract_no, func, ns, vartab, opt));
+    let storage_deposit_limit = call_args
+        .storage_deposit_limit
+        .as_ref()
+        .map(|e| expression(e, cfg, callee_contract_no, func, ns, vartab, opt));
 
     let salt = call_args
         .salt
@@ -110,6 +118,8 @@ pub(super) fn call_constructor(
             encoded_args,
             value,
             gas,
+            proof_size,
+            storage_deposit_limit,
             salt,
             address,
             seeds,
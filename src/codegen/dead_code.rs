@@ -0,0 +1,319 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::cfg::{ControlFlowGraph, Instr, InternalCallTy};
+use super::reaching_definitions;
+use super::vartable::Storage;
+use super::Expression;
+use crate::sema::ast::{Diagnostic, ExternalCallAccounts, Namespace, Statement};
+use crate::sema::diagnostics::codes;
+use crate::sema::Recurse;
+use indexmap::IndexMap;
+use solang_parser::pt::{CodeLocation, Loc};
+use std::collections::HashSet;
+
+/// A condition of an `if`, `while`, `for` or `do while` statement, with the statements which are
+/// never executed if the condition is always false or always true
+struct Condition {
+    loc: Loc,
+    dead_if_false: Option<Loc>,
+    dead_if_true: Option<Loc>,
+}
+
+/// Warn about conditions in the body of the function which constant folding found to be always true
+/// or always false, and about the statements which can never be reached because of it.
+pub(super) fn constant_conditions(func_no: usize, conditions: &[(Loc, bool)], ns: &mut Namespace) {
+    if conditions.is_empty() {
+        return;
+    }
+
+    let mut statements = Vec::new();
+
+    for stmt in &ns.functions[func_no].body {
+        stmt.recurse(&mut statements, find_conditions);
+    }
+
+    for (loc, value) in conditions {
+        let Some(cond) = statements.iter().find(|cond| cond.loc == *loc) else {
+            continue;
+        };
+
+        ns.diagnostics.push(
+            Diagnostic::warning(*loc, format!("condition is always {value}"))
+                .with_code(codes::CONSTANT_CONDITION),
+        );
+
+        let dead = if *value {
+            cond.dead_if_true
+        } else {
+            cond.dead_if_false
+        };
+
+        if let Some(dead) = dead {
+            ns.diagnostics.push(
+                Diagnostic::warning(dead, "unreachable code".into())
+                    .with_code(codes::UNREACHABLE_CODE),
+            );
+        }
+    }
+}
+
+fn find_conditions(stmt: &Statement, conditions: &mut Vec<Condition>) -> bool {
+    match stmt {
+        Statement::If(_, _, cond, then_stmt, else_stmt) => conditions.push(Condition {
+            loc: cond.loc(),
+            dead_if_false: statements_loc(then_stmt),
+            dead_if_true: statements_loc(else_stmt),
+        }),
+        Statement::While(_, _, cond, body)
+        | Statement::For {
+            cond: Some(cond),
+            body,
+            ..
+        } => conditions.push(Condition {
+            loc: cond.loc(),
+            dead_if_false: statements_loc(body),
+            dead_if_true: None,
+        }),
+        Statement::DoWhile(_, _, _, cond) => conditions.push(Condition {
+            loc: cond.loc(),
+            dead_if_false: None,
+            dead_if_true: None,
+        }),
+        _ => (),
+    }
+
+    true
+}
+
+fn statements_loc(stmts: &[Statement]) -> Option<Loc> {
+    // point at the statements inside the braces, not at the block itself
+    if let [Statement::Block { statements, .. }] = stmts {
+        return statements_loc(statements);
+    }
+
+    let first = stmts.first()?.loc();
+    let last = stmts.last()?.loc();
+
+    Some(first.with_end_from(&last))
+}
+
+/// Remove the blocks which can never be reached from the entry block, and assignments to
+/// variables which are never read. Both can be left behind by constant folding and the other
+/// passes.
+pub(super) fn dead_code(cfg: &mut ControlFlowGraph) {
+    let removed_blocks = remove_unreachable_blocks(cfg);
+    let removed_assignments = remove_dead_assignments(cfg);
+
+    // The reaching definitions refer to block and instruction numbers
+    if removed_blocks || removed_assignments {
+        for block in &mut cfg.blocks {
+            block.defs = IndexMap::new();
+        }
+
+        reaching_definitions::find(cfg);
+    }
+}
+
+fn remove_unreachable_blocks(cfg: &mut ControlFlowGraph) -> bool {
+    let mut reachable = vec![false; cfg.blocks.len()];
+    let mut todo = vec![0];
+
+    reachable[0] = true;
+
+    while let Some(block_no) = todo.pop() {
+        for succ in cfg.blocks[block_no].successors() {
+            if !reachable[succ] {
+                reachable[succ] = true;
+                todo.push(succ);
+            }
+        }
+    }
+
+    if reachable.iter().all(|r| *r) {
+        return false;
+    }
+
+    // the new number of each block which is kept
+    let mut renumber = vec![0; cfg.blocks.len()];
+    let mut next = 0;
+
+    for (block_no, reachable) in reachable.iter().enumerate() {
+        if *reachable {
+            renumber[block_no] = next;
+            next += 1;
+        }
+    }
+
+    let blocks = std::mem::take(&mut cfg.blocks);
+
+    for (mut block, _) in blocks
+        .into_iter()
+        .zip(reachable)
+        .filter(|(_, reachable)| *reachable)
+    {
+        if let Some(instr) = block.instr.last_mut() {
+            match instr {
+                Instr::Branch { block } => *block = renumber[*block],
+                Instr::BranchCond {
+                    true_block,
+                    false_block,
+                    ..
+                } => {
+                    *true_block = renumber[*true_block];
+                    *false_block = renumber[*false_block];
+                }
+                Instr::Switch { cases, default, .. } => {
+                    for (_, block) in cases {
+                        *block = renumber[*block];
+                    }
+                    *default = renumber[*default];
+                }
+                _ => (),
+            }
+        }
+
+        cfg.blocks.push(block);
+    }
+
+    true
+}
+
+fn remove_dead_assignments(cfg: &mut ControlFlowGraph) -> bool {
+    let mut removed = false;
+
+    // Removing an assignment may mean another variable is no longer read, so repeat until
+    // nothing changes
+    loop {
+        let mut keep = read_variables(cfg);
+
+        // Only local variables can be removed, and the array length temporaries are used by
+        // codegen for bounds checks
+        keep.extend(
+            cfg.vars
+                .iter()
+                .filter(|(_, var)| !matches!(var.storage, Storage::Local))
+                .map(|(var_no, _)| *var_no),
+        );
+        keep.extend(cfg.array_lengths_temps.keys());
+        keep.extend(cfg.array_lengths_temps.values());
+
+        let mut changed = false;
+
+        for block in &mut cfg.blocks {
+            let before = block.instr.len();
+
+            block.instr.retain(|instr| match instr {
+                Instr::Set { res, expr, .. } => keep.contains(res) || !is_pure(expr),
+                _ => true,
+            });
+
+            changed |= block.instr.len() != before;
+        }
+
+        if !changed {
+            return removed;
+        }
+
+        removed = true;
+    }
+}
+
+/// Collect all the variables which are read by any instruction
+fn read_variables(cfg: &ControlFlowGraph) -> HashSet<usize> {
+    let mut read = HashSet::new();
+
+    for block in &cfg.blocks {
+        for instr in &block.instr {
+            instr.recurse_expressions(&mut read, read_variable);
+
+            // recurse_expressions() does not visit these
+            match instr {
+                Instr::PushMemory { array, .. } | Instr::PopMemory { array, .. } => {
+                    read.insert(*array);
+                }
+                Instr::Call {
+                    call: InternalCallTy::Dynamic(expr),
+                    ..
+                }
+                | Instr::WriteBuffer { buf: expr, .. } => {
+                    expr.recurse(&mut read, read_variable);
+                }
+                Instr::Constructor { seeds, .. } => {
+                    if let Some(expr) = seeds {
+                        expr.recurse(&mut read, read_variable);
+                    }
+                }
+                Instr::ExternalCall {
+                    accounts,
+                    seeds,
+                    flags,
+                    ..
+                } => {
+                    if let ExternalCallAccounts::Present(expr) = accounts {
+                        expr.recurse(&mut read, read_variable);
+                    }
+                    for expr in [seeds, flags].into_iter().flatten() {
+                        expr.recurse(&mut read, read_variable);
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+
+    read
+}
+
+fn read_variable(expr: &Expression, read: &mut HashSet<usize>) -> bool {
+    if let Expression::Variable { var_no, .. } = expr {
+        read.insert(*var_no);
+    }
+
+    true
+}
+
+/// Can this expression be evaluated without any side effects, or a runtime error such as
+/// overflow or divide by zero
+fn is_pure(expr: &Expression) -> bool {
+    let mut pure = true;
+
+    expr.recurse(&mut pure, |expr, pure| {
+        match expr {
+            Expression::Add {
+                overflowing: true, ..
+            }
+            | Expression::Subtract {
+                overflowing: true, ..
+            }
+            | Expression::Multiply {
+                overflowing: true, ..
+            }
+            | Expression::BitwiseAnd { .. }
+            | Expression::BitwiseOr { .. }
+            | Expression::BitwiseXor { .. }
+            | Expression::BitwiseNot { .. }
+            | Expression::ShiftLeft { .. }
+            | Expression::ShiftRight { .. }
+            | Expression::BoolLiteral { .. }
+            | Expression::NumberLiteral { .. }
+            | Expression::BytesLiteral { .. }
+            | Expression::Equal { .. }
+            | Expression::NotEqual { .. }
+            | Expression::More { .. }
+            | Expression::MoreEqual { .. }
+            | Expression::Less { .. }
+            | Expression::LessEqual { .. }
+            | Expression::Not { .. }
+            | Expression::ZeroExt { .. }
+            | Expression::SignExt { .. }
+            | Expression::Trunc { .. }
+            | Expression::FunctionArg { .. }
+            | Expression::Variable { .. } => (),
+            _ => *pure = false,
+        }
+
+        *pure
+    });
+
+    pure
+}
@@ -274,6 +274,13 @@ fn instr_transfers(block_no: usize, block: &BasicBlock) -> Vec<Vec<Transfer>> {
                     Transfer::Store { def, expr: None },
                 ]
             }
+            // transient storage is not tracked by this pass
+            Instr::ClearStorage {
+                transient: true, ..
+            }
+            | Instr::SetStorage {
+                transient: true, ..
+            } => Vec::new(),
             Instr::SetStorageBytes { storage, .. }
             | Instr::ClearStorage { storage, .. }
             | Instr::SetStorage { storage, .. } => {
@@ -488,7 +495,12 @@ pub fn dead_storage(cfg: &mut ControlFlowGraph, _ns: &mut Namespace) {
             let vars = &block_vars[&block_no][instr_no];
 
             match &cfg.blocks[block_no].instr[instr_no] {
-                Instr::LoadStorage { res, ty, storage } => {
+                Instr::LoadStorage {
+                    res,
+                    ty,
+                    storage,
+                    transient: false,
+                } => {
                     // is there a definition which has the same storage expression
                     let mut found = None;
 
@@ -556,9 +568,13 @@ pub fn dead_storage(cfg: &mut ControlFlowGraph, _ns: &mut Namespace) {
                         }
                     }
                 }
-                Instr::SetStorage { .. }
+                Instr::SetStorage {
+                    transient: false, ..
+                }
                 | Instr::SetStorageBytes { .. }
-                | Instr::ClearStorage { .. } => {
+                | Instr::ClearStorage {
+                    transient: false, ..
+                } => {
                     let def = Definition::Instr {
                         block_no,
                         instr_no,
@@ -617,7 +633,10 @@ fn get_storage_definition<'a>(
     {
         match &cfg.blocks[*block_no].instr[*instr_no] {
             Instr::LoadStorage {
-                storage, res, ty, ..
+                storage,
+                res,
+                ty,
+                transient: false,
             } => Some(StorageDef {
                 var_no: *res,
                 slot: storage,
@@ -14,7 +14,7 @@ pub(super) fn function_dispatch(
 ) -> Vec<ControlFlowGraph> {
     match &ns.target {
         Target::Solana => vec![solana::function_dispatch(contract_no, all_cfg, ns, opt)],
-        Target::Polkadot { .. } | Target::EVM => {
+        Target::Polkadot { .. } | Target::PolkadotRevive | Target::EVM => {
             polkadot::function_dispatch(contract_no, all_cfg, ns, opt)
         }
         Target::Soroban => vec![],
@@ -96,6 +96,7 @@ impl AbiEncoding for BorshEncoding {
         &self,
         buffer: &Expression,
         offset: &Expression,
+        _ns: &Namespace,
         vartab: &mut Vartable,
         cfg: &mut ControlFlowGraph,
     ) -> (usize, Expression) {
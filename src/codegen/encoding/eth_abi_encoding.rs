@@ -0,0 +1,1632 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::codegen::cfg::{ControlFlowGraph, Instr};
+use crate::codegen::encoding::buffer_validator::BufferValidator;
+use crate::codegen::encoding::{
+    allocate_array, array_outer_length, finish_array_loop, index_array, load_struct_member,
+    set_array_loop, AbiEncoding,
+};
+use crate::codegen::expression::load_storage;
+use crate::codegen::revert::{assert_failure, SolidityError};
+use crate::codegen::vartable::Vartable;
+use crate::codegen::{Builtin, Expression};
+use crate::sema::ast::{ArrayLength, Namespace, RetrieveType, StructType, Type, Type::Uint};
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use solang_parser::pt::Loc::Codegen;
+use std::collections::HashMap;
+
+/// The Ethereum ABI works on 32 bytes words
+const WORD_SIZE: u8 = 32;
+
+/// This struct implements the trait AbiEncoding for the Ethereum ABI, which is used by
+/// PolkaVM contracts on pallet-revive.
+///
+/// Every value in the head of a tuple takes whole words. Dynamic values (strings, dynamic arrays
+/// and anything containing those) are stored in the tail of the tuple, and the head only holds
+/// the offset to them, relative to the start of the tuple.
+///
+/// See <https://docs.soliditylang.org/en/latest/abi-spec.html>
+pub(super) struct EthAbiEncoding {
+    storage_cache: HashMap<usize, Expression>,
+    /// Are we packed encoding?
+    packed_encoder: bool,
+}
+
+impl AbiEncoding for EthAbiEncoding {
+    fn size_width(
+        &self,
+        _size: &Expression,
+        _vartab: &mut Vartable,
+        _cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        word()
+    }
+
+    fn encode_args(
+        &mut self,
+        args: &[Expression],
+        buffer: &Expression,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) {
+        let mut offset = number(0);
+        let mut items = Vec::with_capacity(args.len());
+
+        for (arg_no, arg) in args.iter().enumerate() {
+            let item = self.load_item(arg, arg_no);
+
+            // The selector of a call is not part of the encoded tuple
+            if arg_no == 0 && item.ty() == Type::FunctionSelector {
+                offset = self.encode_directly(
+                    &item,
+                    buffer,
+                    &offset,
+                    vartab,
+                    cfg,
+                    ns.target.selector_length().into(),
+                );
+                continue;
+            }
+
+            items.push(item);
+        }
+
+        if self.is_packed() {
+            for item in &items {
+                let advance = self.encode_packed(item, buffer, &offset, ns, vartab, cfg);
+                offset = offset.add_u32(advance);
+            }
+        } else {
+            self.encode_tuple(&items, buffer, &offset, ns, vartab, cfg);
+        }
+    }
+
+    fn decode_args(
+        &self,
+        buffer: &Expression,
+        types: &[Type],
+        validator: &mut BufferValidator,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Vec<Expression> {
+        let mut read_items = Vec::with_capacity(types.len());
+        let mut offset = number(0);
+        let mut types = types;
+
+        // Error data starts with the selector, which is not part of the tuple
+        if let Some(Type::FunctionSelector) = types.first() {
+            let (selector, size) = self.read_from_buffer(
+                buffer,
+                &offset,
+                &Type::FunctionSelector,
+                validator,
+                ns,
+                vartab,
+                cfg,
+            );
+            read_items.push(selector);
+            offset = size;
+            types = &types[1..];
+        }
+
+        read_items.extend(self.decode_tuple(buffer, &offset, types, validator, ns, vartab, cfg));
+
+        read_items
+    }
+
+    fn encode(
+        &mut self,
+        expr: &Expression,
+        buffer: &Expression,
+        offset: &Expression,
+        _arg_no: usize,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        if self.is_packed() {
+            self.encode_packed(expr, buffer, offset, ns, vartab, cfg)
+        } else {
+            self.encode_value(expr, buffer, offset, ns, vartab, cfg)
+        }
+    }
+
+    fn encode_size(
+        &mut self,
+        expr: &Expression,
+        buffer: &Expression,
+        offset: &Expression,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        self.encode_word(expr, buffer, offset, ns, vartab, cfg)
+    }
+
+    fn encode_external_function(
+        &mut self,
+        expr: &Expression,
+        buffer: &Expression,
+        offset: &Expression,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        // An external function is encoded as bytes24: the address followed by the selector
+        cfg.add(
+            vartab,
+            Instr::WriteBuffer {
+                buf: buffer.clone(),
+                offset: offset.clone(),
+                value: expr.external_function_address(),
+            },
+        );
+        cfg.add(
+            vartab,
+            Instr::WriteBuffer {
+                buf: buffer.clone(),
+                offset: offset.clone().add_u32(number(ns.address_length)),
+                value: expr.external_function_selector(),
+            },
+        );
+
+        word()
+    }
+
+    fn read_from_buffer(
+        &self,
+        buffer: &Expression,
+        offset: &Expression,
+        ty: &Type,
+        validator: &mut BufferValidator,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> (Expression, Expression) {
+        let ty = ty.clone().unwrap_user_type(ns);
+
+        match &ty {
+            Type::FunctionSelector => {
+                let size = number(ns.target.selector_length());
+                validator.validate_offset(offset.clone().add_u32(size.clone()), ns, vartab, cfg);
+                let selector = Expression::Builtin {
+                    loc: Codegen,
+                    tys: vec![Type::FunctionSelector],
+                    kind: Builtin::ReadFromBuffer,
+                    args: vec![buffer.clone(), offset.clone()],
+                };
+                (self.temp(selector, vartab, cfg), size)
+            }
+            Type::Struct(struct_ty) => self.decode_struct(
+                buffer,
+                offset.clone(),
+                &ty,
+                struct_ty,
+                validator,
+                ns,
+                vartab,
+                cfg,
+            ),
+            Type::Array(elem_ty, dims) => self.decode_array(
+                buffer, offset, &ty, elem_ty, dims, validator, ns, vartab, cfg,
+            ),
+            Type::String | Type::DynamicBytes => {
+                validator.validate_offset(offset.clone().add_u32(word()), ns, vartab, cfg);
+                let (length, _) = self.retrieve_array_length(buffer, offset, ns, vartab, cfg);
+                let length_expr = Expression::Variable {
+                    loc: Codegen,
+                    ty: Uint(32),
+                    var_no: length,
+                };
+                let data_start = offset.clone().add_u32(word());
+                validator.validate_offset(
+                    data_start.clone().add_u32(length_expr.clone()),
+                    ns,
+                    vartab,
+                    cfg,
+                );
+
+                let allocated = allocate_array(&ty, length, vartab, cfg);
+                let allocated = Expression::Variable {
+                    loc: Codegen,
+                    ty: ty.clone(),
+                    var_no: allocated,
+                };
+                cfg.add(
+                    vartab,
+                    Instr::MemCopy {
+                        source: Expression::AdvancePointer {
+                            pointer: buffer.clone().into(),
+                            bytes_offset: data_start.into(),
+                        },
+                        destination: allocated.clone(),
+                        bytes: length_expr.clone(),
+                    },
+                );
+
+                (allocated, word().add_u32(padded(length_expr)))
+            }
+            Type::ExternalFunction { .. } => {
+                self.decode_external_function(buffer, offset, &ty, validator, ns, vartab, cfg)
+            }
+            _ => {
+                validator.validate_offset(offset.clone().add_u32(word()), ns, vartab, cfg);
+                let value = self.decode_word(buffer, offset, &ty, ns, vartab, cfg);
+                (self.temp(value, vartab, cfg), word())
+            }
+        }
+    }
+
+    fn retrieve_array_length(
+        &self,
+        buffer: &Expression,
+        offset: &Expression,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> (usize, Expression) {
+        let length = self.read_u32_word(buffer, offset, ns, vartab, cfg);
+        let array_length = vartab.temp_anonymous(&Uint(32));
+        cfg.add(
+            vartab,
+            Instr::Set {
+                loc: Codegen,
+                res: array_length,
+                expr: length,
+            },
+        );
+        (array_length, word())
+    }
+
+    fn decode_array(
+        &self,
+        buffer: &Expression,
+        offset: &Expression,
+        array_ty: &Type,
+        elem_ty: &Type,
+        dims: &[ArrayLength],
+        validator: &mut BufferValidator,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> (Expression, Expression) {
+        let array_var = vartab.temp_anonymous(array_ty);
+        let array = Expression::Variable {
+            loc: Codegen,
+            ty: array_ty.clone(),
+            var_no: array_var,
+        };
+        let start = self.temp(offset.clone(), vartab, cfg);
+
+        // the elements of the outer dimension form a tuple
+        let (elements_start, length) = if let Some(ArrayLength::Fixed(length)) = dims.last() {
+            cfg.add(
+                vartab,
+                Instr::Set {
+                    loc: Codegen,
+                    res: array_var,
+                    expr: Expression::ArrayLiteral {
+                        loc: Codegen,
+                        ty: array_ty.clone(),
+                        dimensions: vec![],
+                        values: vec![],
+                    },
+                },
+            );
+            (
+                start.clone(),
+                Expression::NumberLiteral {
+                    loc: Codegen,
+                    ty: Uint(32),
+                    value: length.clone(),
+                },
+            )
+        } else {
+            validator.validate_offset(start.clone().add_u32(word()), ns, vartab, cfg);
+            let (length, _) = self.retrieve_array_length(buffer, &start, ns, vartab, cfg);
+            let allocated = allocate_array(array_ty, length, vartab, cfg);
+            cfg.add(
+                vartab,
+                Instr::Set {
+                    loc: Codegen,
+                    res: array_var,
+                    expr: Expression::Variable {
+                        loc: Codegen,
+                        ty: array_ty.clone(),
+                        var_no: allocated,
+                    },
+                },
+            );
+            (
+                self.temp(start.clone().add_u32(word()), vartab, cfg),
+                Expression::Variable {
+                    loc: Codegen,
+                    ty: Uint(32),
+                    var_no: length,
+                },
+            )
+        };
+
+        let sub_ty = outer_element_type(elem_ty, dims);
+        let sub_dynamic = sub_ty.is_dynamic(ns);
+        let head_size = head_size(&sub_ty, ns);
+
+        let mut indexes = Vec::new();
+        let for_loop = set_array_loop(&array, dims, dims.len() - 1, &mut indexes, vartab, cfg);
+        cfg.set_basic_block(for_loop.body_block);
+
+        let index = Expression::Variable {
+            loc: Codegen,
+            ty: Uint(32),
+            var_no: for_loop.index,
+        };
+        let head = elements_start
+            .clone()
+            .add_u32(multiply(index, head_size.clone()));
+        let elem_offset = if sub_dynamic {
+            validator.validate_offset(head.clone().add_u32(word()), ns, vartab, cfg);
+            let elem_offset = self.read_u32_word(buffer, &head, ns, vartab, cfg);
+            elements_start.clone().add_u32(elem_offset)
+        } else {
+            head
+        };
+
+        let (value, _) =
+            self.read_from_buffer(buffer, &elem_offset, &sub_ty, validator, ns, vartab, cfg);
+        let dest = index_array(array.clone(), dims, &indexes, true);
+        cfg.add(
+            vartab,
+            Instr::Store {
+                dest,
+                data: if sub_ty.is_fixed_reference_type(ns) {
+                    // Structs and fixed arrays are pointers, we must store their contents
+                    Expression::Load {
+                        loc: Codegen,
+                        ty: value.ty(),
+                        expr: value.into(),
+                    }
+                } else {
+                    value
+                },
+            },
+        );
+
+        finish_array_loop(&for_loop, vartab, cfg);
+
+        // The size of the array is only needed when it is inline in its parent
+        let size = if sub_dynamic {
+            Expression::Poison
+        } else {
+            let size = multiply(length, head_size);
+            if matches!(dims.last(), Some(ArrayLength::Dynamic)) {
+                size.add_u32(word())
+            } else {
+                size
+            }
+        };
+
+        (array, size)
+    }
+
+    fn decode_struct(
+        &self,
+        buffer: &Expression,
+        offset: Expression,
+        expr_ty: &Type,
+        struct_ty: &StructType,
+        validator: &mut BufferValidator,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> (Expression, Expression) {
+        let field_tys = struct_ty
+            .definition(ns)
+            .fields
+            .iter()
+            .map(|field| field.ty.clone())
+            .collect::<Vec<Type>>();
+
+        let offset = self.temp(offset, vartab, cfg);
+        let values = self.decode_tuple(buffer, &offset, &field_tys, validator, ns, vartab, cfg);
+
+        let struct_var = vartab.temp_anonymous(expr_ty);
+        cfg.add(
+            vartab,
+            Instr::Set {
+                loc: Codegen,
+                res: struct_var,
+                expr: Expression::StructLiteral {
+                    loc: Codegen,
+                    ty: expr_ty.clone(),
+                    values,
+                },
+            },
+        );
+
+        (
+            Expression::Variable {
+                loc: Codegen,
+                ty: expr_ty.clone(),
+                var_no: struct_var,
+            },
+            number(static_size(expr_ty, ns)),
+        )
+    }
+
+    fn get_expr_size(
+        &mut self,
+        arg_no: usize,
+        expr: &Expression,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        let expr = if let Type::StorageRef(_, r) = expr.ty() {
+            let var = load_storage(&Codegen, &r, expr.clone(), false, cfg, vartab);
+            self.storage_cache_insert(arg_no, var.clone());
+            var
+        } else {
+            expr.clone()
+        };
+        let expr = self.load_item(&expr, arg_no);
+        let ty = expr.ty().deref_any().clone().unwrap_user_type(ns);
+
+        if self.is_packed() {
+            self.packed_size(&expr, &ty, ns, vartab, cfg)
+        } else if ty == Type::FunctionSelector {
+            number(ns.target.selector_length())
+        } else if ty.is_dynamic(ns) {
+            word().add_u32(self.encoded_size(&expr, &ty, ns, vartab, cfg))
+        } else {
+            number(static_size(&ty, ns))
+        }
+    }
+
+    fn decode_external_function(
+        &self,
+        buffer: &Expression,
+        offset: &Expression,
+        ty: &Type,
+        validator: &mut BufferValidator,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> (Expression, Expression) {
+        validator.validate_offset(offset.clone().add_u32(word()), ns, vartab, cfg);
+
+        let address = Expression::Builtin {
+            loc: Codegen,
+            tys: vec![Type::Address(false)],
+            kind: Builtin::ReadFromBuffer,
+            args: vec![buffer.clone(), offset.clone()],
+        };
+        let selector = Expression::Builtin {
+            loc: Codegen,
+            tys: vec![Type::FunctionSelector],
+            kind: Builtin::ReadFromBuffer,
+            args: vec![
+                buffer.clone(),
+                offset.clone().add_u32(number(ns.address_length)),
+            ],
+        };
+
+        let external_func = Expression::Cast {
+            loc: Codegen,
+            ty: ty.clone(),
+            expr: Expression::StructLiteral {
+                loc: Codegen,
+                ty: Type::Struct(StructType::ExternalFunction),
+                values: vec![selector, address],
+            }
+            .into(),
+        };
+
+        (external_func, word())
+    }
+
+    fn calculate_string_size(
+        &self,
+        expr: &Expression,
+        _vartab: &mut Vartable,
+        _cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        let length = Expression::Builtin {
+            loc: Codegen,
+            tys: vec![Uint(32)],
+            kind: Builtin::ArrayLength,
+            args: vec![expr.clone()],
+        };
+
+        if self.is_packed() {
+            length
+        } else {
+            word().add_u32(padded(length))
+        }
+    }
+
+    fn storage_cache_insert(&mut self, arg_no: usize, expr: Expression) {
+        self.storage_cache.insert(arg_no, expr);
+    }
+
+    fn storage_cache_remove(&mut self, arg_no: usize) -> Option<Expression> {
+        self.storage_cache.remove(&arg_no)
+    }
+
+    fn is_packed(&self) -> bool {
+        self.packed_encoder
+    }
+
+    fn const_encode(&self, args: &[Expression]) -> Option<Vec<u8>> {
+        let mut args = args;
+        let mut result = vec![];
+
+        if let Some(Expression::NumberLiteral {
+            ty: Type::FunctionSelector | Type::Bytes(4),
+            value,
+            ..
+        }) = args.first()
+        {
+            let bytes = value.to_bytes_be().1;
+            result.resize(4 - bytes.len(), 0);
+            result.extend_from_slice(&bytes);
+            args = &args[1..];
+        }
+
+        let mut head = vec![];
+        let mut tail = vec![];
+
+        for arg in args {
+            match arg {
+                Expression::AllocDynamicBytes {
+                    initializer: Some(data),
+                    ty: Type::String | Type::DynamicBytes,
+                    ..
+                } => {
+                    head.extend(const_word(&BigInt::from(args.len() * 32 + tail.len())));
+                    tail.extend(const_word(&BigInt::from(data.len())));
+                    tail.extend_from_slice(data);
+                    tail.resize(tail.len().next_multiple_of(32), 0);
+                }
+                Expression::NumberLiteral {
+                    ty: Type::Uint(256),
+                    value,
+                    ..
+                } => head.extend(const_word(value)),
+                _ => return None,
+            }
+        }
+
+        result.extend(head);
+        result.extend(tail);
+
+        result.into()
+    }
+}
+
+impl EthAbiEncoding {
+    pub fn new(packed: bool) -> EthAbiEncoding {
+        EthAbiEncoding {
+            storage_cache: HashMap::new(),
+            packed_encoder: packed,
+        }
+    }
+
+    /// Retrieve the value to be encoded: storage items are loaded during the size calculation,
+    /// and references to anything but structs must be loaded.
+    fn load_item(&self, expr: &Expression, arg_no: usize) -> Expression {
+        match expr.ty() {
+            Type::StorageRef(..) => self
+                .storage_cache
+                .get(&arg_no)
+                .cloned()
+                .expect("storage item should have been loaded"),
+            Type::Ref(r) if !matches!(*r, Type::Struct(_)) => Expression::Load {
+                loc: Codegen,
+                ty: *r,
+                expr: expr.clone().into(),
+            },
+            _ => expr.clone(),
+        }
+    }
+
+    /// Save an expression in a temporary, so it is not evaluated more than once
+    fn temp(
+        &self,
+        expr: Expression,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        if matches!(
+            expr,
+            Expression::Variable { .. } | Expression::NumberLiteral { .. }
+        ) {
+            return expr;
+        }
+
+        let ty = expr.ty();
+        let var_no = vartab.temp_anonymous(&ty);
+        cfg.add(
+            vartab,
+            Instr::Set {
+                loc: Codegen,
+                res: var_no,
+                expr,
+            },
+        );
+        Expression::Variable {
+            loc: Codegen,
+            ty,
+            var_no,
+        }
+    }
+
+    /// Encode the `items` as a tuple at `offset`: all the static values and the offsets to
+    /// the dynamic values first, followed by the dynamic values. Returns the encoded size.
+    fn encode_tuple(
+        &mut self,
+        items: &[Expression],
+        buffer: &Expression,
+        offset: &Expression,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        let offset = self.temp(offset.clone(), vartab, cfg);
+        let tys = items
+            .iter()
+            .map(|item| item.ty().unwrap_user_type(ns))
+            .collect::<Vec<Type>>();
+        let heads_size: BigInt = tys.iter().map(|ty| head_size(ty, ns)).sum();
+
+        let tail = vartab.temp_anonymous(&Uint(32));
+        cfg.add(
+            vartab,
+            Instr::Set {
+                loc: Codegen,
+                res: tail,
+                expr: number(heads_size),
+            },
+        );
+        let tail_expr = Expression::Variable {
+            loc: Codegen,
+            ty: Uint(32),
+            var_no: tail,
+        };
+
+        let mut head = BigInt::zero();
+
+        for (item, ty) in items.iter().zip(&tys) {
+            let head_offset = offset.clone().add_u32(Expression::NumberLiteral {
+                loc: Codegen,
+                ty: Uint(32),
+                value: head.clone(),
+            });
+
+            if ty.is_dynamic(ns) {
+                self.encode_word(&tail_expr, buffer, &head_offset, ns, vartab, cfg);
+                let size = self.encode_value(
+                    item,
+                    buffer,
+                    &offset.clone().add_u32(tail_expr.clone()),
+                    ns,
+                    vartab,
+                    cfg,
+                );
+                cfg.add(
+                    vartab,
+                    Instr::Set {
+                        loc: Codegen,
+                        res: tail,
+                        expr: tail_expr.clone().add_u32(size),
+                    },
+                );
+            } else {
+                self.encode_value(item, buffer, &head_offset, ns, vartab, cfg);
+            }
+
+            head += head_size(ty, ns);
+        }
+
+        tail_expr
+    }
+
+    /// Encode a single value at `offset`. Returns the encoded size, which does not include the
+    /// offset in the head of the enclosing tuple for dynamic values.
+    fn encode_value(
+        &mut self,
+        expr: &Expression,
+        buffer: &Expression,
+        offset: &Expression,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        let expr = self.load_item(expr, 0);
+        let ty = expr.ty().unwrap_user_type(ns);
+
+        match ty.deref_any() {
+            Type::Contract(_) | Type::Address(_) => {
+                // addresses are right aligned
+                let padding = WORD_SIZE as usize - ns.address_length;
+                self.encode_directly(
+                    &expr,
+                    buffer,
+                    &offset.clone().add_u32(number(padding)),
+                    vartab,
+                    cfg,
+                    ns.address_length.into(),
+                );
+                word()
+            }
+            Type::Bytes(_) => {
+                // fixed length bytes are left aligned
+                self.encode_directly(&expr, buffer, offset, vartab, cfg, WORD_SIZE.into());
+                word()
+            }
+            Type::FunctionSelector => {
+                let size = ns.target.selector_length().into();
+                self.encode_directly(&expr, buffer, offset, vartab, cfg, size)
+            }
+            Type::ExternalFunction { .. } => {
+                self.encode_external_function(&expr, buffer, offset, ns, vartab, cfg)
+            }
+            Type::String | Type::DynamicBytes => {
+                let length = array_outer_length(&expr, vartab, cfg);
+                self.encode_word(&length, buffer, offset, ns, vartab, cfg);
+                cfg.add(
+                    vartab,
+                    Instr::MemCopy {
+                        source: expr.clone(),
+                        destination: Expression::AdvancePointer {
+                            pointer: buffer.clone().into(),
+                            bytes_offset: offset.clone().add_u32(word()).into(),
+                        },
+                        bytes: length.clone(),
+                    },
+                );
+                word().add_u32(padded(length))
+            }
+            Type::Struct(struct_ty) => {
+                let fields = struct_ty
+                    .definition(ns)
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(no, field)| load_struct_member(field.ty.clone(), expr.clone(), no, ns))
+                    .collect::<Vec<Expression>>();
+                self.encode_tuple(&fields, buffer, offset, ns, vartab, cfg)
+            }
+            Type::Array(elem_ty, dims) => {
+                self.encode_array_tuple(&expr, elem_ty, dims, buffer, offset, ns, vartab, cfg)
+            }
+            _ => self.encode_word(&expr, buffer, offset, ns, vartab, cfg),
+        }
+    }
+
+    /// Encode an array at `offset`. The length is written first for dynamic arrays, and the
+    /// elements of the outer dimension are encoded as a tuple.
+    fn encode_array_tuple(
+        &mut self,
+        array: &Expression,
+        elem_ty: &Type,
+        dims: &[ArrayLength],
+        buffer: &Expression,
+        offset: &Expression,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        let offset = self.temp(offset.clone(), vartab, cfg);
+
+        let (elements_start, length, prefix) = match dims.last() {
+            Some(ArrayLength::Fixed(length)) => (
+                offset.clone(),
+                Expression::NumberLiteral {
+                    loc: Codegen,
+                    ty: Uint(32),
+                    value: length.clone(),
+                },
+                None,
+            ),
+            _ => {
+                let length = array_outer_length(array, vartab, cfg);
+                self.encode_word(&length, buffer, &offset, ns, vartab, cfg);
+                (
+                    self.temp(offset.clone().add_u32(word()), vartab, cfg),
+                    length,
+                    Some(word()),
+                )
+            }
+        };
+
+        let sub_ty = outer_element_type(elem_ty, dims);
+        let head_size = head_size(&sub_ty, ns);
+        let sub_dynamic = sub_ty.is_dynamic(ns);
+
+        // When the elements are dynamic, this keeps track of where the next one goes
+        let tail = vartab.temp_anonymous(&Uint(32));
+        cfg.add(
+            vartab,
+            Instr::Set {
+                loc: Codegen,
+                res: tail,
+                expr: multiply(length, head_size.clone()),
+            },
+        );
+        let tail_expr = Expression::Variable {
+            loc: Codegen,
+            ty: Uint(32),
+            var_no: tail,
+        };
+
+        let mut indexes = Vec::new();
+        let for_loop = set_array_loop(array, dims, dims.len() - 1, &mut indexes, vartab, cfg);
+        cfg.set_basic_block(for_loop.body_block);
+
+        let index = Expression::Variable {
+            loc: Codegen,
+            ty: Uint(32),
+            var_no: for_loop.index,
+        };
+        let head = elements_start.clone().add_u32(multiply(index, head_size));
+        let element = index_array(array.clone(), dims, &indexes, false);
+
+        if sub_dynamic {
+            self.encode_word(&tail_expr, buffer, &head, ns, vartab, cfg);
+            let size = self.encode_value(
+                &element,
+                buffer,
+                &elements_start.clone().add_u32(tail_expr.clone()),
+                ns,
+                vartab,
+                cfg,
+            );
+            cfg.add(
+                vartab,
+                Instr::Set {
+                    loc: Codegen,
+                    res: tail,
+                    expr: tail_expr.clone().add_u32(size),
+                },
+            );
+        } else {
+            self.encode_value(&element, buffer, &head, ns, vartab, cfg);
+        }
+
+        finish_array_loop(&for_loop, vartab, cfg);
+
+        if let Some(prefix) = prefix {
+            prefix.add_u32(tail_expr)
+        } else {
+            tail_expr
+        }
+    }
+
+    /// Encode a value using the non-standard packed mode, i.e. `abi.encodePacked()`.
+    /// Values are not padded and no lengths are written, except for array elements, which
+    /// take a whole word each.
+    fn encode_packed(
+        &mut self,
+        expr: &Expression,
+        buffer: &Expression,
+        offset: &Expression,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        let expr = self.load_item(expr, 0);
+        let ty = expr.ty().unwrap_user_type(ns);
+
+        match &ty {
+            Type::Uint(width) | Type::Int(width) => {
+                let bytes = Type::Bytes((*width / 8) as u8);
+                let value = Expression::Cast {
+                    loc: Codegen,
+                    ty: bytes,
+                    expr: expr.clone().into(),
+                };
+                self.encode_directly(&value, buffer, offset, vartab, cfg, (*width / 8).into())
+            }
+            Type::Value => {
+                let bytes = Type::Bytes(ns.value_length as u8);
+                let value = Expression::Cast {
+                    loc: Codegen,
+                    ty: bytes,
+                    expr: expr.clone().into(),
+                };
+                self.encode_directly(&value, buffer, offset, vartab, cfg, ns.value_length.into())
+            }
+            Type::Bool | Type::Enum(_) => {
+                self.encode_directly(&expr, buffer, offset, vartab, cfg, BigInt::one())
+            }
+            Type::Contract(_) | Type::Address(_) => {
+                self.encode_directly(&expr, buffer, offset, vartab, cfg, ns.address_length.into())
+            }
+            Type::Bytes(length) => {
+                self.encode_directly(&expr, buffer, offset, vartab, cfg, (*length).into())
+            }
+            Type::String | Type::DynamicBytes => {
+                let length = array_outer_length(&expr, vartab, cfg);
+                cfg.add(
+                    vartab,
+                    Instr::MemCopy {
+                        source: expr.clone(),
+                        destination: Expression::AdvancePointer {
+                            pointer: buffer.clone().into(),
+                            bytes_offset: offset.clone().into(),
+                        },
+                        bytes: length.clone(),
+                    },
+                );
+                length
+            }
+            Type::Array(elem_ty, dims) => {
+                // Array elements are padded, but the array has no length
+                let size =
+                    self.encode_array_tuple(&expr, elem_ty, dims, buffer, offset, ns, vartab, cfg);
+                if matches!(dims.last(), Some(ArrayLength::Dynamic)) {
+                    // undo the length prefix
+                    self.remove_length_prefix(buffer, offset, &size, vartab, cfg)
+                } else {
+                    size
+                }
+            }
+            _ => self.encode_value(&expr, buffer, offset, ns, vartab, cfg),
+        }
+    }
+
+    /// Dynamic arrays are encoded with their length first. In packed mode, the length is
+    /// not included, so move the elements down by one word.
+    fn remove_length_prefix(
+        &mut self,
+        buffer: &Expression,
+        offset: &Expression,
+        size: &Expression,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        let size = self.temp(
+            Expression::Subtract {
+                loc: Codegen,
+                ty: Uint(32),
+                overflowing: false,
+                left: size.clone().into(),
+                right: word().into(),
+            },
+            vartab,
+            cfg,
+        );
+        cfg.add(
+            vartab,
+            Instr::MemCopy {
+                source: Expression::AdvancePointer {
+                    pointer: buffer.clone().into(),
+                    bytes_offset: offset.clone().add_u32(word()).into(),
+                },
+                destination: Expression::AdvancePointer {
+                    pointer: buffer.clone().into(),
+                    bytes_offset: offset.clone().into(),
+                },
+                bytes: size.clone(),
+            },
+        );
+        size
+    }
+
+    /// Encode an integer like value as a whole word, in big endian
+    fn encode_word(
+        &mut self,
+        expr: &Expression,
+        buffer: &Expression,
+        offset: &Expression,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        let ty = expr.ty().unwrap_user_type(ns);
+        let bits = match &ty {
+            Type::Bool => 1,
+            _ => ty.bits(ns),
+        };
+
+        let value = if bits == 256 {
+            expr.clone()
+        } else if ty.is_signed_int(ns) {
+            Expression::SignExt {
+                loc: Codegen,
+                ty: Type::Int(256),
+                expr: expr.clone().into(),
+            }
+        } else {
+            Expression::ZeroExt {
+                loc: Codegen,
+                ty: Uint(256),
+                expr: expr.clone().into(),
+            }
+        };
+
+        // bytes32 is written in big endian
+        let value = Expression::Cast {
+            loc: Codegen,
+            ty: Type::Bytes(WORD_SIZE),
+            expr: value.into(),
+        };
+
+        self.encode_directly(&value, buffer, offset, vartab, cfg, WORD_SIZE.into())
+    }
+
+    /// Calculate the size of the encoded dynamic value, not including its offset in the head of
+    /// the enclosing tuple.
+    fn encoded_size(
+        &mut self,
+        expr: &Expression,
+        ty: &Type,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        if !ty.is_dynamic(ns) {
+            return number(static_size(ty, ns));
+        }
+
+        match ty {
+            Type::String | Type::DynamicBytes => self.calculate_string_size(expr, vartab, cfg),
+            Type::Struct(struct_ty) => {
+                let mut size = number(
+                    struct_ty
+                        .definition(ns)
+                        .fields
+                        .iter()
+                        .map(|field| head_size(&field.ty, ns))
+                        .sum::<BigInt>(),
+                );
+                for (no, field) in struct_ty.definition(ns).fields.iter().enumerate() {
+                    if field.ty.is_dynamic(ns) {
+                        let member = load_struct_member(field.ty.clone(), expr.clone(), no, ns);
+                        let member = self.load_item(&member, 0);
+                        let field_size = self.encoded_size(&member, &field.ty, ns, vartab, cfg);
+                        size = size.add_u32(field_size);
+                    }
+                }
+                size
+            }
+            Type::Array(elem_ty, dims) => self.array_size(expr, elem_ty, dims, ns, vartab, cfg),
+            Type::Ref(r) => self.encoded_size(expr, r, ns, vartab, cfg),
+            _ => unreachable!("{} is not dynamic", ty.to_string(ns)),
+        }
+    }
+
+    /// Calculate the encoded size of an array
+    fn array_size(
+        &mut self,
+        array: &Expression,
+        elem_ty: &Type,
+        dims: &[ArrayLength],
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        let (length, prefix) = match dims.last() {
+            Some(ArrayLength::Fixed(length)) => (
+                Expression::NumberLiteral {
+                    loc: Codegen,
+                    ty: Uint(32),
+                    value: length.clone(),
+                },
+                None,
+            ),
+            _ => (array_outer_length(array, vartab, cfg), Some(word())),
+        };
+
+        let sub_ty = outer_element_type(elem_ty, dims);
+        let size_var = vartab.temp_anonymous(&Uint(32));
+        cfg.add(
+            vartab,
+            Instr::Set {
+                loc: Codegen,
+                res: size_var,
+                expr: multiply(length, head_size(&sub_ty, ns)),
+            },
+        );
+        let size = Expression::Variable {
+            loc: Codegen,
+            ty: Uint(32),
+            var_no: size_var,
+        };
+
+        if sub_ty.is_dynamic(ns) {
+            let mut indexes = Vec::new();
+            let for_loop = set_array_loop(array, dims, dims.len() - 1, &mut indexes, vartab, cfg);
+            cfg.set_basic_block(for_loop.body_block);
+            let element = index_array(array.clone(), dims, &indexes, false);
+            let element = self.load_item(&element, 0);
+            let element_size = self.encoded_size(&element, &sub_ty, ns, vartab, cfg);
+            cfg.add(
+                vartab,
+                Instr::Set {
+                    loc: Codegen,
+                    res: size_var,
+                    expr: size.clone().add_u32(element_size),
+                },
+            );
+            finish_array_loop(&for_loop, vartab, cfg);
+        }
+
+        if let Some(prefix) = prefix {
+            prefix.add_u32(size)
+        } else {
+            size
+        }
+    }
+
+    /// Calculate the size of a value in packed mode
+    fn packed_size(
+        &mut self,
+        expr: &Expression,
+        ty: &Type,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        match ty {
+            Type::Uint(width) | Type::Int(width) => number(*width / 8),
+            Type::Value => number(ns.value_length),
+            Type::FunctionSelector => number(ns.target.selector_length()),
+            Type::Bool | Type::Enum(_) => number(1),
+            Type::Contract(_) | Type::Address(_) => number(ns.address_length),
+            Type::Bytes(length) => number(*length),
+            Type::String | Type::DynamicBytes => self.calculate_string_size(expr, vartab, cfg),
+            Type::Array(elem_ty, dims) => {
+                let size = self.array_size(expr, elem_ty, dims, ns, vartab, cfg);
+                if matches!(dims.last(), Some(ArrayLength::Dynamic)) {
+                    Expression::Subtract {
+                        loc: Codegen,
+                        ty: Uint(32),
+                        overflowing: false,
+                        left: size.into(),
+                        right: word().into(),
+                    }
+                } else {
+                    size
+                }
+            }
+            _ => self.encoded_size(expr, ty, ns, vartab, cfg),
+        }
+    }
+
+    /// Decode a tuple of `types` at `offset`
+    fn decode_tuple(
+        &self,
+        buffer: &Expression,
+        offset: &Expression,
+        types: &[Type],
+        validator: &mut BufferValidator,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Vec<Expression> {
+        let mut read_items = Vec::with_capacity(types.len());
+        let mut head = BigInt::zero();
+
+        for ty in types {
+            let ty = ty.clone().unwrap_user_type(ns);
+            let head_offset = offset.clone().add_u32(Expression::NumberLiteral {
+                loc: Codegen,
+                ty: Uint(32),
+                value: head.clone(),
+            });
+
+            let value_offset = if ty.is_dynamic(ns) {
+                validator.validate_offset(head_offset.clone().add_u32(word()), ns, vartab, cfg);
+                let value_offset = self.read_u32_word(buffer, &head_offset, ns, vartab, cfg);
+                self.temp(offset.clone().add_u32(value_offset), vartab, cfg)
+            } else {
+                head_offset
+            };
+
+            let (value, _) =
+                self.read_from_buffer(buffer, &value_offset, &ty, validator, ns, vartab, cfg);
+            read_items.push(value);
+
+            head += head_size(&ty, ns);
+        }
+
+        read_items
+    }
+
+    /// Read a word which holds a length or an offset. The word must fit in 32 bits, else a huge
+    /// value would wrap around to a small one which is valid.
+    fn read_u32_word(
+        &self,
+        buffer: &Expression,
+        offset: &Expression,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        let value = self.temp(read_word(buffer, offset), vartab, cfg);
+        revert_unless(
+            Expression::LessEqual {
+                loc: Codegen,
+                signed: false,
+                left: value.clone().into(),
+                right: uint256(u32::MAX).into(),
+            },
+            ns,
+            vartab,
+            cfg,
+        );
+
+        Expression::Trunc {
+            loc: Codegen,
+            ty: Uint(32),
+            expr: value.into(),
+        }
+    }
+
+    /// Decode a word into a value of type `ty`. Like solc, we revert if the word is not in its
+    /// canonical form, i.e. the padding is not zero or the value is out of range for its type.
+    fn decode_word(
+        &self,
+        buffer: &Expression,
+        offset: &Expression,
+        ty: &Type,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Expression {
+        let value = self.temp(read_word(buffer, offset), vartab, cfg);
+
+        match ty {
+            Type::Address(_) | Type::Contract(_) => {
+                // The leading bytes must be zero
+                if ns.address_length < WORD_SIZE as usize {
+                    check_less_than(
+                        &value,
+                        BigInt::one() << (ns.address_length * 8),
+                        ns,
+                        vartab,
+                        cfg,
+                    );
+                }
+
+                Expression::Builtin {
+                    loc: Codegen,
+                    tys: vec![ty.clone()],
+                    kind: Builtin::ReadFromBuffer,
+                    args: vec![
+                        buffer.clone(),
+                        offset
+                            .clone()
+                            .add_u32(number(WORD_SIZE as usize - ns.address_length)),
+                    ],
+                }
+            }
+            Type::Bytes(length) => {
+                // The trailing bytes must be zero
+                if *length < WORD_SIZE {
+                    let padding = (WORD_SIZE - length) as usize * 8;
+                    revert_unless(
+                        Expression::Equal {
+                            loc: Codegen,
+                            left: Expression::BitwiseAnd {
+                                loc: Codegen,
+                                ty: Uint(256),
+                                left: value.clone().into(),
+                                right: uint256((BigInt::one() << padding) - 1).into(),
+                            }
+                            .into(),
+                            right: uint256(0).into(),
+                        },
+                        ns,
+                        vartab,
+                        cfg,
+                    );
+                }
+
+                Expression::Builtin {
+                    loc: Codegen,
+                    tys: vec![ty.clone()],
+                    kind: Builtin::ReadFromBuffer,
+                    args: vec![buffer.clone(), offset.clone()],
+                }
+            }
+            Type::Bool => {
+                check_less_than(&value, 2.into(), ns, vartab, cfg);
+
+                Expression::NotEqual {
+                    loc: Codegen,
+                    left: value.into(),
+                    right: uint256(0).into(),
+                }
+            }
+            _ => {
+                let bits = ty.bits(ns);
+                let int_ty = if ty.is_signed_int(ns) {
+                    Type::Int(bits)
+                } else {
+                    Uint(bits)
+                };
+
+                if let Type::Enum(enum_no) = ty {
+                    check_less_than(
+                        &value,
+                        ns.enums[*enum_no].values.len().into(),
+                        ns,
+                        vartab,
+                        cfg,
+                    );
+                } else if bits < 256 && ty.is_signed_int(ns) {
+                    // The value must be sign extended
+                    revert_unless(
+                        Expression::Equal {
+                            loc: Codegen,
+                            left: Expression::SignExt {
+                                loc: Codegen,
+                                ty: Type::Int(256),
+                                expr: Expression::Trunc {
+                                    loc: Codegen,
+                                    ty: int_ty.clone(),
+                                    expr: value.clone().into(),
+                                }
+                                .into(),
+                            }
+                            .into(),
+                            right: Expression::Cast {
+                                loc: Codegen,
+                                ty: Type::Int(256),
+                                expr: value.clone().into(),
+                            }
+                            .into(),
+                        },
+                        ns,
+                        vartab,
+                        cfg,
+                    );
+                } else if bits < 256 {
+                    check_less_than(&value, BigInt::one() << bits, ns, vartab, cfg);
+                }
+
+                let value = if bits == 256 {
+                    Expression::Cast {
+                        loc: Codegen,
+                        ty: int_ty.clone(),
+                        expr: value.into(),
+                    }
+                } else {
+                    Expression::Trunc {
+                        loc: Codegen,
+                        ty: int_ty.clone(),
+                        expr: value.into(),
+                    }
+                };
+                if &int_ty == ty {
+                    value
+                } else {
+                    Expression::Cast {
+                        loc: Codegen,
+                        ty: ty.clone(),
+                        expr: value.into(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Revert with empty error data unless `valid` holds. This is what solc does when the data to
+/// decode is not in canonical form.
+fn revert_unless(
+    valid: Expression,
+    ns: &Namespace,
+    vartab: &mut Vartable,
+    cfg: &mut ControlFlowGraph,
+) {
+    let valid_block = cfg.new_basic_block("abi_valid".to_string());
+    let invalid_block = cfg.new_basic_block("abi_invalid".to_string());
+
+    cfg.add(
+        vartab,
+        Instr::BranchCond {
+            cond: valid,
+            true_block: valid_block,
+            false_block: invalid_block,
+        },
+    );
+
+    cfg.set_basic_block(invalid_block);
+    assert_failure(&Codegen, SolidityError::Empty, ns, cfg, vartab);
+
+    cfg.set_basic_block(valid_block);
+}
+
+/// Revert unless the word `value` is less than `bound`
+fn check_less_than(
+    value: &Expression,
+    bound: BigInt,
+    ns: &Namespace,
+    vartab: &mut Vartable,
+    cfg: &mut ControlFlowGraph,
+) {
+    revert_unless(
+        Expression::Less {
+            loc: Codegen,
+            signed: false,
+            left: value.clone().into(),
+            right: uint256(bound).into(),
+        },
+        ns,
+        vartab,
+        cfg,
+    );
+}
+
+/// The type of the elements of the outer dimension of an array
+fn outer_element_type(elem_ty: &Type, dims: &[ArrayLength]) -> Type {
+    if dims.len() == 1 {
+        elem_ty.clone()
+    } else {
+        Type::Array(elem_ty.clone().into(), dims[..dims.len() - 1].to_vec())
+    }
+}
+
+/// The size of a static type when encoded
+fn static_size(ty: &Type, ns: &Namespace) -> BigInt {
+    match ty {
+        Type::Struct(struct_ty) => struct_ty
+            .definition(ns)
+            .fields
+            .iter()
+            .map(|field| static_size(&field.ty, ns))
+            .sum(),
+        Type::Array(elem_ty, dims) => {
+            dims.iter()
+                .map(|dim| dim.array_length().cloned().unwrap_or_else(BigInt::one))
+                .product::<BigInt>()
+                * static_size(elem_ty, ns)
+        }
+        Type::Ref(r) | Type::StorageRef(_, r) => static_size(r, ns),
+        Type::UserType(no) => static_size(&ns.user_types[*no].ty, ns),
+        _ => WORD_SIZE.into(),
+    }
+}
+
+/// The size a value of type `ty` takes in the head of a tuple
+fn head_size(ty: &Type, ns: &Namespace) -> BigInt {
+    if ty.is_dynamic(ns) {
+        WORD_SIZE.into()
+    } else {
+        static_size(ty, ns)
+    }
+}
+
+/// Read a whole word from the buffer as an unsigned 256 bit integer
+fn read_word(buffer: &Expression, offset: &Expression) -> Expression {
+    Expression::Cast {
+        loc: Codegen,
+        ty: Uint(256),
+        expr: Expression::Builtin {
+            loc: Codegen,
+            tys: vec![Type::Bytes(WORD_SIZE)],
+            kind: Builtin::ReadFromBuffer,
+            args: vec![buffer.clone(), offset.clone()],
+        }
+        .into(),
+    }
+}
+
+/// Round the length up to a multiple of the word size
+fn padded(length: Expression) -> Expression {
+    Expression::BitwiseAnd {
+        loc: Codegen,
+        ty: Uint(32),
+        left: length.add_u32(number(WORD_SIZE - 1)).into(),
+        right: number(!(WORD_SIZE as u32 - 1)).into(),
+    }
+}
+
+fn multiply(left: Expression, right: BigInt) -> Expression {
+    Expression::Multiply {
+        loc: Codegen,
+        ty: Uint(32),
+        overflowing: false,
+        left: left.into(),
+        right: Expression::NumberLiteral {
+            loc: Codegen,
+            ty: Uint(32),
+            value: right,
+        }
+        .into(),
+    }
+}
+
+fn number<T: Into<BigInt>>(value: T) -> Expression {
+    Expression::NumberLiteral {
+        loc: Codegen,
+        ty: Uint(32),
+        value: value.into(),
+    }
+}
+
+fn uint256<T: Into<BigInt>>(value: T) -> Expression {
+    Expression::NumberLiteral {
+        loc: Codegen,
+        ty: Uint(256),
+        value: value.into(),
+    }
+}
+
+fn word() -> Expression {
+    number(WORD_SIZE)
+}
+
+/// Encode a constant as a big endian word
+fn const_word(value: &BigInt) -> Vec<u8> {
+    let bytes = value.to_bytes_be().1;
+    let mut word = vec![0; WORD_SIZE as usize - bytes.len()];
+    word.extend(bytes);
+    word
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codegen::encoding::eth_abi_encoding::EthAbiEncoding;
+    use crate::codegen::encoding::AbiEncoding;
+    use crate::codegen::Expression;
+    use crate::sema::ast::Type;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn const_encode_error_string() {
+        let encoder = EthAbiEncoding::new(false);
+        let selector = Expression::NumberLiteral {
+            loc: Default::default(),
+            ty: Type::FunctionSelector,
+            value: BigInt::from(0x08c379a0u32),
+        };
+        let message = Expression::AllocDynamicBytes {
+            loc: Default::default(),
+            ty: Type::String,
+            size: Expression::Poison.into(),
+            initializer: b"hi".to_vec().into(),
+        };
+
+        let encoded = encoder.const_encode(&[selector, message]).unwrap();
+
+        let mut expected = vec![0x08, 0xc3, 0x79, 0xa0];
+        expected.extend([0; 31]);
+        expected.push(0x20);
+        expected.extend([0; 31]);
+        expected.push(2);
+        expected.extend(b"hi");
+        expected.extend([0; 30]);
+
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn const_encode_panic() {
+        let encoder = EthAbiEncoding::new(false);
+        let selector = Expression::NumberLiteral {
+            loc: Default::default(),
+            ty: Type::FunctionSelector,
+            value: BigInt::from(0x4e487b71u32),
+        };
+        let code = Expression::NumberLiteral {
+            loc: Default::default(),
+            ty: Type::Uint(256),
+            value: BigInt::from(0x11),
+        };
+
+        let encoded = encoder.const_encode(&[selector, code]).unwrap();
+
+        let mut expected = vec![0x4e, 0x48, 0x7b, 0x71];
+        expected.extend([0; 31]);
+        expected.push(0x11);
+
+        assert_eq!(encoded, expected);
+    }
+}
@@ -10,10 +10,12 @@
 ///   Any such helper function should work fine regardless of the encoding scheme being used.
 mod borsh_encoding;
 mod buffer_validator;
+mod eth_abi_encoding;
 pub(super) mod scale_encoding;
 
 use crate::codegen::cfg::{ControlFlowGraph, Instr};
 use crate::codegen::encoding::borsh_encoding::BorshEncoding;
+use crate::codegen::encoding::eth_abi_encoding::EthAbiEncoding;
 use crate::codegen::encoding::scale_encoding::ScaleEncoding;
 use crate::codegen::expression::load_storage;
 use crate::codegen::vartable::Vartable;
@@ -38,7 +40,37 @@ pub(super) fn abi_encode(
     cfg: &mut ControlFlowGraph,
     packed: bool,
 ) -> (Expression, Expression) {
-    let mut encoder = create_encoder(ns, packed);
+    encode_with(create_encoder(ns, packed), loc, args, ns, vartab, cfg)
+}
+
+/// Insert encoding instructions into the `cfg` for any `Expression` in `args`, using the
+/// Ethereum ABI regardless of the target.
+/// Returns a pointer to the encoded data and the size as a 32bit integer.
+pub(super) fn abi_encode_ethereum(
+    loc: &Loc,
+    args: Vec<Expression>,
+    ns: &Namespace,
+    vartab: &mut Vartable,
+    cfg: &mut ControlFlowGraph,
+) -> (Expression, Expression) {
+    encode_with(
+        Box::new(EthAbiEncoding::new(false)),
+        loc,
+        args,
+        ns,
+        vartab,
+        cfg,
+    )
+}
+
+fn encode_with(
+    mut encoder: Box<dyn AbiEncoding>,
+    loc: &Loc,
+    args: Vec<Expression>,
+    ns: &Namespace,
+    vartab: &mut Vartable,
+    cfg: &mut ControlFlowGraph,
+) -> (Expression, Expression) {
     let size = calculate_size_args(&mut encoder, &args, ns, vartab, cfg);
     let encoded_bytes = vartab.temp_name("abi_encoded", &Type::DynamicBytes);
     let expr = Expression::AllocDynamicBytes {
@@ -56,26 +88,12 @@ pub(super) fn abi_encode(
         },
     );
 
-    let mut offset = Expression::NumberLiteral {
-        loc: *loc,
-        ty: Uint(32),
-        value: BigInt::zero(),
-    };
     let buffer = Expression::Variable {
         loc: *loc,
         ty: Type::DynamicBytes,
         var_no: encoded_bytes,
     };
-    for (arg_no, item) in args.iter().enumerate() {
-        let advance = encoder.encode(item, &buffer, &offset, arg_no, ns, vartab, cfg);
-        offset = Expression::Add {
-            loc: *loc,
-            ty: Uint(32),
-            overflowing: false,
-            left: offset.into(),
-            right: advance.into(),
-        };
-    }
+    encoder.encode_args(&args, &buffer, ns, vartab, cfg);
     (buffer, size)
 }
 
@@ -89,6 +107,51 @@ pub(super) fn abi_decode(
     vartab: &mut Vartable,
     cfg: &mut ControlFlowGraph,
     buffer_size_expr: Option<Expression>,
+) -> Vec<Expression> {
+    decode_with(
+        create_encoder(ns, false),
+        loc,
+        buffer,
+        types,
+        ns,
+        vartab,
+        cfg,
+        buffer_size_expr,
+    )
+}
+
+/// Insert decoding routines into the `cfg` for the `Expression`s in `args`, using the
+/// Ethereum ABI regardless of the target.
+/// Returns a vector containing the encoded data.
+pub(super) fn abi_decode_ethereum(
+    loc: &Loc,
+    buffer: &Expression,
+    types: &[Type],
+    ns: &Namespace,
+    vartab: &mut Vartable,
+    cfg: &mut ControlFlowGraph,
+) -> Vec<Expression> {
+    decode_with(
+        Box::new(EthAbiEncoding::new(false)),
+        loc,
+        buffer,
+        types,
+        ns,
+        vartab,
+        cfg,
+        None,
+    )
+}
+
+fn decode_with(
+    encoder: Box<dyn AbiEncoding>,
+    loc: &Loc,
+    buffer: &Expression,
+    types: &[Type],
+    ns: &Namespace,
+    vartab: &mut Vartable,
+    cfg: &mut ControlFlowGraph,
+    buffer_size_expr: Option<Expression>,
 ) -> Vec<Expression> {
     let buffer_size = vartab.temp_anonymous(&Uint(32));
     if let Some(length_expression) = buffer_size_expr {
@@ -118,34 +181,15 @@ pub(super) fn abi_decode(
 
     let mut validator = BufferValidator::new(buffer_size, types);
 
-    let mut read_items: Vec<Expression> = vec![Expression::Poison; types.len()];
-    let mut offset = Expression::NumberLiteral {
+    let offset = Expression::NumberLiteral {
         loc: *loc,
         ty: Uint(32),
         value: BigInt::zero(),
     };
 
     validator.initialize_validation(&offset, ns, vartab, cfg);
-    let encoder = create_encoder(ns, false);
-
-    for (item_no, item) in types.iter().enumerate() {
-        validator.set_argument_number(item_no);
-        validator.validate_buffer(&offset, ns, vartab, cfg);
-        let (read_item, advance) =
-            encoder.read_from_buffer(buffer, &offset, item, &mut validator, ns, vartab, cfg);
-        read_items[item_no] = read_item;
-        offset = Expression::Add {
-            loc: *loc,
-            ty: Uint(32),
-            overflowing: false,
-            left: Box::new(offset),
-            right: Box::new(advance),
-        };
-    }
 
-    validator.validate_all_bytes_read(offset, ns, vartab, cfg);
-
-    read_items
+    encoder.decode_args(buffer, types, &mut validator, ns, vartab, cfg)
 }
 
 /// Calculate the size of a set of arguments to encoding functions
@@ -189,6 +233,71 @@ pub(crate) trait AbiEncoding {
         cfg: &mut ControlFlowGraph,
     ) -> Expression;
 
+    /// Encode all `args` into `buffer`, starting at offset zero. The default implementation
+    /// writes the arguments one after the other.
+    fn encode_args(
+        &mut self,
+        args: &[Expression],
+        buffer: &Expression,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) {
+        let mut offset = Expression::NumberLiteral {
+            loc: Codegen,
+            ty: Uint(32),
+            value: BigInt::zero(),
+        };
+        for (arg_no, item) in args.iter().enumerate() {
+            let advance = self.encode(item, buffer, &offset, arg_no, ns, vartab, cfg);
+            offset = Expression::Add {
+                loc: Codegen,
+                ty: Uint(32),
+                overflowing: false,
+                left: offset.into(),
+                right: advance.into(),
+            };
+        }
+    }
+
+    /// Decode values of the given `types` from `buffer`, starting at offset zero. The default
+    /// implementation reads the values one after the other, and checks that all bytes were read.
+    fn decode_args(
+        &self,
+        buffer: &Expression,
+        types: &[Type],
+        validator: &mut BufferValidator,
+        ns: &Namespace,
+        vartab: &mut Vartable,
+        cfg: &mut ControlFlowGraph,
+    ) -> Vec<Expression> {
+        let mut read_items: Vec<Expression> = vec![Expression::Poison; types.len()];
+        let mut offset = Expression::NumberLiteral {
+            loc: Codegen,
+            ty: Uint(32),
+            value: BigInt::zero(),
+        };
+
+        for (item_no, item) in types.iter().enumerate() {
+            validator.set_argument_number(item_no);
+            validator.validate_buffer(&offset, ns, vartab, cfg);
+            let (read_item, advance) =
+                self.read_from_buffer(buffer, &offset, item, validator, ns, vartab, cfg);
+            read_items[item_no] = read_item;
+            offset = Expression::Add {
+                loc: Codegen,
+                ty: Uint(32),
+                overflowing: false,
+                left: Box::new(offset),
+                right: Box::new(advance),
+            };
+        }
+
+        validator.validate_all_bytes_read(offset, ns, vartab, cfg);
+
+        read_items
+    }
+
     /// Provide generic encoding for any given `expr` into `buffer`, depending on its `Type`.
     /// Relies on the methods encoding individual expressions (`encode_*`) to return the encoded size.
     fn encode(
@@ -762,7 +871,8 @@ pub(crate) trait AbiEncoding {
             | Type::Contract(_)
             | Type::Enum(_)
             | Type::Value
-            | Type::Bytes(_) => {
+            | Type::Bytes(_)
+            | Type::FunctionSelector => {
                 let read_bytes = ty.memory_size_of(ns);
 
                 let size = Expression::NumberLiteral {
@@ -801,7 +911,7 @@ pub(crate) trait AbiEncoding {
             Type::DynamicBytes | Type::String => {
                 // String and Dynamic bytes are encoded as size + elements
                 let (array_length_var, size_length) =
-                    self.retrieve_array_length(buffer, offset, vartab, cfg);
+                    self.retrieve_array_length(buffer, offset, ns, vartab, cfg);
                 let array_start = offset.clone().add_u32(size_length.clone());
                 validator.validate_offset(array_start.clone(), ns, vartab, cfg);
                 let array_length = Expression::Variable {
@@ -883,7 +993,6 @@ pub(crate) trait AbiEncoding {
             | Type::InternalFunction { .. }
             | Type::Unreachable
             | Type::Void
-            | Type::FunctionSelector
             | Type::Mapping(..) => unreachable!("Type should not appear on an encoded buffer"),
         }
     }
@@ -894,6 +1003,7 @@ pub(crate) trait AbiEncoding {
         &self,
         buffer: &Expression,
         offset: &Expression,
+        ns: &Namespace,
         vartab: &mut Vartable,
         cfg: &mut ControlFlowGraph,
     ) -> (usize, Expression);
@@ -949,7 +1059,7 @@ pub(crate) trait AbiEncoding {
                     )
                 } else {
                     let (array_length, size_width) =
-                        self.retrieve_array_length(buffer, offset, vartab, cfg);
+                        self.retrieve_array_length(buffer, offset, ns, vartab, cfg);
                     let array_start = offset.clone().add_u32(size_width.clone());
                     validator.validate_offset(array_start.clone(), ns, vartab, cfg);
                     (
@@ -1108,7 +1218,7 @@ pub(crate) trait AbiEncoding {
         // Dynamic dimensions mean that the subarray we are processing must be allocated in memory.
         if dims[dimension] == ArrayLength::Dynamic {
             let (array_length, size_length) =
-                self.retrieve_array_length(buffer, offset_expr, vartab, cfg);
+                self.retrieve_array_length(buffer, offset_expr, ns, vartab, cfg);
             let array_start = offset_expr.clone().add_u32(size_length);
             validator.validate_offset(array_start.clone(), ns, vartab, cfg);
             cfg.add(
@@ -1422,7 +1532,7 @@ pub(crate) trait AbiEncoding {
                 self.get_expr_size(arg_no, &loaded, ns, vartab, cfg)
             }
             Type::StorageRef(_, r) => {
-                let var = load_storage(&Codegen, r, expr.clone(), cfg, vartab);
+                let var = load_storage(&Codegen, r, expr.clone(), false, cfg, vartab);
                 let size = self.get_expr_size(arg_no, &var, ns, vartab, cfg);
                 self.storage_cache_insert(arg_no, var.clone());
                 size
@@ -1746,7 +1856,8 @@ pub(crate) trait AbiEncoding {
 pub(crate) fn create_encoder(ns: &Namespace, packed: bool) -> Box<dyn AbiEncoding> {
     match &ns.target {
         Target::Solana => Box::new(BorshEncoding::new(packed)),
-        // Solana utilizes Borsh encoding and Polkadot, SCALE encoding.
+        Target::PolkadotRevive => Box::new(EthAbiEncoding::new(packed)),
+        // Solana utilizes Borsh encoding, PolkaVM the Ethereum ABI and Polkadot, SCALE encoding.
         // All other targets are using the SCALE encoding, because we have tests for a
         // fake Ethereum target that checks the presence of Instr::AbiDecode and
         // Expression::AbiEncode.
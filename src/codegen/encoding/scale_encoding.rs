@@ -543,6 +543,7 @@ impl AbiEncoding for ScaleEncoding {
         &self,
         buffer: &Expression,
         offset: &Expression,
+        _ns: &Namespace,
         vartab: &mut Vartable,
         cfg: &mut ControlFlowGraph,
     ) -> (usize, Expression) {
@@ -597,7 +598,7 @@ impl AbiEncoding for ScaleEncoding {
                     ..
                 } if matches!(**inner, Type::Bytes(1)) => result.extend_from_slice(data),
                 Expression::NumberLiteral {
-                    ty: Type::Bytes(4),
+                    ty: Type::Bytes(4) | Type::FunctionSelector,
                     value,
                     ..
                 } => {
@@ -11,9 +11,11 @@ use crate::codegen::{Builtin, Expression, Options};
 use crate::sema::ast::{self, Function, Namespace, RetrieveType, Type};
 use ink_env::hash::{Blake2x256, CryptoHash};
 use solang_parser::pt;
+use tiny_keccak::{Hasher, Keccak};
 
 /// Implements [EventEmitter] to handle the emission of events on Polkadot.
-/// Data and topic encoding follow [ink! v5.0][0].
+/// Data and topic encoding follow [ink! v5.0][0]. On PolkaVM, topics are hashed with
+/// keccak256 and only the non-indexed fields are part of the data, like on Ethereum.
 ///
 /// [0]: https://use.ink/basics/events/#topics
 pub(super) struct PolkadotEventEmitter<'a> {
@@ -27,7 +29,13 @@ impl EventEmitter for PolkadotEventEmitter<'_> {
     fn selector(&self, _emitting_contract_no: usize) -> Vec<u8> {
         let signature = self.ns.events[self.event_no].signature.as_bytes();
         let mut buf = [0; 32];
-        <Blake2x256 as CryptoHash>::hash(signature, &mut buf);
+        if self.ns.target.is_polkadot_revive() {
+            let mut hasher = Keccak::v256();
+            hasher.update(signature);
+            hasher.finalize(&mut buf);
+        } else {
+            <Blake2x256 as CryptoHash>::hash(signature, &mut buf);
+        }
         buf.into()
     }
 
@@ -47,6 +55,11 @@ impl EventEmitter for PolkadotEventEmitter<'_> {
             value: 32.into(),
         });
         let (mut data, mut topics) = (Vec::new(), Vec::new());
+        let hash_builtin = if self.ns.target.is_polkadot_revive() {
+            Builtin::Keccak256
+        } else {
+            Builtin::Blake2_256
+        };
 
         // Events that are not anonymous always have themselves as a topic.
         // This is static and can be calculated at compile time.
@@ -129,7 +142,7 @@ impl EventEmitter for PolkadotEventEmitter<'_> {
                     value: Expression::Builtin {
                         loc,
                         tys: vec![Type::Bytes(32)],
-                        kind: Builtin::Blake2_256,
+                        kind: hash_builtin,
                         args: vec![buffer.clone()],
                     },
                 },
@@ -146,7 +159,9 @@ impl EventEmitter for PolkadotEventEmitter<'_> {
         let data = self
             .args
             .iter()
-            .map(|e| expression(e, cfg, contract_no, Some(func), self.ns, vartab, opt))
+            .zip(event.fields.iter())
+            .filter(|(_, field)| !(field.indexed && self.ns.target.is_polkadot_revive()))
+            .map(|(e, _)| expression(e, cfg, contract_no, Some(func), self.ns, vartab, opt))
             .collect::<Vec<_>>();
         let encoded_data = data
             .is_empty()
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use super::encoding::{abi_decode, abi_encode};
+use super::encoding::{abi_decode, abi_decode_ethereum, abi_encode, abi_encode_ethereum};
 use super::revert::{
     assert_failure, expr_assert, log_runtime_error, require, PanicCode, SolidityError,
 };
@@ -60,7 +60,7 @@ pub fn expression(
         ast::Expression::StorageLoad { loc, ty, expr } => {
             let storage = expression(expr, cfg, contract_no, func, ns, vartab, opt);
 
-            load_storage(loc, ty, storage, cfg, vartab)
+            load_storage(loc, ty, storage, is_transient(expr, ns), cfg, vartab)
         }
         ast::Expression::Add {
             loc,
@@ -543,7 +543,7 @@ pub fn expression(
                                 elem_ty: elem_ty.clone(),
                             }
                         } else {
-                            load_storage(loc, &ns.storage_type(), array, cfg, vartab)
+                            load_storage(loc, &ns.storage_type(), array, false, cfg, vartab)
                         }
                     }
                     ArrayLength::Fixed(length) => {
@@ -610,7 +610,7 @@ pub fn expression(
         | ast::Expression::ExternalFunctionCall { .. }
         | ast::Expression::ExternalFunctionCallRaw { .. }
         | ast::Expression::Builtin {
-            kind: ast::Builtin::AbiDecode,
+            kind: ast::Builtin::AbiDecode | ast::Builtin::AbiDecodeEthereum,
             ..
         } => {
             let mut returns = emit_function_call(expr, contract_no, cfg, func, ns, vartab, opt);
@@ -996,6 +996,12 @@ pub fn expression(
             args,
             ..
         } => abi_encode_call(args, cfg, contract_no, func, ns, vartab, loc, opt),
+        ast::Expression::Builtin {
+            loc,
+            kind: ast::Builtin::AbiEncodeEthereum,
+            args,
+            ..
+        } => abi_encode_ethereum_many(args, cfg, contract_no, func, ns, vartab, loc, opt),
         // The Polkadot gas price builtin takes an argument; the others do not
         ast::Expression::Builtin {
             loc,
@@ -1222,7 +1228,14 @@ fn post_incdec(
             ty: ty.as_ref().clone(),
             expr: Box::new(v),
         },
-        Type::StorageRef(_, ty) => load_storage(&var.loc(), ty.as_ref(), v, cfg, vartab),
+        Type::StorageRef(_, ty) => load_storage(
+            &var.loc(),
+            ty.as_ref(),
+            v,
+            is_transient(var, ns),
+            cfg,
+            vartab,
+        ),
         _ => v,
     };
     cfg.add(
@@ -1298,6 +1311,7 @@ fn post_incdec(
                             },
                             ty: ty.clone(),
                             storage: dest,
+                            transient: is_transient(var, ns),
                         },
                     );
                 }
@@ -1346,7 +1360,14 @@ fn pre_incdec(
             ty: ty.as_ref().clone(),
             expr: Box::new(v),
         },
-        Type::StorageRef(_, ty) => load_storage(&var.loc(), ty.as_ref(), v, cfg, vartab),
+        Type::StorageRef(_, ty) => load_storage(
+            &var.loc(),
+            ty.as_ref(),
+            v,
+            is_transient(var, ns),
+            cfg,
+            vartab,
+        ),
         _ => v,
     };
     let one = Box::new(Expression::NumberLiteral {
@@ -1409,6 +1430,7 @@ fn pre_incdec(
                             },
                             ty: ty.clone(),
                             storage: dest,
+                            transient: is_transient(var, ns),
                         },
                     );
                 }
@@ -1621,6 +1643,8 @@ fn payable_send(
                 callty: CallTy::Regular,
                 contract_function_no: None,
                 flags: None,
+                proof_size: None,
+                storage_deposit_limit: None,
             },
         );
         return Expression::Variable {
@@ -1639,11 +1663,7 @@ fn payable_send(
         },
     );
 
-    if ns.target != Target::Solana {
-        polkadot::check_transfer_ret(loc, success, cfg, ns, opt, vartab, false).unwrap()
-    } else {
-        unreachable!("Value transfer does not exist on Solana");
-    }
+    polkadot::check_transfer_ret(loc, success, cfg, ns, opt, vartab, false).unwrap()
 }
 
 fn payable_transfer(
@@ -1687,14 +1707,14 @@ fn payable_transfer(
                 callty: CallTy::Regular,
                 contract_function_no: None,
                 flags: None,
+                proof_size: None,
+                storage_deposit_limit: None,
             },
         );
         return Expression::Poison;
     }
 
-    let success = ns
-        .target
-        .is_polkadot()
+    let success = (ns.target.is_polkadot() || ns.target == Target::Solana)
         .then(|| vartab.temp_name("success", &Type::Uint(32)));
     let ins = Instr::ValueTransfer {
         success,
@@ -1703,8 +1723,8 @@ fn payable_transfer(
     };
     cfg.add(vartab, ins);
 
-    if ns.target.is_polkadot() {
-        polkadot::check_transfer_ret(loc, success.unwrap(), cfg, ns, opt, vartab, true);
+    if let Some(success) = success {
+        polkadot::check_transfer_ret(loc, success, cfg, ns, opt, vartab, true);
     }
 
     Expression::Poison
@@ -1728,6 +1748,24 @@ fn abi_encode_many(
     abi_encode(loc, args, ns, vartab, cfg, false).0
 }
 
+fn abi_encode_ethereum_many(
+    args: &[ast::Expression],
+    cfg: &mut ControlFlowGraph,
+    contract_no: usize,
+    func: Option<&Function>,
+    ns: &Namespace,
+    vartab: &mut Vartable,
+    loc: &pt::Loc,
+    opt: &Options,
+) -> Expression {
+    let args = args
+        .iter()
+        .map(|v| expression(v, cfg, contract_no, func, ns, vartab, opt))
+        .collect::<Vec<Expression>>();
+
+    abi_encode_ethereum(loc, args, ns, vartab, cfg).0
+}
+
 fn abi_encode_packed(
     args: &[ast::Expression],
     cfg: &mut ControlFlowGraph,
@@ -1755,6 +1793,16 @@ fn encode_many_with_selector(
     vartab: &mut Vartable,
     cfg: &mut ControlFlowGraph,
 ) -> Expression {
+    // The selector is not part of the encoded arguments
+    let selector = if selector.ty() == Type::Bytes(ns.target.selector_length()) {
+        Expression::Cast {
+            loc: *loc,
+            ty: Type::FunctionSelector,
+            expr: selector.into(),
+        }
+    } else {
+        selector
+    };
     let mut encoder_args: Vec<Expression> = Vec::with_capacity(args.len() + 1);
     encoder_args.push(selector);
     encoder_args.append(&mut args);
@@ -2694,6 +2742,7 @@ pub fn assign_single(
                             },
                             ty: ty.deref_any().clone(),
                             storage: dest,
+                            transient: is_transient(left, ns),
                         },
                     );
                 }
@@ -2905,6 +2954,14 @@ pub fn emit_function_call(
                 .flags
                 .as_ref()
                 .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
+            let proof_size = call_args
+                .proof_size
+                .as_ref()
+                .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
+            let storage_deposit_limit = call_args
+                .storage_deposit_limit
+                .as_ref()
+                .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
 
             cfg.add(
                 vartab,
@@ -2920,6 +2977,8 @@ pub fn emit_function_call(
                     callty: ty.clone(),
                     contract_function_no: None,
                     flags,
+                    proof_size,
+                    storage_deposit_limit,
                 },
             );
 
@@ -2998,13 +3057,13 @@ pub fn emit_function_call(
 
                 let selector = dest_func.selector(ns, &caller_contract_no);
 
-                tys.insert(0, Type::Bytes(selector.len() as u8));
+                tys.insert(0, Type::FunctionSelector);
 
                 args.insert(
                     0,
                     Expression::BytesLiteral {
                         loc: *loc,
-                        ty: Type::Bytes(selector.len() as u8),
+                        ty: Type::FunctionSelector,
                         value: selector,
                     },
                 );
@@ -3015,6 +3074,14 @@ pub fn emit_function_call(
                     .flags
                     .as_ref()
                     .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
+                let proof_size = call_args
+                    .proof_size
+                    .as_ref()
+                    .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
+                let storage_deposit_limit = call_args
+                    .storage_deposit_limit
+                    .as_ref()
+                    .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
 
                 let success = ns
                     .target
@@ -3034,6 +3101,8 @@ pub fn emit_function_call(
                         callty: CallTy::Regular,
                         contract_function_no,
                         flags,
+                        proof_size,
+                        storage_deposit_limit,
                     },
                 );
 
@@ -3094,7 +3163,7 @@ pub fn emit_function_call(
                 let selector = function.external_function_selector();
                 let address = function.external_function_address();
 
-                tys.insert(0, Type::Bytes(ns.target.selector_length()));
+                tys.insert(0, Type::FunctionSelector);
                 args.insert(0, selector);
 
                 let (payload, _) = abi_encode(loc, args, ns, vartab, cfg, false);
@@ -3103,6 +3172,14 @@ pub fn emit_function_call(
                     .flags
                     .as_ref()
                     .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
+                let proof_size = call_args
+                    .proof_size
+                    .as_ref()
+                    .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
+                let storage_deposit_limit = call_args
+                    .storage_deposit_limit
+                    .as_ref()
+                    .map(|expr| expression(expr, cfg, caller_contract_no, func, ns, vartab, opt));
                 let success = ns
                     .target
                     .is_polkadot()
@@ -3121,6 +3198,8 @@ pub fn emit_function_call(
                         callty: CallTy::Regular,
                         contract_function_no: None,
                         flags,
+                        proof_size,
+                        storage_deposit_limit,
                     },
                 );
 
@@ -3159,6 +3238,15 @@ pub fn emit_function_call(
             let data = expression(&args[0], cfg, caller_contract_no, func, ns, vartab, opt);
             abi_decode(loc, &data, tys, ns, vartab, cfg, None)
         }
+        ast::Expression::Builtin {
+            loc,
+            tys,
+            kind: ast::Builtin::AbiDecodeEthereum,
+            args,
+        } => {
+            let data = expression(&args[0], cfg, caller_contract_no, func, ns, vartab, opt);
+            abi_decode_ethereum(loc, &data, tys, ns, vartab, cfg)
+        }
         _ => unreachable!(),
     }
 }
@@ -3253,7 +3341,7 @@ fn array_subscript(
                         }
                     } else {
                         let array_length =
-                            load_storage(loc, &Type::Uint(256), array.clone(), cfg, vartab);
+                            load_storage(loc, &Type::Uint(256), array.clone(), false, cfg, vartab);
 
                         array = Expression::Keccak256 {
                             loc: *loc,
@@ -3598,6 +3686,7 @@ pub fn load_storage(
     loc: &pt::Loc,
     ty: &Type,
     storage: Expression,
+    transient: bool,
     cfg: &mut ControlFlowGraph,
     vartab: &mut Vartable,
 ) -> Expression {
@@ -3608,6 +3697,7 @@ pub fn load_storage(
             res,
             ty: ty.clone(),
             storage,
+            transient,
         },
     );
 
@@ -3618,6 +3708,19 @@ pub fn load_storage(
     }
 }
 
+/// Does this expression refer to a state variable declared `transient`. Transient variables
+/// are value types, so there are no subscripts or struct members to look through.
+pub(crate) fn is_transient(expr: &ast::Expression, ns: &Namespace) -> bool {
+    match expr {
+        ast::Expression::StorageVariable {
+            contract_no,
+            var_no,
+            ..
+        } => ns.contracts[*contract_no].variables[*var_no].transient,
+        _ => false,
+    }
+}
+
 fn array_literal_to_memory_array(
     loc: &pt::Loc,
     expr: &Expression,
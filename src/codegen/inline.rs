@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::cfg::{optimize_and_check_cfg, ASTFunction, ControlFlowGraph, Instr, InternalCallTy};
+use super::{Expression, Options};
+use crate::sema::ast::Namespace;
+use indexmap::IndexMap;
+use solang_parser::pt::CodeLocation;
+use std::collections::{BTreeSet, HashSet};
+
+/// Functions with more instructions than this are not inlined
+const INLINE_INSTR_LIMIT: usize = 24;
+
+/// Replace calls to small internal functions with the body of the function. Every call has a cost,
+/// and after inlining, the other passes can optimize the function body for the arguments given at
+/// the call site.
+///
+/// Only functions which do not call other functions are inlined, so there is no recursion to
+/// worry about. Variable numbers are unique within a namespace, so the variables of the callee can
+/// be added to the caller as they are.
+pub(super) fn inline(all_cfg: &mut [ControlFlowGraph], ns: &mut Namespace, opt: &Options) {
+    for caller_no in 0..all_cfg.len() {
+        // Only inline into functions, not into modifiers, the storage initializer or the default
+        // constructor
+        if all_cfg[caller_no].is_placeholder()
+            || all_cfg[caller_no].function_no == ASTFunction::None
+        {
+            continue;
+        }
+
+        let mut inlined = HashSet::new();
+
+        while let Some((block_no, instr_no, callee_no)) = find_call(caller_no, all_cfg, &inlined) {
+            let callee = all_cfg[callee_no].clone();
+
+            inline_call(&mut all_cfg[caller_no], block_no, instr_no, &callee);
+
+            inlined.insert(callee_no);
+        }
+
+        if !inlined.is_empty() {
+            let cfg = &mut all_cfg[caller_no];
+            let function_no = cfg.function_no;
+
+            for block in &mut cfg.blocks {
+                block.defs = IndexMap::new();
+            }
+
+            // The diagnostics were reported when the functions were optimized the first time
+            let diagnostics = std::mem::take(&mut ns.diagnostics);
+
+            optimize_and_check_cfg(cfg, ns, function_no, opt);
+
+            ns.diagnostics = diagnostics;
+        }
+    }
+}
+
+/// Find a call in the caller which can be inlined
+fn find_call(
+    caller_no: usize,
+    all_cfg: &[ControlFlowGraph],
+    inlined: &HashSet<usize>,
+) -> Option<(usize, usize, usize)> {
+    let caller = &all_cfg[caller_no];
+
+    for (block_no, block) in caller.blocks.iter().enumerate() {
+        for (instr_no, instr) in block.instr.iter().enumerate() {
+            if let Instr::Call {
+                call: InternalCallTy::Static { cfg_no },
+                ..
+            } = instr
+            {
+                if *cfg_no != caller_no
+                    && can_inline(&all_cfg[*cfg_no])
+                    && (inlined.contains(cfg_no)
+                        || all_cfg[*cfg_no]
+                            .vars
+                            .keys()
+                            .all(|var_no| !caller.vars.contains_key(var_no)))
+                {
+                    return Some((block_no, instr_no, *cfg_no));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Can this function be inlined
+fn can_inline(callee: &ControlFlowGraph) -> bool {
+    // A public function is emitted for the dispatcher anyway
+    if callee.is_placeholder() || callee.public {
+        return false;
+    }
+
+    let mut size = 0;
+
+    for block in &callee.blocks {
+        for instr in &block.instr {
+            size += 1;
+
+            match instr {
+                Instr::Call { .. } => return false,
+                // Arguments must only be used to set the parameter variables
+                Instr::Set {
+                    expr: Expression::FunctionArg { .. },
+                    ..
+                } => (),
+                _ => {
+                    let mut uses_arg = false;
+
+                    instr.recurse_expressions(&mut uses_arg, |expr, uses_arg| {
+                        if matches!(expr, Expression::FunctionArg { .. }) {
+                            *uses_arg = true;
+                        }
+                        !*uses_arg
+                    });
+
+                    if uses_arg {
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+
+    size <= INLINE_INSTR_LIMIT
+}
+
+/// Replace the call at the given instruction with the body of the callee
+fn inline_call(
+    caller: &mut ControlFlowGraph,
+    block_no: usize,
+    instr_no: usize,
+    callee: &ControlFlowGraph,
+) {
+    let tail = caller.blocks[block_no].instr.split_off(instr_no + 1);
+
+    let Some(Instr::Call { res, args, .. }) = caller.blocks[block_no].instr.pop() else {
+        unreachable!();
+    };
+
+    let offset = caller.blocks.len();
+    let continuation = offset + callee.blocks.len();
+
+    caller.blocks[block_no]
+        .instr
+        .push(Instr::Branch { block: offset });
+
+    for block in &callee.blocks {
+        let mut block = block.clone();
+
+        block.name = format!("{}::{}", callee.name, block.name);
+        block.defs = IndexMap::new();
+        block.transfers = Vec::new();
+
+        for instr in &mut block.instr {
+            match instr {
+                Instr::Set { expr, .. } => {
+                    if let Expression::FunctionArg { arg_no, .. } = expr {
+                        *expr = args[*arg_no].clone();
+                    }
+                }
+                Instr::Branch { block } => *block += offset,
+                Instr::BranchCond {
+                    true_block,
+                    false_block,
+                    ..
+                } => {
+                    *true_block += offset;
+                    *false_block += offset;
+                }
+                Instr::Switch { cases, default, .. } => {
+                    for (_, block) in cases {
+                        *block += offset;
+                    }
+                    *default += offset;
+                }
+                _ => (),
+            }
+        }
+
+        // The return values are assigned to the result variables of the call
+        if let Some(Instr::Return { value }) = block.instr.last().cloned() {
+            block.instr.pop();
+
+            for (res, expr) in res.iter().zip(value) {
+                block.instr.push(Instr::Set {
+                    loc: expr.loc(),
+                    res: *res,
+                    expr,
+                });
+            }
+
+            block.instr.push(Instr::Branch {
+                block: continuation,
+            });
+        }
+
+        caller.blocks.push(block);
+    }
+
+    for (var_no, var) in &callee.vars {
+        caller.vars.entry(*var_no).or_insert_with(|| var.clone());
+    }
+
+    for (array, length) in &callee.array_lengths_temps {
+        caller.array_lengths_temps.insert(*array, *length);
+    }
+
+    let continuation = caller.new_basic_block(format!("{}::return", callee.name));
+
+    caller.blocks[continuation].instr = tail;
+
+    // The callee may return from more than one block
+    caller.set_phis(continuation, res.into_iter().collect::<BTreeSet<usize>>());
+}
@@ -4,11 +4,13 @@ mod array_boundary;
 pub mod cfg;
 mod constant_folding;
 mod constructor;
+mod dead_code;
 mod dead_storage;
 pub(crate) mod dispatch;
 pub(crate) mod encoding;
 mod events;
 mod expression;
+mod inline;
 pub(super) mod polkadot;
 mod reaching_definitions;
 pub mod revert;
@@ -37,12 +39,14 @@ use crate::sema::ast::{
 };
 use crate::{sema::ast, Target};
 use std::cmp::Ordering;
+use std::collections::HashMap;
 
 use crate::codegen::cfg::ASTFunction;
 use crate::codegen::solana_accounts::account_management::manage_contract_accounts;
 use crate::codegen::yul::generate_yul_function_cfg;
 use crate::sema::diagnostics::Diagnostics;
 use crate::sema::eval::eval_const_number;
+use crate::sema::types::erc7201_slot;
 use crate::sema::Recurse;
 #[cfg(feature = "wasm_opt")]
 use contract_build::OptimizationPasses;
@@ -101,6 +105,8 @@ pub struct Options {
     pub strength_reduce: bool,
     pub vector_to_slice: bool,
     pub common_subexpression_elimination: bool,
+    pub inline: bool,
+    pub dead_code: bool,
     pub generate_debug_information: bool,
     pub opt_level: OptimizationLevel,
     pub log_runtime_errors: bool,
@@ -117,6 +123,8 @@ impl Default for Options {
             strength_reduce: true,
             vector_to_slice: true,
             common_subexpression_elimination: true,
+            inline: true,
+            dead_code: true,
             generate_debug_information: false,
             opt_level: OptimizationLevel::Default,
             log_runtime_errors: false,
@@ -134,6 +142,15 @@ pub fn codegen(ns: &mut Namespace, opt: &Options) {
         return;
     }
 
+    // Without the linker, contracts for PolkaVM cannot be emitted
+    if ns.target == Target::PolkadotRevive && !cfg!(feature = "polkavm") {
+        ns.diagnostics.push(Diagnostic::error(
+            pt::Loc::CommandLine,
+            "polkadot-revive target requires solang to be built with the polkavm feature".into(),
+        ));
+        return;
+    }
+
     let mut contracts_done = Vec::new();
 
     contracts_done.resize(ns.contracts.len(), false);
@@ -247,6 +264,10 @@ fn contract(contract_no: usize, ns: &mut Namespace, opt: &Options) {
             ns.contracts[contract_no].default_constructor = Some((func, cfg_no));
         }
 
+        if opt.inline {
+            inline::inline(&mut all_cfg, ns, opt);
+        }
+
         for mut dispatch_cfg in function_dispatch(contract_no, &all_cfg, ns, opt) {
             optimize_and_check_cfg(&mut dispatch_cfg, ns, ASTFunction::None, opt);
             all_cfg.push(dispatch_cfg);
@@ -282,6 +303,7 @@ fn storage_initializer(contract_no: usize, ns: &mut Namespace, opt: &Options) ->
                     value,
                     ty: var.ty.clone(),
                     storage,
+                    transient: false,
                 },
             );
         }
@@ -300,15 +322,76 @@ fn storage_initializer(contract_no: usize, ns: &mut Namespace, opt: &Options) ->
 fn layout(contract_no: usize, ns: &mut Namespace) {
     let mut slot = if ns.target == Target::Solana {
         BigInt::from(SOLANA_FIRST_OFFSET)
+    } else if let Some(base) = &ns.contracts[contract_no].layout_base {
+        base.clone()
     } else {
         BigInt::zero()
     };
 
+    // ERC-7201 namespaces in use, and the variable which uses it
+    let mut namespaces: HashMap<String, pt::Loc> = HashMap::new();
+
+    // Transient variables have their own storage, which starts at zero
+    let mut transient_slot = BigInt::zero();
+
     for base_contract_no in ns.contract_bases(contract_no) {
         for var_no in 0..ns.contracts[base_contract_no].variables.len() {
             if !ns.contracts[base_contract_no].variables[var_no].constant {
                 let ty = ns.contracts[base_contract_no].variables[var_no].ty.clone();
 
+                if ns.contracts[base_contract_no].variables[var_no].transient {
+                    if ns.target == Target::Solana {
+                        let alignment = ty.align_of(ns);
+
+                        let offset = transient_slot.clone() % alignment;
+
+                        if offset > BigInt::zero() {
+                            transient_slot += alignment - offset;
+                        }
+                    }
+
+                    ns.contracts[contract_no].transient_layout.push(Layout {
+                        slot: transient_slot.clone(),
+                        contract_no: base_contract_no,
+                        var_no,
+                        ty: ty.clone(),
+                    });
+
+                    transient_slot += ty.storage_slots(ns);
+
+                    continue;
+                }
+
+                // A struct with a storage namespace lives at its own slot, outside the regular layout
+                if let Type::Struct(ast::StructType::UserDefined(struct_no)) = &ty {
+                    if let Some(namespace) = ns.structs[*struct_no]
+                        .storage_namespace()
+                        .map(str::to_owned)
+                    {
+                        let var_loc = ns.contracts[base_contract_no].variables[var_no].loc;
+
+                        if let Some(prev_loc) = namespaces.get(&namespace) {
+                            ns.diagnostics.push(Diagnostic::error_with_note(
+                                var_loc,
+                                format!("storage namespace '{namespace}' is already in use"),
+                                *prev_loc,
+                                "previous use of namespace".into(),
+                            ));
+                        } else {
+                            namespaces.insert(namespace.clone(), var_loc);
+                        }
+
+                        ns.contracts[contract_no].layout.push(Layout {
+                            slot: erc7201_slot(&namespace),
+                            contract_no: base_contract_no,
+                            var_no,
+                            ty,
+                        });
+
+                        continue;
+                    }
+                }
+
                 if ns.target == Target::Solana {
                     // elements need to be aligned on solana
                     let alignment = ty.align_of(ns);
@@ -353,6 +436,16 @@ fn layout(contract_no: usize, ns: &mut Namespace) {
         }
     }
 
+    if ns.contracts[contract_no].layout_base.is_some() && slot > BigInt::from(1) << 256 {
+        ns.diagnostics.push(Diagnostic::error(
+            ns.contracts[contract_no].loc,
+            format!(
+                "contract '{}' storage extends beyond the last storage slot",
+                ns.contracts[contract_no].id
+            ),
+        ));
+    }
+
     ns.contracts[contract_no].fixed_layout_size = slot;
 }
 
@@ -121,7 +121,7 @@ impl RetCodeCheck {
     }
 }
 
-/// Check the return code of `transfer`.
+/// Check the return code of `transfer`. This is also used for lamport transfers on Solana.
 ///
 /// If `bubble_up` is set to true, this will revert the contract execution on failure.
 /// Otherwise, the expression comparing the return code against `0` is returned.
@@ -24,9 +24,9 @@ use solang_parser::pt::{CodeLocation, Loc, Loc::Codegen};
 use tiny_keccak::{Hasher, Keccak};
 
 /// Signature of `Keccak256('Error(string)')[:4]`
-pub(crate) const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+pub const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
 /// Signature of `Keccak256('Panic(uint256)')[:4]`
-pub(crate) const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+pub const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
 
 /// Corresponds to the error types from the Solidity language.
 ///
@@ -52,7 +52,7 @@ impl SolidityError {
     pub fn selector_expression(&self, ns: &Namespace) -> Expression {
         Expression::NumberLiteral {
             loc: Codegen,
-            ty: Type::Bytes(4),
+            ty: Type::FunctionSelector,
             value: BigInt::from_bytes_be(Sign::Plus, &self.selector(ns)),
         }
     }
@@ -441,7 +441,12 @@ fn check_instruction(instr: &Instr, data: &mut RecurseData) {
             }
         }
 
-        Instr::ValueTransfer { .. } => unreachable!("Value transfer does not exist on Solana"),
+        Instr::ValueTransfer { address, value, .. } => {
+            address.recurse(data, check_expression);
+            value.recurse(data, check_expression);
+            // If the data account is not owned by the program, the system program transfers
+            data.add_system_account();
+        }
         Instr::AccountAccess { .. } => (),
     }
 }
@@ -609,6 +609,8 @@ pub(super) fn solana_deploy(
                 callty: CallTy::Regular,
                 contract_function_no: None,
                 flags: None,
+                proof_size: None,
+                storage_deposit_limit: None,
             },
         );
 
@@ -639,6 +641,7 @@ pub(super) fn solana_deploy(
                 ty: Type::Uint(64),
                 value: BigInt::zero(),
             },
+            transient: false,
         },
     );
 
@@ -663,6 +666,7 @@ pub(super) fn solana_deploy(
                 ty: Type::Uint(64),
                 value: BigInt::from(12),
             },
+            transient: false,
         },
     );
 }
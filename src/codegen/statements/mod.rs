@@ -3,7 +3,7 @@
 use super::{
     cfg::{ControlFlowGraph, Instr},
     events::new_event_emitter,
-    expression::{assign_single, emit_function_call, expression},
+    expression::{assign_single, emit_function_call, expression, is_transient, load_storage},
     revert::revert,
     unused_variable::{
         should_remove_assignment, should_remove_variable, SideEffectsCheckParameters,
@@ -221,6 +221,7 @@ pub(crate) fn statement(
                 Instr::ClearStorage {
                     ty: ty.clone(),
                     storage: var_expr,
+                    transient: is_transient(expr, ns),
                 },
             );
         }
@@ -816,7 +817,7 @@ fn returns(
         }
 
         ast::Expression::Builtin {
-            kind: ast::Builtin::AbiDecode,
+            kind: ast::Builtin::AbiDecode | ast::Builtin::AbiDecodeEthereum,
             ..
         }
         | ast::Expression::InternalFunctionCall { .. }
@@ -913,7 +914,15 @@ fn destructure(
 
             for expr in list {
                 let loc = expr.loc();
-                let expr = expression(expr, cfg, contract_no, Some(func), ns, vartab, opt);
+                let transient = is_transient(expr, ns);
+                let mut expr = expression(expr, cfg, contract_no, Some(func), ns, vartab, opt);
+
+                // try_load_and_cast() cannot tell transient storage apart, so load it here
+                if transient {
+                    let ty = expr.ty().deref_any().clone();
+                    expr = load_storage(&loc, &ty, expr, true, cfg, vartab);
+                }
+
                 let ty = expr.ty();
 
                 let res = vartab.temp_anonymous(&ty);
@@ -1002,6 +1011,7 @@ fn try_load_and_cast(
                     res: anonymous_no,
                     ty: (*ty).clone(),
                     storage: expr.cast(to_ty, ns),
+                    transient: false,
                 },
             );
 
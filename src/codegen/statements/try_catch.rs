@@ -254,6 +254,12 @@ fn exec_try(
                 let flags = call_args.flags.as_ref().map(|expr| {
                     expression(expr, cfg, callee_contract_no, Some(func), ns, vartab, opt)
                 });
+                let proof_size = call_args.proof_size.as_ref().map(|expr| {
+                    expression(expr, cfg, callee_contract_no, Some(func), ns, vartab, opt)
+                });
+                let storage_deposit_limit = call_args.storage_deposit_limit.as_ref().map(|expr| {
+                    expression(expr, cfg, callee_contract_no, Some(func), ns, vartab, opt)
+                });
 
                 cfg.add(
                     vartab,
@@ -269,6 +275,8 @@ fn exec_try(
                         callty: CallTy::Regular,
                         contract_function_no: None,
                         flags,
+                        proof_size,
+                        storage_deposit_limit,
                     },
                 );
 
@@ -449,7 +457,10 @@ fn insert_catch_clauses(
             let clause_body_block = cfg.new_basic_block(format!("catch_error_{}", n));
 
             cfg.set_basic_block(clause_body_block);
-            let types = &[Type::Bytes(4), clause.param.as_ref().unwrap().ty.clone()];
+            let types = &[
+                Type::FunctionSelector,
+                clause.param.as_ref().unwrap().ty.clone(),
+            ];
             let instruction = Instr::Set {
                 loc: Codegen,
                 res: clause
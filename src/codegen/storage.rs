@@ -98,7 +98,7 @@ pub fn storage_slots_array_push(
 
     let var_expr = expression(&args[0], cfg, contract_no, func, ns, vartab, opt);
 
-    let expr = load_storage(loc, &slot_ty, var_expr.clone(), cfg, vartab);
+    let expr = load_storage(loc, &slot_ty, var_expr.clone(), false, cfg, vartab);
 
     cfg.add(
         vartab,
@@ -149,6 +149,7 @@ pub fn storage_slots_array_push(
                     ty: slot_ty.clone(),
                     var_no: entry_pos,
                 },
+                transient: false,
             },
         );
     }
@@ -176,6 +177,7 @@ pub fn storage_slots_array_push(
             ty: slot_ty,
             value: new_length,
             storage: var_expr,
+            transient: false,
         },
     );
 
@@ -210,7 +212,7 @@ pub fn storage_slots_array_pop(
     let ty = args[0].ty();
     let var_expr = expression(&args[0], cfg, contract_no, func, ns, vartab, opt);
 
-    let expr = load_storage(loc, &length_ty, var_expr.clone(), cfg, vartab);
+    let expr = load_storage(loc, &length_ty, var_expr.clone(), false, cfg, vartab);
 
     cfg.add(
         vartab,
@@ -322,6 +324,7 @@ pub fn storage_slots_array_pop(
                 ty: elem_ty.clone(),
                 var_no: entry_pos,
             },
+            false,
             cfg,
             vartab,
         );
@@ -354,6 +357,7 @@ pub fn storage_slots_array_pop(
                 ty: slot_ty.clone(),
                 var_no: entry_pos,
             },
+            transient: false,
         },
     );
 
@@ -368,6 +372,7 @@ pub fn storage_slots_array_pop(
                 var_no: new_length,
             },
             storage: var_expr,
+            transient: false,
         },
     );
 
@@ -163,6 +163,8 @@ fn block_reduce(
                 encoded_args,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
                 salt,
                 accounts,
                 ..
@@ -178,12 +180,20 @@ fn block_reduce(
                     *accounts = expression_reduce(accounts, &vars, ns);
                 }
                 *gas = expression_reduce(gas, &vars, ns);
+                if let Some(proof_size) = proof_size {
+                    *proof_size = expression_reduce(proof_size, &vars, ns);
+                }
+                if let Some(limit) = storage_deposit_limit {
+                    *limit = expression_reduce(limit, &vars, ns);
+                }
             }
             Instr::ExternalCall {
                 address,
                 payload,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
                 ..
             } => {
                 *value = expression_reduce(value, &vars, ns);
@@ -192,6 +202,12 @@ fn block_reduce(
                 }
                 *payload = expression_reduce(payload, &vars, ns);
                 *gas = expression_reduce(gas, &vars, ns);
+                if let Some(proof_size) = proof_size {
+                    *proof_size = expression_reduce(proof_size, &vars, ns);
+                }
+                if let Some(limit) = storage_deposit_limit {
+                    *limit = expression_reduce(limit, &vars, ns);
+                }
             }
             Instr::ValueTransfer { address, value, .. } => {
                 *address = expression_reduce(address, &vars, ns);
@@ -107,6 +107,8 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 encoded_args,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
                 salt,
                 address,
                 accounts,
@@ -119,6 +121,14 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
 
                 let _ = self.gen_expression(gas, ave, cst);
 
+                if let Some(expr) = proof_size {
+                    let _ = self.gen_expression(expr, ave, cst);
+                }
+
+                if let Some(expr) = storage_deposit_limit {
+                    let _ = self.gen_expression(expr, ave, cst);
+                }
+
                 if let Some(expr) = salt {
                     let _ = self.gen_expression(expr, ave, cst);
                 }
@@ -137,6 +147,8 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 payload,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
                 accounts,
                 seeds,
                 ..
@@ -153,6 +165,12 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 let _ = self.gen_expression(payload, ave, cst);
                 let _ = self.gen_expression(value, ave, cst);
                 let _ = self.gen_expression(gas, ave, cst);
+                if let Some(expr) = proof_size {
+                    let _ = self.gen_expression(expr, ave, cst);
+                }
+                if let Some(expr) = storage_deposit_limit {
+                    let _ = self.gen_expression(expr, ave, cst);
+                }
             }
 
             Instr::ValueTransfer { address, value, .. } => {
@@ -267,21 +285,38 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 expr: self.regenerate_expression(expr, ave, cst).1,
             },
 
-            Instr::LoadStorage { res, ty, storage } => Instr::LoadStorage {
+            Instr::LoadStorage {
+                res,
+                ty,
+                storage,
+                transient,
+            } => Instr::LoadStorage {
                 res: *res,
                 ty: ty.clone(),
                 storage: self.regenerate_expression(storage, ave, cst).1,
+                transient: *transient,
             },
 
-            Instr::ClearStorage { ty, storage } => Instr::ClearStorage {
+            Instr::ClearStorage {
+                ty,
+                storage,
+                transient,
+            } => Instr::ClearStorage {
                 ty: ty.clone(),
                 storage: self.regenerate_expression(storage, ave, cst).1,
+                transient: *transient,
             },
 
-            Instr::SetStorage { ty, value, storage } => Instr::SetStorage {
+            Instr::SetStorage {
+                ty,
+                value,
+                storage,
+                transient,
+            } => Instr::SetStorage {
                 ty: ty.clone(),
                 value: self.regenerate_expression(value, ave, cst).1,
                 storage: self.regenerate_expression(storage, ave, cst).1,
+                transient: *transient,
             },
 
             Instr::SetStorageBytes {
@@ -333,6 +368,8 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 encoded_args,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
                 salt,
                 address,
                 seeds,
@@ -344,6 +381,14 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                     .as_ref()
                     .map(|expr| self.regenerate_expression(expr, ave, cst).1);
 
+                let new_proof_size = proof_size
+                    .as_ref()
+                    .map(|expr| self.regenerate_expression(expr, ave, cst).1);
+
+                let new_storage_deposit_limit = storage_deposit_limit
+                    .as_ref()
+                    .map(|expr| self.regenerate_expression(expr, ave, cst).1);
+
                 let new_salt = salt
                     .as_ref()
                     .map(|expr| self.regenerate_expression(expr, ave, cst).1);
@@ -368,6 +413,8 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                     encoded_args: self.regenerate_expression(encoded_args, ave, cst).1,
                     value: new_value,
                     gas: self.regenerate_expression(gas, ave, cst).1,
+                    proof_size: new_proof_size,
+                    storage_deposit_limit: new_storage_deposit_limit,
                     salt: new_salt,
                     address: new_address,
                     seeds: new_seeds,
@@ -388,6 +435,8 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 seeds,
                 contract_function_no,
                 flags,
+                proof_size,
+                storage_deposit_limit,
             } => {
                 let new_address = address
                     .as_ref()
@@ -405,6 +454,14 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                     .as_ref()
                     .map(|expr| self.regenerate_expression(expr, ave, cst).1);
 
+                let proof_size = proof_size
+                    .as_ref()
+                    .map(|expr| self.regenerate_expression(expr, ave, cst).1);
+
+                let storage_deposit_limit = storage_deposit_limit
+                    .as_ref()
+                    .map(|expr| self.regenerate_expression(expr, ave, cst).1);
+
                 Instr::ExternalCall {
                     loc: *loc,
                     success: *success,
@@ -417,6 +474,8 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                     callty: callty.clone(),
                     contract_function_no: *contract_function_no,
                     flags,
+                    proof_size,
+                    storage_deposit_limit,
                 }
             }
 
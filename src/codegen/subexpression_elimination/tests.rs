@@ -417,6 +417,8 @@ fn string() {
         encoded_args: compare.clone(),
         value: None,
         gas: compare2.clone(),
+        proof_size: None,
+        storage_deposit_limit: None,
         salt: None,
         address: None,
         seeds: None,
@@ -134,13 +134,13 @@ fn add_diagnostic(
         return;
     }
 
-    diagnostics.entry(var_no).or_insert(Diagnostic {
-        level: Level::Error,
-        ty: ErrorType::TypeError,
-        loc: var.id.loc,
-        message: format!("Variable '{}' is undefined", var.id.name),
-        notes: vec![],
-    });
+    diagnostics.entry(var_no).or_insert(Diagnostic::new(
+        var.id.loc,
+        Level::Error,
+        ErrorType::TypeError,
+        format!("Variable '{}' is undefined", var.id.name),
+        vec![],
+    ));
 
     let diag = diagnostics.get_mut(&var_no).unwrap();
     diag.notes.push(Note {
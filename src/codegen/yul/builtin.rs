@@ -149,6 +149,20 @@ pub(crate) fn process_builtin(
             Expression::Poison
         }
 
+        YulBuiltInFunction::TLoad => {
+            let storage = expression(&args[0], contract_no, ns, vartab, cfg, opt).cast(&ns.storage_type(), ns);
+            let res = vartab.temp_anonymous(&Type::Uint(256));
+            cfg.add(vartab, Instr::LoadStorage { res, ty: Type::Uint(256), storage, transient: true });
+            Expression::Variable { loc: *loc, ty: Type::Uint(256), var_no: res }
+        }
+
+        YulBuiltInFunction::TStore => {
+            let storage = expression(&args[0], contract_no, ns, vartab, cfg, opt).cast(&ns.storage_type(), ns);
+            let value = expression(&args[1], contract_no, ns, vartab, cfg, opt).cast(&Type::Uint(256), ns);
+            cfg.add(vartab, Instr::SetStorage { ty: Type::Uint(256), value, storage, transient: true });
+            Expression::Poison
+        }
+
         YulBuiltInFunction::Gas => {
             Expression::Builtin { loc: *loc, tys: vec![Type::Uint(64)], kind: Builtin::Gasleft, args: vec![] }
         }
@@ -126,6 +126,7 @@ fn contract_constant_variable() {
         visibility: Visibility::Public(None),
         constant: false,
         immutable: false,
+        transient: false,
         initializer: Some(sema::ast::Expression::NumberLiteral {
             loc,
             ty: Type::Uint(64),
@@ -146,6 +147,8 @@ fn contract_constant_variable() {
         bases: vec![],
         using: vec![],
         layout: vec![],
+        layout_base: None,
+        transient_layout: vec![],
         fixed_layout_size: Default::default(),
         functions: vec![],
         all_functions: Default::default(),
@@ -191,6 +194,7 @@ fn global_constant_variable() {
         visibility: Visibility::Public(None),
         constant: false,
         immutable: false,
+        transient: false,
         initializer: Some(sema::ast::Expression::NumberLiteral {
             loc,
             ty: Type::Uint(64),
@@ -284,6 +288,8 @@ fn slot_suffix() {
         bases: vec![],
         using: vec![],
         layout: vec![layout],
+        layout_base: None,
+        transient_layout: vec![],
         fixed_layout_size: Default::default(),
         functions: vec![],
         all_functions: Default::default(),
@@ -179,7 +179,7 @@ impl<'a> Binary<'a> {
     ) -> Self {
         let std_lib = load_stdlib(context, &ns.target);
         match ns.target {
-            Target::Polkadot { .. } => {
+            Target::Polkadot { .. } | Target::PolkadotRevive => {
                 polkadot::PolkadotTarget::build(context, &std_lib, contract, ns, opt)
             }
             Target::Solana => solana::SolanaTarget::build(context, &std_lib, contract, ns, opt),
@@ -249,7 +249,11 @@ impl<'a> Binary<'a> {
             .map_err(|s| s.to_string())?;
 
         #[cfg(feature = "wasm_opt")]
-        if let Some(level) = self.options.wasm_opt.filter(|_| self.target.is_polkadot()) {
+        if let Some(level) = self
+            .options
+            .wasm_opt
+            .filter(|_| self.target.is_polkadot() && !self.target.is_polkadot_revive())
+        {
             let mut infile = tempdir().map_err(|e| e.to_string())?.into_path();
             infile.push("code.wasm");
             let outfile = infile.with_extension("wasmopt");
@@ -330,6 +334,7 @@ impl<'a> Binary<'a> {
     ) -> Self {
         LLVM_INIT.get_or_init(|| {
             inkwell::targets::Target::initialize_webassembly(&Default::default());
+            inkwell::targets::Target::initialize_riscv(&Default::default());
 
             extern "C" {
                 fn LLVMInitializeSBFTarget();
@@ -1271,6 +1276,23 @@ fn load_stdlib<'a>(context: &'a Context, target: &Target) -> Module<'a> {
         return module;
     }
 
+    if target.is_polkadot_revive() {
+        let memory = MemoryBuffer::create_from_memory_range(RISCV_IR[0], "riscv_bc");
+
+        let module = Module::parse_bitcode_from_buffer(&memory, context).unwrap();
+
+        // pallet-revive does not provide ripemd160 either, so it is included here
+        for bc in RISCV_IR.iter().skip(1) {
+            let memory = MemoryBuffer::create_from_memory_range(bc, "riscv_bc");
+
+            module
+                .link_in_module(Module::parse_bitcode_from_buffer(&memory, context).unwrap())
+                .unwrap();
+        }
+
+        return module;
+    }
+
     let memory = MemoryBuffer::create_from_memory_range(WASM_IR[0], "wasm_bc");
 
     let module = Module::parse_bitcode_from_buffer(&memory, context).unwrap();
@@ -1311,4 +1333,12 @@ static WASM_IR: [&[u8]; 4] = [
     include_bytes!("../../target/wasm/format.bc"),
 ];
 
+static RISCV_IR: [&[u8]; 5] = [
+    include_bytes!("../../target/riscv/stdlib.bc"),
+    include_bytes!("../../target/riscv/heap.bc"),
+    include_bytes!("../../target/riscv/bigint.bc"),
+    include_bytes!("../../target/riscv/format.bc"),
+    include_bytes!("../../target/riscv/ripemd160.bc"),
+];
+
 static RIPEMD160_IR: &[u8] = include_bytes!("../../target/wasm/ripemd160.bc");
@@ -6,7 +6,7 @@ use crate::emit::instructions::process_instruction;
 use crate::emit::{TargetRuntime, Variable};
 use crate::sema::ast::{Contract, Namespace};
 use crate::Target;
-use inkwell::debug_info::{AsDIScope, DISubprogram, DIType};
+use inkwell::debug_info::{AsDIScope, DILexicalBlock, DISubprogram, DIType};
 use inkwell::types::BasicType;
 use inkwell::values::{FunctionValue, PhiValue};
 use inkwell::AddressSpace;
@@ -79,7 +79,7 @@ pub(super) fn emit_cfg<'a, T: TargetRuntime<'a> + ?Sized>(
                 let func_loc = cfg.blocks[0].instr.first().unwrap().loc();
                 let line_num = if let pt::Loc::File(file_offset, offset, _) = func_loc {
                     let (line, _) = ns.files[file_offset].offset_to_line_column(offset);
-                    line
+                    line + 1
                 } else {
                     0
                 };
@@ -103,6 +103,7 @@ pub(super) fn emit_cfg<'a, T: TargetRuntime<'a> + ?Sized>(
     }
 
     let mut blocks: HashMap<usize, BasicBlock> = HashMap::new();
+    let mut file_scopes: HashMap<usize, DILexicalBlock> = HashMap::new();
 
     let mut work = VecDeque::new();
 
@@ -183,13 +184,32 @@ pub(super) fn emit_cfg<'a, T: TargetRuntime<'a> + ?Sized>(
         for ins in &cfg.blocks[w.block_no].instr {
             if bin.options.generate_debug_information {
                 let debug_loc = ins.loc();
-                if let pt::Loc::File(file_offset, offset, _) = debug_loc {
-                    let (line, col) = ns.files[file_offset].offset_to_line_column(offset);
+                if let pt::Loc::File(file_no, offset, _) = debug_loc {
+                    let (line, col) = ns.files[file_no].offset_to_line_column(offset);
+                    // The instruction may come from an imported file, which is not the file of
+                    // the compile unit. The file is given by a lexical block for that file.
+                    let scope = *file_scopes.entry(file_no).or_insert_with(|| {
+                        let file = &ns.files[file_no];
+                        let directory = file
+                            .path
+                            .parent()
+                            .map(|dir| dir.display().to_string())
+                            .unwrap_or_default();
+                        let di_file = dibuilder.create_file(&file.file_name(), &directory);
+
+                        dibuilder.create_lexical_block(
+                            di_func_scope.unwrap().as_debug_info_scope(),
+                            di_file,
+                            0,
+                            0,
+                        )
+                    });
+                    // DWARF lines and columns start at 1; line 0 means no location
                     let debug_loc = dibuilder.create_debug_location(
                         bin.context,
-                        line as u32,
-                        col as u32,
-                        di_func_scope.unwrap().as_debug_info_scope(),
+                        line as u32 + 1,
+                        col as u32 + 1,
+                        scope.as_debug_info_scope(),
                         None,
                     );
                     bin.builder.set_current_debug_location(debug_loc);
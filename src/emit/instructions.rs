@@ -112,23 +112,48 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
                 .build_conditional_branch(cond.into_int_value(), bb_true, bb_false)
                 .unwrap();
         }
-        Instr::LoadStorage { res, ty, storage } => {
+        Instr::LoadStorage {
+            res,
+            ty,
+            storage,
+            transient,
+        } => {
             let mut slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
 
-            w.vars.get_mut(res).unwrap().value =
-                target.storage_load(bin, ty, &mut slot, function, ns);
+            w.vars.get_mut(res).unwrap().value = if *transient {
+                target.transient_load(bin, ty, slot, function, ns)
+            } else {
+                target.storage_load(bin, ty, &mut slot, function, ns)
+            };
         }
-        Instr::ClearStorage { ty, storage } => {
+        Instr::ClearStorage {
+            ty,
+            storage,
+            transient,
+        } => {
             let mut slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
 
-            target.storage_delete(bin, ty, &mut slot, function, ns);
+            if *transient {
+                target.transient_delete(bin, ty, slot, function, ns);
+            } else {
+                target.storage_delete(bin, ty, &mut slot, function, ns);
+            }
         }
-        Instr::SetStorage { ty, value, storage } => {
+        Instr::SetStorage {
+            ty,
+            value,
+            storage,
+            transient,
+        } => {
             let value = expression(target, bin, value, &w.vars, function, ns);
 
             let mut slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
 
-            target.storage_store(bin, ty, true, &mut slot, value, function, ns);
+            if *transient {
+                target.transient_store(bin, ty, slot, value, function, ns);
+            } else {
+                target.storage_store(bin, ty, true, &mut slot, value, function, ns);
+            }
         }
         Instr::SetStorageBytes {
             storage,
@@ -745,6 +770,8 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             encoded_args,
             value,
             gas,
+            proof_size,
+            storage_deposit_limit,
             salt,
             address,
             seeds,
@@ -758,6 +785,12 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             let address_stack = bin.build_alloca(function, bin.address_type(ns), "address");
 
             let gas = expression(target, bin, gas, &w.vars, function, ns).into_int_value();
+            let proof_size = proof_size
+                .as_ref()
+                .map(|v| expression(target, bin, v, &w.vars, function, ns).into_int_value());
+            let storage_deposit_limit = storage_deposit_limit
+                .as_ref()
+                .map(|v| expression(target, bin, v, &w.vars, function, ns).into_int_value());
             let value = value
                 .as_ref()
                 .map(|v| expression(target, bin, v, &w.vars, function, ns).into_int_value());
@@ -854,6 +887,8 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
                     program_id: None,
                     accounts: llvm_accounts,
                     gas: Some(gas),
+                    proof_size,
+                    storage_deposit_limit,
                     value,
                     salt,
                     seeds,
@@ -878,6 +913,8 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             accounts,
             seeds,
             flags,
+            proof_size,
+            storage_deposit_limit,
             ..
         } => {
             let loc = payload.loc();
@@ -930,6 +967,12 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             let flags = flags
                 .as_ref()
                 .map(|e| expression(target, bin, e, &w.vars, function, ns).into_int_value());
+            let proof_size = proof_size
+                .as_ref()
+                .map(|e| expression(target, bin, e, &w.vars, function, ns).into_int_value());
+            let storage_deposit_limit = storage_deposit_limit
+                .as_ref()
+                .map(|e| expression(target, bin, e, &w.vars, function, ns).into_int_value());
             let success = match success {
                 Some(n) => Some(&mut w.vars.get_mut(n).unwrap().value),
                 None => None,
@@ -946,6 +989,8 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
                     program_id: None,
                     value: Some(value),
                     gas: Some(gas),
+                    proof_size,
+                    storage_deposit_limit,
                     salt: None,
                     seeds,
                     accounts,
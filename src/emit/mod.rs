@@ -42,6 +42,8 @@ pub struct ContractArgs<'b> {
     program_id: Option<PointerValue<'b>>,
     value: Option<IntValue<'b>>,
     gas: Option<IntValue<'b>>,
+    proof_size: Option<IntValue<'b>>,
+    storage_deposit_limit: Option<IntValue<'b>>,
     salt: Option<IntValue<'b>>,
     seeds: Option<(PointerValue<'b>, IntValue<'b>)>,
     accounts: Option<(PointerValue<'b>, IntValue<'b>)>,
@@ -109,6 +111,37 @@ pub trait TargetRuntime<'a> {
         ns: &Namespace,
     );
 
+    /// Load a value type from transient storage
+    fn transient_load(
+        &self,
+        bin: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    ) -> BasicValueEnum<'a>;
+
+    /// Store a value type in transient storage
+    fn transient_store(
+        &self,
+        bin: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        value: BasicValueEnum<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    );
+
+    /// Clear a value type from transient storage
+    fn transient_delete(
+        &self,
+        bin: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    );
+
     // Bytes and string have special storage layout
     fn set_storage_string(
         &self,
@@ -337,6 +370,8 @@ impl Target {
     fn llvm_target_name(&self) -> &'static str {
         if *self == Target::Solana {
             "sbf"
+        } else if self.is_polkadot_revive() {
+            "riscv32"
         } else {
             "wasm32"
         }
@@ -346,6 +381,8 @@ impl Target {
     fn llvm_target_triple(&self) -> TargetTriple {
         TargetTriple::create(if *self == Target::Solana {
             "sbf-unknown-unknown"
+        } else if self.is_polkadot_revive() {
+            "riscv32-unknown-unknown-elf"
         } else {
             "wasm32-unknown-unknown-wasm"
         })
@@ -355,6 +392,9 @@ impl Target {
     fn llvm_features(&self) -> &'static str {
         if *self == Target::Solana {
             "+solana"
+        } else if self.is_polkadot_revive() {
+            // PolkaVM implements the RV32E base with the multiply extension
+            "+e,+m"
         } else {
             ""
         }
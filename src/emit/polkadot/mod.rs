@@ -14,6 +14,7 @@ use crate::codegen::dispatch::polkadot::DispatchType;
 use crate::emit::functions::emit_functions;
 use crate::emit::{Binary, TargetRuntime};
 
+mod polkavm;
 mod storage;
 pub(super) mod target;
 
@@ -68,7 +69,7 @@ impl PolkadotTarget {
 
         let mut target = PolkadotTarget;
 
-        target.declare_externals(&binary);
+        target.declare_externals(&binary, ns);
 
         emit_functions(&mut target, &mut binary, contract, ns);
 
@@ -93,14 +94,17 @@ impl PolkadotTarget {
             "set_storage",
             "get_storage",
             "clear_storage",
+            "set_transient_storage",
+            "get_transient_storage",
+            "clear_transient_storage",
             "hash_keccak_256",
             "hash_sha2_256",
             "hash_blake2_128",
             "hash_blake2_256",
             "seal_return",
             "debug_message",
-            "instantiate",
-            "seal_call",
+            "instantiate_v2",
+            "call_v2",
             "delegate_call",
             "code_hash",
             "value_transferred",
@@ -119,8 +123,21 @@ impl PolkadotTarget {
             "is_contract",
             "set_code_hash",
             "caller_is_root",
+            "sr25519_verify",
+            "ecdsa_to_eth_address",
+            "take_storage",
+            "contains_storage",
+            "reentrance_count",
+            "account_reentrance_count",
+            "lock_delegate_dependency",
+            "xcm_send",
+            "xcm_execute",
         ]);
 
+        if ns.target.is_polkadot_revive() {
+            polkavm::emit_metadata(&binary, &["deploy", "call"]);
+        }
+
         binary
     }
 
@@ -186,7 +203,7 @@ impl PolkadotTarget {
         (scratch_buf, args_length.into_int_value())
     }
 
-    fn declare_externals(&self, binary: &Binary) {
+    fn declare_externals(&self, binary: &Binary, ns: &Namespace) {
         let ctx = binary.context;
         let u8_ptr = ctx.i8_type().ptr_type(AddressSpace::default()).into();
         let u32_val = ctx.i32_type().into();
@@ -222,36 +239,54 @@ impl PolkadotTarget {
         external!("debug_message", i32_type, u8_ptr, u32_val);
         external!("clear_storage", i32_type, u8_ptr, u32_val);
         external!("get_storage", i32_type, u8_ptr, u32_val, u8_ptr, u32_ptr);
-        external!("seal_return", void_type, u32_val, u8_ptr, u32_val);
         external!(
-            "instantiate",
+            "set_transient_storage",
             i32_type,
             u8_ptr,
-            u64_val,
-            u8_ptr,
-            u8_ptr,
             u32_val,
             u8_ptr,
-            u32_ptr,
-            u8_ptr,
-            u32_ptr,
-            u8_ptr,
             u32_val
         );
-        // We still use prefixed seal_call because it would collide with the exported call function.
-        // TODO: Refactor emit to use a dedicated module for the externals to avoid any collisions.
+        external!("clear_transient_storage", i32_type, u8_ptr, u32_val);
         external!(
-            "seal_call",
+            "get_transient_storage",
             i32_type,
-            u32_val,
-            u8_ptr,
-            u64_val,
-            u8_ptr,
             u8_ptr,
             u32_val,
             u8_ptr,
             u32_ptr
         );
+        external!("seal_return", void_type, u32_val, u8_ptr, u32_val);
+        // The weight v2 versions take the ref time and proof size limits, and a storage deposit
+        // limit. They are renamed to `instantiate` and `call` in the "seal2" module by the linker,
+        // because `call` would collide with the exported call function. On pallet-revive, the
+        // arguments are passed packed in a struct, since there are not enough registers.
+        if ns.target.is_polkadot_revive() {
+            external!("instantiate_v2", i32_type, u8_ptr);
+            external!("call_v2", i32_type, u8_ptr);
+        } else {
+            external!(
+                "instantiate_v2",
+                i32_type,
+                u8_ptr,
+                u64_val,
+                u64_val,
+                u8_ptr,
+                u8_ptr,
+                u8_ptr,
+                u32_val,
+                u8_ptr,
+                u32_ptr,
+                u8_ptr,
+                u32_ptr,
+                u8_ptr,
+                u32_val
+            );
+            external!(
+                "call_v2", i32_type, u32_val, u8_ptr, u64_val, u64_val, u8_ptr, u8_ptr, u8_ptr,
+                u32_val, u8_ptr, u32_ptr
+            );
+        }
         external!(
             "delegate_call",
             i32_type,
@@ -278,6 +313,15 @@ impl PolkadotTarget {
         external!("is_contract", i32_type, u8_ptr);
         external!("set_code_hash", i32_type, u8_ptr);
         external!("caller_is_root", i32_type,);
+        external!("sr25519_verify", i32_type, u8_ptr, u8_ptr, u32_val, u8_ptr);
+        external!("ecdsa_to_eth_address", i32_type, u8_ptr, u8_ptr);
+        external!("take_storage", i32_type, u8_ptr, u32_val, u8_ptr, u32_ptr);
+        external!("contains_storage", i32_type, u8_ptr, u32_val);
+        external!("reentrance_count", i32_type,);
+        external!("account_reentrance_count", i32_type, u8_ptr);
+        external!("lock_delegate_dependency", void_type, u8_ptr);
+        external!("xcm_send", i32_type, u8_ptr, u32_val, u8_ptr, u32_val, u8_ptr);
+        external!("xcm_execute", i32_type, u8_ptr, u32_val);
     }
 
     /// Emits the "deploy" function if `storage_initializer` is `Some`, otherwise emits the "call" function.
@@ -312,4 +356,29 @@ impl PolkadotTarget {
 
         bin.builder.build_unreachable().unwrap();
     }
+
+    /// Pointer to the storage deposit limit for `call_v2` and `instantiate_v2`. Without a limit,
+    /// the sentinel `u32::MAX` is passed, so the callee may use up to the limit of this call.
+    fn storage_deposit_limit<'a>(
+        &self,
+        bin: &Binary<'a>,
+        limit: Option<IntValue<'a>>,
+        ns: &Namespace,
+    ) -> PointerValue<'a> {
+        match limit {
+            Some(limit) => {
+                let limit_ptr = bin
+                    .builder
+                    .build_alloca(bin.value_type(ns), "storage_deposit_limit")
+                    .unwrap();
+                bin.builder.build_store(limit_ptr, limit).unwrap();
+                limit_ptr
+            }
+            None => bin
+                .context
+                .i32_type()
+                .const_all_ones()
+                .const_to_pointer(bin.context.i8_type().ptr_type(AddressSpace::default())),
+        }
+    }
 }
@@ -0,0 +1,366 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::emit::Binary;
+use inkwell::module::Linkage;
+use inkwell::types::{BasicMetadataTypeEnum, BasicType, BasicTypeEnum};
+use inkwell::values::{BasicMetadataValueEnum, BasicValueEnum, FunctionValue, PointerValue};
+use std::fmt::Write;
+
+/// Prefix of the declarations of pallet-revive host functions, which are called by adapters
+const REVIVE_PREFIX: &str = "__revive_";
+
+/// pallet-revive host functions take at most six arguments in registers. The calls which need more
+/// receive a pointer to their arguments packed in a struct instead.
+pub(super) fn pack_arguments<'a>(
+    binary: &Binary<'a>,
+    args: &[BasicValueEnum<'a>],
+    name: &str,
+) -> PointerValue<'a> {
+    let fields = args
+        .iter()
+        .map(|arg| arg.get_type())
+        .collect::<Vec<BasicTypeEnum>>();
+    let ty = binary.context.struct_type(&fields, true);
+
+    let packed = binary.builder.build_alloca(ty, name).unwrap();
+
+    for (field_no, arg) in args.iter().enumerate() {
+        let field = binary
+            .builder
+            .build_struct_gep(ty, packed, field_no as u32, "field")
+            .unwrap();
+        binary.builder.build_store(field, *arg).unwrap();
+    }
+
+    packed
+}
+
+/// The name of the host function in pallet-revive, given the name of the declaration.
+fn host_function_name(name: &str) -> &str {
+    if let Some(name) = name.strip_prefix(REVIVE_PREFIX) {
+        return name;
+    }
+
+    match name {
+        "call_v2" => "call",
+        "instantiate_v2" => "instantiate",
+        "gas_left" => "weight_left",
+        _ => name,
+    }
+}
+
+/// An argument passed to a pallet-revive host function by an adapter
+#[derive(Clone, Copy)]
+enum Arg {
+    /// The argument of the pallet-contracts declaration with this index
+    Param(u32),
+    /// A constant 32 bit value
+    Int(u64),
+    /// A constant 64 bit value
+    Long(u64),
+}
+
+/// The Polkadot emitter declares the host functions with the arguments of pallet-contracts.
+/// Where pallet-revive takes different arguments, the declaration gets a body which calls
+/// the pallet-revive host function instead.
+enum Adapter {
+    /// Call the host function with the given arguments. If `out_len` is set, pallet-revive
+    /// writes an output of fixed length, which is stored in the output length (the last
+    /// argument of the declaration).
+    Args {
+        name: &'static str,
+        args: &'static [Arg],
+        out_len: Option<u64>,
+    },
+    /// pallet-revive takes the topics as an array of hashes and their count, rather than a SCALE
+    /// encoded vector of hashes.
+    DepositEvent,
+}
+
+/// Storage flag for transient storage
+const TRANSIENT: u64 = 1;
+
+/// Return how to call pallet-revive for a pallet-contracts host function, if the arguments
+/// differ.
+fn adapter(name: &str) -> Option<Adapter> {
+    use Arg::*;
+
+    let (name, args, out_len): (_, &'static [Arg], _) = match name {
+        // storage functions take flags as the first argument, which select transient storage
+        "set_storage" => (
+            "set_storage",
+            &[Int(0), Param(0), Param(1), Param(2), Param(3)],
+            None,
+        ),
+        "set_transient_storage" => (
+            "set_storage",
+            &[Int(TRANSIENT), Param(0), Param(1), Param(2), Param(3)],
+            None,
+        ),
+        "get_storage" => (
+            "get_storage",
+            &[Int(0), Param(0), Param(1), Param(2), Param(3)],
+            None,
+        ),
+        "get_transient_storage" => (
+            "get_storage",
+            &[Int(TRANSIENT), Param(0), Param(1), Param(2), Param(3)],
+            None,
+        ),
+        "take_storage" => (
+            "take_storage",
+            &[Int(0), Param(0), Param(1), Param(2), Param(3)],
+            None,
+        ),
+        "clear_storage" => ("clear_storage", &[Int(0), Param(0), Param(1)], None),
+        "clear_transient_storage" => ("clear_storage", &[Int(TRANSIENT), Param(0), Param(1)], None),
+        "contains_storage" => ("contains_storage", &[Int(0), Param(0), Param(1)], None),
+        // addresses and values have a fixed length, so no lengths are passed
+        "caller" => ("caller", &[Param(0)], Some(20)),
+        "address" => ("address", &[Param(0)], Some(20)),
+        "balance" => ("balance", &[Param(0)], Some(32)),
+        "value_transferred" => ("value_transferred", &[Param(0)], Some(32)),
+        "minimum_balance" => ("minimum_balance", &[Param(0)], Some(32)),
+        "now" => ("now", &[Param(0)], Some(32)),
+        "block_number" => ("block_number", &[Param(0)], Some(32)),
+        "code_hash" => ("code_hash", &[Param(0), Param(1)], Some(32)),
+        "transfer" => ("transfer", &[Param(0), Param(2)], None),
+        // the fee is for the ref time only
+        "weight_to_fee" => ("weight_to_fee", &[Param(0), Long(0), Param(1)], Some(32)),
+        "deposit_event" => return Some(Adapter::DepositEvent),
+        _ => return None,
+    };
+
+    Some(Adapter::Args {
+        name,
+        args,
+        out_len,
+    })
+}
+
+/// The number of registers needed to pass a value of the given type, in 32 bit RISC-V.
+fn registers(ty: Option<BasicTypeEnum>) -> u32 {
+    match ty {
+        None => 0,
+        Some(BasicTypeEnum::IntType(ty)) => ty.get_bit_width().div_ceil(32),
+        Some(_) => 1,
+    }
+}
+
+/// PolkaVM does not support ELF imports and exports. The PolkaVM linker instead looks for
+/// metadata describing them in the `.polkavm_metadata` and `.polkavm_exports` sections. Each
+/// host function which is used gets a stub, which executes the `ecalli` instruction for it.
+pub(super) fn emit_metadata(binary: &Binary, exports: &[&str]) {
+    adapt_host_functions(binary);
+
+    let mut asm = String::new();
+
+    let mut func = binary.module.get_first_function();
+
+    while let Some(f) = func {
+        func = f.get_next_function();
+
+        let name = f.get_name().to_str().unwrap();
+
+        if f.count_basic_blocks() != 0 || name.starts_with("llvm.") || f.get_first_use().is_none() {
+            continue;
+        }
+
+        emit_function_metadata(&mut asm, f, host_function_name(name));
+
+        writeln!(
+            asm,
+            ".pushsection .text.polkavm_import.{name},\"ax\",@progbits"
+        )
+        .unwrap();
+        writeln!(asm, ".globl {name}").unwrap();
+        writeln!(asm, ".type {name},@function").unwrap();
+        writeln!(asm, "{name}:").unwrap();
+        writeln!(asm, "  .insn.r 0xb, 3, 0, zero, zero, zero").unwrap();
+        writeln!(asm, "  .4byte .Lpolkavm_metadata_{name}").unwrap();
+        writeln!(asm, "  ret").unwrap();
+        writeln!(asm, ".popsection").unwrap();
+    }
+
+    for name in exports {
+        let f = binary.module.get_function(name).unwrap();
+
+        emit_function_metadata(&mut asm, f, name);
+
+        writeln!(asm, ".pushsection .polkavm_exports,\"R\",@progbits").unwrap();
+        writeln!(asm, "  .byte 1").unwrap();
+        writeln!(asm, "  .4byte .Lpolkavm_metadata_{name}").unwrap();
+        writeln!(asm, "  .4byte {name}").unwrap();
+        writeln!(asm, ".popsection").unwrap();
+    }
+
+    binary.module.set_inline_assembly(&asm);
+}
+
+/// Give the used host function declarations which need an adapter a body, which calls the
+/// pallet-revive host function.
+fn adapt_host_functions(binary: &Binary) {
+    let mut adapt = Vec::new();
+
+    let mut func = binary.module.get_first_function();
+
+    while let Some(f) = func {
+        func = f.get_next_function();
+
+        if f.count_basic_blocks() == 0 && f.get_first_use().is_some() {
+            if let Some(adapter) = adapter(f.get_name().to_str().unwrap()) {
+                adapt.push((f, adapter));
+            }
+        }
+    }
+
+    for (f, adapter) in adapt {
+        let entry = binary.context.append_basic_block(f, "entry");
+        binary.builder.position_at_end(entry);
+
+        let params = f.get_params();
+
+        let ret = match adapter {
+            Adapter::Args {
+                name,
+                args,
+                out_len,
+            } => {
+                let args: Vec<BasicMetadataValueEnum> = args
+                    .iter()
+                    .map(|arg| match arg {
+                        Arg::Param(no) => params[*no as usize].into(),
+                        Arg::Int(value) => {
+                            binary.context.i32_type().const_int(*value, false).into()
+                        }
+                        Arg::Long(value) => {
+                            binary.context.i64_type().const_int(*value, false).into()
+                        }
+                    })
+                    .collect();
+
+                let ret = call_host_function(binary, f, name, &args);
+
+                if let Some(len) = out_len {
+                    binary
+                        .builder
+                        .build_store(
+                            params.last().unwrap().into_pointer_value(),
+                            binary.context.i32_type().const_int(len, false),
+                        )
+                        .unwrap();
+                }
+
+                ret
+            }
+            Adapter::DepositEvent => {
+                // skip the length prefix of the vector; without topics, nothing is read
+                let topics = unsafe {
+                    binary
+                        .builder
+                        .build_gep(
+                            binary.context.i8_type(),
+                            params[0].into_pointer_value(),
+                            &[binary.context.i32_type().const_int(1, false)],
+                            "topics",
+                        )
+                        .unwrap()
+                };
+                let topic_count = binary
+                    .builder
+                    .build_int_unsigned_div(
+                        params[1].into_int_value(),
+                        binary.context.i32_type().const_int(32, false),
+                        "topic_count",
+                    )
+                    .unwrap();
+
+                call_host_function(
+                    binary,
+                    f,
+                    "deposit_event",
+                    &[
+                        topics.into(),
+                        topic_count.into(),
+                        params[2].into(),
+                        params[3].into(),
+                    ],
+                )
+            }
+        };
+
+        match ret {
+            Some(ret) => binary.builder.build_return(Some(&ret)).unwrap(),
+            None => binary.builder.build_return(None).unwrap(),
+        };
+
+        f.set_linkage(Linkage::Internal);
+    }
+}
+
+/// Call the pallet-revive host function from the adapter `f`. The host function returns the same
+/// type as the adapter.
+fn call_host_function<'a>(
+    binary: &Binary<'a>,
+    f: FunctionValue<'a>,
+    name: &str,
+    args: &[BasicMetadataValueEnum<'a>],
+) -> Option<BasicValueEnum<'a>> {
+    let import_name = format!("{REVIVE_PREFIX}{name}");
+
+    let import = binary.module.get_function(&import_name).unwrap_or_else(|| {
+        let params: Vec<BasicMetadataTypeEnum> = args
+            .iter()
+            .map(|arg| match arg {
+                BasicMetadataValueEnum::IntValue(v) => v.get_type().into(),
+                BasicMetadataValueEnum::PointerValue(v) => v.get_type().into(),
+                _ => unreachable!(),
+            })
+            .collect();
+
+        let ty = match f.get_type().get_return_type() {
+            Some(ret) => ret.fn_type(&params, false),
+            None => binary.context.void_type().fn_type(&params, false),
+        };
+
+        binary
+            .module
+            .add_function(&import_name, ty, Some(Linkage::External))
+    });
+
+    binary
+        .builder
+        .build_call(import, args, name)
+        .unwrap()
+        .try_as_basic_value()
+        .left()
+}
+
+/// Describe the symbol name and the registers used by a function
+fn emit_function_metadata(asm: &mut String, f: FunctionValue, symbol: &str) {
+    let name = f.get_name().to_str().unwrap();
+    let ty = f.get_type();
+    let input_regs: u32 = ty
+        .get_param_types()
+        .into_iter()
+        .map(|ty| match ty {
+            BasicMetadataTypeEnum::IntType(ty) => registers(Some(ty.into())),
+            _ => 1,
+        })
+        .sum();
+    let output_regs = registers(ty.get_return_type());
+
+    writeln!(asm, ".pushsection .polkavm_metadata,\"\",@progbits").unwrap();
+    writeln!(asm, ".Lpolkavm_metadata_{name}:").unwrap();
+    // version
+    writeln!(asm, "  .byte 1").unwrap();
+    // flags
+    writeln!(asm, "  .4byte 0").unwrap();
+    writeln!(asm, "  .4byte {}", symbol.len()).unwrap();
+    writeln!(asm, "  .4byte .Lpolkavm_symbol_{name}").unwrap();
+    writeln!(asm, "  .byte {input_regs}").unwrap();
+    writeln!(asm, "  .byte {output_regs}").unwrap();
+    writeln!(asm, ".Lpolkavm_symbol_{name}:").unwrap();
+    writeln!(asm, "  .ascii \"{symbol}\"").unwrap();
+    writeln!(asm, ".popsection").unwrap();
+}
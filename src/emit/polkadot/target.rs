@@ -5,7 +5,7 @@ use crate::codegen::polkadot::SCRATCH_SIZE;
 use crate::codegen::revert::PanicCode;
 use crate::emit::binary::Binary;
 use crate::emit::expression::expression;
-use crate::emit::polkadot::PolkadotTarget;
+use crate::emit::polkadot::{polkavm, PolkadotTarget};
 use crate::emit::storage::StorageSlot;
 use crate::emit::{ContractArgs, TargetRuntime, Variable};
 use crate::sema::ast;
@@ -19,6 +19,7 @@ use inkwell::values::{
 use inkwell::{AddressSpace, IntPredicate};
 use solang_parser::pt::Loc;
 use std::collections::HashMap;
+use tiny_keccak::{Hasher, Keccak};
 
 impl<'a> TargetRuntime<'a> for PolkadotTarget {
     fn set_storage_extfunc(
@@ -874,10 +875,21 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .unwrap_or_else(|| binary.value_type(ns).const_zero());
         binary.builder.build_store(value_ptr, value).unwrap();
 
-        // code hash
+        // code hash; pallet-revive uses keccak256
+        let codehash = if ns.target.is_polkadot_revive() {
+            let mut hash = [0u8; 32];
+            let mut hasher = Keccak::v256();
+            hasher.update(&code);
+            hasher.finalize(&mut hash);
+            hash
+        } else {
+            let mut hash = [0u8; 32];
+            hash.copy_from_slice(blake2_rfc::blake2b::blake2b(32, &[], &code).as_bytes());
+            hash
+        };
         let codehash = binary.emit_global_string(
             &format!("binary_{}_codehash", created_contract.id),
-            blake2_rfc::blake2b::blake2b(32, &[], &code).as_bytes(),
+            &codehash,
             true,
         );
 
@@ -896,27 +908,36 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
             .build_store(scratch_len, i32_const!(SCRATCH_SIZE as u64 * 32))
             .unwrap();
 
-        *success.unwrap() = call!(
-            "instantiate",
-            &[
-                codehash.into(),
-                contract_args.gas.unwrap().into(),
-                value_ptr.into(),
-                encoded_args.into(),
-                encoded_args_len.into(),
-                address.into(),
-                address_len_ptr.into(),
-                scratch_buf.into(),
-                scratch_len.into(),
-                salt_buf.into(),
-                salt_len.into(),
-            ]
-        )
-        .try_as_basic_value()
-        .left()
-        .unwrap()
-        .into_int_value()
-        .into();
+        let deposit_limit =
+            self.storage_deposit_limit(binary, contract_args.storage_deposit_limit, ns);
+
+        let args: [BasicValueEnum; 13] = [
+            codehash.into(),
+            contract_args.gas.unwrap().into(),
+            contract_args.proof_size.unwrap_or(i64_zero!()).into(),
+            deposit_limit.into(),
+            value_ptr.into(),
+            encoded_args.into(),
+            encoded_args_len.into(),
+            address.into(),
+            address_len_ptr.into(),
+            scratch_buf.into(),
+            scratch_len.into(),
+            salt_buf.into(),
+            salt_len.into(),
+        ];
+        let args: Vec<BasicMetadataValueEnum> = if ns.target.is_polkadot_revive() {
+            vec![polkavm::pack_arguments(binary, &args, "instantiate_args").into()]
+        } else {
+            args.iter().map(|arg| (*arg).into()).collect()
+        };
+
+        *success.unwrap() = call!("instantiate_v2", &args)
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value()
+            .into();
     }
 
     /// Call external binary
@@ -952,24 +973,31 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
                     .builder
                     .build_store(value_ptr, contract_args.value.unwrap())
                     .unwrap();
-                call!(
-                    "seal_call",
-                    &[
-                        contract_args.flags.unwrap_or(i32_zero!()).into(),
-                        address.unwrap().into(),
-                        contract_args.gas.unwrap().into(),
-                        value_ptr.into(),
-                        payload.into(),
-                        payload_len.into(),
-                        scratch_buf.into(),
-                        scratch_len.into(),
-                    ]
-                )
-                .try_as_basic_value()
-                .left()
-                .unwrap()
-                .into_int_value()
-                .as_basic_value_enum()
+                let deposit_limit =
+                    self.storage_deposit_limit(binary, contract_args.storage_deposit_limit, ns);
+                let args: [BasicValueEnum; 10] = [
+                    contract_args.flags.unwrap_or(i32_zero!()).into(),
+                    address.unwrap().into(),
+                    contract_args.gas.unwrap().into(),
+                    contract_args.proof_size.unwrap_or(i64_zero!()).into(),
+                    deposit_limit.into(),
+                    value_ptr.into(),
+                    payload.into(),
+                    payload_len.into(),
+                    scratch_buf.into(),
+                    scratch_len.into(),
+                ];
+                let args: Vec<BasicMetadataValueEnum> = if ns.target.is_polkadot_revive() {
+                    vec![polkavm::pack_arguments(binary, &args, "call_args").into()]
+                } else {
+                    args.iter().map(|arg| (*arg).into()).collect()
+                };
+                call!("call_v2", &args)
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_int_value()
+                    .as_basic_value_enum()
             }
             ast::CallTy::Delegate => {
                 // delegate_call asks for a code hash instead of an address
@@ -1101,7 +1129,7 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
     fn return_data<'b>(&self, binary: &Binary<'b>, _function: FunctionValue) -> PointerValue<'b> {
         emit_context!(binary);
 
-        // The `seal_call` syscall leaves the return data in the scratch buffer
+        // The `call_v2` syscall leaves the return data in the scratch buffer
         let (scratch_buf, scratch_len) = scratch_buf!();
         let ty = binary.context.i32_type();
         let length = binary
@@ -1616,10 +1644,114 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
         self.storage_delete_slot(bin, ty, slot, slot_ptr, function, ns);
     }
 
+    fn transient_load(
+        &self,
+        binary: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    ) -> BasicValueEnum<'a> {
+        emit_context!(binary);
+
+        let slot_ptr = binary.build_alloca(function, slot.get_type(), "slot");
+        binary.builder.build_store(slot_ptr, slot).unwrap();
+
+        let llvm_ty = binary.llvm_type(ty.deref_any(), ns);
+        let (scratch_buf, scratch_len) = scratch_buf!();
+        let size = llvm_ty
+            .size_of()
+            .unwrap()
+            .const_cast(binary.context.i32_type(), false);
+        binary.builder.build_store(scratch_len, size).unwrap();
+
+        let ret = call!(
+            "get_transient_storage",
+            &[
+                slot_ptr.into(),
+                i32_const!(32).into(),
+                scratch_buf.into(),
+                scratch_len.into()
+            ]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        let exists = binary
+            .builder
+            .build_int_compare(IntPredicate::EQ, ret, i32_zero!(), "transient_exists")
+            .unwrap();
+
+        let value = binary
+            .builder
+            .build_load(llvm_ty, scratch_buf, "transient")
+            .unwrap();
+
+        binary
+            .builder
+            .build_select(exists, value, llvm_ty.const_zero(), "transient_value")
+            .unwrap()
+    }
+
+    fn transient_store(
+        &self,
+        binary: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        value: BasicValueEnum<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    ) {
+        emit_context!(binary);
+
+        let slot_ptr = binary.build_alloca(function, slot.get_type(), "slot");
+        binary.builder.build_store(slot_ptr, slot).unwrap();
+
+        let llvm_ty = binary.llvm_type(ty.deref_any(), ns);
+        let value_ptr = binary.build_alloca(function, llvm_ty, "value");
+        binary.builder.build_store(value_ptr, value).unwrap();
+
+        let size = llvm_ty
+            .size_of()
+            .unwrap()
+            .const_cast(binary.context.i32_type(), false);
+
+        call!(
+            "set_transient_storage",
+            &[
+                slot_ptr.into(),
+                i32_const!(32).into(),
+                value_ptr.into(),
+                size.into()
+            ]
+        );
+    }
+
+    fn transient_delete(
+        &self,
+        binary: &Binary<'a>,
+        _ty: &Type,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+        _ns: &Namespace,
+    ) {
+        emit_context!(binary);
+
+        let slot_ptr = binary.build_alloca(function, slot.get_type(), "slot");
+        binary.builder.build_store(slot_ptr, slot).unwrap();
+
+        call!(
+            "clear_transient_storage",
+            &[slot_ptr.into(), i32_const!(32).into()]
+        );
+    }
+
     fn builtin_function(
         &self,
         binary: &Binary<'a>,
-        _function: FunctionValue<'a>,
+        function: FunctionValue<'a>,
         builtin_func: &Function,
         args: &[BasicMetadataValueEnum<'a>],
         _first_arg_type: Option<BasicTypeEnum>,
@@ -1722,6 +1854,256 @@ impl<'a> TargetRuntime<'a> for PolkadotTarget {
                     .unwrap();
                 None
             }
+            "sr25519_verify" => {
+                let message = args[2].into_pointer_value();
+                let ret = call!(
+                    "sr25519_verify",
+                    &[
+                        args[0].into_pointer_value().into(),
+                        args[1].into_pointer_value().into(),
+                        binary.vector_len(message.into()).into(),
+                        binary.vector_bytes(message.into()).into(),
+                    ],
+                    "seal_sr25519_verify"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+                let valid = binary
+                    .builder
+                    .build_int_compare(IntPredicate::EQ, ret, i32_zero!(), "valid")
+                    .unwrap();
+                binary
+                    .builder
+                    .build_store(args[3].into_pointer_value(), valid)
+                    .unwrap();
+                None
+            }
+            "ecdsa_to_eth_address" => {
+                let eth_address = call!("__malloc", &[i32_const!(20).into()])
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_pointer_value();
+                let ret = call!(
+                    "ecdsa_to_eth_address",
+                    &[args[0].into_pointer_value().into(), eth_address.into()],
+                    "seal_ecdsa_to_eth_address"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+                binary
+                    .builder
+                    .build_store(args[1].into_pointer_value(), ret)
+                    .unwrap();
+                binary
+                    .builder
+                    .build_store(args[2].into_pointer_value(), eth_address)
+                    .unwrap();
+                None
+            }
+            "take_storage" => {
+                let key = args[0].into_pointer_value();
+                let (output_ptr, output_len_ptr) = scratch_buf!();
+                binary
+                    .builder
+                    .build_store(output_len_ptr, i32_const!(SCRATCH_SIZE as u64))
+                    .unwrap();
+                let ret = call!(
+                    "take_storage",
+                    &[
+                        binary.vector_bytes(key.into()).into(),
+                        binary.vector_len(key.into()).into(),
+                        output_ptr.into(),
+                        output_len_ptr.into(),
+                    ],
+                    "seal_take_storage"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+                let found = binary
+                    .builder
+                    .build_int_compare(IntPredicate::EQ, ret, i32_zero!(), "found")
+                    .unwrap();
+                // pallet-contracts traps if the value does not fit into the scratch buffer. Any
+                // return code other than KeyNotFound must not be mistaken for a missing key.
+                let not_found = binary
+                    .builder
+                    .build_int_compare(IntPredicate::EQ, ret, i32_const!(3), "not_found")
+                    .unwrap();
+                let expected = binary
+                    .builder
+                    .build_or(found, not_found, "expected")
+                    .unwrap();
+                let success_block = binary.context.append_basic_block(function, "take_success");
+                let failure_block = binary.context.append_basic_block(function, "take_failure");
+                binary
+                    .builder
+                    .build_conditional_branch(expected, success_block, failure_block)
+                    .unwrap();
+                binary.builder.position_at_end(failure_block);
+                self.assert_failure(binary, byte_ptr!().const_null(), i32_zero!());
+                binary.builder.position_at_end(success_block);
+                let buf_len = binary
+                    .builder
+                    .build_load(binary.context.i32_type(), output_len_ptr, "buf_len")
+                    .unwrap()
+                    .into_int_value();
+                let buf_len = binary
+                    .builder
+                    .build_select(found, buf_len, i32_zero!(), "value_len")
+                    .unwrap();
+                let value = call!(
+                    "vector_new",
+                    &[buf_len.into(), i32_const!(1).into(), output_ptr.into()]
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap();
+                binary
+                    .builder
+                    .build_store(args[1].into_pointer_value(), found)
+                    .unwrap();
+                binary
+                    .builder
+                    .build_store(args[2].into_pointer_value(), value.into_pointer_value())
+                    .unwrap();
+                None
+            }
+            "contains_storage" => {
+                let key = args[0].into_pointer_value();
+                let ret = call!(
+                    "contains_storage",
+                    &[
+                        binary.vector_bytes(key.into()).into(),
+                        binary.vector_len(key.into()).into(),
+                    ],
+                    "seal_contains_storage"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+                // u32::MAX is the "none sentinel", which means the key was not found
+                let found = binary
+                    .builder
+                    .build_int_compare(IntPredicate::NE, ret, i32_const!(u32::MAX as u64), "found")
+                    .unwrap();
+                let size = binary
+                    .builder
+                    .build_select(found, ret, i32_zero!(), "size")
+                    .unwrap();
+                binary
+                    .builder
+                    .build_store(args[1].into_pointer_value(), found)
+                    .unwrap();
+                binary
+                    .builder
+                    .build_store(args[2].into_pointer_value(), size)
+                    .unwrap();
+                None
+            }
+            "reentrance_count" => {
+                let count = call!("reentrance_count", &[], "seal_reentrance_count")
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_int_value();
+                binary
+                    .builder
+                    .build_store(args[0].into_pointer_value(), count)
+                    .unwrap();
+                None
+            }
+            "account_reentrance_count" => {
+                let account = binary
+                    .builder
+                    .build_alloca(binary.address_type(ns), "account")
+                    .unwrap();
+                binary
+                    .builder
+                    .build_store(account, args[0].into_array_value())
+                    .unwrap();
+                let count = call!(
+                    "account_reentrance_count",
+                    &[account.into()],
+                    "seal_account_reentrance_count"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+                binary
+                    .builder
+                    .build_store(args[1].into_pointer_value(), count)
+                    .unwrap();
+                None
+            }
+            "lock_delegate_dependency" => {
+                call!(
+                    "lock_delegate_dependency",
+                    &[args[0].into_pointer_value().into()]
+                );
+                None
+            }
+            "xcm_send" => {
+                let destination = args[0].into_pointer_value();
+                let message = args[1].into_pointer_value();
+                let message_id = call!("__malloc", &[i32_const!(32).into()])
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_pointer_value();
+                let ret = call!(
+                    "xcm_send",
+                    &[
+                        binary.vector_bytes(destination.into()).into(),
+                        binary.vector_len(destination.into()).into(),
+                        binary.vector_bytes(message.into()).into(),
+                        binary.vector_len(message.into()).into(),
+                        message_id.into(),
+                    ],
+                    "seal_xcm_send"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+                binary
+                    .builder
+                    .build_store(args[2].into_pointer_value(), ret)
+                    .unwrap();
+                binary
+                    .builder
+                    .build_store(args[3].into_pointer_value(), message_id)
+                    .unwrap();
+                None
+            }
+            "xcm_execute" => {
+                let message = args[0].into_pointer_value();
+                let ret = call!(
+                    "xcm_execute",
+                    &[
+                        binary.vector_bytes(message.into()).into(),
+                        binary.vector_len(message.into()).into(),
+                    ],
+                    "seal_xcm_execute"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+                binary
+                    .builder
+                    .build_store(args[1].into_pointer_value(), ret)
+                    .unwrap();
+                None
+            }
             _ => unimplemented!(),
         }
     }
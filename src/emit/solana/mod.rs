@@ -72,6 +72,25 @@ impl SolanaTarget {
 
         emit_functions(&mut target, &mut binary, contract, ns);
 
+        // The entrypoint allocates the transient storage on the heap for each instruction
+        let transient_size = contract
+            .transient_layout
+            .last()
+            .map(|layout| &layout.slot + layout.ty.storage_slots(ns))
+            .unwrap_or_default();
+
+        let global = binary
+            .module
+            .get_global("solang_transient_size")
+            .expect("solang_transient_size should be declared in the stdlib");
+        global.set_initializer(
+            &context
+                .i32_type()
+                .const_int(transient_size.to_u64().unwrap(), false),
+        );
+        global.set_constant(true);
+        global.set_linkage(Linkage::Internal);
+
         binary.internalize(&[
             "entrypoint",
             "sol_log_",
@@ -316,6 +335,34 @@ impl SolanaTarget {
             .into_pointer_value()
     }
 
+    /// Returns the transient storage, which the entrypoint allocates for each instruction
+    fn transient_storage_data<'b>(&self, binary: &Binary<'b>) -> PointerValue<'b> {
+        let parameters = self.sol_parameters(binary);
+
+        let transient = binary
+            .builder
+            .build_struct_gep(
+                binary
+                    .module
+                    .get_struct_type("struct.SolParameters")
+                    .unwrap(),
+                parameters,
+                7,
+                "transient",
+            )
+            .unwrap();
+
+        binary
+            .builder
+            .build_load(
+                binary.context.i8_type().ptr_type(AddressSpace::default()),
+                transient,
+                "transient",
+            )
+            .unwrap()
+            .into_pointer_value()
+    }
+
     /// Free binary storage and zero out
     fn storage_free<'b>(
         &self,
@@ -459,6 +506,16 @@ impl SolanaTarget {
 
                 self.storage_free(binary, &field.ty, data, offset, function, zero, ns);
             }
+        } else if let Type::ExternalFunction { .. } = ty {
+            let size = ty.solana_storage_size(ns).to_u32().unwrap();
+
+            binary
+                .builder
+                .build_store(
+                    member,
+                    binary.context.i8_type().array_type(size).const_zero(),
+                )
+                .unwrap();
         } else if matches!(ty, Type::Address(_) | Type::Contract(_)) {
             let ty = binary.llvm_type(ty, ns);
 
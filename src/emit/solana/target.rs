@@ -33,24 +33,127 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         self.storage_free(binary, ty, data, *slot, function, true, ns);
     }
 
+    /// An external function is stored in account data as the program id followed by the selector
     fn set_storage_extfunc(
         &self,
-        _binary: &Binary,
+        binary: &Binary,
         _function: FunctionValue,
-        _slot: PointerValue,
-        _dest: PointerValue,
-        _dest_ty: BasicTypeEnum,
+        slot: PointerValue,
+        dest: PointerValue,
+        dest_ty: BasicTypeEnum,
     ) {
-        unimplemented!();
+        let dest_ty = dest_ty.into_struct_type();
+        let selector_ty = dest_ty.get_field_type_at_index(0).unwrap();
+        let address_ty = dest_ty.get_field_type_at_index(1).unwrap();
+
+        let selector = binary
+            .builder
+            .build_struct_gep(dest_ty, dest, 0, "selector")
+            .unwrap();
+        let selector = binary
+            .builder
+            .build_load(selector_ty, selector, "selector")
+            .unwrap();
+
+        let address = binary
+            .builder
+            .build_struct_gep(dest_ty, dest, 1, "address")
+            .unwrap();
+        let address = binary
+            .builder
+            .build_load(address_ty, address, "address")
+            .unwrap();
+
+        binary.builder.build_store(slot, address).unwrap();
+
+        let selector_slot = unsafe {
+            binary
+                .builder
+                .build_gep(
+                    binary.context.i8_type(),
+                    slot,
+                    &[binary
+                        .context
+                        .i32_type()
+                        .const_int(address_ty.into_array_type().len() as u64, false)],
+                    "selector_slot",
+                )
+                .unwrap()
+        };
+
+        binary.builder.build_store(selector_slot, selector).unwrap();
     }
+
     fn get_storage_extfunc(
         &self,
-        _binary: &Binary<'a>,
+        binary: &Binary<'a>,
         _function: FunctionValue,
-        _slot: PointerValue<'a>,
-        _ns: &ast::Namespace,
+        slot: PointerValue<'a>,
+        ns: &ast::Namespace,
     ) -> PointerValue<'a> {
-        unimplemented!();
+        let selector_ty = binary.llvm_type(&ast::Type::FunctionSelector, ns);
+        let address_ty = binary.llvm_type(&ast::Type::Address(false), ns);
+        let ty = binary
+            .context
+            .struct_type(&[selector_ty, address_ty], false);
+
+        // LLVMSizeOf() produces an i64
+        let size = binary
+            .builder
+            .build_int_truncate(ty.size_of().unwrap(), binary.context.i32_type(), "size_of")
+            .unwrap();
+
+        let dest = binary
+            .builder
+            .build_call(
+                binary.module.get_function("__malloc").unwrap(),
+                &[size.into()],
+                "",
+            )
+            .unwrap()
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value();
+
+        let address = binary
+            .builder
+            .build_load(address_ty, slot, "address")
+            .unwrap();
+
+        let selector_slot = unsafe {
+            binary
+                .builder
+                .build_gep(
+                    binary.context.i8_type(),
+                    slot,
+                    &[binary
+                        .context
+                        .i32_type()
+                        .const_int(ns.address_length as u64, false)],
+                    "selector_slot",
+                )
+                .unwrap()
+        };
+
+        let selector = binary
+            .builder
+            .build_load(selector_ty, selector_slot, "selector")
+            .unwrap();
+
+        let elem = binary
+            .builder
+            .build_struct_gep(ty, dest, 0, "selector")
+            .unwrap();
+        binary.builder.build_store(elem, selector).unwrap();
+
+        let elem = binary
+            .builder
+            .build_struct_gep(ty, dest, 1, "address")
+            .unwrap();
+        binary.builder.build_store(elem, address).unwrap();
+
+        dest
     }
 
     fn set_storage_string(
@@ -880,6 +983,9 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
 
                 dest.into()
             }
+            ast::Type::ExternalFunction { .. } => self
+                .get_storage_extfunc(binary, function, member, ns)
+                .into(),
             _ => binary
                 .builder
                 .build_load(binary.llvm_var_ty(ty, ns), member, "")
@@ -887,6 +993,76 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         }
     }
 
+    /// Load a value type from the transient storage area on the heap
+    fn transient_load(
+        &self,
+        binary: &Binary<'a>,
+        ty: &ast::Type,
+        slot: IntValue<'a>,
+        _function: FunctionValue<'a>,
+        ns: &ast::Namespace,
+    ) -> BasicValueEnum<'a> {
+        let data = self.transient_storage_data(binary);
+
+        let member = unsafe {
+            binary
+                .builder
+                .build_gep(binary.context.i8_type(), data, &[slot], "transient")
+                .unwrap()
+        };
+
+        binary
+            .builder
+            .build_load(binary.llvm_type(ty.deref_any(), ns), member, "transient")
+            .unwrap()
+    }
+
+    /// Store a value type in the transient storage area on the heap
+    fn transient_store(
+        &self,
+        binary: &Binary<'a>,
+        _ty: &ast::Type,
+        slot: IntValue<'a>,
+        value: BasicValueEnum<'a>,
+        _function: FunctionValue<'a>,
+        _ns: &ast::Namespace,
+    ) {
+        let data = self.transient_storage_data(binary);
+
+        let member = unsafe {
+            binary
+                .builder
+                .build_gep(binary.context.i8_type(), data, &[slot], "transient")
+                .unwrap()
+        };
+
+        binary.builder.build_store(member, value).unwrap();
+    }
+
+    /// Zero a value type in the transient storage area on the heap
+    fn transient_delete(
+        &self,
+        binary: &Binary<'a>,
+        ty: &ast::Type,
+        slot: IntValue<'a>,
+        _function: FunctionValue<'a>,
+        ns: &ast::Namespace,
+    ) {
+        let data = self.transient_storage_data(binary);
+
+        let member = unsafe {
+            binary
+                .builder
+                .build_gep(binary.context.i8_type(), data, &[slot], "transient")
+                .unwrap()
+        };
+
+        binary
+            .builder
+            .build_store(member, binary.llvm_type(ty.deref_any(), ns).const_zero())
+            .unwrap();
+    }
+
     fn storage_store(
         &self,
         binary: &Binary<'a>,
@@ -1298,6 +1474,14 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                     ns,
                 );
             }
+        } else if let ast::Type::ExternalFunction { .. } = ty {
+            self.set_storage_extfunc(
+                binary,
+                function,
+                member,
+                val.into_pointer_value(),
+                binary.llvm_type(ty, ns),
+            );
         } else {
             binary.builder.build_store(member, val).unwrap();
         }
@@ -1649,23 +1833,76 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         unreachable!();
     }
 
-    /// Send value to address
+    /// Send lamports from the data account to address
     fn value_transfer<'b>(
         &self,
-        _binary: &Binary<'b>,
+        binary: &Binary<'b>,
         _function: FunctionValue,
-        _success: Option<&mut BasicValueEnum<'b>>,
-        _address: PointerValue<'b>,
-        _value: IntValue<'b>,
+        success: Option<&mut BasicValueEnum<'b>>,
+        address: PointerValue<'b>,
+        value: IntValue<'b>,
         _ns: &ast::Namespace,
         _loc: Loc,
     ) {
-        unreachable!();
+        let parameters = self.sol_parameters(binary);
+
+        let ret = binary
+            .builder
+            .build_call(
+                binary.module.get_function("sol_transfer").unwrap(),
+                &[address.into(), value.into(), parameters.into()],
+                "transfer",
+            )
+            .unwrap()
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+        // Solana error codes are in the upper 32 bits, so they would be lost by truncation
+        let failed = binary
+            .builder
+            .build_int_compare(
+                IntPredicate::NE,
+                ret,
+                binary.context.i64_type().const_zero(),
+                "failed",
+            )
+            .unwrap();
+
+        *success.unwrap() = binary
+            .builder
+            .build_int_z_extend(failed, binary.context.i32_type(), "success")
+            .unwrap()
+            .into();
     }
 
-    /// Terminate execution, destroy binary and send remaining funds to addr
-    fn selfdestruct<'b>(&self, _binary: &Binary<'b>, _addr: ArrayValue<'b>, _ns: &ast::Namespace) {
-        unimplemented!();
+    /// Close the data account, sending its lamports to addr, and stop execution
+    fn selfdestruct<'b>(&self, binary: &Binary<'b>, addr: ArrayValue<'b>, ns: &ast::Namespace) {
+        let address = binary
+            .builder
+            .build_alloca(binary.address_type(ns), "address")
+            .unwrap();
+
+        binary.builder.build_store(address, addr).unwrap();
+
+        let parameters = self.sol_parameters(binary);
+
+        let ret = binary
+            .builder
+            .build_call(
+                binary.module.get_function("sol_selfdestruct").unwrap(),
+                &[address.into(), parameters.into()],
+                "terminated",
+            )
+            .unwrap()
+            .try_as_basic_value()
+            .left()
+            .unwrap();
+
+        // every caller returns the code without continuing, and the entrypoint turns it
+        // into success
+        binary.builder.build_return(Some(&ret)).unwrap();
     }
 
     /// Emit event
@@ -66,6 +66,40 @@ impl<'a> TargetRuntime<'a> for SorobanTarget {
         unimplemented!()
     }
 
+    fn transient_load(
+        &self,
+        bin: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    ) -> BasicValueEnum<'a> {
+        unimplemented!()
+    }
+
+    fn transient_store(
+        &self,
+        bin: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        value: BasicValueEnum<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    ) {
+        unimplemented!()
+    }
+
+    fn transient_delete(
+        &self,
+        bin: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    ) {
+        unimplemented!()
+    }
+
     // Bytes and string have special storage layout
     fn set_storage_string(
         &self,
@@ -3,7 +3,7 @@
 use crate::sema::ast;
 use itertools::Itertools;
 use normalize_path::NormalizePath;
-use solang_parser::pt::Loc;
+use solang_parser::pt::{self, Loc};
 use std::collections::HashMap;
 use std::ffi::{OsStr, OsString};
 use std::fs::File;
@@ -19,8 +19,16 @@ pub struct FileResolver {
     cached_paths: HashMap<PathBuf, usize>,
     /// The actual file contents
     files: Vec<ResolvedFile>,
+    /// Parse trees by file number in the resolver, if enabled. Every location in a parse tree
+    /// includes the file number in the namespace, so a tree is only reused for the same number.
+    parse_cache: Option<HashMap<usize, (usize, Arc<ParseResult>)>>,
+    /// Set when a file is added or its contents change, see [`FileResolver::is_modified`]
+    modified: bool,
 }
 
+/// The parse tree and comments of a file, or the parser errors
+pub type ParseResult = Result<(pt::SourceUnit, Vec<pt::Comment>), Vec<ast::Diagnostic>>;
+
 /// When we resolve a file, we need to know its base compared to the import so
 /// we can resolve the next import, and the full path on the filesystem.
 /// Since the same filename can exists in multiple imports, we need to tell the
@@ -74,20 +82,81 @@ impl FileResolver {
             .map(|(_, pb)| pb)
     }
 
+    /// Keep the parse tree of every file, so that files which have not changed are not parsed
+    /// again when the resolver is reused for another namespace. This is used by the language
+    /// server, which resolves the same files on every edit.
+    pub fn enable_parse_cache(&mut self) {
+        self.parse_cache.get_or_insert_with(HashMap::new);
+    }
+
     /// Update the cache for the filename with the given contents
     pub fn set_file_contents(&mut self, path: &str, contents: String) {
-        let pos = self.files.len();
-
         let pathbuf = PathBuf::from(path);
 
-        self.files.push(ResolvedFile {
+        let file = ResolvedFile {
             path: path.into(),
             full_path: pathbuf.clone(),
             contents: Arc::from(contents),
             import_no: None,
-        });
+        };
+
+        if let Some(pos) = self.cached_paths.get(&pathbuf) {
+            let existing = &mut self.files[*pos];
+
+            // keep the parse tree if nothing changed
+            if existing.contents != file.contents
+                || existing.path != file.path
+                || existing.import_no.is_some()
+            {
+                *existing = file;
+                self.modified = true;
+
+                if let Some(parse_cache) = &mut self.parse_cache {
+                    parse_cache.remove(pos);
+                }
+            }
+        } else {
+            self.cached_paths.insert(pathbuf, self.files.len());
+            self.files.push(file);
+            self.modified = true;
+        }
+    }
+
+    /// Has any file been added or changed since [`FileResolver::clear_modified`] was called.
+    /// Resolving a namespace resolves all of its files together, so if nothing has changed,
+    /// the namespace from the previous resolve can be used as it is.
+    pub fn is_modified(&self) -> bool {
+        self.modified
+    }
+
+    /// Mark the files as unchanged, once a namespace has been resolved from them
+    pub fn clear_modified(&mut self) {
+        self.modified = false;
+    }
+
+    /// Parse the `cache_no`th file, which is file `file_no` in the namespace. If the parse
+    /// cache is enabled, the parse tree from a previous call is returned if there is one.
+    pub(crate) fn parse(&mut self, cache_no: usize, file_no: usize) -> Arc<ParseResult> {
+        if let Some((cached_file_no, result)) = self
+            .parse_cache
+            .as_ref()
+            .and_then(|parse_cache| parse_cache.get(&cache_no))
+        {
+            if *cached_file_no == file_no {
+                return result.clone();
+            }
+        }
+
+        let result = Arc::new(solang_parser::parse(
+            &self.files[cache_no].contents,
+            file_no,
+        ));
+
+        if let Some(parse_cache) = &mut self.parse_cache {
+            parse_cache.insert(cache_no, (file_no, result.clone()));
+        }
 
-        self.cached_paths.insert(pathbuf, pos);
+        result
     }
 
     /// Get the file contents of `file_no`th file if it exists
@@ -171,6 +240,7 @@ impl FileResolver {
         });
 
         self.cached_paths.insert(path.to_path_buf(), pos);
+        self.modified = true;
 
         Ok(&self.files[pos])
     }
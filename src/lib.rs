@@ -7,7 +7,11 @@ pub mod emit;
 pub mod file_resolver;
 #[cfg(feature = "llvm")]
 mod linker;
+pub mod lint;
+pub mod sarif;
+pub mod sourcemap;
 pub mod standard_json;
+pub mod storage_layout;
 
 // In Sema, we use result unit for returning early
 // when code-misparses. The error will be added to the namespace diagnostics, no need to have anything but unit
@@ -30,6 +34,9 @@ pub enum Target {
         address_length: usize,
         value_length: usize,
     },
+    /// Parachains with the `revive` pallet, which runs RISC-V contracts on PolkaVM and uses
+    /// Ethereum ABI encoding, see <https://github.com/paritytech/polkavm>
+    PolkadotRevive,
     /// Ethereum EVM, see <https://ethereum.org/en/developers/docs/evm/>
     EVM,
     Soroban,
@@ -40,6 +47,7 @@ impl fmt::Display for Target {
         match self {
             Target::Solana => write!(f, "Solana"),
             Target::Polkadot { .. } => write!(f, "Polkadot"),
+            Target::PolkadotRevive => write!(f, "Polkadot revive"),
             Target::EVM => write!(f, "EVM"),
             Target::Soroban => write!(f, "Soroban"),
         }
@@ -48,11 +56,13 @@ impl fmt::Display for Target {
 
 impl PartialEq for Target {
     // Equality should check if it the same chain, not compare parameters. This
-    // is needed for builtins for example
+    // is needed for builtins for example. Use is_polkadot() for checks which apply
+    // to both pallet-contracts and pallet-revive.
     fn eq(&self, other: &Self) -> bool {
         match self {
             Target::Solana => matches!(other, Target::Solana),
             Target::Polkadot { .. } => matches!(other, Target::Polkadot { .. }),
+            Target::PolkadotRevive => matches!(other, Target::PolkadotRevive),
             Target::EVM => matches!(other, Target::EVM),
             Target::Soroban => matches!(other, Target::Soroban),
         }
@@ -60,9 +70,14 @@ impl PartialEq for Target {
 }
 
 impl Target {
-    /// Short-hand for checking for Polkadot target
+    /// Short-hand for checking for Polkadot target. This includes the PolkaVM target.
     pub fn is_polkadot(&self) -> bool {
-        matches!(self, Target::Polkadot { .. })
+        matches!(self, Target::Polkadot { .. } | Target::PolkadotRevive)
+    }
+
+    /// Short-hand for checking for the Polkadot revive (PolkaVM) target
+    pub fn is_polkadot_revive(&self) -> bool {
+        matches!(self, Target::PolkadotRevive)
     }
 
     /// Create the target Polkadot with default parameters
@@ -78,6 +93,7 @@ impl Target {
         match name {
             "solana" => Some(Target::Solana),
             "polkadot" => Some(Target::default_polkadot()),
+            "polkadot-revive" => Some(Target::PolkadotRevive),
             "evm" => Some(Target::EVM),
             _ => None,
         }
@@ -88,6 +104,8 @@ impl Target {
         match self {
             // Solana uses ELF dynamic shared object (BPF)
             Target::Solana => "so",
+            // PolkaVM program blob
+            Target::PolkadotRevive => "polkavm",
             // Everything else generates webassembly
             _ => "wasm",
         }
@@ -99,7 +117,7 @@ impl Target {
             // Solana is BPF, which is 64 bit
             64
         } else {
-            // All others are WebAssembly in 32 bit mode
+            // All others are WebAssembly or RISC-V in 32 bit mode
             32
         }
     }
@@ -173,13 +191,13 @@ pub fn parse_and_resolve(
 
     match resolver.resolve_file(None, filename) {
         Err(message) => {
-            ns.diagnostics.push(sema::ast::Diagnostic {
-                ty: sema::ast::ErrorType::ParserError,
-                level: sema::ast::Level::Error,
+            ns.diagnostics.push(sema::ast::Diagnostic::new(
+                pt::Loc::CommandLine,
+                sema::ast::Level::Error,
+                sema::ast::ErrorType::ParserError,
                 message,
-                loc: pt::Loc::CommandLine,
-                notes: Vec::new(),
-            });
+                Vec::new(),
+            ));
         }
         Ok(file) => {
             sema::sema(&file, resolver, &mut ns);
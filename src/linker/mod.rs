@@ -2,6 +2,8 @@
 
 mod bpf;
 mod polkadot_wasm;
+#[cfg(feature = "polkavm")]
+mod polkavm;
 mod soroban_wasm;
 use crate::Target;
 use once_cell::sync::Lazy;
@@ -23,7 +25,11 @@ pub fn link(input: &[u8], name: &str, target: Target) -> Vec<u8> {
             address_length: _,
             value_length: _,
         } => polkadot_wasm::link(input, name),
-        _ => panic!("linker not implemented for target {:?}", target),
+        #[cfg(feature = "polkavm")]
+        Target::PolkadotRevive => polkavm::link(input, name),
+        // EVM is never emitted, and codegen reports an error for polkadot-revive without the
+        // polkavm feature
+        _ => unreachable!("linker not implemented for target {:?}", target),
     }
 }
 
@@ -98,14 +98,17 @@ fn generate_import_section(section: SectionLimited<Import>, module: &mut Module)
             }),
             _ => panic!("unexpected WASM import section {:?}", import),
         };
-        let module_name = match import.name {
-            "memory" => import.module,
-            "set_storage" => "seal2",
-            "clear_storage" | "contains_storage" | "get_storage" | "instantiate" | "terminate"
-            | "seal_call" => "seal1",
-            _ => "seal0",
+        let (module_name, name) = match import.name {
+            "memory" => (import.module, import.name),
+            "set_storage" => ("seal2", import.name),
+            "call_v2" => ("seal2", "call"),
+            "instantiate_v2" => ("seal2", "instantiate"),
+            "clear_storage" | "contains_storage" | "get_storage" | "terminate" => {
+                ("seal1", import.name)
+            }
+            _ => ("seal0", import.name),
         };
-        imports.import(module_name, import.name, import_type);
+        imports.import(module_name, name, import_type);
     }
     module.section(&imports);
 }
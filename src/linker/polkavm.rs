@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// Use the llvm lld linker to create a relocatable RISC-V ELF file, and then
+// convert it into a PolkaVM blob for pallet-revive. The PolkaVM linker needs
+// the relocations to be able to rewrite the code.
+
+use std::ffi::CString;
+use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use tempfile::tempdir;
+
+pub fn link(input: &[u8], name: &str) -> Vec<u8> {
+    let dir = tempdir().expect("failed to create temp directory for linking");
+
+    let object_filename = dir.path().join(format!("{name}.o"));
+    let res_filename = dir.path().join(format!("{name}.elf"));
+
+    let mut objectfile =
+        File::create(object_filename.clone()).expect("failed to create object file");
+
+    objectfile
+        .write_all(input)
+        .expect("failed to write object file to temp file");
+
+    let command_line = vec![
+        CString::new("--emit-relocs").unwrap(),
+        CString::new("--unique").unwrap(),
+        CString::new("--relocatable").unwrap(),
+        CString::new(
+            object_filename
+                .to_str()
+                .expect("temp path should be unicode"),
+        )
+        .unwrap(),
+        CString::new("-o").unwrap(),
+        CString::new(res_filename.to_str().expect("temp path should be unicode")).unwrap(),
+    ];
+
+    assert!(!super::elf_linker(&command_line), "linker failed");
+
+    let mut output = Vec::new();
+    // read the whole file
+    let mut outputfile = File::open(res_filename).expect("output file should exist");
+
+    outputfile
+        .read_to_end(&mut output)
+        .expect("failed to read output file");
+
+    polkavm_linker::program_from_elf(polkavm_linker::Config::default(), &output)
+        .unwrap_or_else(|err| panic!("failed to create PolkaVM blob for {name}: {err}"))
+}
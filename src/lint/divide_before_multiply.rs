@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{recurse_expressions, strip_casts};
+use crate::sema::ast::{Diagnostic, Expression, Namespace, Statement};
+use crate::sema::diagnostics::codes;
+
+/// Integer division rounds down, so `a / b * c` can be much less precise than `a * c / b`
+pub(super) fn check(body: &[Statement], ns: &Namespace, diagnostics: &mut Vec<Diagnostic>) {
+    let mut state = State {
+        ns,
+        diagnostics: Vec::new(),
+    };
+
+    recurse_expressions(body, &mut state, divide_before_multiply);
+
+    diagnostics.append(&mut state.diagnostics);
+}
+
+struct State<'a> {
+    ns: &'a Namespace,
+    diagnostics: Vec<Diagnostic>,
+}
+
+fn divide_before_multiply(expr: &Expression, state: &mut State) -> bool {
+    if let Expression::Multiply {
+        loc,
+        ty,
+        left,
+        right,
+        ..
+    } = expr
+    {
+        if ty.is_integer(state.ns) {
+            for operand in [left, right] {
+                if let Expression::Divide { loc: div_loc, .. } = strip_casts(operand) {
+                    state.diagnostics.push(
+                        Diagnostic::warning_with_note(
+                            *loc,
+                            "multiplication of the result of a division loses precision"
+                                .to_string(),
+                            *div_loc,
+                            "division rounds down".to_string(),
+                        )
+                        .with_code(codes::DIVIDE_BEFORE_MULTIPLY),
+                    );
+                }
+            }
+        }
+    }
+
+    true
+}
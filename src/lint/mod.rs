@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Security lints for `solang check`. Most lints inspect the resolved function bodies; the
+//! reentrancy lint works on the control flow graphs, so [`codegen`](crate::codegen::codegen)
+//! must have been run before [`check`] for it to find anything.
+
+use crate::sema::ast::{DestructureField, Expression, Namespace, Statement};
+use crate::sema::Recurse;
+
+mod divide_before_multiply;
+mod reentrancy;
+mod signer;
+mod storage_pointer;
+mod tx_origin;
+mod unchecked_call;
+
+/// Run all the lints and add any findings to the diagnostics of the namespace. The lints
+/// are reported as warnings, each with their own code so they can be allowed or denied.
+pub fn check(ns: &mut Namespace) {
+    if ns.diagnostics.any_errors() {
+        return;
+    }
+
+    let mut diagnostics = Vec::new();
+
+    for func in ns.functions.iter().filter(|func| func.has_body) {
+        unchecked_call::check(&func.body, ns, &mut diagnostics);
+        tx_origin::check(&func.body, &mut diagnostics);
+        divide_before_multiply::check(&func.body, ns, &mut diagnostics);
+        signer::check(func, ns, &mut diagnostics);
+        storage_pointer::check(&func.body, &mut diagnostics);
+    }
+
+    reentrancy::check(ns, &mut diagnostics);
+
+    ns.diagnostics.append(&mut diagnostics);
+    ns.diagnostics.sort_and_dedup();
+}
+
+/// Call `f` on every expression in the statements, in the order in which they appear
+/// in the source. Nested statements are visited too, but inline assembly is not.
+fn recurse_expressions<T>(
+    stmts: &[Statement],
+    cx: &mut T,
+    f: fn(expr: &Expression, ctx: &mut T) -> bool,
+) {
+    for stmt in stmts {
+        match stmt {
+            Statement::Block { statements, .. } => recurse_expressions(statements, cx, f),
+            Statement::VariableDecl(_, _, _, Some(expr)) => expr.recurse(cx, f),
+            Statement::If(_, _, cond, then_, else_) => {
+                cond.recurse(cx, f);
+                recurse_expressions(then_, cx, f);
+                recurse_expressions(else_, cx, f);
+            }
+            Statement::While(_, _, cond, body) => {
+                cond.recurse(cx, f);
+                recurse_expressions(body, cx, f);
+            }
+            Statement::DoWhile(_, _, body, cond) => {
+                recurse_expressions(body, cx, f);
+                cond.recurse(cx, f);
+            }
+            Statement::For {
+                init,
+                cond,
+                next,
+                body,
+                ..
+            } => {
+                recurse_expressions(init, cx, f);
+                if let Some(cond) = cond {
+                    cond.recurse(cx, f);
+                }
+                recurse_expressions(body, cx, f);
+                if let Some(next) = next {
+                    next.recurse(cx, f);
+                }
+            }
+            Statement::Expression(_, _, expr)
+            | Statement::Delete(_, _, expr)
+            | Statement::Return(_, Some(expr)) => expr.recurse(cx, f),
+            Statement::Destructure(_, fields, expr) => {
+                expr.recurse(cx, f);
+
+                for field in fields {
+                    if let DestructureField::Expression(expr) = field {
+                        expr.recurse(cx, f);
+                    }
+                }
+            }
+            Statement::Revert { args, .. } | Statement::Emit { args, .. } => {
+                for arg in args {
+                    arg.recurse(cx, f);
+                }
+            }
+            Statement::TryCatch(_, _, try_catch) => {
+                try_catch.expr.recurse(cx, f);
+                recurse_expressions(&try_catch.ok_stmt, cx, f);
+                for clause in &try_catch.errors {
+                    recurse_expressions(&clause.stmt, cx, f);
+                }
+                if let Some(clause) = &try_catch.catch_all {
+                    recurse_expressions(&clause.stmt, cx, f);
+                }
+            }
+            Statement::VariableDecl(_, _, _, None)
+            | Statement::Return(_, None)
+            | Statement::Continue(_)
+            | Statement::Break(_)
+            | Statement::Underscore(_)
+            | Statement::Assembly(..) => (),
+        }
+    }
+}
+
+/// Look through any implicit or explicit conversions of an expression
+fn strip_casts(expr: &Expression) -> &Expression {
+    match expr {
+        Expression::Cast { expr, .. }
+        | Expression::ZeroExt { expr, .. }
+        | Expression::SignExt { expr, .. }
+        | Expression::Trunc { expr, .. }
+        | Expression::CheckingTrunc { expr, .. } => strip_casts(expr),
+        _ => expr,
+    }
+}
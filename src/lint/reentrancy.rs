@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::codegen::cfg::{ASTFunction, ControlFlowGraph, Instr};
+use crate::sema::ast::{CallTy, Diagnostic, Namespace};
+use crate::sema::diagnostics::codes;
+use crate::Target;
+use solang_parser::pt::{CodeLocation, Loc};
+
+/// Storage which is modified after an external call can be observed in its old state by the
+/// callee, if it calls back into the contract. This is a forward dataflow analysis over the
+/// CFG of each function, which tracks whether an external call may have happened.
+///
+/// On Solana, a program may only reenter itself directly, so the lint does not apply.
+pub(super) fn check(ns: &Namespace, diagnostics: &mut Vec<Diagnostic>) {
+    if ns.target == Target::Solana {
+        return;
+    }
+
+    for contract in &ns.contracts {
+        for cfg in &contract.cfg {
+            if matches!(cfg.function_no, ASTFunction::SolidityFunction(_)) {
+                check_cfg(cfg, diagnostics);
+            }
+        }
+    }
+}
+
+fn check_cfg(cfg: &ControlFlowGraph, diagnostics: &mut Vec<Diagnostic>) {
+    // For each block, the location of an external call which may have been made before the
+    // block is entered
+    let mut call_before: Vec<Option<Loc>> = vec![None; cfg.blocks.len()];
+
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+
+        for (block_no, block) in cfg.blocks.iter().enumerate() {
+            let call = block
+                .instr
+                .iter()
+                .filter_map(external_call)
+                .last()
+                .or(call_before[block_no]);
+
+            if let Some(call) = call {
+                for succ in block.successors() {
+                    if call_before[succ].is_none() {
+                        call_before[succ] = Some(call);
+                        changed = true;
+                    }
+                }
+            }
+        }
+    }
+
+    for (block_no, block) in cfg.blocks.iter().enumerate() {
+        let mut call = call_before[block_no];
+
+        for instr in &block.instr {
+            if let Some(loc) = external_call(instr) {
+                call = Some(loc);
+            } else if let (Some(call), Some(loc)) = (call, storage_write(instr)) {
+                diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        loc,
+                        "storage modified after external call, which may allow reentrancy"
+                            .to_string(),
+                        call,
+                        "external call".to_string(),
+                    )
+                    .with_code(codes::REENTRANCY),
+                );
+            }
+        }
+    }
+}
+
+/// Location of an instruction which calls another contract, if it has one in a source file
+fn external_call(instr: &Instr) -> Option<Loc> {
+    match instr {
+        Instr::ExternalCall {
+            loc: loc @ Loc::File(..),
+            callty,
+            ..
+        } if *callty != CallTy::Static => Some(*loc),
+        Instr::Constructor {
+            loc: loc @ Loc::File(..),
+            ..
+        } => Some(*loc),
+        _ => None,
+    }
+}
+
+/// Location of an instruction which modifies storage, if it has one in a source file
+fn storage_write(instr: &Instr) -> Option<Loc> {
+    match instr {
+        // Releasing a lock held in transient storage after the call is the usual guard
+        Instr::SetStorage {
+            transient: true, ..
+        }
+        | Instr::ClearStorage {
+            transient: true, ..
+        } => None,
+        Instr::SetStorage { storage, .. }
+        | Instr::SetStorageBytes { storage, .. }
+        | Instr::PushStorage { storage, .. }
+        | Instr::PopStorage { storage, .. }
+        | Instr::ClearStorage { storage, .. } => match storage.loc() {
+            loc @ Loc::File(..) => Some(loc),
+            _ => None,
+        },
+        _ => None,
+    }
+}
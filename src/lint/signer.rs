@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::recurse_expressions;
+use crate::sema::ast::{
+    Diagnostic, Expression, Function, Namespace, RetrieveType, StructType, Type,
+};
+use crate::sema::diagnostics::codes;
+use crate::sema::solana_accounts::BuiltinAccounts;
+use crate::sema::Recurse;
+use crate::Target;
+use solang_parser::pt;
+
+/// On Solana, a function which modifies the data account and compares an address with one held
+/// in storage is usually checking that the caller is the authority. Anyone can pass the
+/// authority's account to the function, so unless the function also checks that the account
+/// signed the transaction, the comparison proves nothing.
+pub(super) fn check(func: &Function, ns: &Namespace, diagnostics: &mut Vec<Diagnostic>) {
+    if ns.target != Target::Solana || !func.is_public() || func.is_constructor() {
+        return;
+    }
+
+    let accounts = func.solana_accounts.borrow();
+
+    let writes_storage = accounts
+        .get(&BuiltinAccounts::DataAccount.to_string())
+        .is_some_and(|account| account.is_writer);
+
+    // accounts declared with @signer or @mutableSigner are checked by the generated code
+    let has_signer = accounts
+        .values()
+        .any(|account| account.is_signer && !account.generated);
+
+    if !writes_storage || has_signer {
+        return;
+    }
+
+    let mut state = State {
+        ns,
+        signer_checked: false,
+        comparisons: Vec::new(),
+    };
+
+    recurse_expressions(&func.body, &mut state, missing_signer);
+
+    if !state.signer_checked {
+        diagnostics.append(&mut state.comparisons);
+    }
+}
+
+struct State<'a> {
+    ns: &'a Namespace,
+    signer_checked: bool,
+    comparisons: Vec<Diagnostic>,
+}
+
+fn missing_signer(expr: &Expression, state: &mut State) -> bool {
+    match expr {
+        Expression::StructMember { expr, field, .. }
+            if expr.ty().deref_any() == &Type::Struct(StructType::AccountInfo) =>
+        {
+            if StructType::AccountInfo.definition(state.ns).fields[*field].name_as_str()
+                == "is_signer"
+            {
+                state.signer_checked = true;
+            }
+        }
+        Expression::Equal { loc, left, right } | Expression::NotEqual { loc, left, right } => {
+            if let Some(authority) = stored_address(left).or_else(|| stored_address(right)) {
+                state.comparisons.push(
+                    Diagnostic::warning_with_note(
+                        *loc,
+                        "account is compared with an authority, but the function does not \
+                        check that any account signed the transaction"
+                            .to_string(),
+                        authority,
+                        "authority loaded from storage here".to_string(),
+                    )
+                    .with_code(codes::MISSING_SIGNER_CHECK),
+                );
+            }
+        }
+        _ => (),
+    }
+
+    true
+}
+
+/// If the expression loads an address from storage, return the location of the load
+fn stored_address(expr: &Expression) -> Option<pt::Loc> {
+    let mut found = None;
+
+    expr.recurse(&mut found, |expr, found| {
+        if let Expression::StorageLoad {
+            loc,
+            ty: Type::Address(_),
+            ..
+        } = expr
+        {
+            *found = Some(*loc);
+        }
+
+        found.is_none()
+    });
+
+    found
+}
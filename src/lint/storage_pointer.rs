@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::sema::ast::{Diagnostic, Statement, Type};
+use crate::sema::diagnostics::codes;
+use crate::sema::Recurse;
+
+/// A storage reference which is declared without a value does not point anywhere. Reading it
+/// before it is assigned is an error, but declaring it separately makes it easy to end up
+/// writing to the wrong storage on some path, so the reference should be given a value when
+/// it is declared.
+pub(super) fn check(body: &[Statement], diagnostics: &mut Vec<Diagnostic>) {
+    for stmt in body {
+        stmt.recurse(diagnostics, uninitialized_storage_pointer);
+    }
+}
+
+fn uninitialized_storage_pointer(stmt: &Statement, diagnostics: &mut Vec<Diagnostic>) -> bool {
+    if let Statement::VariableDecl(loc, _, param, None) = stmt {
+        if let (Type::StorageRef(..), Some(id)) = (&param.ty, &param.id) {
+            diagnostics.push(
+                Diagnostic::warning(
+                    *loc,
+                    format!(
+                        "storage reference '{}' is declared without a value; assign it in the declaration",
+                        id.name
+                    ),
+                )
+                .with_code(codes::UNINITIALIZED_STORAGE_POINTER),
+            );
+        }
+    }
+
+    true
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{recurse_expressions, strip_casts};
+use crate::sema::ast::{Builtin, Diagnostic, Expression, Statement};
+use crate::sema::diagnostics::codes;
+
+/// `tx.origin` is the account which signed the transaction, not the caller. A contract which
+/// compares it with an owner can be exploited by any contract the owner interacts with.
+pub(super) fn check(body: &[Statement], diagnostics: &mut Vec<Diagnostic>) {
+    recurse_expressions(body, diagnostics, tx_origin);
+}
+
+fn tx_origin(expr: &Expression, diagnostics: &mut Vec<Diagnostic>) -> bool {
+    if let Expression::Equal { loc, left, right } | Expression::NotEqual { loc, left, right } = expr
+    {
+        let left = builtin(left);
+        let right = builtin(right);
+
+        // `tx.origin == msg.sender` checks that the caller is not a contract, which is fine
+        if (left == Some(Builtin::Origin) && right != Some(Builtin::Sender))
+            || (right == Some(Builtin::Origin) && left != Some(Builtin::Sender))
+        {
+            diagnostics.push(
+                Diagnostic::warning(
+                    *loc,
+                    "'tx.origin' used for authorization; use 'msg.sender' instead".to_string(),
+                )
+                .with_code(codes::TX_ORIGIN),
+            );
+        }
+    }
+
+    true
+}
+
+fn builtin(expr: &Expression) -> Option<Builtin> {
+    if let Expression::Builtin { kind, .. } = strip_casts(expr) {
+        Some(*kind)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::strip_casts;
+use crate::sema::ast::{Builtin, DestructureField, Diagnostic, Expression, Namespace, Statement};
+use crate::sema::diagnostics::codes;
+use crate::sema::Recurse;
+use crate::Target;
+use solang_parser::pt::CodeLocation;
+
+/// Low-level calls and `send()` do not revert when the callee fails; they return a bool
+/// which must be checked. On Solana, a failed cross program invocation aborts the
+/// transaction, so there is nothing to check.
+pub(super) fn check(body: &[Statement], ns: &Namespace, diagnostics: &mut Vec<Diagnostic>) {
+    if ns.target == Target::Solana {
+        return;
+    }
+
+    for stmt in body {
+        stmt.recurse(diagnostics, unchecked_call);
+    }
+}
+
+fn unchecked_call(stmt: &Statement, diagnostics: &mut Vec<Diagnostic>) -> bool {
+    match stmt {
+        Statement::Expression(_, _, expr) => {
+            if let Some(message) = call_description(expr) {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        expr.loc(),
+                        format!("return value of {message} is not checked"),
+                    )
+                    .with_code(codes::UNCHECKED_CALL),
+                );
+            }
+        }
+        Statement::Destructure(_, fields, expr)
+            if matches!(fields.first(), Some(DestructureField::None)) =>
+        {
+            if let Some(message) = call_description(expr) {
+                diagnostics.push(
+                    Diagnostic::warning(expr.loc(), format!("success of {message} is discarded"))
+                        .with_code(codes::UNCHECKED_CALL),
+                );
+            }
+        }
+        _ => (),
+    }
+
+    true
+}
+
+fn call_description(expr: &Expression) -> Option<&'static str> {
+    match strip_casts(expr) {
+        Expression::ExternalFunctionCallRaw { .. } => Some("low-level call"),
+        Expression::Builtin {
+            kind: Builtin::PayableSend,
+            ..
+        } => Some("'send'"),
+        _ => None,
+    }
+}
@@ -208,6 +208,8 @@ impl Converter<'_> {
                 callty,
                 contract_function_no,
                 flags,
+                proof_size,
+                storage_deposit_limit,
             } => {
                 let address_op = self.to_operand_option_and_insns(address, vartable, results);
                 let accounts_op =
@@ -217,6 +219,9 @@ impl Converter<'_> {
                 let value_op = self.to_operand_and_insns(value, vartable, results);
                 let gas_op = self.to_operand_and_insns(gas, vartable, results);
                 let flags_op = self.to_operand_option_and_insns(flags, vartable, results);
+                let proof_size_op = self.to_operand_option_and_insns(proof_size, vartable, results);
+                let storage_deposit_limit_op =
+                    self.to_operand_option_and_insns(storage_deposit_limit, vartable, results);
 
                 results.push(Instruction::ExternalCall {
                     loc: *loc,
@@ -230,6 +235,8 @@ impl Converter<'_> {
                     callty: callty.clone(),
                     contract_function_no: *contract_function_no,
                     flags: flags_op,
+                    proof_size: proof_size_op,
+                    storage_deposit_limit: storage_deposit_limit_op,
                 });
             }
             Instr::ValueTransfer {
@@ -345,6 +352,8 @@ impl Converter<'_> {
                 encoded_args,
                 value,
                 gas,
+                proof_size,
+                storage_deposit_limit,
                 salt,
                 address,
                 seeds,
@@ -354,6 +363,9 @@ impl Converter<'_> {
                 let args_op = self.to_operand_and_insns(encoded_args, vartable, results);
                 let value_op = self.to_operand_option_and_insns(value, vartable, results);
                 let gas_op = self.to_operand_and_insns(gas, vartable, results);
+                let proof_size_op = self.to_operand_option_and_insns(proof_size, vartable, results);
+                let storage_deposit_limit_op =
+                    self.to_operand_option_and_insns(storage_deposit_limit, vartable, results);
                 let salt_op = self.to_operand_option_and_insns(salt, vartable, results);
                 let address_op = self.to_operand_option_and_insns(address, vartable, results);
                 let seeds_op = self.to_operand_option_and_insns(seeds, vartable, results);
@@ -369,6 +381,8 @@ impl Converter<'_> {
                     encoded_args: args_op,
                     value: value_op,
                     gas: gas_op,
+                    proof_size: proof_size_op,
+                    storage_deposit_limit: storage_deposit_limit_op,
                     salt: salt_op,
                     address: address_op,
                     seeds: seeds_op,
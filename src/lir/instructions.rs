@@ -54,6 +54,8 @@ pub enum Instruction {
         encoded_args: Operand,
         value: Option<Operand>,
         gas: Operand,
+        proof_size: Option<Operand>,
+        storage_deposit_limit: Option<Operand>,
         salt: Option<Operand>,
         address: Option<Operand>,
         seeds: Option<Operand>,
@@ -133,6 +135,10 @@ pub enum Instruction {
         contract_function_no: Option<(usize, usize)>,
         /// Polkadot specific
         flags: Option<Operand>,
+        /// Polkadot specific
+        proof_size: Option<Operand>,
+        /// Polkadot specific
+        storage_deposit_limit: Option<Operand>,
     },
     /// Value transfer; either address.send() or address.transfer()
     /// transfer tokens from one addr to another
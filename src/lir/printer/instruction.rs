@@ -289,9 +289,11 @@ impl Printer<'_> {
                 callty,
                 contract_function_no,
                 flags,
+                proof_size,
+                storage_deposit_limit,
                 ..
             } => {
-                // {} = call_ext ty:{} address:{} payload:{} value:{} gas:{} accounts:{} seeds:{} contract_no:{}, function_no:{} flags:{};
+                // {} = call_ext ty:{} address:{} payload:{} value:{} gas:{} proof_size:{} storage_deposit_limit:{} accounts:{} seeds:{} contract_no:{}, function_no:{} flags:{};
                 match success {
                     Some(success) => {
                         let success_op = self.get_var_operand(success);
@@ -327,6 +329,26 @@ impl Printer<'_> {
 
                 write!(f, " ").unwrap();
 
+                match proof_size {
+                    Some(proof_size) => {
+                        write!(f, "proof_size:").unwrap();
+                        self.print_rhs_operand(f, proof_size);
+                    }
+                    None => write!(f, "proof_size:_").unwrap(),
+                };
+
+                write!(f, " ").unwrap();
+
+                match storage_deposit_limit {
+                    Some(storage_deposit_limit) => {
+                        write!(f, "storage_deposit_limit:").unwrap();
+                        self.print_rhs_operand(f, storage_deposit_limit);
+                    }
+                    None => write!(f, "storage_deposit_limit:_").unwrap(),
+                };
+
+                write!(f, " ").unwrap();
+
                 match accounts {
                     ast::ExternalCallAccounts::NoAccount => write!(f, "accounts:none").unwrap(),
                     ast::ExternalCallAccounts::Present(acc) => {
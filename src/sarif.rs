@@ -0,0 +1,247 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module defines the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+//! format for `solang compile --diagnostics-format sarif`, which is understood by code scanning tools.
+
+use crate::file_resolver::FileResolver;
+use crate::sema::{
+    ast::{Code, File},
+    diagnostics::code_description,
+};
+use serde::Serialize;
+use solang_parser::pt::Loc;
+use std::path::Path;
+use url::Url;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+#[derive(Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    pub version: &'static str,
+    pub runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Run {
+    pub tool: Tool,
+    pub results: Vec<SarifResult>,
+    /// Columns are counted in Unicode code points rather than the default UTF-16 code units
+    pub column_kind: &'static str,
+}
+
+#[derive(Serialize)]
+pub struct Tool {
+    pub driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Driver {
+    pub name: &'static str,
+    pub version: &'static str,
+    pub information_uri: &'static str,
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rule {
+    pub id: String,
+    pub short_description: Message,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: String,
+    pub rule_index: usize,
+    pub level: &'static str,
+    pub message: Message,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub related_locations: Vec<Location>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<usize>,
+    pub physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalLocation {
+    pub artifact_location: ArtifactLocation,
+    pub region: Region,
+    pub context_region: ContextRegion,
+}
+
+#[derive(Serialize)]
+pub struct ArtifactLocation {
+    pub uri: String,
+}
+
+/// Lines and columns are 1-based, and the end column is exclusive. Columns are counted in
+/// Unicode code points.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    /// The source text of the region itself
+    pub snippet: Message,
+}
+
+/// The whole lines which contain a region
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextRegion {
+    pub start_line: usize,
+    pub end_line: usize,
+    pub snippet: Message,
+}
+
+#[derive(Serialize)]
+pub struct Message {
+    pub text: String,
+}
+
+impl SarifLog {
+    /// Create a log with a single run of solang, with a rule for each code which occurs
+    /// in the results
+    pub fn new(mut results: Vec<SarifResult>) -> Self {
+        let mut codes: Vec<&str> = results.iter().map(|r| r.rule_id.as_str()).collect();
+        codes.sort();
+        codes.dedup();
+
+        let rules: Vec<Rule> = codes
+            .iter()
+            .map(|id| Rule {
+                id: id.to_string(),
+                short_description: Message {
+                    text: code_description(id.parse::<Code>().unwrap()).to_owned(),
+                },
+            })
+            .collect();
+
+        for result in &mut results {
+            result.rule_index = rules.iter().position(|r| r.id == result.rule_id).unwrap();
+        }
+
+        SarifLog {
+            schema: SCHEMA,
+            version: "2.1.0",
+            runs: vec![Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: "solang",
+                        version: env!("SOLANG_VERSION"),
+                        information_uri: "https://solang.readthedocs.io/",
+                        rules,
+                    },
+                },
+                results,
+                column_kind: "unicodeCodePoints",
+            }],
+        }
+    }
+}
+
+impl Location {
+    /// Create the location of a diagnostic or a note. Returns `None` if the location is not in
+    /// a source file, or if it does not lie within the contents of the file.
+    pub fn new(
+        files: &[File],
+        cache: &FileResolver,
+        loc: &Loc,
+        note: Option<(usize, String)>,
+    ) -> Option<Self> {
+        let (file_no, start, end) = match loc {
+            Loc::File(file_no, start, end) => (*file_no, *start, *end),
+            _ => return None,
+        };
+
+        let file = files.get(file_no)?;
+
+        file.cache_no?;
+
+        let (contents, _) = cache.get_file_contents_and_number(&file.path);
+
+        if start > end
+            || end > contents.len()
+            || !contents.is_char_boundary(start)
+            || !contents.is_char_boundary(end)
+        {
+            return None;
+        }
+
+        let (start_line, start_column) = file.offset_to_line_column(start);
+        let (end_line, end_column) = file.offset_to_line_column(end);
+
+        // the columns are byte offsets from the start of the line
+        let start_line_offset = start - start_column;
+        let end_line_offset = end - end_column;
+
+        let snippet_end = contents[end..]
+            .find('\n')
+            .map_or(contents.len(), |len| end + len);
+
+        let (id, message) = match note {
+            Some((id, message)) => (Some(id), Some(Message { text: message })),
+            None => (None, None),
+        };
+
+        Some(Location {
+            id,
+            physical_location: PhysicalLocation {
+                artifact_location: ArtifactLocation {
+                    uri: artifact_uri(&file.path)?,
+                },
+                region: Region {
+                    start_line: start_line + 1,
+                    start_column: contents[start_line_offset..start].chars().count() + 1,
+                    end_line: end_line + 1,
+                    end_column: contents[end_line_offset..end].chars().count() + 1,
+                    snippet: Message {
+                        text: contents[start..end].to_owned(),
+                    },
+                },
+                context_region: ContextRegion {
+                    start_line: start_line + 1,
+                    end_line: end_line + 1,
+                    snippet: Message {
+                        text: contents[start_line_offset..snippet_end]
+                            .trim_end_matches('\r')
+                            .to_owned(),
+                    },
+                },
+            },
+            message,
+        })
+    }
+}
+
+/// The uri of a file. This is relative to the current directory if the file is inside it, so
+/// that code scanning tools can match it with the files in the repository.
+fn artifact_uri(path: &Path) -> Option<String> {
+    let cwd = std::env::current_dir().ok()?;
+    let path = cwd.join(path);
+    let url = Url::from_file_path(&path).ok()?;
+
+    let relative = Url::from_directory_path(&cwd)
+        .ok()
+        .filter(|_| path.starts_with(&cwd))
+        .and_then(|base| base.make_relative(&url));
+
+    Some(relative.unwrap_or_else(|| url.to_string()))
+}
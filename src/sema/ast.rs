@@ -218,7 +218,7 @@ impl fmt::Display for StructDecl {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct EnumDecl {
     pub tags: Vec<Tag>,
     pub id: pt::Identifier,
@@ -316,7 +316,7 @@ impl fmt::Display for Mutability {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Function {
     pub tags: Vec<Tag>,
     /// The location of the prototype (not body)
@@ -371,7 +371,7 @@ pub struct SolanaAccount {
     pub generated: bool,
 }
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct ConstructorAnnotations {
     // (annotation location, annotation expression)
     pub seeds: Vec<(pt::Loc, Expression)>,
@@ -578,7 +578,7 @@ impl fmt::Display for UserTypeDecl {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Variable {
     pub tags: Vec<Tag>,
     pub name: String,
@@ -587,6 +587,8 @@ pub struct Variable {
     pub visibility: pt::Visibility,
     pub constant: bool,
     pub immutable: bool,
+    /// Stored in transient storage, which is cleared at the end of the transaction
+    pub transient: bool,
     pub initializer: Option<Expression>,
     pub assigned: bool,
     pub read: bool,
@@ -666,11 +668,14 @@ pub struct File {
 }
 
 /// When resolving a Solidity file, this holds all the resolved items
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Namespace {
     pub target: Target,
     pub pragmas: Vec<Pragma>,
     pub files: Vec<File>,
+    /// The file number of the file which was compiled, which does not have to be the first file
+    /// since its imports may have been resolved before it
+    pub top_file: Option<usize>,
     pub enums: Vec<EnumDecl>,
     pub structs: Vec<StructDecl>,
     pub events: Vec<EventDecl>,
@@ -704,7 +709,7 @@ pub struct Namespace {
     pub hover_overrides: HashMap<pt::Loc, String>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Pragma {
     Identifier {
         loc: pt::Loc,
@@ -722,7 +727,7 @@ pub enum Pragma {
     },
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum VersionReq {
     Plain {
         loc: pt::Loc,
@@ -767,7 +772,7 @@ impl fmt::Display for Version {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Layout {
     pub slot: BigInt,
     pub contract_no: usize,
@@ -775,35 +780,35 @@ pub struct Layout {
     pub ty: Type,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Base {
     pub loc: pt::Loc,
     pub contract_no: usize,
     pub constructor: Option<(usize, Vec<Expression>)>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Using {
     pub list: UsingList,
     pub ty: Option<Type>,
     pub file_no: Option<usize>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum UsingList {
     Library(usize),
     Functions(Vec<UsingFunction>),
 }
 
 /// Using binding for a function, optionally for an operator
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct UsingFunction {
     pub loc: pt::Loc,
     pub function_no: usize,
     pub oper: Option<pt::UserDefinedOperator>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Contract {
     pub tags: Vec<Tag>,
     pub loc: pt::Loc,
@@ -812,6 +817,10 @@ pub struct Contract {
     pub bases: Vec<Base>,
     pub using: Vec<Using>,
     pub layout: Vec<Layout>,
+    /// The first storage slot, as given with `layout at`
+    pub layout_base: Option<BigInt>,
+    /// Layout of the transient state variables
+    pub transient_layout: Vec<Layout>,
     pub fixed_layout_size: BigInt,
     pub functions: Vec<usize>,
     pub all_functions: BTreeMap<usize, usize>,
@@ -1269,7 +1278,12 @@ pub enum Expression {
 
 #[derive(PartialEq, Eq, Clone, Default, Debug)]
 pub struct CallArgs {
+    /// The gas limit, or the `refTime` limit on Polkadot
     pub gas: Option<Box<Expression>>,
+    /// The `proofSize` limit on Polkadot
+    pub proof_size: Option<Box<Expression>>,
+    /// The `storageDepositLimit` on Polkadot
+    pub storage_deposit_limit: Option<Box<Expression>>,
     pub salt: Option<Box<Expression>>,
     pub value: Option<Box<Expression>>,
     pub accounts: ExternalCallAccounts<Box<Expression>>,
@@ -1341,6 +1355,12 @@ impl Recurse for CallArgs {
         if let Some(gas) = &self.gas {
             gas.recurse(cx, f);
         }
+        if let Some(proof_size) = &self.proof_size {
+            proof_size.recurse(cx, f);
+        }
+        if let Some(limit) = &self.storage_deposit_limit {
+            limit.recurse(cx, f);
+        }
         if let Some(salt) = &self.salt {
             salt.recurse(cx, f);
         }
@@ -1732,6 +1752,8 @@ pub enum Builtin {
     AbiEncodeWithSelector,
     AbiEncodeWithSignature,
     AbiEncodeCall,
+    AbiDecodeEthereum,
+    AbiEncodeEthereum,
     MulMod,
     AddMod,
     ChainId,
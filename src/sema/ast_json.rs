@@ -0,0 +1,3021 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Export the resolved AST in the compact JSON format of solc, so that tools which consume solc's
+//! AST (e.g. Slither, Surya and coverage tools) can be used on Solidity compiled with Solang.
+//!
+//! Every node has an `id`, a `nodeType` and a `src` of the form `start:length:file`, where the
+//! file is the file number in the namespace. The ids are allocated in a fixed order, so they do
+//! not change between runs. Like solc, Solidity's global symbols like `msg` and `abi` have
+//! negative ids.
+
+use crate::sema::{
+    ast::*,
+    builtin::{get_prototype, BUILTIN_VARIABLE},
+    symtable::Symtable,
+    yul::ast::{InlineAssembly, YulBlock, YulExpression, YulStatement},
+};
+use serde_json::{json, Map, Value};
+use solang_parser::{pt, pt::CodeLocation, pt::Loc};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+/// The global symbols of Solidity, which have negative ids
+const GLOBALS: [&str; 25] = [
+    "abi",
+    "addmod",
+    "assert",
+    "blake2_128",
+    "blake2_256",
+    "block",
+    "blockhash",
+    "bytes",
+    "ecrecover",
+    "gasleft",
+    "keccak256",
+    "msg",
+    "mulmod",
+    "print",
+    "require",
+    "revert",
+    "ripemd160",
+    "selfdestruct",
+    "sha256",
+    "signatureVerify",
+    "string",
+    "super",
+    "this",
+    "tx",
+    "type",
+];
+
+/// The declarations which can be referenced by id from elsewhere in the AST
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Decl {
+    SourceUnit(usize),
+    Contract(usize),
+    Function(usize),
+    /// A constant or a state variable
+    Variable(Option<usize>, usize),
+    /// A local variable, parameter or return value in a symbol table
+    Local(usize),
+    Struct(StructType),
+    StructField(StructType, usize),
+    Enum(usize),
+    EnumValue(usize, usize),
+    Event(usize),
+    Error(usize),
+    UserType(usize),
+}
+
+/// A declaration which is a node of a source unit or contract
+#[derive(Clone, Copy)]
+enum Item {
+    Contract(usize),
+    Function(usize),
+    Constant(usize),
+    StateVariable(usize, usize),
+    Struct(usize),
+    Enum(usize),
+    Event(usize),
+    Error(usize),
+    UserType(usize),
+}
+
+struct AstJson<'a> {
+    ns: &'a Namespace,
+    ids: RefCell<HashMap<Decl, i64>>,
+    next_id: Cell<i64>,
+    /// The symbol table of the function being exported
+    symtable: Cell<Option<&'a Symtable>>,
+    /// The id of the return parameters of the function being exported
+    return_parameters: Cell<Option<i64>>,
+}
+
+impl<'a> AstJson<'a> {
+    fn new_id(&self) -> i64 {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        id
+    }
+
+    /// The id of a declaration. A declaration may be referenced before it is declared, so the
+    /// id is allocated on first use.
+    fn id(&self, decl: Decl) -> i64 {
+        if let Some(id) = self.ids.borrow().get(&decl) {
+            return *id;
+        }
+
+        let id = self.new_id();
+        self.ids.borrow_mut().insert(decl, id);
+        id
+    }
+
+    fn src(&self, loc: &Loc) -> String {
+        match loc {
+            Loc::File(file_no, start, end) => format!("{start}:{}:{file_no}", end - start),
+            _ => String::from("-1:-1:-1"),
+        }
+    }
+
+    fn node(&self, node_type: &str, loc: &Loc, fields: Value) -> Value {
+        let id = self.new_id();
+
+        self.node_with_id(id, node_type, loc, fields)
+    }
+
+    fn node_with_id(&self, id: i64, node_type: &str, loc: &Loc, fields: Value) -> Value {
+        let mut node = Map::new();
+
+        node.insert("id".into(), id.into());
+        node.insert("nodeType".into(), node_type.into());
+        node.insert("src".into(), self.src(loc).into());
+
+        if let Value::Object(fields) = fields {
+            node.extend(fields);
+        }
+
+        Value::Object(node)
+    }
+
+    fn source_unit(&self, file_no: usize, items: &[(Loc, Item)]) -> Value {
+        let mut nodes = Vec::new();
+        let mut exported_symbols = Map::new();
+        let mut end = 0;
+
+        for pragma in &self.ns.pragmas {
+            let (loc, literals) = match pragma {
+                Pragma::Identifier { loc, name, value } => {
+                    (loc, vec![name.name.clone(), value.name.clone()])
+                }
+                Pragma::StringLiteral { loc, name, value } => {
+                    (loc, vec![name.name.clone(), value.string.clone()])
+                }
+                Pragma::SolidityVersion { loc, versions } => {
+                    let mut literals = vec![String::from("solidity")];
+
+                    for version in versions {
+                        version_literals(version, &mut literals);
+                    }
+
+                    (loc, literals)
+                }
+            };
+
+            if loc.try_file_no() == Some(file_no) {
+                let pragma = self.node("PragmaDirective", loc, json!({ "literals": literals }));
+
+                nodes.push((loc.start(), pragma));
+            }
+        }
+
+        for using in &self.ns.using {
+            let loc = using_loc(using);
+
+            if using.file_no == Some(file_no)
+                || (using.file_no.is_none() && loc.try_file_no() == Some(file_no))
+            {
+                nodes.push((
+                    loc.try_file_no().map_or(0, |_| loc.start()),
+                    self.using(using),
+                ));
+            }
+        }
+
+        for (loc, item) in items {
+            if loc.file_no() != file_no {
+                continue;
+            }
+
+            end = end.max(loc.end());
+
+            // contract members are nodes of the contract
+            if !matches!(item, Item::Contract(_))
+                && self.ns.contracts.iter().any(|c| contains(&c.loc, loc))
+            {
+                continue;
+            }
+
+            let node = self.item(*item);
+
+            if let Some(name) = self.item_name(*item) {
+                exported_symbols.insert(name, json!([node["id"]]));
+            }
+
+            nodes.push((loc.start(), node));
+        }
+
+        nodes.sort_by_key(|(start, _)| *start);
+
+        let nodes: Vec<Value> = nodes.into_iter().map(|(_, node)| node).collect();
+
+        self.node_with_id(
+            self.id(Decl::SourceUnit(file_no)),
+            "SourceUnit",
+            &Loc::File(file_no, 0, end),
+            json!({
+                "absolutePath": self.ns.files[file_no].path.display().to_string(),
+                "exportedSymbols": exported_symbols,
+                "nodes": nodes,
+            }),
+        )
+    }
+
+    /// All the declarations in source files, in source order
+    fn items(&self) -> Vec<(Loc, Item)> {
+        let ns = self.ns;
+        let mut items = Vec::new();
+
+        items.extend(
+            ns.contracts
+                .iter()
+                .enumerate()
+                .map(|(no, c)| (c.loc, Item::Contract(no))),
+        );
+        items.extend(
+            ns.functions
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| !f.is_accessor)
+                .map(|(no, f)| (f.loc, Item::Function(no))),
+        );
+        items.extend(
+            ns.constants
+                .iter()
+                .enumerate()
+                .map(|(no, v)| (v.loc, Item::Constant(no))),
+        );
+        for (contract_no, contract) in ns.contracts.iter().enumerate() {
+            items.extend(
+                contract
+                    .variables
+                    .iter()
+                    .enumerate()
+                    .map(|(no, v)| (v.loc, Item::StateVariable(contract_no, no))),
+            );
+        }
+        items.extend(
+            ns.structs
+                .iter()
+                .enumerate()
+                .map(|(no, s)| (s.loc, Item::Struct(no))),
+        );
+        items.extend(
+            ns.enums
+                .iter()
+                .enumerate()
+                .map(|(no, e)| (e.loc, Item::Enum(no))),
+        );
+        items.extend(
+            ns.events
+                .iter()
+                .enumerate()
+                .map(|(no, e)| (e.loc, Item::Event(no))),
+        );
+        items.extend(
+            ns.errors
+                .iter()
+                .enumerate()
+                .map(|(no, e)| (e.loc, Item::Error(no))),
+        );
+        items.extend(
+            ns.user_types
+                .iter()
+                .enumerate()
+                .map(|(no, t)| (t.loc, Item::UserType(no))),
+        );
+
+        items.retain(|(loc, _)| matches!(loc, Loc::File(..)));
+        items.sort_by_key(|(loc, _)| (loc.file_no(), loc.start()));
+
+        items
+    }
+
+    fn item_name(&self, item: Item) -> Option<String> {
+        let ns = self.ns;
+
+        match item {
+            Item::Contract(no) => Some(ns.contracts[no].id.name.clone()),
+            Item::Function(no) => Some(ns.functions[no].id.name.clone()),
+            Item::Constant(no) => Some(ns.constants[no].name.clone()),
+            Item::Struct(no) => Some(ns.structs[no].id.name.clone()),
+            Item::Enum(no) => Some(ns.enums[no].id.name.clone()),
+            Item::Event(no) => Some(ns.events[no].id.name.clone()),
+            Item::Error(no) => Some(ns.errors[no].name.clone()),
+            Item::UserType(no) => Some(ns.user_types[no].name.clone()),
+            Item::StateVariable(..) => None,
+        }
+    }
+
+    fn item(&self, item: Item) -> Value {
+        match item {
+            Item::Contract(no) => self.contract(no),
+            Item::Function(no) => self.function(no),
+            Item::Constant(no) => self.variable(None, no),
+            Item::StateVariable(contract_no, no) => self.variable(Some(contract_no), no),
+            Item::Struct(no) => self.struct_definition(no),
+            Item::Enum(no) => self.enum_definition(no),
+            Item::Event(no) => self.event_definition(no),
+            Item::Error(no) => self.error_definition(no),
+            Item::UserType(no) => self.user_type_definition(no),
+        }
+    }
+
+    /// The id of the contract or source unit a declaration is in
+    fn scope(&self, contract_no: Option<usize>, loc: &Loc) -> Value {
+        match (contract_no, loc) {
+            (Some(contract_no), _) => self.id(Decl::Contract(contract_no)).into(),
+            (None, Loc::File(file_no, ..)) => self.id(Decl::SourceUnit(*file_no)).into(),
+            _ => Value::Null,
+        }
+    }
+
+    /// The contract a struct, enum or user type is declared in. These only record the name.
+    fn contract_no(&self, name: &Option<String>, loc: &Loc) -> Option<usize> {
+        let name = name.as_ref()?;
+
+        self.ns
+            .contracts
+            .iter()
+            .position(|c| c.id.name == *name && contains(&c.loc, loc))
+    }
+
+    fn documentation(&self, tags: &[Tag]) -> Value {
+        let (Some(first), Some(last)) = (tags.first(), tags.last()) else {
+            return Value::Null;
+        };
+
+        let text = tags
+            .iter()
+            .map(|tag| {
+                if tag.tag == "notice" {
+                    tag.value.clone()
+                } else {
+                    format!("@{} {}", tag.tag, tag.value)
+                }
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        let loc = match (first.loc, last.loc) {
+            (Loc::File(..), Loc::File(..)) => first.loc.with_end_from(&last.loc),
+            _ => Loc::Builtin,
+        };
+
+        self.node("StructuredDocumentation", &loc, json!({ "text": text }))
+    }
+
+    fn contract(&self, contract_no: usize) -> Value {
+        let ns = self.ns;
+        let contract = &ns.contracts[contract_no];
+
+        let base_contracts: Vec<Value> = contract
+            .bases
+            .iter()
+            .map(|base| {
+                let name = &ns.contracts[base.contract_no].id.name;
+                let base_name = self.identifier_path(
+                    &prefix_loc(&base.loc, name.len()),
+                    name,
+                    Some(self.id(Decl::Contract(base.contract_no))),
+                );
+                let arguments = base
+                    .constructor
+                    .as_ref()
+                    .map(|(_, args)| self.expressions(args));
+
+                self.node(
+                    "InheritanceSpecifier",
+                    &base.loc,
+                    json!({
+                        "baseName": base_name,
+                        "arguments": arguments,
+                    }),
+                )
+            })
+            .collect();
+
+        let mut nodes: Vec<Value> = contract.using.iter().map(|u| self.using(u)).collect();
+
+        for (loc, item) in self.items() {
+            if !matches!(item, Item::Contract(_)) && contains(&contract.loc, &loc) {
+                nodes.push(self.item(item));
+            }
+        }
+
+        let linearized: Vec<i64> = ns
+            .contract_bases(contract_no)
+            .iter()
+            .rev()
+            .map(|no| self.id(Decl::Contract(*no)))
+            .collect();
+
+        let dependencies: Vec<i64> = contract
+            .creates
+            .iter()
+            .map(|no| self.id(Decl::Contract(*no)))
+            .collect();
+
+        let events: Vec<i64> = contract
+            .emits_events
+            .iter()
+            .map(|no| self.id(Decl::Event(*no)))
+            .collect();
+
+        let kind = match contract.ty {
+            pt::ContractTy::Interface(_) => "interface",
+            pt::ContractTy::Library(_) => "library",
+            _ => "contract",
+        };
+
+        let is_abstract = matches!(contract.ty, pt::ContractTy::Abstract(_));
+
+        self.node_with_id(
+            self.id(Decl::Contract(contract_no)),
+            "ContractDefinition",
+            &contract.loc,
+            json!({
+                "name": contract.id.name,
+                "nameLocation": self.src(&contract.id.loc),
+                "contractKind": kind,
+                "abstract": is_abstract,
+                "fullyImplemented": !is_abstract && kind != "interface",
+                "baseContracts": base_contracts,
+                "contractDependencies": dependencies,
+                "linearizedBaseContracts": linearized,
+                "usedEvents": events,
+                "documentation": self.documentation(&contract.tags),
+                "nodes": nodes,
+                "scope": self.scope(None, &contract.loc),
+            }),
+        )
+    }
+
+    fn using(&self, using: &Using) -> Value {
+        let ns = self.ns;
+        let loc = using_loc(using);
+
+        let mut fields = match &using.list {
+            UsingList::Library(library_no) => {
+                let library = &ns.contracts[*library_no];
+
+                json!({
+                    "libraryName": self.identifier_path(
+                        &Loc::Builtin,
+                        &library.id.name,
+                        Some(self.id(Decl::Contract(*library_no))),
+                    ),
+                })
+            }
+            UsingList::Functions(functions) => {
+                let list: Vec<Value> = functions
+                    .iter()
+                    .map(|using| {
+                        let path = self.identifier_path(
+                            &using.loc,
+                            &ns.functions[using.function_no].id.name,
+                            Some(self.id(Decl::Function(using.function_no))),
+                        );
+
+                        match &using.oper {
+                            Some(oper) => json!({
+                                "definition": path,
+                                "operator": oper.to_string(),
+                            }),
+                            None => json!({ "function": path }),
+                        }
+                    })
+                    .collect();
+
+                json!({ "functionList": list })
+            }
+        };
+
+        fields["typeName"] = match &using.ty {
+            Some(ty) => self.type_name(ty, &loc),
+            None => Value::Null,
+        };
+        fields["global"] = using.file_no.is_none().into();
+
+        self.node("UsingForDirective", &loc, fields)
+    }
+
+    fn struct_definition(&self, struct_no: usize) -> Value {
+        let decl = &self.ns.structs[struct_no];
+        let ty = StructType::UserDefined(struct_no);
+        let contract_no = self.contract_no(&decl.contract, &decl.loc);
+
+        let members: Vec<Value> = decl
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(field_no, field)| {
+                self.variable_declaration(
+                    self.id(Decl::StructField(ty, field_no)),
+                    field,
+                    &field.ty,
+                    json!({ "scope": self.id(Decl::Struct(ty)) }),
+                )
+            })
+            .collect();
+
+        self.node_with_id(
+            self.id(Decl::Struct(ty)),
+            "StructDefinition",
+            &decl.loc,
+            json!({
+                "name": decl.id.name,
+                "nameLocation": self.src(&decl.id.loc),
+                "canonicalName": decl.to_string(),
+                "members": members,
+                "visibility": "public",
+                "documentation": self.documentation(&decl.tags),
+                "scope": self.scope(contract_no, &decl.loc),
+            }),
+        )
+    }
+
+    fn enum_definition(&self, enum_no: usize) -> Value {
+        let decl = &self.ns.enums[enum_no];
+        let contract_no = self.contract_no(&decl.contract, &decl.loc);
+
+        let members: Vec<Value> = decl
+            .values
+            .iter()
+            .enumerate()
+            .map(|(value_no, (name, loc))| {
+                self.node_with_id(
+                    self.id(Decl::EnumValue(enum_no, value_no)),
+                    "EnumValue",
+                    loc,
+                    json!({
+                        "name": name,
+                        "nameLocation": self.src(loc),
+                    }),
+                )
+            })
+            .collect();
+
+        self.node_with_id(
+            self.id(Decl::Enum(enum_no)),
+            "EnumDefinition",
+            &decl.loc,
+            json!({
+                "name": decl.id.name,
+                "nameLocation": self.src(&decl.id.loc),
+                "canonicalName": decl.to_string(),
+                "members": members,
+                "documentation": self.documentation(&decl.tags),
+                "scope": self.scope(contract_no, &decl.loc),
+            }),
+        )
+    }
+
+    fn event_definition(&self, event_no: usize) -> Value {
+        let decl = &self.ns.events[event_no];
+
+        self.node_with_id(
+            self.id(Decl::Event(event_no)),
+            "EventDefinition",
+            &decl.loc,
+            json!({
+                "name": decl.id.name,
+                "nameLocation": self.src(&decl.id.loc),
+                "anonymous": decl.anonymous,
+                "parameters": self.parameter_list(&decl.fields, &[], &decl.loc, true),
+                "documentation": self.documentation(&decl.tags),
+            }),
+        )
+    }
+
+    fn error_definition(&self, error_no: usize) -> Value {
+        let decl = &self.ns.errors[error_no];
+
+        self.node_with_id(
+            self.id(Decl::Error(error_no)),
+            "ErrorDefinition",
+            &decl.loc,
+            json!({
+                "name": decl.name,
+                "parameters": self.parameter_list(&decl.fields, &[], &decl.loc, false),
+                "documentation": self.documentation(&decl.tags),
+            }),
+        )
+    }
+
+    fn user_type_definition(&self, type_no: usize) -> Value {
+        let decl = &self.ns.user_types[type_no];
+
+        self.node_with_id(
+            self.id(Decl::UserType(type_no)),
+            "UserDefinedValueTypeDefinition",
+            &decl.loc,
+            json!({
+                "name": decl.name,
+                "canonicalName": decl.to_string(),
+                "underlyingType": self.type_name(&decl.ty, &decl.loc),
+            }),
+        )
+    }
+
+    /// A constant, or a state variable if the contract is given
+    fn variable(&self, contract_no: Option<usize>, var_no: usize) -> Value {
+        let var = match contract_no {
+            Some(contract_no) => &self.ns.contracts[contract_no].variables[var_no],
+            None => &self.ns.constants[var_no],
+        };
+
+        let mutability = if var.constant {
+            "constant"
+        } else if var.immutable {
+            "immutable"
+        } else if var.transient {
+            "transient"
+        } else {
+            "mutable"
+        };
+
+        let ty = if contract_no.is_some() && !var.constant {
+            Type::StorageRef(var.immutable, Box::new(var.ty.clone()))
+        } else {
+            var.ty.clone()
+        };
+
+        let fields = json!({
+            "name": var.name,
+            "constant": var.constant,
+            "mutability": mutability,
+            "stateVariable": contract_no.is_some(),
+            "storageLocation": "default",
+            "visibility": var.visibility.to_string(),
+            "typeName": self.type_name(&var.ty, &var.loc),
+            "typeDescriptions": self.type_descriptions(&ty),
+            "value": var.initializer.as_ref().map(|init| self.expression(init)),
+            "documentation": self.documentation(&var.tags),
+            "scope": self.scope(contract_no, &var.loc),
+        });
+
+        self.node_with_id(
+            self.id(Decl::Variable(contract_no, var_no)),
+            "VariableDeclaration",
+            &var.loc,
+            fields,
+        )
+    }
+
+    /// A parameter, struct field or local variable. The `fields` are added to the defaults.
+    fn variable_declaration(
+        &self,
+        id: i64,
+        param: &Parameter<Type>,
+        ty: &Type,
+        fields: Value,
+    ) -> Value {
+        let mut node = json!({
+            "name": param.name_as_str(),
+            "nameLocation": param.id.as_ref().map(|id| self.src(&id.loc)),
+            "constant": false,
+            "mutability": "mutable",
+            "stateVariable": false,
+            "storageLocation": "default",
+            "visibility": "internal",
+            "typeName": self.type_name(&param.ty, &param.ty_loc.unwrap_or(param.loc)),
+            "typeDescriptions": self.type_descriptions(ty),
+        });
+
+        if let (Value::Object(node), Value::Object(fields)) = (&mut node, fields) {
+            node.extend(fields);
+        }
+
+        self.node_with_id(id, "VariableDeclaration", &param.loc, node)
+    }
+
+    /// The data location of a parameter or local variable
+    fn storage_location(&self, var_no: Option<usize>, ty: &Type) -> &'static str {
+        let location = var_no
+            .and_then(|var_no| self.symtable.get()?.vars.get(&var_no))
+            .and_then(|var| var.storage_location.as_ref());
+
+        match (location, ty) {
+            (Some(location), _) => location.as_str(),
+            (None, Type::StorageRef(..)) => "storage",
+            (None, _) if ty.is_reference_type(self.ns) => "memory",
+            _ => "default",
+        }
+    }
+
+    fn local_variable(&self, var_no: usize, param: &Parameter<Type>) -> Value {
+        self.variable_declaration(
+            self.id(Decl::Local(var_no)),
+            param,
+            &param.ty,
+            json!({ "storageLocation": self.storage_location(Some(var_no), &param.ty) }),
+        )
+    }
+
+    /// The parameters of a function, event or error. `vars` are the variable numbers of the
+    /// parameters in the symbol table, if any.
+    fn parameter_list(
+        &self,
+        params: &[Parameter<Type>],
+        vars: &[Option<usize>],
+        loc: &Loc,
+        indexed: bool,
+    ) -> Value {
+        let parameters: Vec<Value> = params
+            .iter()
+            .enumerate()
+            .map(|(param_no, param)| {
+                let var_no = vars.get(param_no).copied().flatten();
+                let id = match var_no {
+                    Some(var_no) => self.id(Decl::Local(var_no)),
+                    None => self.new_id(),
+                };
+
+                let mut fields = json!({
+                    "storageLocation": self.storage_location(var_no, &param.ty),
+                });
+
+                if indexed {
+                    fields["indexed"] = param.indexed.into();
+                }
+
+                self.variable_declaration(id, param, &param.ty, fields)
+            })
+            .collect();
+
+        let loc = match (params.first(), params.last()) {
+            (Some(first), Some(last)) if matches!(first.loc, Loc::File(..)) => {
+                first.loc.with_end_from(&last.loc)
+            }
+            _ => *loc,
+        };
+
+        self.node("ParameterList", &loc, json!({ "parameters": parameters }))
+    }
+
+    fn function(&self, func_no: usize) -> Value {
+        let ns = self.ns;
+        let func = &ns.functions[func_no];
+
+        let saved_symtable = self.symtable.replace(Some(&func.symtable));
+
+        let parameters = self.parameter_list(
+            &func.params,
+            &func.symtable.arguments,
+            &func.loc_prototype,
+            false,
+        );
+
+        let returns: Vec<Option<usize>> = func.symtable.returns.iter().map(|v| Some(*v)).collect();
+        let return_parameters =
+            self.parameter_list(&func.returns, &returns, &func.loc_prototype, false);
+
+        let saved_returns = self
+            .return_parameters
+            .replace(return_parameters["id"].as_i64());
+
+        let mut modifiers: Vec<Value> = func
+            .bases
+            .iter()
+            .map(|(contract_no, (loc, _, args))| {
+                let name = &ns.contracts[*contract_no].id.name;
+                let modifier_name = self.identifier_path(
+                    &prefix_loc(loc, name.len()),
+                    name,
+                    Some(self.id(Decl::Contract(*contract_no))),
+                );
+
+                self.node(
+                    "ModifierInvocation",
+                    loc,
+                    json!({
+                        "modifierName": modifier_name,
+                        "arguments": self.expressions(args),
+                        "kind": "baseConstructorSpecifier",
+                    }),
+                )
+            })
+            .collect();
+
+        modifiers.extend(func.modifiers.iter().map(|m| self.modifier_invocation(m)));
+
+        let body = if func.has_body {
+            let loc = match (func.loc_prototype, func.loc) {
+                (Loc::File(file_no, _, start), Loc::File(_, _, end)) => {
+                    Loc::File(file_no, start, end)
+                }
+                _ => func.loc,
+            };
+
+            match func.body.as_slice() {
+                [stmt @ Statement::Block { .. }] => self.statement(stmt),
+                body => self.block(body, &loc, false),
+            }
+        } else {
+            Value::Null
+        };
+
+        self.symtable.set(saved_symtable);
+        self.return_parameters.set(saved_returns);
+
+        let overrides = func.is_override.as_ref().map(|(loc, contracts)| {
+            let overrides: Vec<Value> = contracts
+                .iter()
+                .map(|no| {
+                    self.identifier_path(
+                        loc,
+                        &ns.contracts[*no].id.name,
+                        Some(self.id(Decl::Contract(*no))),
+                    )
+                })
+                .collect();
+
+            self.node("OverrideSpecifier", loc, json!({ "overrides": overrides }))
+        });
+
+        let mut fields = json!({
+            "name": func.id.name,
+            "nameLocation": self.src(&func.id.loc),
+            "parameters": parameters,
+            "body": body,
+            "virtual": func.is_virtual,
+            "overrides": overrides,
+            "visibility": func.visibility.to_string(),
+            "documentation": self.documentation(&func.tags),
+            "scope": self.scope(func.contract_no, &func.loc),
+        });
+
+        if func.ty == pt::FunctionTy::Modifier {
+            return self.node_with_id(
+                self.id(Decl::Function(func_no)),
+                "ModifierDefinition",
+                &func.loc,
+                fields,
+            );
+        }
+
+        fields["kind"] = func.ty.to_string().into();
+        fields["implemented"] = func.has_body.into();
+        fields["stateMutability"] = func.mutability.to_string().into();
+        fields["modifiers"] = modifiers.into();
+        fields["returnParameters"] = return_parameters;
+
+        if let (Some(contract_no), pt::FunctionTy::Function) = (func.contract_no, func.ty) {
+            if func.is_public() {
+                fields["functionSelector"] = hex::encode(func.selector(ns, &contract_no)).into();
+            }
+        }
+
+        self.node_with_id(
+            self.id(Decl::Function(func_no)),
+            "FunctionDefinition",
+            &func.loc,
+            fields,
+        )
+    }
+
+    fn modifier_invocation(&self, expr: &Expression) -> Value {
+        let Expression::InternalFunctionCall {
+            loc,
+            function,
+            args,
+            ..
+        } = expr
+        else {
+            return self.expression(expr);
+        };
+
+        let Expression::InternalFunction {
+            id, function_no, ..
+        } = function.as_ref()
+        else {
+            return self.expression(expr);
+        };
+
+        let name = id
+            .identifiers
+            .iter()
+            .map(|id| id.name.as_str())
+            .collect::<Vec<&str>>()
+            .join(".");
+
+        self.node(
+            "ModifierInvocation",
+            loc,
+            json!({
+                "modifierName": self.identifier_path(
+                    &id.loc,
+                    &name,
+                    Some(self.id(Decl::Function(*function_no))),
+                ),
+                "arguments": self.expressions(args),
+                "kind": "modifierInvocation",
+            }),
+        )
+    }
+
+    fn identifier_path(&self, loc: &Loc, name: &str, decl: Option<i64>) -> Value {
+        self.node(
+            "IdentifierPath",
+            loc,
+            json!({
+                "name": name,
+                "referencedDeclaration": decl,
+            }),
+        )
+    }
+
+    fn block(&self, stmts: &[Statement], loc: &Loc, unchecked: bool) -> Value {
+        let statements: Vec<Value> = stmts.iter().map(|stmt| self.statement(stmt)).collect();
+
+        self.node(
+            if unchecked { "UncheckedBlock" } else { "Block" },
+            loc,
+            json!({ "statements": statements }),
+        )
+    }
+
+    /// The body of an `if`, loop or `try` clause, which may be a single statement
+    fn body(&self, stmts: &[Statement], loc: &Loc) -> Value {
+        match stmts {
+            [stmt] => self.statement(stmt),
+            _ => self.block(stmts, &statements_loc(stmts, loc), false),
+        }
+    }
+
+    fn statement(&self, stmt: &Statement) -> Value {
+        match stmt {
+            Statement::Block {
+                loc,
+                unchecked,
+                statements,
+            } => self.block(statements, loc, *unchecked),
+            Statement::VariableDecl(loc, var_no, param, init) => {
+                let id = self.id(Decl::Local(*var_no));
+
+                self.node(
+                    "VariableDeclarationStatement",
+                    loc,
+                    json!({
+                        "assignments": [id],
+                        "declarations": [self.local_variable(*var_no, param)],
+                        "initialValue": init.as_ref().map(|init| self.expression(init)),
+                    }),
+                )
+            }
+            Statement::If(loc, _, cond, then_stmt, else_stmt) => self.node(
+                "IfStatement",
+                loc,
+                json!({
+                    "condition": self.expression(cond),
+                    "trueBody": self.body(then_stmt, loc),
+                    "falseBody": if else_stmt.is_empty() {
+                        Value::Null
+                    } else {
+                        self.body(else_stmt, loc)
+                    },
+                }),
+            ),
+            Statement::While(loc, _, cond, body) => self.node(
+                "WhileStatement",
+                loc,
+                json!({
+                    "condition": self.expression(cond),
+                    "body": self.body(body, loc),
+                }),
+            ),
+            Statement::For {
+                loc,
+                init,
+                cond,
+                next,
+                body,
+                ..
+            } => {
+                let init = match init.as_slice() {
+                    [] => Value::Null,
+                    [stmt] => self.statement(stmt),
+                    stmts => self.block(stmts, &statements_loc(stmts, loc), false),
+                };
+
+                let next = next.as_ref().map(|next| {
+                    self.node(
+                        "ExpressionStatement",
+                        &next.loc(),
+                        json!({ "expression": self.expression(next) }),
+                    )
+                });
+
+                self.node(
+                    "ForStatement",
+                    loc,
+                    json!({
+                        "initializationExpression": init,
+                        "condition": cond.as_ref().map(|cond| self.expression(cond)),
+                        "loopExpression": next,
+                        "body": self.body(body, loc),
+                    }),
+                )
+            }
+            Statement::DoWhile(loc, _, body, cond) => self.node(
+                "DoWhileStatement",
+                loc,
+                json!({
+                    "body": self.body(body, loc),
+                    "condition": self.expression(cond),
+                }),
+            ),
+            Statement::Expression(loc, _, expr) => self.node(
+                "ExpressionStatement",
+                loc,
+                json!({ "expression": self.expression(expr) }),
+            ),
+            Statement::Delete(loc, _, expr) => {
+                let delete = self.node(
+                    "UnaryOperation",
+                    loc,
+                    json!({
+                        "operator": "delete",
+                        "prefix": true,
+                        "subExpression": self.expression(expr),
+                        "typeDescriptions": self.types_descriptions(&[]),
+                    }),
+                );
+
+                self.node("ExpressionStatement", loc, json!({ "expression": delete }))
+            }
+            Statement::Destructure(loc, fields, expr) => {
+                if fields
+                    .iter()
+                    .any(|field| matches!(field, DestructureField::VariableDecl(..)))
+                {
+                    let mut assignments = Vec::new();
+                    let mut declarations = Vec::new();
+
+                    for field in fields {
+                        if let DestructureField::VariableDecl(var_no, param) = field {
+                            assignments.push(Value::from(self.id(Decl::Local(*var_no))));
+                            declarations.push(self.local_variable(*var_no, param));
+                        } else {
+                            assignments.push(Value::Null);
+                            declarations.push(Value::Null);
+                        }
+                    }
+
+                    self.node(
+                        "VariableDeclarationStatement",
+                        loc,
+                        json!({
+                            "assignments": assignments,
+                            "declarations": declarations,
+                            "initialValue": self.expression(expr),
+                        }),
+                    )
+                } else {
+                    let components: Vec<Value> = fields
+                        .iter()
+                        .map(|field| match field {
+                            DestructureField::Expression(expr) => self.expression(expr),
+                            _ => Value::Null,
+                        })
+                        .collect();
+
+                    let tuple = self.node(
+                        "TupleExpression",
+                        loc,
+                        json!({
+                            "components": components,
+                            "isInlineArray": false,
+                        }),
+                    );
+
+                    let assignment = self.node(
+                        "Assignment",
+                        loc,
+                        json!({
+                            "operator": "=",
+                            "leftHandSide": tuple,
+                            "rightHandSide": self.expression(expr),
+                            "typeDescriptions": self.types_descriptions(&[]),
+                        }),
+                    );
+
+                    self.node(
+                        "ExpressionStatement",
+                        loc,
+                        json!({ "expression": assignment }),
+                    )
+                }
+            }
+            Statement::Continue(loc) => self.node("Continue", loc, json!({})),
+            Statement::Break(loc) => self.node("Break", loc, json!({})),
+            Statement::Return(loc, expr) => self.node(
+                "Return",
+                loc,
+                json!({
+                    "expression": expr.as_ref().map(|expr| self.expression(expr)),
+                    "functionReturnParameters": self.return_parameters.get(),
+                }),
+            ),
+            Statement::Revert {
+                loc,
+                error_no: Some(error_no),
+                args,
+            } => {
+                let error = &self.ns.errors[*error_no];
+                let callee = self.identifier(
+                    &prefix_loc(loc, "revert ".len() + error.name.len()),
+                    &error.name,
+                    Some(self.id(Decl::Error(*error_no))),
+                    Value::Null,
+                );
+                let call = self.function_call(
+                    loc,
+                    callee,
+                    self.expressions(args),
+                    "functionCall",
+                    self.types_descriptions(&[]),
+                );
+
+                self.node("RevertStatement", loc, json!({ "errorCall": call }))
+            }
+            Statement::Revert {
+                loc,
+                error_no: None,
+                args,
+            } => {
+                let callee = self.global(&prefix_loc(loc, "revert".len()), "revert", Value::Null);
+                let call = self.function_call(
+                    loc,
+                    callee,
+                    self.expressions(args),
+                    "functionCall",
+                    self.types_descriptions(&[]),
+                );
+
+                self.node("ExpressionStatement", loc, json!({ "expression": call }))
+            }
+            Statement::Emit {
+                loc,
+                event_no,
+                event_loc,
+                args,
+            } => {
+                let callee = self.identifier(
+                    event_loc,
+                    &self.ns.events[*event_no].id.name,
+                    Some(self.id(Decl::Event(*event_no))),
+                    Value::Null,
+                );
+                let call = self.function_call(
+                    loc,
+                    callee,
+                    self.expressions(args),
+                    "functionCall",
+                    self.types_descriptions(&[]),
+                );
+
+                self.node("EmitStatement", loc, json!({ "eventCall": call }))
+            }
+            Statement::TryCatch(loc, _, try_catch) => self.try_catch(loc, try_catch),
+            Statement::Underscore(loc) => self.node("PlaceholderStatement", loc, json!({})),
+            Statement::Assembly(asm, _) => self.inline_assembly(asm),
+        }
+    }
+
+    fn try_catch(&self, loc: &Loc, try_catch: &TryCatch) -> Value {
+        let vars: Vec<Option<usize>> = try_catch.returns.iter().map(|(v, _)| *v).collect();
+        let returns: Vec<Parameter<Type>> = try_catch
+            .returns
+            .iter()
+            .map(|(_, param)| param.clone())
+            .collect();
+
+        let parameters = if returns.is_empty() {
+            Value::Null
+        } else {
+            self.parameter_list(&returns, &vars, loc, false)
+        };
+
+        let ok_loc = statements_loc(&try_catch.ok_stmt, loc);
+
+        let mut clauses = vec![self.node(
+            "TryCatchClause",
+            &ok_loc,
+            json!({
+                "errorName": "",
+                "parameters": parameters,
+                "block": self.block(&try_catch.ok_stmt, &ok_loc, false),
+            }),
+        )];
+
+        for clause in &try_catch.errors {
+            let error_name = match clause.param.as_ref().map(|param| &param.ty) {
+                Some(Type::String) => "Error",
+                _ => "Panic",
+            };
+
+            clauses.push(self.catch_clause(loc, clause, error_name));
+        }
+
+        if let Some(clause) = &try_catch.catch_all {
+            clauses.push(self.catch_clause(loc, clause, ""));
+        }
+
+        self.node(
+            "TryStatement",
+            loc,
+            json!({
+                "externalCall": self.expression(&try_catch.expr),
+                "clauses": clauses,
+            }),
+        )
+    }
+
+    fn catch_clause(&self, loc: &Loc, clause: &CatchClause, error_name: &str) -> Value {
+        let loc = statements_loc(&clause.stmt, loc);
+
+        let parameters = clause.param.as_ref().map(|param| {
+            self.parameter_list(
+                std::slice::from_ref(param),
+                &[clause.param_pos],
+                &param.loc,
+                false,
+            )
+        });
+
+        self.node(
+            "TryCatchClause",
+            &loc,
+            json!({
+                "errorName": error_name,
+                "parameters": parameters,
+                "block": self.block(&clause.stmt, &loc, false),
+            }),
+        )
+    }
+
+    fn expressions(&self, exprs: &[Expression]) -> Vec<Value> {
+        exprs.iter().map(|expr| self.expression(expr)).collect()
+    }
+
+    fn expression(&self, expr: &Expression) -> Value {
+        let ns = self.ns;
+
+        match expr {
+            // loads and implicit conversions are not in the source
+            Expression::Load { expr, .. }
+            | Expression::StorageLoad { expr, .. }
+            | Expression::GetRef { expr, .. } => self.expression(expr),
+            Expression::ZeroExt { loc, to, expr }
+            | Expression::SignExt { loc, to, expr }
+            | Expression::Trunc { loc, to, expr }
+            | Expression::CheckingTrunc { loc, to, expr }
+            | Expression::Cast { loc, to, expr }
+            | Expression::BytesCast { loc, to, expr, .. } => {
+                if *loc == expr.loc() {
+                    self.expression(expr)
+                } else {
+                    let callee = self.type_expression(to, &prefix_loc(loc, 0));
+
+                    self.function_call(
+                        loc,
+                        callee,
+                        vec![self.expression(expr)],
+                        "typeConversion",
+                        self.type_descriptions(to),
+                    )
+                }
+            }
+            Expression::BoolLiteral { loc, value } => self.node(
+                "Literal",
+                loc,
+                json!({
+                    "kind": "bool",
+                    "value": value.to_string(),
+                    "typeDescriptions": self.type_descriptions(&Type::Bool),
+                }),
+            ),
+            Expression::NumberLiteral { loc, ty, value } => self.node(
+                "Literal",
+                loc,
+                json!({
+                    "kind": "number",
+                    "value": value.to_string(),
+                    "typeDescriptions": self.type_descriptions(ty),
+                }),
+            ),
+            Expression::RationalNumberLiteral { loc, ty, value } => self.node(
+                "Literal",
+                loc,
+                json!({
+                    "kind": "number",
+                    "value": value.to_string(),
+                    "typeDescriptions": self.type_descriptions(ty),
+                }),
+            ),
+            Expression::BytesLiteral { loc, ty, value } => self.string_literal(loc, ty, value),
+            Expression::StructLiteral {
+                loc,
+                id,
+                ty,
+                values,
+            } => {
+                let decl = match ty {
+                    Type::Struct(struct_ty) => Some(self.id(Decl::Struct(*struct_ty))),
+                    _ => None,
+                };
+                let name = id
+                    .identifiers
+                    .iter()
+                    .map(|id| id.name.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(".");
+                let callee = self.identifier(&id.loc, &name, decl, self.meta_descriptions(ty));
+                let names: Vec<&str> = values
+                    .iter()
+                    .filter_map(|(name, _)| name.as_ref().map(|id| id.name.as_str()))
+                    .collect();
+                let args: Vec<Value> = values.iter().map(|(_, e)| self.expression(e)).collect();
+
+                let mut call = self.function_call(
+                    loc,
+                    callee,
+                    args,
+                    "structConstructorCall",
+                    self.type_descriptions(ty),
+                );
+
+                call["names"] = names.into();
+
+                call
+            }
+            Expression::ArrayLiteral {
+                loc,
+                ty,
+                dimensions,
+                values,
+            }
+            | Expression::ConstArrayLiteral {
+                loc,
+                ty,
+                dimensions,
+                values,
+            } => self.array_literal(loc, ty, dimensions, values),
+            Expression::Add { loc, .. }
+            | Expression::Subtract { loc, .. }
+            | Expression::Multiply { loc, .. }
+            | Expression::Divide { loc, .. }
+            | Expression::Modulo { loc, .. }
+            | Expression::Power { loc, .. }
+            | Expression::BitwiseOr { loc, .. }
+            | Expression::BitwiseAnd { loc, .. }
+            | Expression::BitwiseXor { loc, .. }
+            | Expression::ShiftLeft { loc, .. }
+            | Expression::ShiftRight { loc, .. }
+            | Expression::More { loc, .. }
+            | Expression::Less { loc, .. }
+            | Expression::MoreEqual { loc, .. }
+            | Expression::LessEqual { loc, .. }
+            | Expression::Equal { loc, .. }
+            | Expression::NotEqual { loc, .. }
+            | Expression::Or { loc, .. }
+            | Expression::And { loc, .. } => {
+                let (operator, left, right) = binary_operator(expr).unwrap();
+                let left = self.expression(left);
+
+                self.node(
+                    "BinaryOperation",
+                    loc,
+                    json!({
+                        "operator": operator,
+                        "commonType": left["typeDescriptions"],
+                        "leftExpression": left,
+                        "rightExpression": self.expression(right),
+                        "typeDescriptions": self.expression_descriptions(expr),
+                    }),
+                )
+            }
+            Expression::StringCompare { loc, left, right } => {
+                let left = self.string_location(loc, left);
+
+                self.node(
+                    "BinaryOperation",
+                    loc,
+                    json!({
+                        "operator": "==",
+                        "commonType": left["typeDescriptions"],
+                        "leftExpression": left,
+                        "rightExpression": self.string_location(loc, right),
+                        "typeDescriptions": self.type_descriptions(&Type::Bool),
+                    }),
+                )
+            }
+            Expression::Not { loc, expr: operand } => {
+                self.unary_operation(loc, "!", true, operand, expr)
+            }
+            Expression::BitwiseNot {
+                loc, expr: operand, ..
+            } => self.unary_operation(loc, "~", true, operand, expr),
+            Expression::Negate {
+                loc, expr: operand, ..
+            } => self.unary_operation(loc, "-", true, operand, expr),
+            Expression::PreIncrement {
+                loc, expr: operand, ..
+            } => self.unary_operation(loc, "++", true, operand, expr),
+            Expression::PreDecrement {
+                loc, expr: operand, ..
+            } => self.unary_operation(loc, "--", true, operand, expr),
+            Expression::PostIncrement {
+                loc, expr: operand, ..
+            } => self.unary_operation(loc, "++", false, operand, expr),
+            Expression::PostDecrement {
+                loc, expr: operand, ..
+            } => self.unary_operation(loc, "--", false, operand, expr),
+            Expression::Assign {
+                loc, left, right, ..
+            } => {
+                // `a += b` is resolved to `a = a + b`, where the addition has the location
+                // of the assignment
+                let (operator, right) = match binary_operator(strip_casts(right)) {
+                    Some((operator, _, right)) if strip_casts(right).loc() != *loc => {
+                        (format!("{operator}="), right)
+                    }
+                    _ => (String::from("="), right.as_ref()),
+                };
+
+                self.node(
+                    "Assignment",
+                    loc,
+                    json!({
+                        "operator": operator,
+                        "leftHandSide": self.expression(left),
+                        "rightHandSide": self.expression(right),
+                        "typeDescriptions": self.expression_descriptions(expr),
+                    }),
+                )
+            }
+            Expression::ConditionalOperator {
+                loc,
+                cond,
+                true_option,
+                false_option,
+                ..
+            } => self.node(
+                "Conditional",
+                loc,
+                json!({
+                    "condition": self.expression(cond),
+                    "trueExpression": self.expression(true_option),
+                    "falseExpression": self.expression(false_option),
+                    "typeDescriptions": self.expression_descriptions(expr),
+                }),
+            ),
+            Expression::Subscript {
+                loc, array, index, ..
+            } => self.node(
+                "IndexAccess",
+                loc,
+                json!({
+                    "baseExpression": self.expression(array),
+                    "indexExpression": self.expression(index),
+                    "typeDescriptions": self.expression_descriptions(expr),
+                }),
+            ),
+            Expression::NamedMember {
+                loc, array, name, ..
+            } => {
+                let base = self.expression(array);
+
+                self.member_access(loc, base, name, None, self.expression_descriptions(expr))
+            }
+            Expression::StructMember {
+                loc,
+                expr: base,
+                field,
+                ..
+            } => {
+                let (name, decl) = match base.ty().deref_any() {
+                    Type::Struct(struct_ty) => (
+                        struct_ty.definition(ns).fields[*field]
+                            .name_as_str()
+                            .to_owned(),
+                        Some(self.id(Decl::StructField(*struct_ty, *field))),
+                    ),
+                    _ => (String::new(), None),
+                };
+                // the location of a struct member is the field name only
+                let loc = span(&[base.loc(), *loc], loc);
+                let base = self.expression(base);
+
+                self.member_access(&loc, base, &name, decl, self.expression_descriptions(expr))
+            }
+            Expression::StorageArrayLength { loc, array, .. } => {
+                let base = self.expression(array);
+
+                self.member_access(
+                    loc,
+                    base,
+                    "length",
+                    None,
+                    self.expression_descriptions(expr),
+                )
+            }
+            Expression::AllocDynamicBytes {
+                loc,
+                ty,
+                length,
+                init,
+            } => match init {
+                Some(init) => self.string_literal(loc, ty, init),
+                None => {
+                    let new = self.node(
+                        "NewExpression",
+                        loc,
+                        json!({
+                            "typeName": self.type_name(ty, loc),
+                            "typeDescriptions": Value::Null,
+                        }),
+                    );
+
+                    self.function_call(
+                        loc,
+                        new,
+                        vec![self.expression(length)],
+                        "functionCall",
+                        self.type_descriptions(ty),
+                    )
+                }
+            },
+            Expression::Variable { loc, ty, var_no } => self.identifier(
+                loc,
+                &self.var_name(*var_no),
+                Some(self.id(Decl::Local(*var_no))),
+                self.type_descriptions(ty),
+            ),
+            Expression::ConstantVariable {
+                loc,
+                ty,
+                contract_no,
+                var_no,
+            } => {
+                let name = match contract_no {
+                    Some(contract_no) => &ns.contracts[*contract_no].variables[*var_no].name,
+                    None => &ns.constants[*var_no].name,
+                };
+
+                self.identifier(
+                    loc,
+                    name,
+                    Some(self.id(Decl::Variable(*contract_no, *var_no))),
+                    self.type_descriptions(ty),
+                )
+            }
+            Expression::StorageVariable {
+                loc,
+                ty,
+                contract_no,
+                var_no,
+            } => self.identifier(
+                loc,
+                &ns.contracts[*contract_no].variables[*var_no].name,
+                Some(self.id(Decl::Variable(Some(*contract_no), *var_no))),
+                self.type_descriptions(ty),
+            ),
+            Expression::InternalFunction {
+                loc,
+                id,
+                ty,
+                function_no,
+                ..
+            } => {
+                let decl = Some(self.id(Decl::Function(*function_no)));
+
+                match id.identifiers.split_last() {
+                    Some((name, path)) if !path.is_empty() => {
+                        let prefix = path
+                            .iter()
+                            .map(|id| id.name.as_str())
+                            .collect::<Vec<&str>>()
+                            .join(".");
+                        let prefix_decl = ns
+                            .contracts
+                            .iter()
+                            .position(|c| c.id.name == prefix)
+                            .map(|no| self.id(Decl::Contract(no)))
+                            .or_else(|| global_id(&prefix));
+                        let base = self.identifier(
+                            &path[0].loc.with_end_from(&path[path.len() - 1].loc),
+                            &prefix,
+                            prefix_decl,
+                            Value::Null,
+                        );
+
+                        self.member_access(loc, base, &name.name, decl, self.type_descriptions(ty))
+                    }
+                    _ => self.identifier(
+                        loc,
+                        &ns.functions[*function_no].id.name,
+                        decl,
+                        self.type_descriptions(ty),
+                    ),
+                }
+            }
+            Expression::ExternalFunction {
+                loc,
+                ty,
+                address,
+                function_no,
+            } => {
+                let base = self.expression(address);
+
+                self.member_access(
+                    loc,
+                    base,
+                    &ns.functions[*function_no].id.name,
+                    Some(self.id(Decl::Function(*function_no))),
+                    self.type_descriptions(ty),
+                )
+            }
+            Expression::InternalFunctionCall {
+                loc,
+                function,
+                args,
+                ..
+            } => {
+                let callee = self.expression(function);
+
+                self.function_call(
+                    loc,
+                    callee,
+                    self.expressions(args),
+                    "functionCall",
+                    self.expression_descriptions(expr),
+                )
+            }
+            Expression::ExternalFunctionCall {
+                loc,
+                function,
+                args,
+                call_args,
+                ..
+            } => {
+                let callee = self.expression(function);
+                let callee = self.call_options(loc, callee, call_args);
+
+                self.function_call(
+                    loc,
+                    callee,
+                    self.expressions(args),
+                    "functionCall",
+                    self.expression_descriptions(expr),
+                )
+            }
+            Expression::ExternalFunctionCallRaw {
+                loc,
+                ty,
+                address,
+                args,
+                call_args,
+            } => {
+                let name = match ty {
+                    CallTy::Regular => "call",
+                    CallTy::Delegate => "delegatecall",
+                    CallTy::Static => "staticcall",
+                };
+                let base = self.expression(address);
+                let callee = self.member_access(loc, base, name, None, Value::Null);
+                let callee = self.call_options(loc, callee, call_args);
+
+                self.function_call(
+                    loc,
+                    callee,
+                    vec![self.expression(args)],
+                    "functionCall",
+                    self.expression_descriptions(expr),
+                )
+            }
+            Expression::Constructor {
+                loc,
+                contract_no,
+                args,
+                call_args,
+                ..
+            } => {
+                let ty = Type::Contract(*contract_no);
+                let new = self.node(
+                    "NewExpression",
+                    loc,
+                    json!({
+                        "typeName": self.type_name(&ty, loc),
+                        "typeDescriptions": Value::Null,
+                    }),
+                );
+                let callee = self.call_options(loc, new, call_args);
+
+                self.function_call(
+                    loc,
+                    callee,
+                    self.expressions(args),
+                    "functionCall",
+                    self.type_descriptions(&ty),
+                )
+            }
+            Expression::FormatString { loc, format } => {
+                let mut string = String::new();
+                let mut args = Vec::new();
+
+                for (arg, expr) in format {
+                    match (arg, expr) {
+                        (FormatArg::StringLiteral, Expression::BytesLiteral { value, .. }) => {
+                            string.push_str(&String::from_utf8_lossy(value));
+                        }
+                        _ => {
+                            string.push_str(&format!("{{{arg}}}"));
+                            args.push(self.expression(expr));
+                        }
+                    }
+                }
+
+                let literal = self.string_literal(loc, &Type::String, string.as_bytes());
+                let callee = self.member_access(loc, literal, "format", None, Value::Null);
+
+                self.function_call(
+                    loc,
+                    callee,
+                    args,
+                    "functionCall",
+                    self.type_descriptions(&Type::String),
+                )
+            }
+            Expression::Builtin {
+                loc,
+                tys,
+                kind,
+                args,
+            } => self.builtin(loc, tys, *kind, args),
+            Expression::List { loc, list } => self.node(
+                "TupleExpression",
+                loc,
+                json!({
+                    "components": self.expressions(list),
+                    "isInlineArray": false,
+                    "typeDescriptions": self.expression_descriptions(expr),
+                }),
+            ),
+            Expression::UserDefinedOperator {
+                loc,
+                ty,
+                oper,
+                function_no,
+                args,
+            } => {
+                let mut fields = json!({
+                    "operator": oper.to_string(),
+                    "function": self.id(Decl::Function(*function_no)),
+                    "typeDescriptions": self.type_descriptions(ty),
+                });
+
+                if let [left, right] = args.as_slice() {
+                    let left = self.expression(left);
+
+                    fields["commonType"] = left["typeDescriptions"].clone();
+                    fields["leftExpression"] = left;
+                    fields["rightExpression"] = self.expression(right);
+
+                    self.node("BinaryOperation", loc, fields)
+                } else {
+                    fields["prefix"] = true.into();
+                    fields["subExpression"] = self.expression(&args[0]);
+
+                    self.node("UnaryOperation", loc, fields)
+                }
+            }
+            Expression::EventSelector { loc, ty, event_no } => {
+                let event = &ns.events[*event_no];
+                let base = self.identifier(
+                    &prefix_loc(loc, event.id.name.len()),
+                    &event.id.name,
+                    Some(self.id(Decl::Event(*event_no))),
+                    Value::Null,
+                );
+
+                self.member_access(loc, base, "selector", None, self.type_descriptions(ty))
+            }
+            Expression::TypeOperator { loc, ty } => {
+                let callee = self.global(&prefix_loc(loc, "type".len()), "type", Value::Null);
+
+                self.function_call(
+                    loc,
+                    callee,
+                    vec![self.type_expression(ty, loc)],
+                    "functionCall",
+                    json!({
+                        "typeIdentifier": format!("t_magic_meta_type_{}", self.type_identifier(ty)),
+                        "typeString": format!("type({})", self.type_string(ty)),
+                    }),
+                )
+            }
+        }
+    }
+
+    fn builtin(&self, loc: &Loc, tys: &[Type], kind: Builtin, args: &[Expression]) -> Value {
+        let member = match kind {
+            Builtin::GetAddress if args.is_empty() => {
+                return self.global(loc, "this", self.types_descriptions(tys));
+            }
+            Builtin::ArrayLength => Some("length"),
+            Builtin::Balance => Some("balance"),
+            Builtin::ContractCode => Some("code"),
+            Builtin::GetAddress | Builtin::ExternalFunctionAddress => Some("address"),
+            Builtin::FunctionSelector => Some("selector"),
+            Builtin::TypeMin => Some("min"),
+            Builtin::TypeMax => Some("max"),
+            Builtin::TypeName => Some("name"),
+            Builtin::TypeInterfaceId => Some("interfaceId"),
+            Builtin::TypeRuntimeCode => Some("runtimeCode"),
+            Builtin::TypeCreatorCode => Some("creationCode"),
+            _ => None,
+        };
+
+        if let Some(member) = member {
+            let base = self.expression(&args[0]);
+
+            return self.member_access(loc, base, member, None, self.types_descriptions(tys));
+        }
+
+        let method = match kind {
+            Builtin::ArrayPush => Some("push"),
+            Builtin::ArrayPop => Some("pop"),
+            Builtin::PayableSend => Some("send"),
+            Builtin::PayableTransfer => Some("transfer"),
+            _ => None,
+        };
+
+        let (callee, args) = if let Some(method) = method {
+            let base = self.expression(&args[0]);
+
+            (
+                self.member_access(loc, base, method, None, Value::Null),
+                self.expressions(&args[1..]),
+            )
+        } else if matches!(kind, Builtin::UserTypeWrap | Builtin::UserTypeUnwrap) {
+            let (user_ty, method) = if kind == Builtin::UserTypeWrap {
+                (tys[0].clone(), "wrap")
+            } else {
+                (args[0].ty(), "unwrap")
+            };
+            let base = self.type_expression(&user_ty, &prefix_loc(loc, 0));
+
+            (
+                self.member_access(loc, base, method, None, Value::Null),
+                self.expressions(args),
+            )
+        } else if let Some(prototype) = get_prototype(kind) {
+            let variable = args.is_empty() && BUILTIN_VARIABLE.iter().any(|p| p.builtin == kind);
+            let descriptions = if variable {
+                self.types_descriptions(tys)
+            } else {
+                Value::Null
+            };
+
+            if !prototype.method.is_empty() {
+                let base = self.expression(&args[0]);
+
+                (
+                    self.member_access(loc, base, prototype.name, None, descriptions),
+                    self.expressions(&args[1..]),
+                )
+            } else {
+                let callee = match prototype.namespace {
+                    Some(namespace) => {
+                        let base =
+                            self.global(&prefix_loc(loc, namespace.len()), namespace, Value::Null);
+
+                        self.member_access(loc, base, prototype.name, None, descriptions)
+                    }
+                    None => self.global(
+                        &prefix_loc(loc, prototype.name.len()),
+                        prototype.name,
+                        descriptions,
+                    ),
+                };
+
+                if variable {
+                    return callee;
+                }
+
+                let mut arguments = self.expressions(args);
+
+                // abi.decode(data, (types)) has the decoded types as a tuple
+                if kind == Builtin::AbiDecode {
+                    let types: Vec<Value> =
+                        tys.iter().map(|ty| self.type_expression(ty, loc)).collect();
+
+                    arguments.push(self.node(
+                        "TupleExpression",
+                        loc,
+                        json!({
+                            "components": types,
+                            "isInlineArray": false,
+                        }),
+                    ));
+                }
+
+                (callee, arguments)
+            }
+        } else {
+            (
+                self.identifier(loc, &format!("{kind:?}"), None, Value::Null),
+                self.expressions(args),
+            )
+        };
+
+        self.function_call(
+            loc,
+            callee,
+            args,
+            "functionCall",
+            self.types_descriptions(tys),
+        )
+    }
+
+    fn unary_operation(
+        &self,
+        loc: &Loc,
+        operator: &str,
+        prefix: bool,
+        operand: &Expression,
+        expr: &Expression,
+    ) -> Value {
+        self.node(
+            "UnaryOperation",
+            loc,
+            json!({
+                "operator": operator,
+                "prefix": prefix,
+                "subExpression": self.expression(operand),
+                "typeDescriptions": self.expression_descriptions(expr),
+            }),
+        )
+    }
+
+    fn string_literal(&self, loc: &Loc, ty: &Type, value: &[u8]) -> Value {
+        let (kind, string) = match std::str::from_utf8(value) {
+            Ok(string) => ("string", Value::from(string)),
+            Err(_) => ("hexString", Value::Null),
+        };
+
+        self.node(
+            "Literal",
+            loc,
+            json!({
+                "kind": kind,
+                "value": string,
+                "hexValue": hex::encode(value),
+                "typeDescriptions": self.type_descriptions(ty),
+            }),
+        )
+    }
+
+    fn string_location(&self, loc: &Loc, string: &StringLocation<Expression>) -> Value {
+        match string {
+            StringLocation::CompileTime(value) => self.string_literal(loc, &Type::String, value),
+            StringLocation::RunTime(expr) => self.expression(expr),
+        }
+    }
+
+    /// Array literals are flattened, so rebuild the nested inline arrays of a multi-dimensional
+    /// array literal
+    fn array_literal(
+        &self,
+        loc: &Loc,
+        ty: &Type,
+        dimensions: &[u32],
+        values: &[Expression],
+    ) -> Value {
+        let mut components = self.expressions(values);
+        let mut locs: Vec<Loc> = values.iter().map(|v| v.loc()).collect();
+
+        for dimension in dimensions.iter().take(dimensions.len().saturating_sub(1)) {
+            let size = (*dimension as usize).max(1);
+            let mut nested = Vec::new();
+            let mut nested_locs = Vec::new();
+
+            for (chunk, chunk_locs) in components.chunks(size).zip(locs.chunks(size)) {
+                let chunk_loc = span(chunk_locs, loc);
+
+                nested.push(self.node(
+                    "TupleExpression",
+                    &chunk_loc,
+                    json!({
+                        "components": chunk,
+                        "isInlineArray": true,
+                    }),
+                ));
+                nested_locs.push(chunk_loc);
+            }
+
+            components = nested;
+            locs = nested_locs;
+        }
+
+        self.node(
+            "TupleExpression",
+            loc,
+            json!({
+                "components": components,
+                "isInlineArray": true,
+                "typeDescriptions": self.type_descriptions(ty),
+            }),
+        )
+    }
+
+    fn call_options(&self, loc: &Loc, callee: Value, call_args: &CallArgs) -> Value {
+        let mut names = Vec::new();
+        let mut options = Vec::new();
+
+        let accounts = match &call_args.accounts {
+            ExternalCallAccounts::Present(accounts) => Some(accounts),
+            _ => None,
+        };
+
+        for (name, arg) in [
+            ("value", call_args.value.as_ref()),
+            ("gas", call_args.gas.as_ref()),
+            ("proofSize", call_args.proof_size.as_ref()),
+            (
+                "storageDepositLimit",
+                call_args.storage_deposit_limit.as_ref(),
+            ),
+            ("salt", call_args.salt.as_ref()),
+            ("accounts", accounts),
+            ("seeds", call_args.seeds.as_ref()),
+            ("flags", call_args.flags.as_ref()),
+            ("program_id", call_args.program_id.as_ref()),
+        ] {
+            if let Some(arg) = arg {
+                names.push(name);
+                options.push(self.expression(arg));
+            }
+        }
+
+        if names.is_empty() {
+            return callee;
+        }
+
+        let descriptions = callee["typeDescriptions"].clone();
+
+        self.node(
+            "FunctionCallOptions",
+            loc,
+            json!({
+                "expression": callee,
+                "names": names,
+                "options": options,
+                "typeDescriptions": descriptions,
+            }),
+        )
+    }
+
+    fn function_call(
+        &self,
+        loc: &Loc,
+        callee: Value,
+        args: Vec<Value>,
+        kind: &str,
+        descriptions: Value,
+    ) -> Value {
+        self.node(
+            "FunctionCall",
+            loc,
+            json!({
+                "expression": callee,
+                "arguments": args,
+                "names": [],
+                "kind": kind,
+                "tryCall": false,
+                "typeDescriptions": descriptions,
+            }),
+        )
+    }
+
+    fn member_access(
+        &self,
+        loc: &Loc,
+        base: Value,
+        name: &str,
+        decl: Option<i64>,
+        descriptions: Value,
+    ) -> Value {
+        self.node(
+            "MemberAccess",
+            loc,
+            json!({
+                "expression": base,
+                "memberName": name,
+                "referencedDeclaration": decl,
+                "typeDescriptions": descriptions,
+            }),
+        )
+    }
+
+    fn identifier(&self, loc: &Loc, name: &str, decl: Option<i64>, descriptions: Value) -> Value {
+        self.node(
+            "Identifier",
+            loc,
+            json!({
+                "name": name,
+                "referencedDeclaration": decl,
+                "overloadedDeclarations": [],
+                "typeDescriptions": descriptions,
+            }),
+        )
+    }
+
+    /// An identifier for one of the global symbols of Solidity
+    fn global(&self, loc: &Loc, name: &str, descriptions: Value) -> Value {
+        self.identifier(loc, name, global_id(name), descriptions)
+    }
+
+    fn var_name(&self, var_no: usize) -> String {
+        self.symtable
+            .get()
+            .and_then(|symtable| symtable.vars.get(&var_no))
+            .map(|var| var.id.name.clone())
+            .unwrap_or_default()
+    }
+
+    fn var_loc(&self, var_no: usize) -> Loc {
+        self.symtable
+            .get()
+            .and_then(|symtable| symtable.vars.get(&var_no))
+            .map_or(Loc::Builtin, |var| var.id.loc)
+    }
+
+    /// The name and the id of the declaration of a user defined type
+    fn user_type(&self, ty: &Type) -> Option<(String, i64)> {
+        let ns = self.ns;
+
+        match ty {
+            Type::Enum(no) => Some((ns.enums[*no].to_string(), self.id(Decl::Enum(*no)))),
+            Type::Struct(struct_ty) => Some((
+                struct_ty.definition(ns).to_string(),
+                self.id(Decl::Struct(*struct_ty)),
+            )),
+            Type::Contract(no) => Some((
+                ns.contracts[*no].id.name.clone(),
+                self.id(Decl::Contract(*no)),
+            )),
+            Type::UserType(no) => {
+                Some((ns.user_types[*no].to_string(), self.id(Decl::UserType(*no))))
+            }
+            _ => None,
+        }
+    }
+
+    /// A type used as an expression, e.g. in a type conversion or `type(T)`
+    fn type_expression(&self, ty: &Type, loc: &Loc) -> Value {
+        if let Some((name, decl)) = self.user_type(ty) {
+            self.identifier(loc, &name, Some(decl), self.meta_descriptions(ty))
+        } else {
+            self.node(
+                "ElementaryTypeNameExpression",
+                loc,
+                json!({
+                    "typeName": self.type_name(ty, loc),
+                    "typeDescriptions": self.meta_descriptions(ty),
+                }),
+            )
+        }
+    }
+
+    fn type_name(&self, ty: &Type, loc: &Loc) -> Value {
+        match ty {
+            Type::Ref(ty) | Type::StorageRef(_, ty) => self.type_name(ty, loc),
+            Type::Enum(_) | Type::Struct(_) | Type::Contract(_) | Type::UserType(_) => {
+                let (name, decl) = self.user_type(ty).unwrap();
+
+                self.node(
+                    "UserDefinedTypeName",
+                    loc,
+                    json!({
+                        "pathNode": self.identifier_path(loc, &name, Some(decl)),
+                        "referencedDeclaration": decl,
+                        "typeDescriptions": self.type_descriptions(ty),
+                    }),
+                )
+            }
+            Type::Array(elem, dims) => {
+                let mut type_name = self.type_name(elem, loc);
+
+                for dim_no in 0..dims.len() {
+                    let length = match &dims[dim_no] {
+                        ArrayLength::Fixed(length) => self.node(
+                            "Literal",
+                            loc,
+                            json!({
+                                "kind": "number",
+                                "value": length.to_string(),
+                            }),
+                        ),
+                        _ => Value::Null,
+                    };
+
+                    let ty = Type::Array(elem.clone(), dims[..=dim_no].to_vec());
+
+                    type_name = self.node(
+                        "ArrayTypeName",
+                        loc,
+                        json!({
+                            "baseType": type_name,
+                            "length": length,
+                            "typeDescriptions": self.type_descriptions(&ty),
+                        }),
+                    );
+                }
+
+                type_name
+            }
+            Type::Mapping(Mapping {
+                key,
+                key_name,
+                value,
+                value_name,
+            }) => self.node(
+                "Mapping",
+                loc,
+                json!({
+                    "keyType": self.type_name(key, loc),
+                    "keyName": key_name.as_ref().map_or("", |id| id.name.as_str()),
+                    "keyNameLocation": key_name.as_ref().map_or_else(
+                        || self.src(&Loc::Builtin),
+                        |id| self.src(&id.loc),
+                    ),
+                    "valueType": self.type_name(value, loc),
+                    "valueName": value_name.as_ref().map_or("", |id| id.name.as_str()),
+                    "valueNameLocation": value_name.as_ref().map_or_else(
+                        || self.src(&Loc::Builtin),
+                        |id| self.src(&id.loc),
+                    ),
+                    "typeDescriptions": self.type_descriptions(ty),
+                }),
+            ),
+            Type::InternalFunction {
+                mutability,
+                params,
+                returns,
+            }
+            | Type::ExternalFunction {
+                mutability,
+                params,
+                returns,
+            } => {
+                let parameter_types = self.type_list(params, loc);
+                let return_types = self.type_list(returns, loc);
+
+                self.node(
+                    "FunctionTypeName",
+                    loc,
+                    json!({
+                        "parameterTypes": parameter_types,
+                        "returnParameterTypes": return_types,
+                        "visibility": if matches!(ty, Type::InternalFunction { .. }) {
+                            "internal"
+                        } else {
+                            "external"
+                        },
+                        "stateMutability": mutability.to_string(),
+                        "typeDescriptions": self.type_descriptions(ty),
+                    }),
+                )
+            }
+            _ => {
+                let mut fields = json!({
+                    "name": match ty {
+                        Type::Address(_) => String::from("address"),
+                        _ => self.type_string(ty),
+                    },
+                    "typeDescriptions": self.type_descriptions(ty),
+                });
+
+                if let Type::Address(payable) = ty {
+                    fields["stateMutability"] =
+                        if *payable { "payable" } else { "nonpayable" }.into();
+                }
+
+                self.node("ElementaryTypeName", loc, fields)
+            }
+        }
+    }
+
+    /// The parameters of a function type
+    fn type_list(&self, tys: &[Type], loc: &Loc) -> Value {
+        let parameters: Vec<Value> = tys
+            .iter()
+            .map(|ty| {
+                let param = Parameter {
+                    loc: *loc,
+                    ..Parameter::new_default(ty.clone())
+                };
+                let id = self.new_id();
+
+                self.variable_declaration(
+                    id,
+                    &param,
+                    ty,
+                    json!({ "storageLocation": self.storage_location(None, ty) }),
+                )
+            })
+            .collect();
+
+        self.node("ParameterList", loc, json!({ "parameters": parameters }))
+    }
+
+    fn expression_descriptions(&self, expr: &Expression) -> Value {
+        self.types_descriptions(&expression_types(expr))
+    }
+
+    fn types_descriptions(&self, tys: &[Type]) -> Value {
+        match tys {
+            [ty] => self.type_descriptions(ty),
+            _ => json!({
+                "typeIdentifier": format!(
+                    "t_tuple$_{}_$",
+                    tys.iter()
+                        .map(|ty| self.type_identifier(ty))
+                        .collect::<Vec<String>>()
+                        .join("_$_")
+                ),
+                "typeString": format!(
+                    "tuple({})",
+                    tys.iter()
+                        .map(|ty| self.type_string(ty))
+                        .collect::<Vec<String>>()
+                        .join(",")
+                ),
+            }),
+        }
+    }
+
+    fn type_descriptions(&self, ty: &Type) -> Value {
+        if *ty == Type::Void {
+            return self.types_descriptions(&[]);
+        }
+
+        json!({
+            "typeIdentifier": self.type_identifier(ty),
+            "typeString": self.type_string(ty),
+        })
+    }
+
+    /// The type descriptions of a type used as an expression
+    fn meta_descriptions(&self, ty: &Type) -> Value {
+        json!({
+            "typeIdentifier": format!("t_type$_{}_$", self.type_identifier(ty)),
+            "typeString": format!("type({})", self.type_string(ty)),
+        })
+    }
+
+    /// The type string of solc, e.g. `uint256[] memory`
+    fn type_string(&self, ty: &Type) -> String {
+        let (ty, location) = match ty {
+            Type::StorageRef(_, ty) => (ty.as_ref(), "storage ref"),
+            Type::Ref(ty) => (ty.as_ref(), "memory"),
+            _ => (ty, "memory"),
+        };
+
+        match ty {
+            Type::String
+            | Type::DynamicBytes
+            | Type::Slice(_)
+            | Type::Array(..)
+            | Type::Struct(_) => format!("{} {location}", self.base_type_string(ty)),
+            _ => self.base_type_string(ty),
+        }
+    }
+
+    /// The type string without the data location
+    fn base_type_string(&self, ty: &Type) -> String {
+        let ns = self.ns;
+
+        match ty {
+            Type::Ref(ty) | Type::StorageRef(_, ty) => self.base_type_string(ty),
+            Type::Value => format!("uint{}", ns.value_length * 8),
+            Type::Rational => String::from("rational_const"),
+            Type::Slice(_) => String::from("bytes"),
+            Type::UserType(no) => ns.user_types[*no].to_string(),
+            Type::Array(elem, dims) => format!(
+                "{}{}",
+                self.base_type_string(elem),
+                dims.iter()
+                    .map(|dim| match dim {
+                        ArrayLength::Fixed(length) => format!("[{length}]"),
+                        _ => String::from("[]"),
+                    })
+                    .collect::<String>()
+            ),
+            Type::Mapping(Mapping { key, value, .. }) => format!(
+                "mapping({} => {})",
+                self.base_type_string(key),
+                self.base_type_string(value)
+            ),
+            Type::InternalFunction {
+                mutability,
+                params,
+                returns,
+            }
+            | Type::ExternalFunction {
+                mutability,
+                params,
+                returns,
+            } => {
+                let mut string = format!(
+                    "function ({})",
+                    params
+                        .iter()
+                        .map(|ty| self.type_string(ty))
+                        .collect::<Vec<String>>()
+                        .join(",")
+                );
+
+                if matches!(ty, Type::ExternalFunction { .. }) {
+                    string.push_str(" external");
+                }
+
+                if !mutability.is_default() {
+                    string.push_str(&format!(" {mutability}"));
+                }
+
+                if !returns.is_empty() {
+                    string.push_str(&format!(
+                        " returns ({})",
+                        returns
+                            .iter()
+                            .map(|ty| self.type_string(ty))
+                            .collect::<Vec<String>>()
+                            .join(",")
+                    ));
+                }
+
+                string
+            }
+            _ => ty.to_string(ns),
+        }
+    }
+
+    /// The type identifier of solc, e.g. `t_array$_t_uint256_$dyn_memory_ptr`
+    fn type_identifier(&self, ty: &Type) -> String {
+        match ty {
+            Type::StorageRef(_, ty) => self.located_type_identifier(ty, "storage"),
+            Type::Ref(ty) => self.located_type_identifier(ty, "memory_ptr"),
+            _ => self.located_type_identifier(ty, "memory_ptr"),
+        }
+    }
+
+    fn located_type_identifier(&self, ty: &Type, location: &str) -> String {
+        let ns = self.ns;
+
+        match ty {
+            Type::Ref(ty) | Type::StorageRef(_, ty) => self.located_type_identifier(ty, location),
+            Type::Bool => String::from("t_bool"),
+            Type::Address(false) => String::from("t_address"),
+            Type::Address(true) => String::from("t_address_payable"),
+            Type::Int(n) => format!("t_int{n}"),
+            Type::Uint(n) => format!("t_uint{n}"),
+            Type::Value => format!("t_uint{}", ns.value_length * 8),
+            Type::Bytes(n) => format!("t_bytes{n}"),
+            Type::Rational => String::from("t_rational_const"),
+            Type::String => format!("t_string_{location}"),
+            Type::DynamicBytes | Type::Slice(_) => format!("t_bytes_{location}"),
+            Type::Enum(_) | Type::Contract(_) | Type::UserType(_) | Type::Struct(_) => {
+                let (name, decl) = self.user_type(ty).unwrap();
+                let kind = match ty {
+                    Type::Enum(_) => "enum",
+                    Type::Contract(_) => "contract",
+                    Type::UserType(_) => "userDefinedValueType",
+                    _ => "struct",
+                };
+
+                if let Type::Struct(_) = ty {
+                    format!("t_{kind}$_{name}_${decl}_{location}")
+                } else {
+                    format!("t_{kind}$_{name}_${decl}")
+                }
+            }
+            Type::Array(elem, dims) => {
+                let (last, rest) = dims.split_last().unwrap();
+                let elem = if rest.is_empty() {
+                    self.located_type_identifier(elem, location)
+                } else {
+                    self.located_type_identifier(
+                        &Type::Array(elem.clone(), rest.to_vec()),
+                        location,
+                    )
+                };
+                let length = match last {
+                    ArrayLength::Fixed(length) => length.to_string(),
+                    _ => String::from("dyn"),
+                };
+
+                format!("t_array$_{elem}_${length}_{location}")
+            }
+            Type::Mapping(Mapping { key, value, .. }) => format!(
+                "t_mapping$_{}_$_{}_$",
+                self.located_type_identifier(key, "memory_ptr"),
+                self.located_type_identifier(value, "storage"),
+            ),
+            Type::InternalFunction {
+                mutability,
+                params,
+                returns,
+            }
+            | Type::ExternalFunction {
+                mutability,
+                params,
+                returns,
+            } => {
+                let list = |tys: &[Type]| {
+                    tys.iter()
+                        .map(|ty| self.type_identifier(ty))
+                        .collect::<Vec<String>>()
+                        .join("_$_")
+                };
+
+                format!(
+                    "t_function_{}_{mutability}$_{}_$returns$_{}_$",
+                    if matches!(ty, Type::InternalFunction { .. }) {
+                        "internal"
+                    } else {
+                        "external"
+                    },
+                    list(params),
+                    list(returns),
+                )
+            }
+            Type::Void => String::from("t_tuple$__$"),
+            _ => format!("t_{}", ty.to_string(ns).replace(' ', "_")),
+        }
+    }
+
+    fn inline_assembly(&self, asm: &InlineAssembly) -> Value {
+        let mut statements: Vec<(usize, Value)> = asm
+            .body
+            .iter()
+            .map(|stmt| (yul_statement_loc(stmt).start(), self.yul_statement(stmt)))
+            .collect();
+
+        // the functions are not part of the body, so add them in source order
+        for func_no in asm.functions.clone() {
+            let func = &self.ns.yul_functions[func_no];
+
+            statements.push((func.loc.start(), self.yul_function(func_no)));
+        }
+
+        statements.sort_by_key(|(start, _)| *start);
+
+        let statements: Vec<Value> = statements.into_iter().map(|(_, stmt)| stmt).collect();
+
+        let block = self.node("YulBlock", &asm.loc, json!({ "statements": statements }));
+
+        self.node(
+            "InlineAssembly",
+            &asm.loc,
+            json!({
+                "AST": block,
+                "externalReferences": [],
+                "flags": if asm.memory_safe { json!(["memory-safe"]) } else { Value::Null },
+            }),
+        )
+    }
+
+    fn yul_function(&self, func_no: usize) -> Value {
+        let func = &self.ns.yul_functions[func_no];
+
+        let saved_symtable = self.symtable.replace(Some(&func.symtable));
+
+        let typed_names = |params: &[Parameter<Type>]| -> Vec<Value> {
+            params
+                .iter()
+                .map(|param| {
+                    self.node(
+                        "YulTypedName",
+                        &param.loc,
+                        json!({
+                            "name": param.name_as_str(),
+                            "type": "",
+                        }),
+                    )
+                })
+                .collect()
+        };
+
+        let parameters = typed_names(&func.params);
+        let returns = typed_names(&func.returns);
+        let body = self.yul_block(&func.body);
+
+        self.symtable.set(saved_symtable);
+
+        self.node(
+            "YulFunctionDefinition",
+            &func.loc,
+            json!({
+                "name": func.name,
+                "parameters": parameters,
+                "returnVariables": returns,
+                "body": body,
+            }),
+        )
+    }
+
+    fn yul_block(&self, block: &YulBlock) -> Value {
+        let statements: Vec<Value> = block
+            .statements
+            .iter()
+            .map(|stmt| self.yul_statement(stmt))
+            .collect();
+
+        self.node("YulBlock", &block.loc, json!({ "statements": statements }))
+    }
+
+    fn yul_statement(&self, stmt: &YulStatement) -> Value {
+        match stmt {
+            YulStatement::FunctionCall(loc, _, func_no, args) => {
+                let call = self.yul_function_call(loc, &self.ns.yul_functions[*func_no].name, args);
+
+                self.node("YulExpressionStatement", loc, json!({ "expression": call }))
+            }
+            YulStatement::BuiltInCall(loc, _, builtin, args) => {
+                let call = self.yul_function_call(loc, builtin.get_prototype_info().name, args);
+
+                self.node("YulExpressionStatement", loc, json!({ "expression": call }))
+            }
+            YulStatement::Block(block) => self.yul_block(block),
+            YulStatement::VariableDeclaration(loc, _, vars, value) => {
+                let variables: Vec<Value> = vars
+                    .iter()
+                    .map(|(var_no, _)| {
+                        self.node(
+                            "YulTypedName",
+                            &self.var_loc(*var_no),
+                            json!({
+                                "name": self.var_name(*var_no),
+                                "type": "",
+                            }),
+                        )
+                    })
+                    .collect();
+
+                self.node(
+                    "YulVariableDeclaration",
+                    loc,
+                    json!({
+                        "variables": variables,
+                        "value": value.as_ref().map(|value| self.yul_expression(value)),
+                    }),
+                )
+            }
+            YulStatement::Assignment(loc, _, vars, value) => {
+                let names: Vec<Value> = vars.iter().map(|var| self.yul_expression(var)).collect();
+
+                self.node(
+                    "YulAssignment",
+                    loc,
+                    json!({
+                        "variableNames": names,
+                        "value": self.yul_expression(value),
+                    }),
+                )
+            }
+            YulStatement::IfBlock(loc, _, cond, block) => self.node(
+                "YulIf",
+                loc,
+                json!({
+                    "condition": self.yul_expression(cond),
+                    "body": self.yul_block(block),
+                }),
+            ),
+            YulStatement::Switch {
+                loc,
+                condition,
+                cases,
+                default,
+                ..
+            } => {
+                let mut nodes: Vec<Value> = cases
+                    .iter()
+                    .map(|case| {
+                        self.node(
+                            "YulCase",
+                            &case.loc,
+                            json!({
+                                "value": self.yul_expression(&case.condition),
+                                "body": self.yul_block(&case.block),
+                            }),
+                        )
+                    })
+                    .collect();
+
+                if let Some(default) = default {
+                    nodes.push(self.node(
+                        "YulCase",
+                        &default.loc,
+                        json!({
+                            "value": "default",
+                            "body": self.yul_block(default),
+                        }),
+                    ));
+                }
+
+                self.node(
+                    "YulSwitch",
+                    loc,
+                    json!({
+                        "expression": self.yul_expression(condition),
+                        "cases": nodes,
+                    }),
+                )
+            }
+            YulStatement::For {
+                loc,
+                init_block,
+                condition,
+                post_block,
+                execution_block,
+                ..
+            } => self.node(
+                "YulForLoop",
+                loc,
+                json!({
+                    "pre": self.yul_block(init_block),
+                    "condition": self.yul_expression(condition),
+                    "post": self.yul_block(post_block),
+                    "body": self.yul_block(execution_block),
+                }),
+            ),
+            YulStatement::Leave(loc, _) => self.node("YulLeave", loc, json!({})),
+            YulStatement::Break(loc, _) => self.node("YulBreak", loc, json!({})),
+            YulStatement::Continue(loc, _) => self.node("YulContinue", loc, json!({})),
+        }
+    }
+
+    fn yul_function_call(&self, loc: &Loc, name: &str, args: &[YulExpression]) -> Value {
+        let function_name = self.node(
+            "YulIdentifier",
+            &prefix_loc(loc, name.len()),
+            json!({ "name": name }),
+        );
+        let arguments: Vec<Value> = args.iter().map(|arg| self.yul_expression(arg)).collect();
+
+        self.node(
+            "YulFunctionCall",
+            loc,
+            json!({
+                "functionName": function_name,
+                "arguments": arguments,
+            }),
+        )
+    }
+
+    fn yul_expression(&self, expr: &YulExpression) -> Value {
+        match expr {
+            YulExpression::BoolLiteral(loc, value, _) => self.node(
+                "YulLiteral",
+                loc,
+                json!({
+                    "kind": "bool",
+                    "value": value.to_string(),
+                    "type": "",
+                }),
+            ),
+            YulExpression::NumberLiteral(loc, value, _) => self.node(
+                "YulLiteral",
+                loc,
+                json!({
+                    "kind": "number",
+                    "value": value.to_string(),
+                    "type": "",
+                }),
+            ),
+            YulExpression::StringLiteral(loc, value, _) => self.node(
+                "YulLiteral",
+                loc,
+                json!({
+                    "kind": "string",
+                    "value": String::from_utf8_lossy(value),
+                    "hexValue": hex::encode(value),
+                    "type": "",
+                }),
+            ),
+            YulExpression::BuiltInCall(loc, builtin, args) => {
+                self.yul_function_call(loc, builtin.get_prototype_info().name, args)
+            }
+            YulExpression::FunctionCall(loc, func_no, args, _) => {
+                self.yul_function_call(loc, &self.ns.yul_functions[*func_no].name, args)
+            }
+            _ => self.node(
+                "YulIdentifier",
+                &expr.loc(),
+                json!({ "name": self.yul_name(expr) }),
+            ),
+        }
+    }
+
+    /// The name of a variable in assembly, e.g. `x` or `x.slot`
+    fn yul_name(&self, expr: &YulExpression) -> String {
+        let ns = self.ns;
+
+        match expr {
+            YulExpression::YulLocalVariable(_, _, var_no)
+            | YulExpression::SolidityLocalVariable(_, _, _, var_no) => self.var_name(*var_no),
+            YulExpression::ConstantVariable(_, _, Some(contract_no), var_no)
+            | YulExpression::StorageVariable(_, _, contract_no, var_no) => {
+                ns.contracts[*contract_no].variables[*var_no].name.clone()
+            }
+            YulExpression::ConstantVariable(_, _, None, var_no) => {
+                ns.constants[*var_no].name.clone()
+            }
+            YulExpression::SuffixAccess(_, expr, suffix) => {
+                format!("{}.{suffix}", self.yul_name(expr))
+            }
+            _ => String::new(),
+        }
+    }
+}
+
+fn version_literals(version: &VersionReq, literals: &mut Vec<String>) {
+    match version {
+        VersionReq::Plain { version, .. } => literals.push(version.to_string()),
+        VersionReq::Operator { op, version, .. } => {
+            literals.push(op.to_string());
+            literals.push(version.to_string());
+        }
+        VersionReq::Range { from, to, .. } => {
+            literals.push(from.to_string());
+            literals.push(String::from("-"));
+            literals.push(to.to_string());
+        }
+        VersionReq::Or { left, right, .. } => {
+            version_literals(left, literals);
+            literals.push(String::from("||"));
+            version_literals(right, literals);
+        }
+    }
+}
+
+fn global_id(name: &str) -> Option<i64> {
+    GLOBALS
+        .iter()
+        .position(|global| *global == name)
+        .map(|index| -(index as i64) - 1)
+}
+
+/// Is `inner` within `outer`
+fn contains(outer: &Loc, inner: &Loc) -> bool {
+    match (outer, inner) {
+        (Loc::File(outer_file, outer_start, outer_end), Loc::File(file, start, end)) => {
+            outer_file == file && outer_start <= start && end <= outer_end
+        }
+        _ => false,
+    }
+}
+
+/// The location of the first `len` bytes of `loc`
+fn prefix_loc(loc: &Loc, len: usize) -> Loc {
+    match loc {
+        Loc::File(file_no, start, end) => Loc::File(*file_no, *start, (*start + len).min(*end)),
+        _ => *loc,
+    }
+}
+
+fn statements_loc(stmts: &[Statement], default: &Loc) -> Loc {
+    let locs: Vec<Loc> = stmts.iter().map(|stmt| stmt.loc()).collect();
+
+    span(&locs, default)
+}
+
+/// The location from the first to the last location, if they are in a file
+fn span(locs: &[Loc], default: &Loc) -> Loc {
+    match (locs.first(), locs.last()) {
+        (Some(first @ Loc::File(..)), Some(last @ Loc::File(..))) => first.with_end_from(last),
+        _ => *default,
+    }
+}
+
+fn using_loc(using: &Using) -> Loc {
+    match &using.list {
+        UsingList::Functions(functions) => {
+            let locs: Vec<Loc> = functions.iter().map(|f| f.loc).collect();
+
+            span(&locs, &Loc::Builtin)
+        }
+        UsingList::Library(_) => Loc::Builtin,
+    }
+}
+
+fn yul_statement_loc(stmt: &YulStatement) -> Loc {
+    match stmt {
+        YulStatement::FunctionCall(loc, ..)
+        | YulStatement::BuiltInCall(loc, ..)
+        | YulStatement::VariableDeclaration(loc, ..)
+        | YulStatement::Assignment(loc, ..)
+        | YulStatement::IfBlock(loc, ..)
+        | YulStatement::Switch { loc, .. }
+        | YulStatement::For { loc, .. }
+        | YulStatement::Leave(loc, _)
+        | YulStatement::Break(loc, _)
+        | YulStatement::Continue(loc, _) => *loc,
+        YulStatement::Block(block) => block.loc,
+    }
+}
+
+/// Skip the implicit conversions which sema adds
+fn strip_casts(expr: &Expression) -> &Expression {
+    match expr {
+        Expression::ZeroExt { loc, expr, .. }
+        | Expression::SignExt { loc, expr, .. }
+        | Expression::Trunc { loc, expr, .. }
+        | Expression::CheckingTrunc { loc, expr, .. }
+        | Expression::Cast { loc, expr, .. }
+        | Expression::BytesCast { loc, expr, .. }
+            if *loc == expr.loc() =>
+        {
+            strip_casts(expr)
+        }
+        Expression::Load { expr, .. }
+        | Expression::StorageLoad { expr, .. }
+        | Expression::GetRef { expr, .. } => strip_casts(expr),
+        _ => expr,
+    }
+}
+
+fn binary_operator(expr: &Expression) -> Option<(&'static str, &Expression, &Expression)> {
+    let (operator, left, right) = match expr {
+        Expression::Add { left, right, .. } => ("+", left, right),
+        Expression::Subtract { left, right, .. } => ("-", left, right),
+        Expression::Multiply { left, right, .. } => ("*", left, right),
+        Expression::Divide { left, right, .. } => ("/", left, right),
+        Expression::Modulo { left, right, .. } => ("%", left, right),
+        Expression::Power { base, exp, .. } => ("**", base, exp),
+        Expression::BitwiseOr { left, right, .. } => ("|", left, right),
+        Expression::BitwiseAnd { left, right, .. } => ("&", left, right),
+        Expression::BitwiseXor { left, right, .. } => ("^", left, right),
+        Expression::ShiftLeft { left, right, .. } => ("<<", left, right),
+        Expression::ShiftRight { left, right, .. } => (">>", left, right),
+        Expression::More { left, right, .. } => (">", left, right),
+        Expression::Less { left, right, .. } => ("<", left, right),
+        Expression::MoreEqual { left, right, .. } => (">=", left, right),
+        Expression::LessEqual { left, right, .. } => ("<=", left, right),
+        Expression::Equal { left, right, .. } => ("==", left, right),
+        Expression::NotEqual { left, right, .. } => ("!=", left, right),
+        Expression::Or { left, right, .. } => ("||", left, right),
+        Expression::And { left, right, .. } => ("&&", left, right),
+        _ => return None,
+    };
+
+    Some((operator, left, right))
+}
+
+/// The types of the values of an expression, which can be none or more than one for function
+/// calls
+fn expression_types(expr: &Expression) -> Vec<Type> {
+    match expr {
+        Expression::Builtin { tys, .. } => tys.clone(),
+        Expression::InternalFunctionCall { returns, .. }
+        | Expression::ExternalFunctionCall { returns, .. } => returns.clone(),
+        Expression::ExternalFunctionCallRaw { .. } => vec![Type::Bool, Type::DynamicBytes],
+        Expression::List { list, .. } => list.iter().flat_map(expression_types).collect(),
+        _ => vec![expr.ty()],
+    }
+}
+
+impl Namespace {
+    /// Export the resolved AST in the compact JSON format of solc, as the `sources` of the
+    /// standard JSON output. Each source file of the namespace is a source unit.
+    pub fn ast_json(&self) -> Value {
+        let ast = AstJson {
+            ns: self,
+            ids: RefCell::new(HashMap::new()),
+            next_id: Cell::new(0),
+            symtable: Cell::new(None),
+            return_parameters: Cell::new(None),
+        };
+
+        let items = ast.items();
+        let mut sources = Map::new();
+
+        for (file_no, file) in self.files.iter().enumerate() {
+            if file.cache_no.is_some() {
+                sources.insert(
+                    file.path.display().to_string(),
+                    json!({
+                        "id": file_no,
+                        "ast": ast.source_unit(file_no, &items),
+                    }),
+                );
+            }
+        }
+
+        json!({ "sources": sources })
+    }
+}
@@ -36,7 +36,7 @@ pub struct Prototype {
 }
 
 // A list of all Solidity builtins functions
-pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 27]> = Lazy::new(|| {
+pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 29]> = Lazy::new(|| {
     [
         Prototype {
             builtin: Builtin::Assert,
@@ -89,7 +89,12 @@ pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 27]> = Lazy::new(|| {
             name: "selfdestruct",
             params: vec![Type::Address(true)],
             ret: vec![Type::Unreachable],
-            target: vec![Target::EVM, Target::default_polkadot()],
+            target: vec![
+                Target::EVM,
+                Target::default_polkadot(),
+                Target::PolkadotRevive,
+                Target::Solana,
+            ],
             doc: "Destroys current account and deposits any remaining balance to address",
             constant: false,
         },
@@ -133,7 +138,7 @@ pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 27]> = Lazy::new(|| {
             name: "blake2_128",
             params: vec![Type::DynamicBytes],
             ret: vec![Type::Bytes(16)],
-            target: vec![Target::default_polkadot()],
+            target: vec![Target::default_polkadot(), Target::PolkadotRevive],
             doc: "Calculates blake2-128 hash",
             constant: true,
         },
@@ -144,7 +149,7 @@ pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 27]> = Lazy::new(|| {
             name: "blake2_256",
             params: vec![Type::DynamicBytes],
             ret: vec![Type::Bytes(32)],
-            target: vec![Target::default_polkadot()],
+            target: vec![Target::default_polkadot(), Target::PolkadotRevive],
             doc: "Calculates blake2-256 hash",
             constant: true,
         },
@@ -155,7 +160,11 @@ pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 27]> = Lazy::new(|| {
             name: "gasleft",
             params: vec![],
             ret: vec![Type::Uint(64)],
-            target: vec![Target::default_polkadot(), Target::EVM],
+            target: vec![
+                Target::default_polkadot(),
+                Target::PolkadotRevive,
+                Target::EVM,
+            ],
             doc: "Return remaining gas left in current call",
             constant: false,
         },
@@ -241,6 +250,29 @@ pub static BUILTIN_FUNCTIONS: Lazy<[Prototype; 27]> = Lazy::new(|| {
             // it should be allowed in constant context, but we don't support that yet
             constant: false,
         },
+        Prototype {
+            builtin: Builtin::AbiDecodeEthereum,
+            namespace: Some("abi"),
+            method: vec![],
+            name: "decodeEthereum",
+            params: vec![Type::DynamicBytes],
+            ret: vec![],
+            target: vec![],
+            doc: "Abi decode byte array with the given types using the Ethereum ABI",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::AbiEncodeEthereum,
+            namespace: Some("abi"),
+            method: vec![],
+            name: "encodeEthereum",
+            params: vec![],
+            ret: vec![],
+            target: vec![],
+            doc: "Abi encode given arguments using the Ethereum ABI",
+            // it should be allowed in constant context, but we don't support that yet
+            constant: false,
+        },
         Prototype {
             builtin: Builtin::Gasprice,
             namespace: Some("tx"),
@@ -426,7 +458,7 @@ pub static BUILTIN_VARIABLE: Lazy<[Prototype; 17]> = Lazy::new(|| {
             name: "minimum_balance",
             params: vec![],
             ret: vec![Type::Value],
-            target: vec![Target::default_polkadot()],
+            target: vec![Target::default_polkadot(), Target::PolkadotRevive],
             doc: "Minimum balance required for an account",
             constant: false,
         },
@@ -514,7 +546,11 @@ pub static BUILTIN_VARIABLE: Lazy<[Prototype; 17]> = Lazy::new(|| {
             name: "gasprice",
             params: vec![],
             ret: vec![Type::Value],
-            target: vec![Target::default_polkadot(), Target::EVM],
+            target: vec![
+                Target::default_polkadot(),
+                Target::PolkadotRevive,
+                Target::EVM,
+            ],
             doc: "gas price for one gas unit",
             constant: false,
         },
@@ -1108,10 +1144,12 @@ pub(super) fn resolve_namespace_call(
         "encodeWithSelector" => Builtin::AbiEncodeWithSelector,
         "encodeWithSignature" => Builtin::AbiEncodeWithSignature,
         "encodeCall" => Builtin::AbiEncodeCall,
+        "decodeEthereum" => Builtin::AbiDecodeEthereum,
+        "encodeEthereum" => Builtin::AbiEncodeEthereum,
         _ => unreachable!(),
     };
 
-    if builtin == Builtin::AbiDecode {
+    if matches!(builtin, Builtin::AbiDecode | Builtin::AbiDecodeEthereum) {
         if args.len() != 2 {
             diagnostics.push(Diagnostic::error(
                 *loc,
@@ -1152,6 +1190,12 @@ pub(super) fn resolve_namespace_call(
                 broken = true;
             }
 
+            if builtin == Builtin::AbiDecodeEthereum
+                && !ethereum_abi_supports(&arg.loc(), &ty, ns, diagnostics)
+            {
+                broken = true;
+            }
+
             tys.push(ty);
         }
 
@@ -1345,6 +1389,12 @@ pub(super) fn resolve_namespace_call(
             return Err(());
         }
 
+        if builtin == Builtin::AbiEncodeEthereum
+            && !ethereum_abi_supports(&arg.loc(), &ty, ns, diagnostics)
+        {
+            return Err(());
+        }
+
         expr = expr.cast(&arg.loc(), ty.deref_any(), true, ns, diagnostics)?;
 
         // A string or hex literal should be encoded as a string
@@ -1363,6 +1413,32 @@ pub(super) fn resolve_namespace_call(
     })
 }
 
+/// The Ethereum ABI encodes an external function as 24 bytes: the address followed by the
+/// selector. This does not fit if the target has longer addresses or selectors.
+fn ethereum_abi_supports(
+    loc: &pt::Loc,
+    ty: &Type,
+    ns: &Namespace,
+    diagnostics: &mut Diagnostics,
+) -> bool {
+    if ns.address_length + ns.target.selector_length() as usize > 24
+        && ty.contains_external_function(ns)
+    {
+        diagnostics.push(Diagnostic::error(
+            *loc,
+            format!(
+                "type '{}' cannot be Ethereum ABI encoded on {}, since the address and selector of an external function do not fit in 24 bytes",
+                ty.to_string(ns),
+                ns.target
+            ),
+        ));
+
+        return false;
+    }
+
+    true
+}
+
 /// Resolve a builtin call
 pub(super) fn resolve_method_call(
     expr: &Expression,
@@ -1688,7 +1764,7 @@ impl Namespace {
         assert!(self.add_symbol(file_no, None, &identifier("Hash"), symbol));
 
         // Chain extensions
-        for mut func in [
+        let mut funcs = vec![
             Function::new(
                 loc,
                 loc,
@@ -1853,7 +1929,430 @@ impl Namespace {
                 }],
                 self,
             ),
-        ] {
+        ];
+
+        // These pallet-contracts host functions are not available on pallet-revive
+        if let Target::Polkadot { .. } = self.target {
+            funcs.extend([
+                // sr25519_verify API
+                Function::new(
+                    loc,
+                    loc,
+                    pt::Identifier {
+                        name: "sr25519_verify".to_string(),
+                        loc,
+                    },
+                    None,
+                    Vec::new(),
+                    pt::FunctionTy::Function,
+                    Some(pt::Mutability::View(loc)),
+                    pt::Visibility::Public(Some(loc)),
+                    vec![
+                        Parameter {
+                            loc,
+                            id: Some(identifier("signature")),
+                            ty: Type::Array(
+                                Type::Uint(8).into(),
+                                vec![ArrayLength::Fixed(64.into())],
+                            ),
+                            ty_loc: Some(loc),
+                            readonly: false,
+                            indexed: false,
+                            infinite_size: false,
+                            recursive: false,
+                            annotation: None,
+                        },
+                        Parameter {
+                            loc,
+                            id: Some(identifier("public_key")),
+                            ty: Type::Array(
+                                Type::Uint(8).into(),
+                                vec![ArrayLength::Fixed(32.into())],
+                            ),
+                            ty_loc: Some(loc),
+                            readonly: false,
+                            indexed: false,
+                            infinite_size: false,
+                            recursive: false,
+                            annotation: None,
+                        },
+                        Parameter {
+                            loc,
+                            id: Some(identifier("message")),
+                            ty: Type::DynamicBytes,
+                            ty_loc: Some(loc),
+                            readonly: false,
+                            indexed: false,
+                            infinite_size: false,
+                            recursive: false,
+                            annotation: None,
+                        },
+                    ],
+                    vec![Parameter {
+                        loc,
+                        id: Some(identifier("valid")),
+                        ty: Type::Bool,
+                        ty_loc: Some(loc),
+                        readonly: false,
+                        indexed: false,
+                        infinite_size: false,
+                        recursive: false,
+                        annotation: None,
+                    }],
+                    self,
+                ),
+                // ecdsa_to_eth_address API
+                Function::new(
+                    loc,
+                    loc,
+                    pt::Identifier {
+                        name: "ecdsa_to_eth_address".to_string(),
+                        loc,
+                    },
+                    None,
+                    Vec::new(),
+                    pt::FunctionTy::Function,
+                    Some(pt::Mutability::View(loc)),
+                    pt::Visibility::Public(Some(loc)),
+                    vec![Parameter {
+                        loc,
+                        id: Some(identifier("public_key")),
+                        ty: Type::Array(Type::Uint(8).into(), vec![ArrayLength::Fixed(33.into())]),
+                        ty_loc: Some(loc),
+                        readonly: false,
+                        indexed: false,
+                        infinite_size: false,
+                        recursive: false,
+                        annotation: None,
+                    }],
+                    vec![
+                        Parameter {
+                            loc,
+                            id: Some(identifier("return_code")),
+                            ty: Type::Uint(32),
+                            ty_loc: Some(loc),
+                            readonly: false,
+                            indexed: false,
+                            infinite_size: false,
+                            recursive: false,
+                            annotation: None,
+                        },
+                        Parameter {
+                            loc,
+                            id: Some(identifier("eth_address")),
+                            ty: Type::Array(
+                                Type::Uint(8).into(),
+                                vec![ArrayLength::Fixed(20.into())],
+                            ),
+                            ty_loc: Some(loc),
+                            readonly: false,
+                            indexed: false,
+                            infinite_size: false,
+                            recursive: false,
+                            annotation: None,
+                        },
+                    ],
+                    self,
+                ),
+                // take_storage API
+                Function::new(
+                    loc,
+                    loc,
+                    pt::Identifier {
+                        name: "take_storage".to_string(),
+                        loc,
+                    },
+                    None,
+                    Vec::new(),
+                    pt::FunctionTy::Function,
+                    None,
+                    pt::Visibility::Public(Some(loc)),
+                    vec![Parameter {
+                        loc,
+                        id: Some(identifier("key")),
+                        ty: Type::DynamicBytes,
+                        ty_loc: Some(loc),
+                        readonly: false,
+                        indexed: false,
+                        infinite_size: false,
+                        recursive: false,
+                        annotation: None,
+                    }],
+                    vec![
+                        Parameter {
+                            loc,
+                            id: Some(identifier("found")),
+                            ty: Type::Bool,
+                            ty_loc: Some(loc),
+                            readonly: false,
+                            indexed: false,
+                            infinite_size: false,
+                            recursive: false,
+                            annotation: None,
+                        },
+                        Parameter {
+                            loc,
+                            id: Some(identifier("value")),
+                            ty: Type::DynamicBytes,
+                            ty_loc: Some(loc),
+                            readonly: false,
+                            indexed: false,
+                            infinite_size: false,
+                            recursive: false,
+                            annotation: None,
+                        },
+                    ],
+                    self,
+                ),
+                // contains_storage API
+                Function::new(
+                    loc,
+                    loc,
+                    pt::Identifier {
+                        name: "contains_storage".to_string(),
+                        loc,
+                    },
+                    None,
+                    Vec::new(),
+                    pt::FunctionTy::Function,
+                    Some(pt::Mutability::View(loc)),
+                    pt::Visibility::Public(Some(loc)),
+                    vec![Parameter {
+                        loc,
+                        id: Some(identifier("key")),
+                        ty: Type::DynamicBytes,
+                        ty_loc: Some(loc),
+                        readonly: false,
+                        indexed: false,
+                        infinite_size: false,
+                        recursive: false,
+                        annotation: None,
+                    }],
+                    vec![
+                        Parameter {
+                            loc,
+                            id: Some(identifier("found")),
+                            ty: Type::Bool,
+                            ty_loc: Some(loc),
+                            readonly: false,
+                            indexed: false,
+                            infinite_size: false,
+                            recursive: false,
+                            annotation: None,
+                        },
+                        Parameter {
+                            loc,
+                            id: Some(identifier("size")),
+                            ty: Type::Uint(32),
+                            ty_loc: Some(loc),
+                            readonly: false,
+                            indexed: false,
+                            infinite_size: false,
+                            recursive: false,
+                            annotation: None,
+                        },
+                    ],
+                    self,
+                ),
+                // reentrance_count API
+                Function::new(
+                    loc,
+                    loc,
+                    pt::Identifier {
+                        name: "reentrance_count".to_string(),
+                        loc,
+                    },
+                    None,
+                    Vec::new(),
+                    pt::FunctionTy::Function,
+                    Some(pt::Mutability::View(loc)),
+                    pt::Visibility::Public(Some(loc)),
+                    vec![],
+                    vec![Parameter {
+                        loc,
+                        id: Some(identifier("reentrance_count")),
+                        ty: Type::Uint(32),
+                        ty_loc: Some(loc),
+                        readonly: false,
+                        indexed: false,
+                        infinite_size: false,
+                        recursive: false,
+                        annotation: None,
+                    }],
+                    self,
+                ),
+                // account_reentrance_count API
+                Function::new(
+                    loc,
+                    loc,
+                    pt::Identifier {
+                        name: "account_reentrance_count".to_string(),
+                        loc,
+                    },
+                    None,
+                    Vec::new(),
+                    pt::FunctionTy::Function,
+                    Some(pt::Mutability::View(loc)),
+                    pt::Visibility::Public(Some(loc)),
+                    vec![Parameter {
+                        loc,
+                        id: Some(identifier("account")),
+                        ty: Type::Address(false),
+                        ty_loc: Some(loc),
+                        readonly: false,
+                        indexed: false,
+                        infinite_size: false,
+                        recursive: false,
+                        annotation: None,
+                    }],
+                    vec![Parameter {
+                        loc,
+                        id: Some(identifier("reentrance_count")),
+                        ty: Type::Uint(32),
+                        ty_loc: Some(loc),
+                        readonly: false,
+                        indexed: false,
+                        infinite_size: false,
+                        recursive: false,
+                        annotation: None,
+                    }],
+                    self,
+                ),
+                // lock_delegate_dependency API
+                Function::new(
+                    loc,
+                    loc,
+                    pt::Identifier {
+                        name: "lock_delegate_dependency".to_string(),
+                        loc,
+                    },
+                    None,
+                    Vec::new(),
+                    pt::FunctionTy::Function,
+                    None,
+                    pt::Visibility::Public(Some(loc)),
+                    vec![Parameter {
+                        loc,
+                        id: Some(identifier("code_hash")),
+                        // FIXME: The hash length should be configurable
+                        ty: Type::Array(Type::Uint(8).into(), vec![ArrayLength::Fixed(32.into())]),
+                        ty_loc: Some(loc),
+                        readonly: false,
+                        indexed: false,
+                        infinite_size: false,
+                        recursive: false,
+                        annotation: None,
+                    }],
+                    vec![],
+                    self,
+                ),
+                // xcm_send API
+                Function::new(
+                    loc,
+                    loc,
+                    pt::Identifier {
+                        name: "xcm_send".to_string(),
+                        loc,
+                    },
+                    None,
+                    Vec::new(),
+                    pt::FunctionTy::Function,
+                    None,
+                    pt::Visibility::Public(Some(loc)),
+                    vec![
+                        Parameter {
+                            loc,
+                            id: Some(identifier("destination")),
+                            ty: Type::DynamicBytes,
+                            ty_loc: Some(loc),
+                            readonly: false,
+                            indexed: false,
+                            infinite_size: false,
+                            recursive: false,
+                            annotation: None,
+                        },
+                        Parameter {
+                            loc,
+                            id: Some(identifier("message")),
+                            ty: Type::DynamicBytes,
+                            ty_loc: Some(loc),
+                            readonly: false,
+                            indexed: false,
+                            infinite_size: false,
+                            recursive: false,
+                            annotation: None,
+                        },
+                    ],
+                    vec![
+                        Parameter {
+                            loc,
+                            id: Some(identifier("return_code")),
+                            ty: Type::Uint(32),
+                            ty_loc: Some(loc),
+                            readonly: false,
+                            indexed: false,
+                            infinite_size: false,
+                            recursive: false,
+                            annotation: None,
+                        },
+                        Parameter {
+                            loc,
+                            id: Some(identifier("message_id")),
+                            ty: Type::Array(
+                                Type::Uint(8).into(),
+                                vec![ArrayLength::Fixed(32.into())],
+                            ),
+                            ty_loc: Some(loc),
+                            readonly: false,
+                            indexed: false,
+                            infinite_size: false,
+                            recursive: false,
+                            annotation: None,
+                        },
+                    ],
+                    self,
+                ),
+                // xcm_execute API
+                Function::new(
+                    loc,
+                    loc,
+                    pt::Identifier {
+                        name: "xcm_execute".to_string(),
+                        loc,
+                    },
+                    None,
+                    Vec::new(),
+                    pt::FunctionTy::Function,
+                    None,
+                    pt::Visibility::Public(Some(loc)),
+                    vec![Parameter {
+                        loc,
+                        id: Some(identifier("message")),
+                        ty: Type::DynamicBytes,
+                        ty_loc: Some(loc),
+                        readonly: false,
+                        indexed: false,
+                        infinite_size: false,
+                        recursive: false,
+                        annotation: None,
+                    }],
+                    vec![Parameter {
+                        loc,
+                        id: Some(identifier("return_code")),
+                        ty: Type::Uint(32),
+                        ty_loc: Some(loc),
+                        readonly: false,
+                        indexed: false,
+                        infinite_size: false,
+                        recursive: false,
+                        annotation: None,
+                    }],
+                    self,
+                ),
+            ]);
+        }
+
+        for mut func in funcs {
             func.has_body = true;
             let func_no = self.functions.len();
             let id = identifier(&func.id.name);
@@ -210,7 +210,10 @@ pub static BUILTIN_STRUCTS: Lazy<[BuiltinStructDeclaration; 3]> = Lazy::new(|| {
                 fields: vec![
                     Parameter {
                         loc: pt::Loc::Builtin,
-                        id: None,
+                        id: Some(pt::Identifier {
+                            name: "selector".to_string(),
+                            loc: pt::Loc::Builtin,
+                        }),
                         ty: Type::FunctionSelector,
                         ty_loc: None,
                         indexed: false,
@@ -221,7 +224,10 @@ pub static BUILTIN_STRUCTS: Lazy<[BuiltinStructDeclaration; 3]> = Lazy::new(|| {
                     },
                     Parameter {
                         loc: pt::Loc::Builtin,
-                        id: None,
+                        id: Some(pt::Identifier {
+                            name: "address".to_string(),
+                            loc: pt::Loc::Builtin,
+                        }),
                         ty: Type::Address(false),
                         ty_loc: None,
                         indexed: false,
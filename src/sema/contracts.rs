@@ -3,16 +3,18 @@
 use super::{
     annotions_not_allowed, ast,
     diagnostics::Diagnostics,
-    expression::{compatible_mutability, ExprContext},
+    eval::eval_const_number,
+    expression::{compatible_mutability, resolve_expression::expression, ExprContext, ResolveTo},
     functions, statements,
     symtable::Symtable,
     using, variables, ContractDefinition,
 };
-use crate::sema::ast::SolanaAccount;
+use crate::sema::ast::{RetrieveType, SolanaAccount};
 use crate::sema::expression::constructor::match_constructor_to_args;
+use crate::Target;
 use crate::{sema::ast::Namespace, sema::unused_variable::emit_warning_local_variable};
 use indexmap::{IndexMap, IndexSet};
-use num_bigint::BigInt;
+use num_bigint::{BigInt, Sign};
 use num_traits::Zero;
 use once_cell::unsync::OnceCell;
 use solang_parser::diagnostics::{Diagnostic, Note};
@@ -39,6 +41,8 @@ impl ast::Contract {
             bases: Vec::new(),
             using: Vec::new(),
             layout: Vec::new(),
+            layout_base: None,
+            transient_layout: Vec::new(),
             fixed_layout_size: BigInt::zero(),
             tags,
             functions: Vec::new(),
@@ -99,6 +103,15 @@ pub fn resolve(contracts: &[ContractDefinition], file_no: usize, ns: &mut ast::N
     // Now we can resolve the initializers
     variables::resolve_initializers(&delayed.initializers, file_no, ns);
 
+    // The storage layout base may refer to constants
+    for contract in contracts {
+        resolve_layout_base(contract, file_no, ns);
+    }
+
+    for contract in contracts {
+        check_base_layout(contract.contract_no, ns);
+    }
+
     // Now we can resolve the bodies
     if !resolve_bodies(delayed.function_bodies, file_no, ns) {
         // only if we could resolve all the bodies
@@ -108,6 +121,95 @@ pub fn resolve(contracts: &[ContractDefinition], file_no: usize, ns: &mut ast::N
     }
 }
 
+/// Resolve the storage layout base given with `contract C layout at <expr>`
+fn resolve_layout_base(contract: &ContractDefinition, file_no: usize, ns: &mut ast::Namespace) {
+    let Some(expr) = contract.layout else {
+        return;
+    };
+
+    let contract_no = contract.contract_no;
+
+    if ns.target == Target::Solana {
+        ns.diagnostics.push(ast::Diagnostic::error(
+            expr.loc(),
+            "storage layout base is not supported on Solana".into(),
+        ));
+        return;
+    }
+
+    if !ns.contracts[contract_no].is_concrete() {
+        ns.diagnostics.push(ast::Diagnostic::error(
+            expr.loc(),
+            format!(
+                "storage layout base can only be specified for contracts, not {} '{}'",
+                ns.contracts[contract_no].ty, ns.contracts[contract_no].id
+            ),
+        ));
+        return;
+    }
+
+    let mut diagnostics = Diagnostics::default();
+    let mut symtable = Symtable::default();
+    let mut context = ExprContext {
+        file_no,
+        unchecked: true,
+        contract_no: Some(contract_no),
+        constant: true,
+        ..Default::default()
+    };
+    context.enter_scope();
+
+    if let Ok(base) = expression(
+        expr,
+        &mut context,
+        ns,
+        &mut symtable,
+        &mut diagnostics,
+        ResolveTo::Type(&ast::Type::Uint(256)),
+    ) {
+        if !matches!(base.ty(), ast::Type::Uint(_) | ast::Type::Int(_)) {
+            diagnostics.push(ast::Diagnostic::error(
+                expr.loc(),
+                "expression is not a number".into(),
+            ));
+        } else if let Ok((_, base)) = eval_const_number(&base, ns, &mut diagnostics) {
+            if base.sign() == Sign::Minus || base.bits() > 256 {
+                diagnostics.push(ast::Diagnostic::error(
+                    expr.loc(),
+                    format!("storage layout base {base} is not a valid storage slot"),
+                ));
+            } else {
+                ns.contracts[contract_no].layout_base = Some(base);
+            }
+        }
+    }
+
+    ns.diagnostics.extend(diagnostics);
+}
+
+/// Only the most derived contract may specify the storage layout base
+fn check_base_layout(contract_no: usize, ns: &mut ast::Namespace) {
+    let mut diagnostics = Diagnostics::default();
+
+    for base in &ns.contracts[contract_no].bases {
+        if let Some(layout_no) = ns
+            .contract_bases(base.contract_no)
+            .into_iter()
+            .find(|no| ns.contracts[*no].layout_base.is_some())
+        {
+            diagnostics.push(ast::Diagnostic::error(
+                base.loc,
+                format!(
+                    "base contract '{}' specifies a storage layout base, which can only be specified in the most derived contract",
+                    ns.contracts[layout_no].id
+                ),
+            ));
+        }
+    }
+
+    ns.diagnostics.extend(diagnostics);
+}
+
 /// Resolve the base contracts list and check for cycles. Returns true if no
 /// issues where found.
 pub fn resolve_base_contracts(
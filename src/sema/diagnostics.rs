@@ -1,21 +1,195 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use super::ast::{Diagnostic, Level, Namespace};
+use super::ast::{Code, Diagnostic, Level, Namespace};
 use crate::file_resolver::FileResolver;
+use crate::sarif::{self, SarifResult};
 use crate::standard_json::{LocJson, OutputJson};
 use codespan_reporting::{diagnostic, files, term};
 use itertools::Itertools;
 use solang_parser::pt::Loc;
 use std::{
     collections::HashMap,
+    ops::Range,
     slice::{Iter, IterMut},
     {io, sync::Arc},
 };
 
-#[derive(Default, Debug)]
+/// Stable codes for specific warnings. These codes are referred to from
+/// `solang-disable-next-line` comments and configuration files, so they must
+/// never be renumbered or reused.
+pub mod codes {
+    use solang_parser::diagnostics::Code;
+
+    /// Function parameter is never used
+    pub const UNUSED_PARAMETER: Code = Code(5001);
+    /// Named return variable is never assigned
+    pub const UNASSIGNED_RETURN_VARIABLE: Code = Code(5002);
+    /// Local variable is never used or never read
+    pub const UNUSED_VARIABLE: Code = Code(5003);
+    /// Storage variable is never used or never read
+    pub const UNUSED_STORAGE_VARIABLE: Code = Code(5004);
+    /// Global constant is never used
+    pub const UNUSED_CONSTANT: Code = Code(5005);
+    /// Event is never emitted
+    pub const UNUSED_EVENT: Code = Code(5006);
+    /// Error is never used
+    pub const UNUSED_ERROR: Code = Code(5007);
+    /// Yul function is never used
+    pub const UNUSED_FUNCTION: Code = Code(5008);
+    /// Declaration shadows another declaration or a builtin
+    pub const SHADOWING: Code = Code(5009);
+    /// Function mutability can be restricted to `pure` or `view`
+    pub const MUTABILITY: Code = Code(5010);
+    /// Statement can never be reached
+    pub const UNREACHABLE_CODE: Code = Code(5011);
+    /// Deprecated syntax
+    pub const DEPRECATED: Code = Code(5012);
+    /// Currency unit for another chain
+    pub const CURRENCY_UNIT: Code = Code(5013);
+    /// Problem with a NatSpec doc comment
+    pub const DOC_COMMENT: Code = Code(5014);
+    /// Argument to `delete` is not a storage reference
+    pub const DELETE_NOT_STORAGE: Code = Code(5015);
+    /// Inline assembly flag is not supported
+    pub const ASSEMBLY_FLAG: Code = Code(5016);
+    /// `emit` can be resolved to multiple events
+    pub const AMBIGUOUS_EMIT: Code = Code(5017);
+    /// Visibility on a constructor is ignored
+    pub const CONSTRUCTOR_VISIBILITY: Code = Code(5018);
+    /// `virtual` on an interface function is redundant
+    pub const INTERFACE_VIRTUAL: Code = Code(5019);
+    /// Conversion to an enum from an out of range ordinal
+    pub const ENUM_ORDINAL: Code = Code(5020);
+    /// Function selector cast to a type which is too small
+    pub const SELECTOR_CAST: Code = Code(5021);
+    /// Call argument ignored on `delegatecall`
+    pub const DELEGATECALL_ARGUMENT: Code = Code(5022);
+    /// Array length truncated to the memory size type
+    pub const MEMORY_SIZE_TRUNCATION: Code = Code(5023);
+    /// Condition is always true or always false
+    pub const CONSTANT_CONDITION: Code = Code(5024);
+
+    // Lints reported by `solang check`
+    /// Storage is modified after an external call
+    pub const REENTRANCY: Code = Code(6001);
+    /// Success of a low-level call or send is not checked
+    pub const UNCHECKED_CALL: Code = Code(6002);
+    /// `tx.origin` is used for authorization
+    pub const TX_ORIGIN: Code = Code(6003);
+    /// Result of a division is multiplied, losing precision
+    pub const DIVIDE_BEFORE_MULTIPLY: Code = Code(6004);
+    /// Authority account is compared without checking it is a signer
+    pub const MISSING_SIGNER_CHECK: Code = Code(6005);
+    /// Storage reference is declared without a value
+    pub const UNINITIALIZED_STORAGE_POINTER: Code = Code(6006);
+}
+
+/// Short description of what a code means
+pub fn code_description(code: Code) -> &'static str {
+    match code {
+        Code::INFO => "Informational message",
+        Code::ERROR => "Error",
+        Code::PARSER_ERROR => "Parser error",
+        Code::SYNTAX_ERROR => "Syntax error",
+        Code::DECLARATION_ERROR => "Declaration error",
+        Code::TYPE_ERROR => "Type error",
+        Code::CAST_ERROR => "Cast error",
+        Code::CAST_WARNING => "Conversion which may lose information",
+        codes::UNUSED_PARAMETER => "Function parameter is never used",
+        codes::UNASSIGNED_RETURN_VARIABLE => "Named return variable is never assigned",
+        codes::UNUSED_VARIABLE => "Local variable is never used or never read",
+        codes::UNUSED_STORAGE_VARIABLE => "Storage variable is never used or never read",
+        codes::UNUSED_CONSTANT => "Global constant is never used",
+        codes::UNUSED_EVENT => "Event is never emitted",
+        codes::UNUSED_ERROR => "Error is never used",
+        codes::UNUSED_FUNCTION => "Yul function is never used",
+        codes::SHADOWING => "Declaration shadows another declaration or a builtin",
+        codes::MUTABILITY => "Function can be declared pure or view",
+        codes::UNREACHABLE_CODE => "Unreachable statement",
+        codes::DEPRECATED => "Deprecated syntax",
+        codes::CURRENCY_UNIT => "Currency unit of another chain",
+        codes::DOC_COMMENT => "Problem with a NatSpec doc comment",
+        codes::DELETE_NOT_STORAGE => "Argument to delete is not a storage reference",
+        codes::ASSEMBLY_FLAG => "Unsupported inline assembly flag",
+        codes::AMBIGUOUS_EMIT => "Emit resolves to multiple events",
+        codes::CONSTRUCTOR_VISIBILITY => "Visibility on constructor is ignored",
+        codes::INTERFACE_VIRTUAL => "Interface functions are implicitly virtual",
+        codes::ENUM_ORDINAL => "Enum has no value with ordinal",
+        codes::SELECTOR_CAST => "Function selector cast to type which is too small",
+        codes::DELEGATECALL_ARGUMENT => "Call argument ignored on delegatecall",
+        codes::MEMORY_SIZE_TRUNCATION => "Array length truncated to memory size",
+        codes::CONSTANT_CONDITION => "Condition is always true or false",
+        codes::REENTRANCY => "Storage modified after external call",
+        codes::UNCHECKED_CALL => "Unchecked low-level call",
+        codes::TX_ORIGIN => "Use of tx.origin for authorization",
+        codes::DIVIDE_BEFORE_MULTIPLY => "Division before multiplication",
+        codes::MISSING_SIGNER_CHECK => "Missing signer check on authority account",
+        codes::UNINITIALIZED_STORAGE_POINTER => "Storage reference declared without a value",
+        _ if code.is_warning() => "Warning",
+        _ => "Error",
+    }
+}
+
+/// How diagnostics with a particular code should be reported
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CodeLevel {
+    /// Do not report the diagnostic at all
+    Allow,
+    /// Report the diagnostic as a warning
+    Warn,
+    /// Report the diagnostic as an error
+    Deny,
+}
+
+/// Overrides for how warnings are reported, from the command line or `solang.toml`.
+/// Only warnings can be allowed or denied; errors are always reported.
+#[derive(Clone, Default, Debug)]
+pub struct DiagnosticsConfig {
+    levels: HashMap<Code, CodeLevel>,
+}
+
+impl DiagnosticsConfig {
+    pub fn set(&mut self, code: Code, level: CodeLevel) {
+        self.levels.insert(code, level);
+    }
+
+    pub fn level(&self, code: Code) -> Option<CodeLevel> {
+        self.levels.get(&code).copied()
+    }
+}
+
+/// A `// solang-disable-next-line` comment. This suppresses warnings which start in the given
+/// range, which is the line following the comment. If no codes are listed, all warnings are
+/// suppressed.
+#[derive(Clone, Debug)]
+struct Suppression {
+    file_no: usize,
+    range: Range<usize>,
+    codes: Vec<Code>,
+}
+
+impl Suppression {
+    fn matches(&self, diagnostic: &Diagnostic) -> bool {
+        if diagnostic.level != Level::Warning {
+            return false;
+        }
+
+        match diagnostic.loc {
+            Loc::File(file_no, start, _) => {
+                file_no == self.file_no
+                    && self.range.contains(&start)
+                    && (self.codes.is_empty() || self.codes.contains(&diagnostic.code))
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
 pub struct Diagnostics {
     contents: Vec<Diagnostic>,
     has_error: bool,
+    suppressions: Vec<Suppression>,
 }
 
 impl Diagnostics {
@@ -40,6 +214,9 @@ impl Diagnostics {
     }
 
     pub fn push(&mut self, diagnostic: Diagnostic) {
+        if self.is_suppressed(&diagnostic) {
+            return;
+        }
         if matches!(diagnostic.level, Level::Error) {
             self.has_error = true;
         }
@@ -47,15 +224,48 @@ impl Diagnostics {
     }
 
     pub fn extend(&mut self, diagnostics: Diagnostics) {
-        self.has_error |= diagnostics.has_error;
-        self.contents.extend(diagnostics.contents);
+        for diagnostic in diagnostics.contents {
+            self.push(diagnostic);
+        }
     }
 
     pub fn append(&mut self, diagnostics: &mut Vec<Diagnostic>) {
-        if !self.has_error {
-            self.has_error = diagnostics.iter().any(|m| m.level == Level::Error);
+        for diagnostic in diagnostics.drain(..) {
+            self.push(diagnostic);
+        }
+    }
+
+    /// Suppress warnings starting in the given range of the file, as requested by a
+    /// `solang-disable-next-line` comment.
+    pub(crate) fn add_suppression(
+        &mut self,
+        file_no: usize,
+        range: Range<usize>,
+        codes: Vec<Code>,
+    ) {
+        self.suppressions.push(Suppression {
+            file_no,
+            range,
+            codes,
+        });
+    }
+
+    fn is_suppressed(&self, diagnostic: &Diagnostic) -> bool {
+        self.suppressions.iter().any(|s| s.matches(diagnostic))
+    }
+
+    /// Allow or deny warnings according to the configuration
+    pub fn apply_config(&mut self, config: &DiagnosticsConfig) {
+        self.contents.retain(|diag| {
+            diag.level != Level::Warning || config.level(diag.code) != Some(CodeLevel::Allow)
+        });
+
+        for diag in &mut self.contents {
+            if diag.level == Level::Warning && config.level(diag.code) == Some(CodeLevel::Deny) {
+                diag.level = Level::Error;
+                self.has_error = true;
+            }
         }
-        self.contents.append(diagnostics);
     }
 
     pub fn first_error(&self) -> String {
@@ -141,6 +351,12 @@ fn convert_diagnostic(
     })
     .with_message(msg.message.to_owned());
 
+    let diagnostic = if matches!(msg.level, Level::Error | Level::Warning) {
+        diagnostic.with_code(msg.code.to_string())
+    } else {
+        diagnostic
+    };
+
     let mut labels = Vec::new();
 
     if let Loc::File(file_no, start, end) = msg.loc {
@@ -238,6 +454,7 @@ impl Namespace {
             json.push(OutputJson {
                 sourceLocation: location,
                 ty: format!("{:?}", msg.ty),
+                errorCode: msg.code.to_string(),
                 component: "general".to_owned(),
                 severity: msg.level.to_string(),
                 message: msg.message.clone(),
@@ -248,6 +465,51 @@ impl Namespace {
         json
     }
 
+    /// Convert the diagnostics to SARIF results. Use [`sarif::SarifLog::new`] to combine
+    /// the results into a log.
+    pub fn diagnostics_as_sarif(&self, cache: &FileResolver) -> Vec<SarifResult> {
+        let mut results = Vec::new();
+
+        for msg in self.diagnostics.iter() {
+            let level = match msg.level {
+                Level::Error => "error",
+                Level::Warning => "warning",
+                Level::Info | Level::Debug => continue,
+            };
+
+            let locations = sarif::Location::new(&self.files, cache, &msg.loc, None)
+                .into_iter()
+                .collect();
+
+            let related_locations = msg
+                .notes
+                .iter()
+                .enumerate()
+                .filter_map(|(id, note)| {
+                    sarif::Location::new(
+                        &self.files,
+                        cache,
+                        &note.loc,
+                        Some((id, note.message.clone())),
+                    )
+                })
+                .collect();
+
+            results.push(SarifResult {
+                rule_id: msg.code.to_string(),
+                rule_index: 0,
+                level,
+                message: sarif::Message {
+                    text: msg.message.clone(),
+                },
+                locations,
+                related_locations,
+            });
+        }
+
+        results
+    }
+
     fn convert_files(
         &self,
         cache: &FileResolver,
@@ -1440,6 +1440,12 @@ impl Dot {
         if let Some(gas) = &call_args.gas {
             self.add_expression(gas, func, ns, node, String::from("gas"));
         }
+        if let Some(proof_size) = &call_args.proof_size {
+            self.add_expression(proof_size, func, ns, node, String::from("proofSize"));
+        }
+        if let Some(limit) = &call_args.storage_deposit_limit {
+            self.add_expression(limit, func, ns, node, String::from("storageDepositLimit"));
+        }
         if let Some(value) = &call_args.value {
             self.add_expression(value, func, ns, node, String::from("value"));
         }
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::sema::ast::{ArrayLength, CallArgs, Expression, Namespace, Note, RetrieveType, Type};
-use crate::sema::diagnostics::Diagnostics;
+use crate::sema::diagnostics::{codes, Diagnostics};
 use crate::sema::expression::function_call::{
     collect_call_args, evaluate_argument, parse_call_args,
 };
@@ -616,16 +616,19 @@ pub fn new(
     }
 
     let size = if size_ty.deref_any().bits(ns) > 32 {
-        diagnostics.push(Diagnostic::warning(
-            size_expr.loc(),
-            format!(
-                "conversion truncates {} to {}, as memory size is type {} on target {}",
-                size_ty.deref_any().to_string(ns),
-                expected_ty.to_string(ns),
-                expected_ty.to_string(ns),
-                ns.target
-            ),
-        ));
+        diagnostics.push(
+            Diagnostic::warning(
+                size_expr.loc(),
+                format!(
+                    "conversion truncates {} to {}, as memory size is type {} on target {}",
+                    size_ty.deref_any().to_string(ns),
+                    expected_ty.to_string(ns),
+                    expected_ty.to_string(ns),
+                    ns.target
+                ),
+            )
+            .with_code(codes::MEMORY_SIZE_TRUNCATION),
+        );
 
         Expression::CheckingTrunc {
             loc: size_loc,
@@ -5,7 +5,7 @@ use crate::sema::ast::{
     Namespace, RetrieveType, StructType, Symbol, Type,
 };
 use crate::sema::contracts::is_base;
-use crate::sema::diagnostics::Diagnostics;
+use crate::sema::diagnostics::{codes, Diagnostics};
 use crate::sema::expression::constructor::{
     deprecated_constructor_arguments, new, solana_constructor_check,
 };
@@ -1292,19 +1292,6 @@ fn try_type_method(
 
         Type::Address(is_payable) => {
             if func.name == "transfer" || func.name == "send" {
-                if ns.target == Target::Solana {
-                    diagnostics.push(Diagnostic::error(
-                        *loc,
-                        format!(
-                            "method '{}' not available on Solana. Use the lamports \
-                        field from the AccountInfo struct directly to operate on balances.",
-                            func.name
-                        ),
-                    ));
-
-                    return Err(());
-                }
-
                 if !is_payable {
                     diagnostics.push(Diagnostic::error(
                         *loc,
@@ -1397,11 +1384,22 @@ fn try_type_method(
                     return Err(());
                 }
 
-                if ty == CallTy::Delegate && ns.target.is_polkadot() && call_args.gas.is_some() {
-                    diagnostics.push(Diagnostic::warning(
-                        *loc,
-                        "'gas' specified on 'delegatecall' will be ignored".into(),
-                    ));
+                if ty == CallTy::Delegate && ns.target.is_polkadot() {
+                    for (name, arg) in [
+                        ("gas", &call_args.gas),
+                        ("proofSize", &call_args.proof_size),
+                        ("storageDepositLimit", &call_args.storage_deposit_limit),
+                    ] {
+                        if arg.is_some() {
+                            diagnostics.push(
+                                Diagnostic::warning(
+                                    *loc,
+                                    format!("'{name}' specified on 'delegatecall' will be ignored"),
+                                )
+                                .with_code(codes::DELEGATECALL_ARGUMENT),
+                            );
+                        }
+                    }
                 }
 
                 if args.len() != 1 {
@@ -2010,17 +2008,30 @@ pub(super) fn parse_call_args(
                     )?));
                 }
             }
-            "gas" => {
-                if ns.target == Target::Solana {
+            "gas" | "refTime" => {
+                if ns.target == Target::Solana
+                    || (arg.name.name == "refTime" && !ns.target.is_polkadot())
+                {
                     diagnostics.push(Diagnostic::error(
                         arg.loc,
                         format!(
-                            "'gas' not permitted for external calls or constructors on {}",
-                            ns.target
+                            "'{}' not permitted for external calls or constructors on {}",
+                            arg.name.name, ns.target
                         ),
                     ));
                     return Err(());
                 }
+
+                // on Polkadot, the gas limit is the reference time limit
+                if arg.name.name == "refTime" && call_args.iter().any(|arg| arg.name.name == "gas")
+                {
+                    diagnostics.push(Diagnostic::error(
+                        arg.loc,
+                        "'gas' and 'refTime' cannot both be specified".to_string(),
+                    ));
+                    return Err(());
+                }
+
                 let ty = Type::Uint(64);
 
                 let expr = expression(
@@ -2040,6 +2051,47 @@ pub(super) fn parse_call_args(
                     diagnostics,
                 )?));
             }
+            "proofSize" | "storageDepositLimit" => {
+                if !ns.target.is_polkadot() {
+                    diagnostics.push(Diagnostic::error(
+                        arg.loc,
+                        format!(
+                            "'{}' not permitted for external calls or constructors on {}",
+                            arg.name.name, ns.target
+                        ),
+                    ));
+                    return Err(());
+                }
+
+                let ty = if arg.name.name == "proofSize" {
+                    Type::Uint(64)
+                } else {
+                    Type::Value
+                };
+
+                let expr = expression(
+                    &arg.expr,
+                    context,
+                    ns,
+                    symtable,
+                    diagnostics,
+                    ResolveTo::Type(&ty),
+                )?;
+
+                let expr = Some(Box::new(expr.cast(
+                    &arg.expr.loc(),
+                    &ty,
+                    true,
+                    ns,
+                    diagnostics,
+                )?));
+
+                if arg.name.name == "proofSize" {
+                    res.proof_size = expr;
+                } else {
+                    res.storage_deposit_limit = expr;
+                }
+            }
             "salt" => {
                 if ns.target == Target::Solana {
                     diagnostics.push(Diagnostic::error(
@@ -2,6 +2,7 @@
 
 use crate::sema::address::to_hexstr_eip55;
 use crate::sema::ast::{ArrayLength, Expression, Namespace, RetrieveType, StructType, Type};
+use crate::sema::diagnostics::codes;
 use crate::sema::diagnostics::Diagnostics;
 use crate::sema::expression::integers::bigint_to_expression;
 use crate::sema::expression::resolve_expression::expression;
@@ -133,7 +134,7 @@ pub(crate) fn hex_number_literal(
     if n.starts_with("0x") && !n.chars().any(|c| c == '_') && n.len() == 42 {
         let address = to_hexstr_eip55(n);
 
-        if ns.target == Target::EVM {
+        if ns.target == Target::EVM || ns.target.is_polkadot_revive() {
             return if address == *n {
                 let s: String = address.chars().skip(2).collect();
 
@@ -545,16 +546,22 @@ pub(crate) fn unit_literal(
     if let Some(unit) = unit {
         match unit.name.as_str() {
             "wei" | "gwei" | "ether" if ns.target != crate::Target::EVM => {
-                diagnostics.push(Diagnostic::warning(
-                    *loc,
-                    format!("ethereum currency unit used while targeting {}", ns.target),
-                ));
+                diagnostics.push(
+                    Diagnostic::warning(
+                        *loc,
+                        format!("ethereum currency unit used while targeting {}", ns.target),
+                    )
+                    .with_code(codes::CURRENCY_UNIT),
+                );
             }
             "sol" | "lamports" if ns.target != crate::Target::Solana => {
-                diagnostics.push(Diagnostic::warning(
-                    *loc,
-                    format!("solana currency unit used while targeting {}", ns.target),
-                ));
+                diagnostics.push(
+                    Diagnostic::warning(
+                        *loc,
+                        format!("solana currency unit used while targeting {}", ns.target),
+                    )
+                    .with_code(codes::CURRENCY_UNIT),
+                );
             }
             _ => (),
         }
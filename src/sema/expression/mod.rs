@@ -15,7 +15,7 @@ mod tests;
 mod variable;
 
 use super::ast::{ArrayLength, Diagnostic, Expression, Mutability, Namespace, RetrieveType, Type};
-use super::diagnostics::Diagnostics;
+use super::diagnostics::{codes, Diagnostics};
 use super::eval::eval_const_rational;
 use super::symtable::{Symtable, VarScope};
 use crate::sema::contracts::is_base;
@@ -435,14 +435,17 @@ impl Expression {
                     }
 
                     // solc does not detect this problem, just warn about it
-                    diagnostics.push(Diagnostic::warning(
-                        *loc,
-                        format!(
-                            "enum {} has no value with ordinal {}",
-                            to.to_string(ns),
-                            big_number
-                        ),
-                    ));
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            *loc,
+                            format!(
+                                "enum {} has no value with ordinal {}",
+                                to.to_string(ns),
+                                big_number
+                            ),
+                        )
+                        .with_code(codes::ENUM_ORDINAL),
+                    );
                 }
 
                 let to_width = enum_ty.ty.bits(ns);
@@ -1253,7 +1256,7 @@ impl Expression {
                             format!(
                                 "function selector should only be casted to bytes{selector_length} or larger"
                             ),
-                        ));
+                        ).with_code(codes::SELECTOR_CAST));
                     }
                     self.cast_types(
                         loc,
@@ -125,10 +125,7 @@ impl Namespace {
 
     /// File number of the top level source unit which was compiled
     pub fn top_file_no(&self) -> usize {
-        self.files
-            .iter()
-            .position(|file| file.cache_no.is_some())
-            .unwrap()
+        self.top_file.unwrap()
     }
 }
 
@@ -35,6 +35,69 @@ pub(super) fn unexpected_parameter_annotation(loc: pt::Loc) -> Diagnostic {
     Diagnostic::error(loc, "unexpected parameter annotation".to_string())
 }
 
+/// The account annotations on Solana functions
+const ACCOUNT_ANNOTATIONS: [&str; 4] = ["account", "signer", "mutableAccount", "mutableSigner"];
+
+/// The annotations on Solana constructors which need a symbol table, so the constructor must
+/// have a body
+const CONSTRUCTOR_ANNOTATIONS: [&str; 4] = ["payer", "seed", "bump", "space"];
+
+/// The annotations on the parameters of Solana constructors
+const PARAMETER_ANNOTATIONS: [&str; 3] = ["seed", "bump", "space"];
+
+/// Can the selector be overridden on a function of this type
+fn selector_permitted(ty: pt::FunctionTy, target: Target) -> bool {
+    ty == pt::FunctionTy::Function || (target.is_polkadot() && ty == pt::FunctionTy::Constructor)
+}
+
+/// Can accounts be declared on a function of this type and visibility
+fn accounts_permitted(ty: pt::FunctionTy, visibility: &Visibility, target: Target) -> bool {
+    target == Target::Solana
+        && (ty == pt::FunctionTy::Constructor || matches!(visibility, Visibility::External(_)))
+}
+
+/// Can the seeds, bump, payer and space of the data account be given on a function of this type
+fn constructor_annotations_permitted(ty: pt::FunctionTy, target: Target) -> bool {
+    target == Target::Solana && ty == pt::FunctionTy::Constructor
+}
+
+/// The annotations which are permitted on a function with the given type and visibility, for
+/// the target. The language server uses this for completion.
+pub fn function_annotations(
+    ty: pt::FunctionTy,
+    visibility: &Visibility,
+    has_body: bool,
+    target: Target,
+) -> Vec<&'static str> {
+    let mut annotations = Vec::new();
+
+    if selector_permitted(ty, target)
+        && matches!(visibility, Visibility::Public(_) | Visibility::External(_))
+    {
+        annotations.push("selector");
+    }
+
+    if accounts_permitted(ty, visibility, target) {
+        annotations.extend(ACCOUNT_ANNOTATIONS);
+    }
+
+    if has_body && constructor_annotations_permitted(ty, target) {
+        annotations.extend(CONSTRUCTOR_ANNOTATIONS);
+    }
+
+    annotations
+}
+
+/// The annotations which are permitted on the parameters of a function with the given type, for
+/// the target. The language server uses this for completion.
+pub fn parameter_annotations(ty: pt::FunctionTy, target: Target) -> Vec<&'static str> {
+    if constructor_annotations_permitted(ty, target) {
+        PARAMETER_ANNOTATIONS.to_vec()
+    } else {
+        Vec::new()
+    }
+}
+
 /// Resolve the prototype annotation for functions (just the selector). These
 /// annotations can be resolved for functions without a body. This means they
 /// do not need to access the symbol table, like `@seed(foo)` annotations do.
@@ -48,10 +111,8 @@ pub fn function_prototype_annotations(
     for annotation in annotations {
         match annotation.id.name.as_str() {
             "selector" => function_selector(func, annotation, &mut diagnostics, ns),
-            "account" | "signer" | "mutableAccount" | "mutableSigner"
-                if ns.target == Target::Solana =>
-            {
-                if !func.is_constructor() && !matches!(func.visibility, Visibility::External(..)) {
+            name if ns.target == Target::Solana && ACCOUNT_ANNOTATIONS.contains(&name) => {
+                if !accounts_permitted(func.ty, &func.visibility, ns.target) {
                     diagnostics.push(Diagnostic::error(
                         annotation.loc,
                         "account declarations are only valid in functions declared as external"
@@ -96,9 +157,7 @@ fn function_selector(
     diagnostics: &mut Diagnostics,
     ns: &mut Namespace,
 ) {
-    if func.ty != pt::FunctionTy::Function
-        && (!ns.target.is_polkadot() || func.ty != pt::FunctionTy::Constructor)
-    {
+    if !selector_permitted(func.ty, ns.target) {
         diagnostics.push(Diagnostic::error(
             annotation.loc,
             format!("overriding selector not permitted on {}", func.ty),
@@ -209,7 +268,7 @@ pub(super) fn function_body_annotations(
 
     let mut annotations = ConstructorAnnotations::default();
     let is_solana_constructor =
-        ns.target == Target::Solana && ns.functions[function_no].ty == pt::FunctionTy::Constructor;
+        constructor_annotations_permitted(ns.functions[function_no].ty, ns.target);
 
     for note in body_annotations {
         match note.id.name.as_str() {
@@ -278,9 +337,8 @@ pub(super) fn function_body_annotations(
                     &mut annotations,
                 );
             }
-            "account" | "signer" | "mutableAccount" | "mutableSigner"
             // We already deal with these cases in `function_prototype_annotation`
-                if ns.target == Target::Solana => (),
+            name if ns.target == Target::Solana && ACCOUNT_ANNOTATIONS.contains(&name) => (),
 
             _ => diagnostics.push(Diagnostic::error(
                 note.loc,
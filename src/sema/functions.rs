@@ -9,6 +9,7 @@ use super::{
     ContractDefinition,
 };
 use crate::sema::ast::ParameterAnnotation;
+use crate::sema::diagnostics::codes;
 use crate::sema::function_annotation::unexpected_parameter_annotation;
 use crate::sema::namespace::ResolveTypeContext;
 use crate::Target;
@@ -139,10 +140,13 @@ pub fn contract_function(
                 }
 
                 if let pt::Mutability::Constant(loc) = m {
-                    ns.diagnostics.push(Diagnostic::warning(
-                        *loc,
-                        "'constant' is deprecated. Use 'view' instead".to_string(),
-                    ));
+                    ns.diagnostics.push(
+                        Diagnostic::warning(
+                            *loc,
+                            "'constant' is deprecated. Use 'view' instead".to_string(),
+                        )
+                        .with_code(codes::DEPRECATED),
+                    );
 
                     mutability = Some(pt::Mutability::View(*loc));
                 } else {
@@ -247,10 +251,13 @@ pub fn contract_function(
 
                 pt::Visibility::Internal(v.loc_opt())
             } else if func.ty == pt::FunctionTy::Constructor {
-                ns.diagnostics.push(Diagnostic::warning(
-                    v.loc_opt().unwrap(),
-                    format!("'{v}': visibility for constructors is ignored"),
-                ));
+                ns.diagnostics.push(
+                    Diagnostic::warning(
+                        v.loc_opt().unwrap(),
+                        format!("'{v}': visibility for constructors is ignored"),
+                    )
+                    .with_code(codes::CONSTRUCTOR_VISIBILITY),
+                );
 
                 pt::Visibility::Public(v.loc_opt())
             } else {
@@ -386,10 +393,13 @@ pub fn contract_function(
     // all functions in an interface are implicitly virtual
     let is_virtual = if ns.contracts[contract_no].is_interface() {
         if let Some(loc) = is_virtual {
-            ns.diagnostics.push(Diagnostic::warning(
-                loc,
-                "functions in an interface are implicitly virtual".to_string(),
-            ));
+            ns.diagnostics.push(
+                Diagnostic::warning(
+                    loc,
+                    "functions in an interface are implicitly virtual".to_string(),
+                )
+                .with_code(codes::INTERFACE_VIRTUAL),
+            );
         }
 
         true
@@ -694,10 +704,13 @@ pub fn function(
                 }
 
                 if let pt::Mutability::Constant(loc) = m {
-                    ns.diagnostics.push(Diagnostic::warning(
-                        *loc,
-                        "'constant' is deprecated. Use 'view' instead".to_string(),
-                    ));
+                    ns.diagnostics.push(
+                        Diagnostic::warning(
+                            *loc,
+                            "'constant' is deprecated. Use 'view' instead".to_string(),
+                        )
+                        .with_code(codes::DEPRECATED),
+                    );
 
                     mutability = Some(pt::Mutability::View(*loc));
                 } else {
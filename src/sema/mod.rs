@@ -9,16 +9,21 @@ use self::{
     variables::variable_decl,
 };
 use crate::file_resolver::{FileResolver, ResolvedFile};
+use crate::Target;
 use num_bigint::BigInt;
 use solang_parser::{
     doccomment::{parse_doccomments, DocComment},
-    parse,
     pt::{self, CodeLocation},
 };
-use std::{ffi::OsString, str};
+use std::{
+    ffi::{OsStr, OsString},
+    str,
+    sync::Arc,
+};
 
 mod address;
 pub mod ast;
+mod ast_json;
 pub mod builtin;
 pub mod builtin_structs;
 pub(crate) mod contracts;
@@ -29,7 +34,7 @@ pub(crate) mod expression;
 mod external_functions;
 pub mod file;
 mod format;
-mod function_annotation;
+pub mod function_annotation;
 mod functions;
 mod mutability;
 mod namespace;
@@ -39,7 +44,7 @@ mod statements;
 pub mod symtable;
 pub mod tags;
 mod tests;
-mod types;
+pub(crate) mod types;
 mod unused_variable;
 mod using;
 mod variables;
@@ -76,12 +81,15 @@ pub struct ContractDefinition<'a> {
     pub annotations: Vec<&'a pt::Annotation>,
     pub name: Option<&'a pt::Identifier>,
     pub base: Vec<pt::Base>,
+    pub layout: Option<&'a pt::Expression>,
     pub parts: Vec<ContractPart<'a>>,
 }
 
 /// Load a file file from the cache, parse and resolve it. The file must be present in
 /// the cache.
 pub fn sema(file: &ResolvedFile, resolver: &mut FileResolver, ns: &mut ast::Namespace) {
+    ns.top_file = Some(ns.files.len());
+
     sema_file(file, resolver, ns);
 
     if !ns.diagnostics.any_errors() {
@@ -92,6 +100,119 @@ pub fn sema(file: &ResolvedFile, resolver: &mut FileResolver, ns: &mut ast::Name
     }
 }
 
+/// The namespace of the files imported by a file, but not the file itself. The file can be
+/// resolved again starting from a copy of this namespace, so that its imports do not have to
+/// be resolved again every time the file changes. This is used by the language server.
+pub struct Imports {
+    target: Target,
+    /// The namespace of the imports, or `None` if they cannot be resolved without the file
+    /// itself, because they have errors or import the file
+    ns: Option<ast::Namespace>,
+    /// The file number the file gets in the namespace
+    file_no: usize,
+    /// The paths of the import directives of the file
+    paths: Vec<String>,
+    /// The contents of the imported files, by file number in the resolver
+    contents: Vec<(usize, Arc<str>)>,
+}
+
+impl Imports {
+    /// Resolve the imports of a file. Returns `None` if the file cannot be found or parsed.
+    pub fn resolve(filename: &OsStr, resolver: &mut FileResolver, target: Target) -> Option<Self> {
+        let file = resolver.resolve_file(None, filename).ok()?;
+        let (source_code, _) = resolver.get_file_contents_and_number(&file.full_path);
+
+        let mut ns = ast::Namespace::new(target);
+        let first_file_no = ns.files.len();
+
+        // the file itself is not part of the namespace, so its parse tree is not cached
+        let (pt, _) = solang_parser::parse(&source_code, first_file_no).ok()?;
+
+        for part in &pt.0 {
+            if let pt::SourceUnitPart::ImportDirective(import) = part {
+                import_file(import, Some(&file), resolver, &mut ns);
+            }
+        }
+
+        let contents = ns
+            .files
+            .iter()
+            .filter_map(|f| f.cache_no)
+            .map(|cache_no| {
+                let contents = resolver.get_contents_of_file_no(cache_no).unwrap();
+
+                (cache_no, contents)
+            })
+            .collect();
+
+        let (ns, file_no) =
+            if ns.diagnostics.any_errors() || ns.files.iter().any(|f| f.path == file.full_path) {
+                (None, first_file_no)
+            } else {
+                let file_no = ns.files.len();
+
+                (Some(ns), file_no)
+            };
+
+        Some(Imports {
+            target,
+            ns,
+            file_no,
+            paths: import_paths(&pt),
+            contents,
+        })
+    }
+
+    /// Parse and resolve the file like [`crate::parse_and_resolve`], starting from a copy of
+    /// the namespace of its imports. Returns `None` if any of the imported files or the import
+    /// directives of the file have changed, so the imports have to be resolved again.
+    pub fn parse_and_resolve(
+        &self,
+        filename: &OsStr,
+        resolver: &mut FileResolver,
+    ) -> Option<ast::Namespace> {
+        if self.contents.iter().any(|(cache_no, contents)| {
+            resolver.get_contents_of_file_no(*cache_no).as_ref() != Some(contents)
+        }) {
+            return None;
+        }
+
+        let file = resolver.resolve_file(None, filename).ok()?;
+        let (_, cache_no) = resolver.get_file_contents_and_number(&file.full_path);
+
+        // parse the file with the file number it will have, so sema can reuse the parse tree
+        let parsed = resolver.parse(cache_no, self.file_no);
+
+        if let Ok((pt, _)) = parsed.as_ref() {
+            if import_paths(pt) != self.paths {
+                return None;
+            }
+        }
+
+        let Some(imports) = &self.ns else {
+            return Some(crate::parse_and_resolve(filename, resolver, self.target));
+        };
+
+        let mut ns = imports.clone();
+
+        sema(&file, resolver, &mut ns);
+
+        ns.diagnostics.sort_and_dedup();
+
+        Some(ns)
+    }
+}
+
+/// The paths of the import directives in a parse tree
+fn import_paths(pt: &pt::SourceUnit) -> Vec<String> {
+    pt.0.iter()
+        .filter_map(|part| match part {
+            pt::SourceUnitPart::ImportDirective(import) => Some(import_path(import).to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
 /// Parse and resolve a file and its imports in a recursive manner.
 fn sema_file(file: &ResolvedFile, resolver: &mut FileResolver, ns: &mut ast::Namespace) {
     let file_no = ns.files.len();
@@ -105,16 +226,20 @@ fn sema_file(file: &ResolvedFile, resolver: &mut FileResolver, ns: &mut ast::Nam
         file.import_no,
     ));
 
-    let (pt, comments) = match parse(&source_code, file_no) {
+    let parsed = resolver.parse(file_cache_no, file_no);
+
+    let (pt, comments) = match parsed.as_ref() {
         Ok(s) => s,
-        Err(mut errors) => {
-            ns.diagnostics.append(&mut errors);
+        Err(errors) => {
+            ns.diagnostics.append(&mut errors.clone());
 
             return;
         }
     };
 
-    let tree = collect_annotations_doccomments(&pt, &comments, ns);
+    collect_suppressions(comments, file_no, source_code.len(), ns);
+
+    let tree = collect_annotations_doccomments(pt, comments, ns);
 
     // first resolve all the types we can find
     let fields = types::resolve_typenames(&tree, file_no, ns);
@@ -225,80 +350,12 @@ fn resolve_import(
     resolver: &mut FileResolver,
     ns: &mut ast::Namespace,
 ) {
-    let path = match import {
-        pt::Import::Plain(f, _)
-        | pt::Import::GlobalSymbol(f, _, _)
-        | pt::Import::Rename(f, _, _) => f,
-    };
-
-    let filename = match path {
-        pt::ImportPath::Filename(f) => f,
-        pt::ImportPath::Path(path) => {
-            ns.diagnostics.push(ast::Diagnostic::error(
-                path.loc,
-                "experimental import paths not supported".into(),
-            ));
-
-            return;
-        }
-    };
-
-    if filename.string.is_empty() {
-        ns.diagnostics.push(ast::Diagnostic::error(
-            filename.loc,
-            "import path empty".into(),
-        ));
-        return;
-    }
-
-    let (valid, bs) = unescape(
-        &filename.string,
-        filename.loc.start(),
-        filename.loc.file_no(),
-        &mut ns.diagnostics,
-    );
-
-    if !valid {
-        return;
-    }
-
-    let os_filename = if let Some(res) = osstring_from_vec(&filename.loc, bs, ns) {
-        res
-    } else {
+    let Some(import_file_no) = import_file(import, parent, resolver, ns) else {
         return;
     };
 
-    let import_file_no = if let Some(builtin_file_no) = ns
-        .files
-        .iter()
-        .position(|file| file.cache_no.is_none() && file.path == os_filename)
-    {
-        // import "solana"
-        builtin_file_no
-    } else {
-        match resolver.resolve_file(parent, &os_filename) {
-            Err(message) => {
-                ns.diagnostics
-                    .push(ast::Diagnostic::error(filename.loc, message));
-
-                return;
-            }
-            Ok(file) => {
-                if !ns.files.iter().any(|f| f.path == file.full_path) {
-                    sema_file(&file, resolver, ns);
-
-                    // give up if we failed
-                    if ns.diagnostics.any_errors() {
-                        return;
-                    }
-                }
-
-                ns.files
-                    .iter()
-                    .position(|f| f.path == file.full_path)
-                    .expect("import should be loaded by now")
-            }
-        }
+    let pt::ImportPath::Filename(filename) = import_path(import) else {
+        unreachable!();
     };
 
     match import {
@@ -425,6 +482,142 @@ fn resolve_import(
     }
 }
 
+/// The path of an import directive
+fn import_path(import: &pt::Import) -> &pt::ImportPath {
+    match import {
+        pt::Import::Plain(f, _)
+        | pt::Import::GlobalSymbol(f, _, _)
+        | pt::Import::Rename(f, _, _) => f,
+    }
+}
+
+/// Find the file of an import directive and resolve it by calling sema, if it has not been
+/// resolved already. Returns the file number of the import, unless there were errors.
+fn import_file(
+    import: &pt::Import,
+    parent: Option<&ResolvedFile>,
+    resolver: &mut FileResolver,
+    ns: &mut ast::Namespace,
+) -> Option<usize> {
+    let filename = match import_path(import) {
+        pt::ImportPath::Filename(f) => f,
+        pt::ImportPath::Path(path) => {
+            ns.diagnostics.push(ast::Diagnostic::error(
+                path.loc,
+                "experimental import paths not supported".into(),
+            ));
+
+            return None;
+        }
+    };
+
+    if filename.string.is_empty() {
+        ns.diagnostics.push(ast::Diagnostic::error(
+            filename.loc,
+            "import path empty".into(),
+        ));
+        return None;
+    }
+
+    let (valid, bs) = unescape(
+        &filename.string,
+        filename.loc.start(),
+        filename.loc.file_no(),
+        &mut ns.diagnostics,
+    );
+
+    if !valid {
+        return None;
+    }
+
+    let os_filename = osstring_from_vec(&filename.loc, bs, ns)?;
+
+    if let Some(builtin_file_no) = ns
+        .files
+        .iter()
+        .position(|file| file.cache_no.is_none() && file.path == os_filename)
+    {
+        // import "solana"
+        Some(builtin_file_no)
+    } else {
+        match resolver.resolve_file(parent, &os_filename) {
+            Err(message) => {
+                ns.diagnostics
+                    .push(ast::Diagnostic::error(filename.loc, message));
+
+                None
+            }
+            Ok(file) => {
+                if !ns.files.iter().any(|f| f.path == file.full_path) {
+                    sema_file(&file, resolver, ns);
+
+                    // give up if we failed
+                    if ns.diagnostics.any_errors() {
+                        return None;
+                    }
+                }
+
+                let import_file_no = ns
+                    .files
+                    .iter()
+                    .position(|f| f.path == file.full_path)
+                    .expect("import should be loaded by now");
+
+                Some(import_file_no)
+            }
+        }
+    }
+}
+
+/// Find all the `// solang-disable-next-line` comments, which suppress warnings on the
+/// following line. The comment can be followed by a list of codes, e.g.
+/// `// solang-disable-next-line S5001, S5003`; without codes all warnings are suppressed.
+fn collect_suppressions(
+    comments: &[pt::Comment],
+    file_no: usize,
+    source_len: usize,
+    ns: &mut ast::Namespace,
+) {
+    for comment in comments {
+        if let pt::Comment::Line(loc, text) = comment {
+            if let Some(list) = text
+                .trim_start_matches('/')
+                .trim()
+                .strip_prefix("solang-disable-next-line")
+            {
+                let mut codes = Vec::new();
+
+                for code in list.split(|c: char| c == ',' || c.is_whitespace()) {
+                    if code.is_empty() {
+                        continue;
+                    }
+
+                    match code.parse() {
+                        Ok(code) => codes.push(code),
+                        Err(message) => {
+                            ns.diagnostics.push(ast::Diagnostic::warning(*loc, message));
+                        }
+                    }
+                }
+
+                let file = &ns.files[file_no];
+                let (line_no, _) = file.offset_to_line_column(loc.end());
+
+                // nothing to suppress if the comment is on the last line of the file
+                if let Some(start) = file.line_starts.get(line_no) {
+                    let end = file
+                        .line_starts
+                        .get(line_no + 1)
+                        .copied()
+                        .unwrap_or(source_len);
+
+                    ns.diagnostics.add_suppression(file_no, *start..end, codes);
+                }
+            }
+        }
+    }
+}
+
 /// Walk through the parse tree and collect all the annotations and doccomments for
 /// each item, also inside contracts.
 fn collect_annotations_doccomments<'a>(
@@ -499,6 +692,7 @@ fn collect_annotations_doccomments<'a>(
                 doccomments,
                 name: contract.name.as_ref(),
                 base: contract.base.clone(),
+                layout: contract.layout.as_ref(),
                 parts,
             });
 
@@ -10,6 +10,7 @@ use super::{
     Recurse,
 };
 use crate::sema::ast::SolanaAccount;
+use crate::sema::diagnostics::codes;
 use crate::sema::solana_accounts::BuiltinAccounts;
 use crate::sema::yul::builtin::YulBuiltInFunction;
 use crate::Target;
@@ -190,29 +191,38 @@ fn check_mutability(func: &Function, ns: &Namespace) -> Diagnostics {
             match func.mutability {
                 Mutability::Payable(_) | Mutability::Pure(_) => (),
                 Mutability::Nonpayable(_) => {
-                    state.diagnostic.push(Diagnostic::warning(
-                        func.loc_prototype,
-                        "function can be declared 'pure'".to_string(),
-                    ));
+                    state.diagnostic.push(
+                        Diagnostic::warning(
+                            func.loc_prototype,
+                            "function can be declared 'pure'".to_string(),
+                        )
+                        .with_code(codes::MUTABILITY),
+                    );
                 }
                 _ => {
-                    state.diagnostic.push(Diagnostic::warning(
-                        func.loc_prototype,
-                        format!(
-                            "function declared '{}' can be declared 'pure'",
-                            func.mutability
-                        ),
-                    ));
+                    state.diagnostic.push(
+                        Diagnostic::warning(
+                            func.loc_prototype,
+                            format!(
+                                "function declared '{}' can be declared 'pure'",
+                                func.mutability
+                            ),
+                        )
+                        .with_code(codes::MUTABILITY),
+                    );
                 }
             }
         }
 
         // don't suggest marking payable as view (declared_access == Value)
         if state.required_access == Access::Read && state.declared_access == Access::Write {
-            state.diagnostic.push(Diagnostic::warning(
-                func.loc_prototype,
-                "function can be declared 'view'".to_string(),
-            ));
+            state.diagnostic.push(
+                Diagnostic::warning(
+                    func.loc_prototype,
+                    "function can be declared 'view'".to_string(),
+                )
+                .with_code(codes::MUTABILITY),
+            );
         }
     }
 
@@ -355,8 +365,16 @@ fn read_expression(expr: &Expression, state: &mut StateCheck) -> bool {
             state.data_account |= DataAccountUsage::READ;
             state.read(loc);
         }
-        Expression::StorageVariable { loc, .. } => {
-            state.data_account |= DataAccountUsage::READ;
+        Expression::StorageVariable {
+            loc,
+            contract_no,
+            var_no,
+            ..
+        } => {
+            // transient storage does not live in the data account
+            if !state.ns.contracts[*contract_no].variables[*var_no].transient {
+                state.data_account |= DataAccountUsage::READ;
+            }
             state.read(loc);
         }
         Expression::Builtin {
@@ -397,7 +415,11 @@ fn read_expression(expr: &Expression, state: &mut StateCheck) -> bool {
             loc,
             kind: Builtin::PayableSend | Builtin::PayableTransfer | Builtin::SelfDestruct,
             ..
-        } => state.write(loc),
+        } => {
+            // on Solana, the lamports come from the data account
+            state.data_account |= DataAccountUsage::WRITE;
+            state.write(loc)
+        }
         Expression::Builtin {
             loc,
             kind: Builtin::Value,
@@ -467,8 +489,15 @@ fn write_expression(expr: &Expression, state: &mut StateCheck) -> bool {
                 return false;
             }
         }
-        Expression::StorageVariable { loc, .. } => {
-            state.data_account |= DataAccountUsage::WRITE;
+        Expression::StorageVariable {
+            loc,
+            contract_no,
+            var_no,
+            ..
+        } => {
+            if !state.ns.contracts[*contract_no].variables[*var_no].transient {
+                state.data_account |= DataAccountUsage::WRITE;
+            }
             state.write(loc);
             return false;
         }
@@ -13,6 +13,7 @@ use super::{
     symtable::Symtable,
     ArrayDimension,
 };
+use crate::sema::diagnostics::codes;
 use crate::Target;
 use itertools::Itertools;
 use num_bigint::BigInt;
@@ -40,6 +41,8 @@ impl Namespace {
                 address_length,
                 value_length,
             } => (address_length, value_length),
+            // pallet-revive uses Ethereum compatible addresses and balances
+            Target::PolkadotRevive => (20, 32),
             Target::Solana => (32, 8),
             Target::Soroban => (32, 8),
         };
@@ -48,6 +51,7 @@ impl Namespace {
             target,
             pragmas: Vec::new(),
             files: Vec::new(),
+            top_file: None,
             enums: Vec::new(),
             structs: Vec::new(),
             events: Vec::new(),
@@ -70,7 +74,7 @@ impl Namespace {
 
         match target {
             Target::Solana => ns.add_solana_builtins(),
-            Target::Polkadot { .. } => ns.add_polkadot_builtins(),
+            Target::Polkadot { .. } | Target::PolkadotRevive => ns.add_polkadot_builtins(),
             Target::Soroban => ns.add_soroban_builtins(),
             _ => {}
         }
@@ -87,10 +91,10 @@ impl Namespace {
         symbol: Symbol,
     ) -> bool {
         if builtin::is_reserved(&id.name) {
-            self.diagnostics.push(Diagnostic::warning(
-                id.loc,
-                format!("'{}' shadows name of a builtin", id.name),
-            ));
+            self.diagnostics.push(
+                Diagnostic::warning(id.loc, format!("'{}' shadows name of a builtin", id.name))
+                    .with_code(codes::SHADOWING),
+            );
         }
 
         if let Some(Symbol::Function(v)) =
@@ -775,10 +779,10 @@ impl Namespace {
         id: &pt::Identifier,
     ) {
         if builtin::is_reserved(&id.name) {
-            self.diagnostics.push(Diagnostic::warning(
-                id.loc,
-                format!("'{}' shadows name of a builtin", id.name),
-            ));
+            self.diagnostics.push(
+                Diagnostic::warning(id.loc, format!("'{}' shadows name of a builtin", id.name))
+                    .with_code(codes::SHADOWING),
+            );
             return;
         }
 
@@ -801,21 +805,27 @@ impl Namespace {
         match s {
             Some(Symbol::Enum(loc, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows enum definition", id.name),
-                    loc,
-                    "previous definition of enum".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows enum definition", id.name),
+                        loc,
+                        "previous definition of enum".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Struct(loc, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows struct definition", id.name),
-                    loc,
-                    "previous definition of struct".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows struct definition", id.name),
+                        loc,
+                        "previous definition of struct".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Event(events)) => {
                 let notes = events
@@ -826,20 +836,26 @@ impl Namespace {
                     })
                     .collect();
 
-                self.diagnostics.push(Diagnostic::warning_with_notes(
-                    id.loc,
-                    format!("declaration of '{}' shadows event definition", id.name),
-                    notes,
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_notes(
+                        id.loc,
+                        format!("declaration of '{}' shadows event definition", id.name),
+                        notes,
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Error(loc, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows error definition", id.name),
-                    loc,
-                    "previous definition of error".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows error definition", id.name),
+                        loc,
+                        "previous definition of error".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Function(v)) => {
                 let notes = v
@@ -849,47 +865,62 @@ impl Namespace {
                         message: "previous declaration of function".to_owned(),
                     })
                     .collect();
-                self.diagnostics.push(Diagnostic::warning_with_notes(
-                    id.loc,
-                    format!("declaration of '{}' shadows function", id.name),
-                    notes,
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_notes(
+                        id.loc,
+                        format!("declaration of '{}' shadows function", id.name),
+                        notes,
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Variable(loc, _, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows state variable", id.name),
-                    loc,
-                    "previous declaration of state variable".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows state variable", id.name),
+                        loc,
+                        "previous declaration of state variable".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Contract(loc, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows contract name", id.name),
-                    loc,
-                    "previous declaration of contract name".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows contract name", id.name),
+                        loc,
+                        "previous declaration of contract name".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::UserType(loc, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows type", id.name),
-                    loc,
-                    "previous declaration of type".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows type", id.name),
+                        loc,
+                        "previous declaration of type".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Import(loc, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows import", id.name),
-                    loc,
-                    "previous declaration of import".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows import", id.name),
+                        loc,
+                        "previous declaration of import".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             None => (),
         }
@@ -1028,10 +1059,14 @@ impl Namespace {
                                 }
 
                                 if let pt::Mutability::Constant(loc) = m {
-                                    diagnostics.push(Diagnostic::warning(
-                                        *loc,
-                                        "'constant' is deprecated. Use 'view' instead".to_string(),
-                                    ));
+                                    diagnostics.push(
+                                        Diagnostic::warning(
+                                            *loc,
+                                            "'constant' is deprecated. Use 'view' instead"
+                                                .to_string(),
+                                        )
+                                        .with_code(codes::DEPRECATED),
+                                    );
 
                                     mutability = Some(pt::Mutability::View(*loc));
                                 } else {
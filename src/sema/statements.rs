@@ -8,6 +8,7 @@ use super::expression::{
     ExprContext, ResolveTo,
 };
 use super::symtable::Symtable;
+use crate::sema::diagnostics::codes;
 use crate::sema::expression::constructor::{
     constructor_named_args, match_constructor_to_args, new,
 };
@@ -435,10 +436,10 @@ fn statement(
 
             for stmt in statements {
                 if !reachable && !already_unreachable {
-                    ns.diagnostics.push(Diagnostic::warning(
-                        stmt.loc(),
-                        "unreachable statement".to_string(),
-                    ));
+                    ns.diagnostics.push(
+                        Diagnostic::warning(stmt.loc(), "unreachable statement".to_string())
+                            .with_code(codes::UNREACHABLE_CODE),
+                    );
                     already_unreachable = true;
                 }
                 reachable = statement(
@@ -726,10 +727,13 @@ fn statement(
 
                         Ok(true)
                     } else {
-                        ns.diagnostics.push(Diagnostic::warning(
-                            *loc,
-                            "argument to 'delete' should be storage reference".to_string(),
-                        ));
+                        ns.diagnostics.push(
+                            Diagnostic::warning(
+                                *loc,
+                                "argument to 'delete' should be storage reference".to_string(),
+                            )
+                            .with_code(codes::DELETE_NOT_STORAGE),
+                        );
 
                         Err(())
                     };
@@ -854,10 +858,13 @@ fn statement(
                             memory_safe = Some(flag.loc);
                         }
                     } else {
-                        ns.diagnostics.push(Diagnostic::warning(
-                            flag.loc,
-                            format!("flag '{}' not supported", flag.string),
-                        ));
+                        ns.diagnostics.push(
+                            Diagnostic::warning(
+                                flag.loc,
+                                format!("flag '{}' not supported", flag.string),
+                            )
+                            .with_code(codes::ASSEMBLY_FLAG),
+                        );
                     }
                 }
             }
@@ -1561,7 +1568,7 @@ fn remove_duplicate_events(
                     }
                 })
                 .collect(),
-        ));
+        ).with_code(codes::AMBIGUOUS_EMIT));
 
         resolved_events.truncate(1);
     }
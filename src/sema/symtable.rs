@@ -155,16 +155,16 @@ impl Symtable {
         context: &mut ExprContext,
     ) -> Option<usize> {
         if let Some(var) = self.find(context, &id.name) {
-            ns.diagnostics.push(Diagnostic {
-                level: Level::Error,
-                ty: ErrorType::DeclarationError,
-                loc: id.loc,
-                message: format!("variable name '{}' already used in this scope", id.name),
-                notes: vec![Note {
+            ns.diagnostics.push(Diagnostic::new(
+                id.loc,
+                Level::Error,
+                ErrorType::DeclarationError,
+                format!("variable name '{}' already used in this scope", id.name),
+                vec![Note {
                     loc: var.id.loc,
                     message: "found previous declaration here".to_string(),
                 }],
-            });
+            ));
             return None;
         }
 
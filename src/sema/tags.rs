@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::ast::{Diagnostic, Namespace, Parameter, Tag, Type};
+use crate::sema::diagnostics::codes;
 use solang_parser::{
     doccomment::{DocComment, DocCommentTag},
     pt,
@@ -43,12 +44,15 @@ pub fn resolve_tags(
                 if let Some(no) = params.unwrap().iter().position(|p| p.name_as_str() == name) {
                     if let Some(other) = res.iter().find(|e| e.tag == "param" && e.no == no) {
                         // Note: solc does not detect this problem
-                        ns.diagnostics.push(Diagnostic::warning_with_note(
-                            loc,
-                            format!("duplicate tag '@param' for '{name}'"),
-                            other.loc,
-                            format!("previous tag '@param' for '{name}'"),
-                        ));
+                        ns.diagnostics.push(
+                            Diagnostic::warning_with_note(
+                                loc,
+                                format!("duplicate tag '@param' for '{name}'"),
+                                other.loc,
+                                format!("previous tag '@param' for '{name}'"),
+                            )
+                            .with_code(codes::DOC_COMMENT),
+                        );
                     } else {
                         res.push(Tag {
                             loc,
@@ -62,17 +66,23 @@ pub fn resolve_tags(
                 {
                     if let Some(other) = res.iter().find(|e| e.tag == "return" && e.no == no) {
                         // Note: solc does not detect this problem
-                        ns.diagnostics.push(Diagnostic::warning_with_note(
-                            loc,
-                            format!("duplicate tag '@param' for '{name}'"),
-                            other.loc,
-                            format!("previous tag '@param' for '{name}'"),
-                        ));
+                        ns.diagnostics.push(
+                            Diagnostic::warning_with_note(
+                                loc,
+                                format!("duplicate tag '@param' for '{name}'"),
+                                other.loc,
+                                format!("previous tag '@param' for '{name}'"),
+                            )
+                            .with_code(codes::DOC_COMMENT),
+                        );
                     } else {
-                        ns.diagnostics.push(Diagnostic::warning(
-                            loc,
-                            format!("'@param' used in stead of '@return' for '{name}'"),
-                        ));
+                        ns.diagnostics.push(
+                            Diagnostic::warning(
+                                loc,
+                                format!("'@param' used in stead of '@return' for '{name}'"),
+                            )
+                            .with_code(codes::DOC_COMMENT),
+                        );
 
                         res.push(Tag {
                             loc,
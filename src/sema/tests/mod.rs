@@ -6,10 +6,12 @@ mod data_account;
 
 use crate::sema::ast::{Expression, Parameter, Statement, TryCatch, Type};
 use crate::sema::yul::ast::InlineAssembly;
+use crate::sema::Imports;
 use crate::{parse_and_resolve, sema::ast, FileResolver, Target};
 use solang_parser::pt::Loc;
 use std::ffi::{OsStr, OsString};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 pub(crate) fn parse(src: &'static str) -> ast::Namespace {
     let mut cache = FileResolver::default();
@@ -655,3 +657,290 @@ fn get_import_path() {
         assert_eq!(Some(&(None, examples.clone())), import_path);
     }
 }
+
+#[test]
+fn reuse_parse_trees() {
+    let mut cache = FileResolver::default();
+    cache.enable_parse_cache();
+
+    cache.set_file_contents("a.sol", "import \"b.sol\"; contract a is b {}".to_string());
+    cache.set_file_contents("b.sol", "contract b { function f() public {} }".to_string());
+
+    let ns = parse_and_resolve(OsStr::new("a.sol"), &mut cache, Target::EVM);
+    assert!(!ns.diagnostics.any_errors());
+
+    let (_, b_no) = cache.get_file_contents_and_number(Path::new("b.sol"));
+    let tree = cache.parse(b_no, 1);
+
+    // b.sol is unchanged, so its parse tree is reused
+    cache.set_file_contents(
+        "a.sol",
+        "import \"b.sol\"; contract a is b { function g() public {} }".to_string(),
+    );
+    cache.set_file_contents("b.sol", "contract b { function f() public {} }".to_string());
+
+    let ns = parse_and_resolve(OsStr::new("a.sol"), &mut cache, Target::EVM);
+    assert!(!ns.diagnostics.any_errors());
+    assert!(Arc::ptr_eq(&tree, &cache.parse(b_no, 1)));
+
+    // the same parse tree cannot be used for a different file number
+    assert!(!Arc::ptr_eq(&tree, &cache.parse(b_no, 2)));
+
+    cache.set_file_contents("b.sol", "contract b { function f() public {".to_string());
+
+    let ns = parse_and_resolve(OsStr::new("a.sol"), &mut cache, Target::EVM);
+    assert!(ns.diagnostics.any_errors());
+    assert!(!Arc::ptr_eq(&tree, &cache.parse(b_no, 1)));
+}
+
+#[test]
+fn erc7201_namespace_slot() {
+    let ns = parse(
+        r#"
+        /// @custom:storage-location erc7201:example.main
+        struct MainStorage {
+            uint256 x;
+        }"#,
+    );
+
+    let namespace = ns.structs[0].storage_namespace().unwrap();
+    assert_eq!(namespace, "example.main");
+
+    assert_eq!(
+        format!("{:x}", crate::sema::types::erc7201_slot(namespace)),
+        "183a6125c38840424c4a85fa12bab2ab606c4b6d0e7cc73c0c06ba5300eab500"
+    );
+}
+
+#[test]
+fn resolver_modified() {
+    let mut cache = FileResolver::default();
+
+    cache.set_file_contents("a.sol", "import \"b.sol\"; contract a is b {}".to_string());
+    assert!(cache.is_modified());
+
+    cache.clear_modified();
+    cache.set_file_contents("a.sol", "import \"b.sol\"; contract a is b {}".to_string());
+    assert!(!cache.is_modified());
+
+    // b.sol is not found, but not being found does not add a file
+    let ns = parse_and_resolve(OsStr::new("a.sol"), &mut cache, Target::EVM);
+    assert!(ns.diagnostics.any_errors());
+    assert!(!cache.is_modified());
+
+    cache.set_file_contents("b.sol", "contract b {}".to_string());
+    assert!(cache.is_modified());
+
+    cache.clear_modified();
+    cache.set_file_contents("b.sol", "contract b { }".to_string());
+    assert!(cache.is_modified());
+}
+
+#[test]
+fn reuse_resolved_imports() {
+    let mut cache = FileResolver::default();
+    cache.enable_parse_cache();
+
+    cache.set_file_contents(
+        "a.sol",
+        "import \"b.sol\"; contract a is b { function g() public { f(); } }".to_string(),
+    );
+    cache.set_file_contents(
+        "b.sol",
+        "contract b { function f() internal {} }".to_string(),
+    );
+
+    let imports = Imports::resolve(OsStr::new("a.sol"), &mut cache, Target::EVM).unwrap();
+
+    let ns = imports
+        .parse_and_resolve(OsStr::new("a.sol"), &mut cache)
+        .unwrap();
+    assert!(!ns.diagnostics.any_errors());
+    assert_eq!(ns.files[ns.top_file_no()].path, PathBuf::from("a.sol"));
+    assert_eq!(ns.contracts.len(), 2);
+
+    // only a.sol has changed, so the imports can be reused
+    cache.set_file_contents(
+        "a.sol",
+        "import \"b.sol\"; contract a is b { function g() public { h(); } }".to_string(),
+    );
+
+    let ns = imports
+        .parse_and_resolve(OsStr::new("a.sol"), &mut cache)
+        .unwrap();
+    assert_eq!(ns.diagnostics.first_error(), "unknown function or type 'h'");
+    assert_eq!(
+        ns.diagnostics.first_error(),
+        parse_and_resolve(OsStr::new("a.sol"), &mut cache, Target::EVM)
+            .diagnostics
+            .first_error()
+    );
+
+    // a different import directive
+    cache.set_file_contents(
+        "a.sol",
+        "import \"c.sol\"; contract a { function g() public {} }".to_string(),
+    );
+    assert!(imports
+        .parse_and_resolve(OsStr::new("a.sol"), &mut cache)
+        .is_none());
+
+    // a changed import
+    cache.set_file_contents(
+        "a.sol",
+        "import \"b.sol\"; contract a is b { function g() public { f(); } }".to_string(),
+    );
+    cache.set_file_contents(
+        "b.sol",
+        "contract b { function f() private {} }".to_string(),
+    );
+    assert!(imports
+        .parse_and_resolve(OsStr::new("a.sol"), &mut cache)
+        .is_none());
+
+    // imports which import the file itself cannot be resolved without it
+    cache.set_file_contents(
+        "b.sol",
+        "import \"a.sol\"; contract b { function f() internal {} }".to_string(),
+    );
+
+    let imports = Imports::resolve(OsStr::new("a.sol"), &mut cache, Target::EVM).unwrap();
+
+    let ns = imports
+        .parse_and_resolve(OsStr::new("a.sol"), &mut cache)
+        .unwrap();
+    assert!(!ns.diagnostics.any_errors());
+    assert_eq!(ns.top_file_no(), 0);
+}
+
+#[test]
+fn external_function_struct_fields() {
+    let ns = parse("contract c {}");
+
+    // the names are used in the Anchor IDL, so changing them breaks clients
+    let names: Vec<_> = ast::StructType::ExternalFunction
+        .definition(&ns)
+        .fields
+        .iter()
+        .map(|field| field.name_as_str())
+        .collect();
+
+    assert_eq!(names, ["selector", "address"]);
+}
+
+#[test]
+fn ast_json() {
+    let ns = parse(
+        r#"
+        pragma solidity ^0.8.0;
+
+        struct S { uint64 f; }
+
+        contract c {
+            uint64 public total;
+
+            event Added(uint64 value);
+
+            function add(S memory s) public returns (uint64) {
+                total += s.f;
+                emit Added(s.f);
+                return msg.value > 0 ? 1 : total;
+            }
+        }"#,
+    );
+
+    let json = ns.ast_json();
+    let ast = &json["sources"]["test.sol"]["ast"];
+
+    assert_eq!(json["sources"]["test.sol"]["id"], 0);
+    assert_eq!(ast["nodeType"], "SourceUnit");
+    assert_eq!(ast["absolutePath"], "test.sol");
+
+    let nodes = ast["nodes"].as_array().unwrap();
+    let node_types: Vec<_> = nodes.iter().map(|node| &node["nodeType"]).collect();
+
+    assert_eq!(
+        node_types,
+        ["PragmaDirective", "StructDefinition", "ContractDefinition"]
+    );
+    assert_eq!(
+        nodes[0]["literals"],
+        serde_json::json!(["solidity", "^", "0.8.0"])
+    );
+    assert_eq!(ast["exportedSymbols"]["c"][0], nodes[2]["id"]);
+
+    let contract = &nodes[2];
+
+    assert_eq!(contract["name"], "c");
+    assert_eq!(contract["linearizedBaseContracts"][0], contract["id"]);
+
+    let members: Vec<_> = contract["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|node| &node["nodeType"])
+        .collect();
+
+    assert_eq!(
+        members,
+        [
+            "VariableDeclaration",
+            "EventDefinition",
+            "FunctionDefinition"
+        ]
+    );
+
+    let function = &contract["nodes"][2];
+
+    assert_eq!(function["functionSelector"], "49edb831");
+    assert_eq!(function["stateMutability"], "nonpayable");
+
+    let param = &function["parameters"]["parameters"][0];
+
+    assert_eq!(param["storageLocation"], "memory");
+    assert_eq!(param["typeName"]["nodeType"], "UserDefinedTypeName");
+    assert_eq!(param["typeName"]["referencedDeclaration"], nodes[1]["id"]);
+
+    let statements = &function["body"]["statements"];
+    let assignment = &statements[0]["expression"];
+
+    assert_eq!(assignment["operator"], "+=");
+    assert_eq!(
+        assignment["leftHandSide"]["referencedDeclaration"],
+        contract["nodes"][0]["id"]
+    );
+    assert_eq!(
+        assignment["rightHandSide"]["expression"]["referencedDeclaration"],
+        param["id"]
+    );
+    assert_eq!(
+        assignment["rightHandSide"]["referencedDeclaration"],
+        nodes[1]["members"][0]["id"]
+    );
+
+    assert_eq!(statements[1]["nodeType"], "EmitStatement");
+    assert_eq!(
+        statements[1]["eventCall"]["expression"]["referencedDeclaration"],
+        contract["nodes"][1]["id"]
+    );
+
+    let condition = &statements[2]["expression"]["condition"];
+
+    assert_eq!(
+        statements[2]["functionReturnParameters"],
+        function["returnParameters"]["id"]
+    );
+    assert_eq!(condition["leftExpression"]["memberName"], "value");
+    assert_eq!(condition["leftExpression"]["expression"]["name"], "msg");
+    assert_eq!(
+        condition["leftExpression"]["expression"]["referencedDeclaration"],
+        -12
+    );
+    assert_eq!(
+        condition["leftExpression"]["typeDescriptions"]["typeString"],
+        "uint256"
+    );
+
+    // the ids are stable
+    assert_eq!(json, ns.ast_json());
+}
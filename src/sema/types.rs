@@ -15,7 +15,7 @@ use crate::Target;
 use base58::{FromBase58, FromBase58Error};
 use indexmap::IndexMap;
 use itertools::Itertools;
-use num_bigint::BigInt;
+use num_bigint::{BigInt, Sign};
 use num_traits::{One, Zero};
 use petgraph::algo::{all_simple_paths, tarjan_scc};
 use petgraph::stable_graph::IndexType;
@@ -26,6 +26,7 @@ use solang_parser::{doccomment::DocComment, pt, pt::CodeLocation};
 use std::collections::HashSet;
 use std::ops::MulAssign;
 use std::{fmt::Write, ops::Mul};
+use tiny_keccak::{Hasher, Keccak};
 
 type Graph = petgraph::Graph<(), usize, Directed, usize>;
 
@@ -792,9 +793,65 @@ pub fn struct_decl(
         ns,
     );
 
+    for tag in doc
+        .iter()
+        .filter(|tag| tag.tag == "custom:storage-location")
+    {
+        if ns.target == Target::Solana {
+            ns.diagnostics.push(Diagnostic::error(
+                tag.loc,
+                "namespaced storage is not supported on Solana".into(),
+            ));
+        } else if !matches!(tag.value.strip_prefix("erc7201:"), Some(namespace) if !namespace.is_empty())
+        {
+            ns.diagnostics.push(Diagnostic::error(
+                tag.loc,
+                format!(
+                    "storage location '{}' should be of the form 'erc7201:<namespace>'",
+                    tag.value
+                ),
+            ));
+        }
+    }
+
     (doc, fields)
 }
 
+impl StructDecl {
+    /// The ERC-7201 namespace given with `@custom:storage-location erc7201:<namespace>`
+    pub fn storage_namespace(&self) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|tag| tag.tag == "custom:storage-location")
+            .and_then(|tag| tag.value.strip_prefix("erc7201:"))
+            .filter(|namespace| !namespace.is_empty())
+    }
+}
+
+/// The storage slot of an ERC-7201 namespace, which is
+/// `keccak256(abi.encode(uint256(keccak256(namespace)) - 1)) & ~bytes32(uint256(0xff))`
+pub fn erc7201_slot(namespace: &str) -> BigInt {
+    let id = BigInt::from_bytes_be(Sign::Plus, &keccak256(namespace.as_bytes())) - 1u8;
+
+    // abi.encode() of uint256 is the 32 bytes big endian value
+    let mut word = [0u8; 32];
+    let (_, bytes) = id.to_bytes_be();
+    word[32 - bytes.len()..].copy_from_slice(&bytes);
+
+    let mut slot = keccak256(&word);
+    slot[31] = 0;
+
+    BigInt::from_bytes_be(Sign::Plus, &slot)
+}
+
+fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    let mut hash = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut hash);
+    hash
+}
+
 /// Resolve a parsed event definition. The return value will be true if the entire
 /// definition is valid; however, whatever could be parsed will be added to the resolved
 /// contract, so that we can continue producing compiler messages for the remainder
@@ -1606,6 +1663,7 @@ impl Type {
             Type::String | Type::DynamicBytes => BigInt::from(4),
             Type::Ref(ty) | Type::StorageRef(_, ty) => ty.solana_storage_size(ns),
             Type::UserType(no) => ns.user_types[*no].ty.solana_storage_size(ns),
+            Type::ExternalFunction { .. } => self.storage_slots(ns),
             // Other types have the same size both in storage and in memory
             _ => self.memory_size_of(ns),
         }
@@ -1761,7 +1819,7 @@ impl Type {
                 Type::InternalFunction { .. } => BigInt::from(ns.target.ptr_size()),
                 Type::ExternalFunction { .. } => {
                     // Address and selector
-                    BigInt::from(ns.address_length + 4)
+                    BigInt::from(ns.address_length + ns.target.selector_length() as usize)
                 }
                 Type::Mapping(..) => BigInt::from(SOLANA_BUCKET_SIZE) * BigInt::from(4),
                 Type::Ref(ty) | Type::StorageRef(_, ty) => ty.storage_slots(ns),
@@ -2002,6 +2060,29 @@ impl Type {
         })
     }
 
+    /// Does the type contain any external function type
+    pub fn contains_external_function(&self, ns: &Namespace) -> bool {
+        self.contains_external_function_internal(ns, &mut HashSet::new())
+    }
+
+    fn contains_external_function_internal(
+        &self,
+        ns: &Namespace,
+        structs_visited: &mut HashSet<usize>,
+    ) -> bool {
+        self.guarded_recursion(structs_visited, false, |structs_visited| match self {
+            Type::ExternalFunction { .. } => true,
+            Type::Array(ty, _) => ty.contains_external_function_internal(ns, structs_visited),
+            Type::Struct(str_ty) => str_ty.definition(ns).fields.iter().any(|f| {
+                f.ty.contains_external_function_internal(ns, structs_visited)
+            }),
+            Type::StorageRef(_, r) | Type::Ref(r) => {
+                r.contains_external_function_internal(ns, structs_visited)
+            }
+            _ => false,
+        })
+    }
+
     /// Is this structure a builtin
     pub fn is_builtin_struct(&self) -> Option<StructType> {
         match self {
@@ -4,6 +4,7 @@ use crate::sema::ast::{
     Builtin, CallArgs, Diagnostic, EventDecl, Expression, ExternalCallAccounts, Namespace,
     RetrieveType,
 };
+use crate::sema::diagnostics::codes;
 use crate::sema::symtable::{Symtable, VariableUsage};
 use crate::sema::{ast, symtable};
 use solang_parser::pt::{ContractTy, Loc};
@@ -267,6 +268,12 @@ fn check_call_args(ns: &mut Namespace, call_args: &CallArgs, symtable: &mut Symt
     if let Some(gas) = &call_args.gas {
         used_variable(ns, gas.as_ref(), symtable);
     }
+    if let Some(proof_size) = &call_args.proof_size {
+        used_variable(ns, proof_size.as_ref(), symtable);
+    }
+    if let Some(limit) = &call_args.storage_deposit_limit {
+        used_variable(ns, limit.as_ref(), symtable);
+    }
     if let Some(salt) = &call_args.salt {
         used_variable(ns, salt.as_ref(), symtable);
     }
@@ -308,10 +315,13 @@ pub fn emit_warning_local_variable(
             if (!variable.read && !variable.ty.is_reference_type(ns))
                 || (!variable.read && !variable.assigned && variable.ty.is_reference_type(ns))
             {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!("function parameter '{}' is unused", variable.id.name),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!("function parameter '{}' is unused", variable.id.name),
+                    )
+                    .with_code(codes::UNUSED_PARAMETER),
+                );
             }
             None
         }
@@ -327,13 +337,16 @@ pub fn emit_warning_local_variable(
                         ),
                     ));
                 } else {
-                    return Some(Diagnostic::warning(
-                        variable.id.loc,
-                        format!(
-                            "return variable '{}' has never been assigned",
-                            variable.id.name
-                        ),
-                    ));
+                    return Some(
+                        Diagnostic::warning(
+                            variable.id.loc,
+                            format!(
+                                "return variable '{}' has never been assigned",
+                                variable.id.name
+                            ),
+                        )
+                        .with_code(codes::UNASSIGNED_RETURN_VARIABLE),
+                    );
                 }
             }
             None
@@ -342,33 +355,42 @@ pub fn emit_warning_local_variable(
         VariableUsage::LocalVariable => {
             let assigned = variable.initializer.has_initializer() || variable.assigned;
             if !variable.assigned && !variable.read {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!("local variable '{}' is unused", variable.id.name),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!("local variable '{}' is unused", variable.id.name),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             } else if assigned && !variable.read && !variable.is_reference(ns) {
                 // Values assigned to variables that reference others change the value of its reference
                 // No warning needed in this case
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!(
-                        "local variable '{}' has been assigned, but never read",
-                        variable.id.name
-                    ),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!(
+                            "local variable '{}' has been assigned, but never read",
+                            variable.id.name
+                        ),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             }
             None
         }
 
         VariableUsage::DestructureVariable => {
             if !variable.read {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!(
-                        "destructure variable '{}' has never been used",
-                        variable.id.name
-                    ),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!(
+                            "destructure variable '{}' has never been used",
+                            variable.id.name
+                        ),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             }
 
             None
@@ -376,13 +398,16 @@ pub fn emit_warning_local_variable(
 
         VariableUsage::TryCatchReturns => {
             if !variable.read {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!(
-                        "try-catch returns variable '{}' has never been read",
-                        variable.id.name
-                    ),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!(
+                            "try-catch returns variable '{}' has never been read",
+                            variable.id.name
+                        ),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             }
 
             None
@@ -390,13 +415,16 @@ pub fn emit_warning_local_variable(
 
         VariableUsage::TryCatchErrorBytes => {
             if !variable.read {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!(
-                        "try-catch error bytes '{}' has never been used",
-                        variable.id.name
-                    ),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!(
+                            "try-catch error bytes '{}' has never been used",
+                            variable.id.name
+                        ),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             }
 
             None
@@ -404,13 +432,16 @@ pub fn emit_warning_local_variable(
 
         VariableUsage::TryCatchErrorString => {
             if !variable.read {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!(
-                        "try-catch error string '{}' has never been used",
-                        variable.id.name
-                    ),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!(
+                            "try-catch error string '{}' has never been used",
+                            variable.id.name
+                        ),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             }
 
             None
@@ -418,18 +449,24 @@ pub fn emit_warning_local_variable(
         VariableUsage::YulLocalVariable => {
             let has_value = variable.assigned || variable.initializer.has_initializer();
             if !variable.read && !has_value {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!(
-                        "yul variable '{}' has never been read or assigned",
-                        variable.id.name
-                    ),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!(
+                            "yul variable '{}' has never been read or assigned",
+                            variable.id.name
+                        ),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             } else if !variable.read {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!("yul variable '{}' has never been read", variable.id.name),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!("yul variable '{}' has never been read", variable.id.name),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             }
             None
         }
@@ -440,18 +477,24 @@ pub fn emit_warning_local_variable(
 /// Emit warnings depending on the storage variable usage
 fn emit_warning_contract_variables(variable: &ast::Variable) -> Option<Diagnostic> {
     if variable.assigned && !variable.read {
-        return Some(Diagnostic::warning(
-            variable.loc,
-            format!(
-                "storage variable '{}' has been assigned, but never read",
-                variable.name
-            ),
-        ));
+        return Some(
+            Diagnostic::warning(
+                variable.loc,
+                format!(
+                    "storage variable '{}' has been assigned, but never read",
+                    variable.name
+                ),
+            )
+            .with_code(codes::UNUSED_STORAGE_VARIABLE),
+        );
     } else if !variable.assigned && !variable.read {
-        return Some(Diagnostic::warning(
-            variable.loc,
-            format!("storage variable '{}' has never been used", variable.name),
-        ));
+        return Some(
+            Diagnostic::warning(
+                variable.loc,
+                format!("storage variable '{}' has never been used", variable.name),
+            )
+            .with_code(codes::UNUSED_STORAGE_VARIABLE),
+        );
     }
 
     //Solidity attributes zero value to contract values that have never been assigned
@@ -473,10 +516,13 @@ pub fn check_unused_namespace_variables(ns: &mut Namespace) {
     // Global constants should have been initialized during declaration
     for constant in &ns.constants {
         if !constant.read {
-            ns.diagnostics.push(Diagnostic::warning(
-                constant.loc,
-                format!("global constant '{}' has never been used", constant.name),
-            ));
+            ns.diagnostics.push(
+                Diagnostic::warning(
+                    constant.loc,
+                    format!("global constant '{}' has never been used", constant.name),
+                )
+                .with_code(codes::UNUSED_CONSTANT),
+            );
         }
     }
 }
@@ -552,10 +598,13 @@ pub fn check_unused_events(ns: &mut Namespace) {
                 }
             }
 
-            ns.diagnostics.push(Diagnostic::warning(
-                event.id.loc,
-                format!("event '{}' has never been emitted", event.id),
-            ));
+            ns.diagnostics.push(
+                Diagnostic::warning(
+                    event.id.loc,
+                    format!("event '{}' has never been emitted", event.id),
+                )
+                .with_code(codes::UNUSED_EVENT),
+            );
         }
     }
 }
@@ -592,10 +641,13 @@ pub fn check_unused_errors(ns: &mut Namespace) {
                 }
             }
 
-            ns.diagnostics.push(Diagnostic::warning(
-                error.loc,
-                format!("error '{}' has never been used", error.name),
-            ));
+            ns.diagnostics.push(
+                Diagnostic::warning(
+                    error.loc,
+                    format!("error '{}' has never been used", error.name),
+                )
+                .with_code(codes::UNUSED_ERROR),
+            );
         }
     }
 }
@@ -15,6 +15,7 @@ use super::{
 };
 use crate::sema::expression::resolve_expression::expression;
 use crate::sema::namespace::ResolveTypeContext;
+use crate::Target;
 use solang_parser::{
     doccomment::DocComment,
     pt::{self, CodeLocation, OptionalCodeLocation},
@@ -135,6 +136,7 @@ pub fn variable_decl<'a>(
     let mut constant = false;
     let mut visibility: Option<pt::Visibility> = None;
     let mut has_immutable: Option<pt::Loc> = None;
+    let mut has_transient: Option<pt::Loc> = None;
     let mut is_override: Option<(pt::Loc, Vec<usize>)> = None;
 
     for attr in attrs {
@@ -159,6 +161,17 @@ pub fn variable_decl<'a>(
                 }
                 has_immutable = Some(*loc);
             }
+            pt::VariableAttribute::Transient(loc) => {
+                if let Some(prev) = &has_transient {
+                    ns.diagnostics.push(Diagnostic::error_with_note(
+                        *loc,
+                        "duplicate 'transient' attribute".to_string(),
+                        *prev,
+                        "previous 'transient' attribute".to_string(),
+                    ));
+                }
+                has_transient = Some(*loc);
+            }
             pt::VariableAttribute::Override(loc, bases) => {
                 if let Some((prev, _)) = &is_override {
                     ns.diagnostics.push(Diagnostic::error_with_note(
@@ -246,6 +259,38 @@ pub fn variable_decl<'a>(
         }
     }
 
+    if let Some(loc) = has_transient {
+        if ns.target == Target::Soroban {
+            ns.diagnostics.push(Diagnostic::error(
+                loc,
+                "transient storage is not supported on Soroban".to_string(),
+            ));
+            has_transient = None;
+        } else if constant || has_immutable.is_some() {
+            ns.diagnostics.push(Diagnostic::error(
+                loc,
+                format!(
+                    "variable cannot be declared both 'transient' and '{}'",
+                    if constant { "constant" } else { "immutable" }
+                ),
+            ));
+            has_transient = None;
+        } else if let Some(initializer) = &def.initializer {
+            ns.diagnostics.push(Diagnostic::error(
+                initializer.loc(),
+                "transient variable cannot have an initializer".to_string(),
+            ));
+        } else if !transient_allowed(&ty, ns) {
+            ns.diagnostics.push(Diagnostic::error(
+                def.ty.loc(),
+                format!(
+                    "transient storage is not supported for type '{}', only for value types",
+                    ty.to_string(ns)
+                ),
+            ));
+        }
+    }
+
     let visibility = match visibility {
         Some(v) => v,
         None => pt::Visibility::Internal(Some(def.ty.loc())),
@@ -389,6 +434,7 @@ pub fn variable_decl<'a>(
         ty: ty.clone(),
         constant,
         immutable: has_immutable.is_some(),
+        transient: has_transient.is_some(),
         assigned: def.initializer.is_some(),
         initializer,
         read: matches!(visibility, pt::Visibility::Public(_)),
@@ -779,6 +825,21 @@ fn accessor_body(
     }
 }
 
+/// Transient storage can only hold value types
+fn transient_allowed(ty: &Type, ns: &Namespace) -> bool {
+    match ty {
+        Type::Bool
+        | Type::Address(_)
+        | Type::Int(_)
+        | Type::Uint(_)
+        | Type::Bytes(_)
+        | Type::Enum(_)
+        | Type::Contract(_) => true,
+        Type::UserType(no) => transient_allowed(&ns.user_types[*no].ty, ns),
+        _ => false,
+    }
+}
+
 pub fn resolve_initializers(
     initializers: &[DelayedResolveInitializer],
     file_no: usize,
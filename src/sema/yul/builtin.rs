@@ -19,7 +19,7 @@ impl YulBuiltinPrototype {
     pub fn is_available(&self, target: &Target) -> bool {
         match target {
             Target::EVM => self.availability[0],
-            Target::Polkadot { .. } => self.availability[1],
+            Target::Polkadot { .. } | Target::PolkadotRevive => self.availability[1],
             Target::Solana => self.availability[2],
             Target::Soroban => unimplemented!(),
         }
@@ -107,6 +107,8 @@ pub enum YulBuiltInFunction {
     Difficulty = 74,
     GasLimit = 75,
     PrevRandao = 76,
+    TLoad = 77,
+    TStore = 78,
 }
 
 // These are functions that do high level stuff in a contract and are not yet implemented.
@@ -198,6 +200,8 @@ static BUILTIN_YUL_FUNCTIONS: phf::Map<&'static str, YulBuiltInFunction> = phf_m
     "difficulty" => YulBuiltInFunction::Difficulty,
     "gaslimit" => YulBuiltInFunction::GasLimit,
     "prevrandao" => YulBuiltInFunction::PrevRandao,
+    "tload" => YulBuiltInFunction::TLoad,
+    "tstore" => YulBuiltInFunction::TStore,
 };
 
 /// Retrieved the builtin function type from an identifier name
@@ -216,6 +220,7 @@ impl YulBuiltInFunction {
         matches!(
             self,
             YulBuiltInFunction::SStore
+                | YulBuiltInFunction::TStore
                 | YulBuiltInFunction::Log0
                 | YulBuiltInFunction::Log1
                 | YulBuiltInFunction::Log2
@@ -255,6 +260,7 @@ impl YulBuiltInFunction {
                 | YulBuiltInFunction::GasLimit
                 | YulBuiltInFunction::StaticCall
                 | YulBuiltInFunction::SLoad
+                | YulBuiltInFunction::TLoad
         )
     }
 }
@@ -268,7 +274,7 @@ impl fmt::Display for YulBuiltInFunction {
 
 // Yul built-in functions.
 // Descriptions copied and slightly modified from: https://docs.soliditylang.org/en/v0.8.12/yul.html
-static YUL_BUILTIN: [YulBuiltinPrototype; 77] =
+static YUL_BUILTIN: [YulBuiltinPrototype; 79] =
     [
         YulBuiltinPrototype {
             name: "stop",
@@ -963,6 +969,24 @@ static YUL_BUILTIN: [YulBuiltinPrototype; 77] =
             stops_execution: false,
             availability: [true, false, false],
         },
+        YulBuiltinPrototype {
+            name: "tload",
+            no_args: 1,
+            no_returns: 1,
+            doc: "tload(p) returns transient storage[p]",
+            ty: YulBuiltInFunction::TLoad,
+            stops_execution: false,
+            availability: [true, true, false],
+        },
+        YulBuiltinPrototype {
+            name: "tstore",
+            no_args: 2,
+            no_returns: 0,
+            doc: "tstore(p, v) stores v into transient storage[p]",
+            ty: YulBuiltInFunction::TStore,
+            stops_execution: false,
+            availability: [true, true, false],
+        },
     ];
 
 #[test]
@@ -51,13 +51,13 @@ pub(crate) fn resolve_yul_expression(
         }
         pt::YulExpression::HexStringLiteral(value, ty) => {
             if (value.hex.len() % 2) != 0 {
-                ns.diagnostics.push(Diagnostic {
-                    loc: value.loc,
-                    ty: ErrorType::DeclarationError,
-                    level: Level::Error,
-                    message: format!("hex string \"{}\" has odd number of characters", value.hex),
-                    notes: vec![],
-                });
+                ns.diagnostics.push(Diagnostic::new(
+                    value.loc,
+                    Level::Error,
+                    ErrorType::DeclarationError,
+                    format!("hex string \"{}\" has odd number of characters", value.hex),
+                    vec![],
+                ));
                 return Err(());
             }
 
@@ -166,13 +166,13 @@ fn resolve_number_literal(
     let new_type = if let Some(type_id) = ty {
         if let Some(asm_type) = get_type_from_string(&type_id.name) {
             if matches!(asm_type, Type::Uint(_)) && matches!(value.sign(), Sign::Minus) {
-                ns.diagnostics.push(Diagnostic {
-                    loc: *loc,
-                    level: Level::Error,
-                    ty: ErrorType::TypeError,
-                    message: "signed integer cannot fit in unsigned integer".to_string(),
-                    notes: vec![],
-                });
+                ns.diagnostics.push(Diagnostic::new(
+                    *loc,
+                    Level::Error,
+                    ErrorType::TypeError,
+                    "signed integer cannot fit in unsigned integer".to_string(),
+                    vec![],
+                ));
                 return Err(());
             }
             asm_type
@@ -195,15 +195,15 @@ fn resolve_number_literal(
     };
 
     if bits_needed > type_size as u64 {
-        ns.diagnostics.push(Diagnostic {
-            level: Level::Error,
-            ty: ErrorType::TypeError,
-            loc: *loc,
-            message: format!(
+        ns.diagnostics.push(Diagnostic::new(
+            *loc,
+            Level::Error,
+            ErrorType::TypeError,
+            format!(
                 "the provided literal requires {bits_needed} bits, but the type only supports {type_size}"
             ),
-            notes: vec![],
-        });
+            vec![],
+        ));
     }
 
     Ok(YulExpression::NumberLiteral(*loc, value, new_type))
@@ -221,17 +221,17 @@ fn resolve_hex_literal(
     let val = BigInt::from_str_radix(&s, 16).unwrap();
     let type_size = new_type.get_type_size();
     if val.bits() > type_size as u64 {
-        ns.diagnostics.push(Diagnostic {
-            level: Level::Error,
-            ty: ErrorType::TypeError,
-            loc: *loc,
-            message: format!(
+        ns.diagnostics.push(Diagnostic::new(
+            *loc,
+            Level::Error,
+            ErrorType::TypeError,
+            format!(
                 "the provided literal requires {} bits, but the type only supports {}",
                 val.bits(),
                 type_size
             ),
-            notes: vec![],
-        });
+            vec![],
+        ));
     }
 
     Ok(YulExpression::NumberLiteral(*loc, val, new_type))
@@ -247,17 +247,17 @@ fn resolve_string_literal(
     let type_size = new_type.get_type_size();
 
     if byte_array.len() * 8 > type_size as usize {
-        ns.diagnostics.push(Diagnostic {
-            level: Level::Error,
-            ty: ErrorType::DeclarationError,
-            loc: *loc,
-            message: format!(
+        ns.diagnostics.push(Diagnostic::new(
+            *loc,
+            Level::Error,
+            ErrorType::DeclarationError,
+            format!(
                 "the provided literal requires {} bits, but the type only supports {}",
                 byte_array.len() * 8,
                 type_size
             ),
-            notes: vec![],
-        });
+            vec![],
+        ));
     }
 
     Ok(YulExpression::StringLiteral(*loc, byte_array, new_type))
@@ -401,18 +401,18 @@ pub(crate) fn resolve_function_call(
             return Err(());
         }
         if prototype.no_args as usize != func_call.arguments.len() {
-            ns.diagnostics.push(Diagnostic {
-                level: Level::Error,
-                ty: ErrorType::TypeError,
-                loc: func_call.loc,
-                message: format!(
+            ns.diagnostics.push(Diagnostic::new(
+                func_call.loc,
+                Level::Error,
+                ErrorType::TypeError,
+                format!(
                     "builtin function '{}' requires {} arguments, but {} were provided",
                     prototype.name,
                     prototype.no_args,
                     func_call.arguments.len()
                 ),
-                notes: vec![],
-            });
+                vec![],
+            ));
             return Err(());
         }
 
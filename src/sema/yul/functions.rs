@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::sema::ast::{Namespace, Parameter, Type};
+use crate::sema::diagnostics::codes;
 use crate::sema::expression::ExprContext;
 use crate::sema::symtable::{LoopScopes, Symtable, VariableInitializer, VariableUsage};
 use crate::sema::yul::ast::YulFunction;
@@ -55,10 +56,13 @@ impl FunctionsTable {
             if header.called {
                 self.resolved_functions[*function_no - self.offset].called = true;
             } else {
-                ns.diagnostics.push(Diagnostic::warning(
-                    header.id.loc,
-                    "yul function has never been used".to_string(),
-                ));
+                ns.diagnostics.push(
+                    Diagnostic::warning(
+                        header.id.loc,
+                        "yul function has never been used".to_string(),
+                    )
+                    .with_code(codes::UNUSED_FUNCTION),
+                );
             }
         }
     }
@@ -87,16 +91,16 @@ impl FunctionsTable {
         returns: Vec<Parameter<Type>>,
     ) -> Option<Diagnostic> {
         if let Some(func) = self.find(&id.name) {
-            return Some(Diagnostic {
-                level: Level::Error,
-                ty: ErrorType::DeclarationError,
-                loc: id.loc,
-                message: format!("function name '{}' is already taken", id.name),
-                notes: vec![Note {
+            return Some(Diagnostic::new(
+                id.loc,
+                Level::Error,
+                ErrorType::DeclarationError,
+                format!("function name '{}' is already taken", id.name),
+                vec![Note {
                     loc: func.id.loc,
                     message: "previous declaration found here".to_string(),
                 }],
-            });
+            ));
         }
 
         self.scopes
@@ -185,16 +189,16 @@ pub(crate) fn process_function_header(
     ns: &mut Namespace,
 ) {
     if let Some(defined_func) = functions_table.find(&func_def.id.name) {
-        ns.diagnostics.push(Diagnostic {
-            level: Level::Error,
-            ty: ErrorType::DeclarationError,
-            loc: func_def.id.loc,
-            message: format!("function '{}' is already defined", func_def.id.name),
-            notes: vec![Note {
+        ns.diagnostics.push(Diagnostic::new(
+            func_def.id.loc,
+            Level::Error,
+            ErrorType::DeclarationError,
+            format!("function '{}' is already defined", func_def.id.name),
+            vec![Note {
                 loc: defined_func.id.loc,
                 message: "found definition here".to_string(),
             }],
-        });
+        ));
         return;
     } else if parse_builtin_keyword(&func_def.id.name).is_some()
         || yul_unsupported_builtin(&func_def.id.name)
@@ -227,16 +227,16 @@ fn resolve_variable_declaration(
     let mut added_variables: Vec<(usize, Type)> = Vec::with_capacity(variables.len());
     for item in variables {
         if let Some(func) = function_table.find(&item.id.name) {
-            ns.diagnostics.push(Diagnostic {
-                level: Level::Error,
-                ty: ErrorType::DeclarationError,
-                loc: item.loc,
-                message: format!("name '{}' has been defined as a function", item.id.name),
-                notes: vec![Note {
+            ns.diagnostics.push(Diagnostic::new(
+                item.loc,
+                Level::Error,
+                ErrorType::DeclarationError,
+                format!("name '{}' has been defined as a function", item.id.name),
+                vec![Note {
                     loc: func.id.loc,
                     message: "function defined here".to_string(),
                 }],
-            });
+            ));
             return Err(());
         } else if yul_unsupported_builtin(&item.id.name)
             || parse_builtin_keyword(&item.id.name).is_some()
@@ -367,6 +367,7 @@ fn resolve_variable_contract() {
         visibility: Visibility::Public(None),
         constant: true,
         immutable: false,
+        transient: false,
         initializer: None,
         assigned: false,
         read: false,
@@ -379,6 +380,7 @@ fn resolve_variable_contract() {
         visibility: Visibility::Public(None),
         constant: false,
         immutable: false,
+        transient: false,
         initializer: None,
         assigned: false,
         read: false,
@@ -392,6 +394,7 @@ fn resolve_variable_contract() {
         visibility: Visibility::Public(None),
         constant: false,
         immutable: true,
+        transient: false,
         initializer: None,
         assigned: false,
         read: false,
@@ -407,6 +410,7 @@ fn resolve_variable_contract() {
         visibility: Visibility::Public(None),
         constant: true,
         immutable: false,
+        transient: false,
         initializer: None,
         assigned: false,
         read: false,
@@ -918,6 +922,7 @@ fn test_member_access() {
         visibility: Visibility::Public(None),
         constant: false,
         immutable: false,
+        transient: false,
         initializer: None,
         assigned: false,
         read: false,
@@ -1040,6 +1045,7 @@ fn test_check_types() {
         visibility: Visibility::Public(None),
         constant: true,
         immutable: false,
+        transient: false,
         initializer: None,
         assigned: false,
         read: false,
@@ -1144,7 +1150,7 @@ contract testTypes {
 
     let ns = parse(file);
     assert!(ns.diagnostics.contains_message(
-        r#"unrecognised token ':=', expected "abstract", "address", "anonymous", "as", "assembly", "bool", "break", "byte", "bytes", "calldata", "catch", "constant", "constructor", "continue", "contract", "do", "else", "emit", "enum", "event", "external", "fallback", "for", "function", "if", "immutable", "import", "indexed", "interface", "internal", "is", "leave", "let", "library", "mapping", "memory", "modifier", "new", "override", "payable", "pragma", "private", "public", "pure", "receive", "return", "returns", "revert", "storage", "string", "struct", "switch", "throw", "try", "unchecked", "using", "view", "virtual", "while", "{", "}", Int, Uint, identifier"#
+        r#"unrecognised token ':=', expected "abstract", "address", "anonymous", "as", "assembly", "bool", "break", "byte", "bytes", "calldata", "catch", "constant", "constructor", "continue", "contract", "do", "else", "emit", "enum", "event", "external", "fallback", "for", "function", "if", "immutable", "import", "indexed", "interface", "internal", "is", "leave", "let", "library", "mapping", "memory", "modifier", "new", "override", "payable", "pragma", "private", "public", "pure", "receive", "return", "returns", "revert", "storage", "string", "struct", "switch", "throw", "transient", "try", "unchecked", "using", "view", "virtual", "while", "{", "}", Int, Uint, identifier"#
     ));
 
     let file = r#"
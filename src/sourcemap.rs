@@ -0,0 +1,864 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Source maps from offsets in the linked binary to locations in the Solidity source, as written
+//! by `solang compile -g` and read by `solang symbolize`.
+//!
+//! The source map is built from the DWARF line table which LLVM emits when debug information is
+//! enabled. For Wasm, the line table is in the `.debug_line` custom section; for Solana, it is in
+//! the `.debug_line` section of the ELF shared object.
+//!
+//! Offsets in a Wasm source map are offsets into the module, which is what Wasm runtimes report
+//! for traps. Offsets in a Solana source map are instruction indexes from the start of the
+//! `.text` section, which is the program counter reported by the Solana runtime.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use wasmparser::{Parser, Payload};
+
+/// The current version of the source map format
+pub const VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct SourceMap {
+    pub version: u32,
+    /// Source file names, indexed by the file field of an entry
+    pub files: Vec<String>,
+    /// Entries sorted by offset. An entry covers the offsets up to the next entry; entries with
+    /// line 0 cover code which does not correspond to any source location
+    pub entries: Vec<SourceMapEntry>,
+}
+
+/// An entry is serialized as `[offset, file, line, column]`, to keep the source map small
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct SourceMapEntry(pub u64, pub usize, pub u32, pub u32);
+
+/// A source location found for an offset
+#[derive(Debug, PartialEq)]
+pub struct SourceLocation<'a> {
+    pub file: &'a str,
+    /// The line number, starting at 1
+    pub line: u32,
+    /// The column number, starting at 1. 0 means the column is not known
+    pub column: u32,
+}
+
+impl SourceMap {
+    /// Build the source map from a linked Wasm module or Solana ELF shared object
+    pub fn from_binary(code: &[u8]) -> Result<SourceMap, String> {
+        let (debug_line, strings, code) = if code.starts_with(b"\0asm") {
+            wasm_sections(code)?
+        } else if code.starts_with(b"\x7fELF") {
+            elf_sections(code)?
+        } else {
+            return Err("not a Wasm module or ELF shared object".into());
+        };
+
+        let Some(debug_line) = debug_line else {
+            return Err("no debug information found, compile with -g".into());
+        };
+
+        let rows = parse_line_table(debug_line, &strings)?;
+
+        let mut map = SourceMap {
+            version: VERSION,
+            files: Vec::new(),
+            entries: Vec::new(),
+        };
+
+        let mut entries = Vec::new();
+
+        for row in rows {
+            let Some(offset) = code.offset(row.address) else {
+                continue;
+            };
+
+            let file = match map.files.iter().position(|f| *f == row.file) {
+                Some(no) => no,
+                None => {
+                    map.files.push(row.file);
+                    map.files.len() - 1
+                }
+            };
+
+            entries.push(SourceMapEntry(offset, file, row.line, row.column));
+        }
+
+        // the sort is stable, so rows for the same offset stay in the order of the line table
+        entries.sort_by_key(|entry| entry.0);
+
+        for entry in entries {
+            match map.entries.last_mut() {
+                // the last row for an offset applies, but the end of a sequence must not hide the
+                // start of the next sequence
+                Some(last) if last.0 == entry.0 => {
+                    if entry.2 != 0 || last.2 == 0 {
+                        *last = entry;
+                    }
+                }
+                _ => map.entries.push(entry),
+            }
+        }
+
+        Ok(map)
+    }
+
+    /// Find the source location for an offset in the binary
+    pub fn lookup(&self, offset: u64) -> Option<SourceLocation<'_>> {
+        let pos = self.entries.partition_point(|entry| entry.0 <= offset);
+
+        let SourceMapEntry(_, file, line, column) = self.entries[..pos].last()?;
+
+        if *line == 0 {
+            return None;
+        }
+
+        Some(SourceLocation {
+            file: self.files.get(*file)?,
+            line: *line,
+            column: *column,
+        })
+    }
+}
+
+/// The `.debug_line` section, the string sections, and where the code is
+type Sections<'a> = (Option<&'a [u8]>, Strings<'a>, Code);
+
+/// How addresses in the line table are converted to offsets in the source map
+struct Code {
+    /// The address of the first instruction
+    address: u64,
+    /// The size of an instruction, or 1 if offsets are in bytes
+    insn_size: u64,
+    /// The offset of the first instruction
+    offset: u64,
+}
+
+impl Code {
+    fn offset(&self, address: u64) -> Option<u64> {
+        Some(address.checked_sub(self.address)? / self.insn_size + self.offset)
+    }
+}
+
+/// The string sections which the line table may refer to
+#[derive(Default)]
+struct Strings<'a> {
+    debug_str: &'a [u8],
+    debug_line_str: &'a [u8],
+}
+
+/// In Wasm, the addresses in the line table are relative to the start of the code section
+fn wasm_sections(code: &[u8]) -> Result<Sections<'_>, String> {
+    let mut debug_line = None;
+    let mut strings = Strings::default();
+    let mut code_start = 0;
+
+    for payload in Parser::new(0).parse_all(code) {
+        match payload.map_err(|err| err.to_string())? {
+            Payload::CodeSectionStart { range, .. } => {
+                code_start = range.start as u64;
+            }
+            Payload::CustomSection(section) => match section.name() {
+                ".debug_line" => debug_line = Some(section.data()),
+                ".debug_str" => strings.debug_str = section.data(),
+                ".debug_line_str" => strings.debug_line_str = section.data(),
+                _ => (),
+            },
+            _ => (),
+        }
+    }
+
+    let code = Code {
+        address: 0,
+        insn_size: 1,
+        offset: code_start,
+    };
+
+    Ok((debug_line, strings, code))
+}
+
+/// Solana programs are 64 bit little endian ELF files. Each BPF instruction is 8 bytes
+fn elf_sections(code: &[u8]) -> Result<Sections<'_>, String> {
+    let invalid = || "invalid ELF file".to_string();
+
+    let section_headers = read_u64(code, 0x28).ok_or_else(invalid)? as usize;
+    let entry_size = read_u16(code, 0x3a).ok_or_else(invalid)? as usize;
+    let count = read_u16(code, 0x3c).ok_or_else(invalid)? as usize;
+    let names_index = read_u16(code, 0x3e).ok_or_else(invalid)? as usize;
+
+    let section = |no: usize| -> Option<(u32, u64, &[u8])> {
+        let header = section_headers.checked_add(no.checked_mul(entry_size)?)?;
+        let name = read_u32(code, header)?;
+        let addr = read_u64(code, header + 16)?;
+        let offset = read_u64(code, header + 24)? as usize;
+        let size = read_u64(code, header + 32)? as usize;
+
+        // sections like .bss have no data in the file
+        let data = offset
+            .checked_add(size)
+            .and_then(|end| code.get(offset..end))
+            .unwrap_or_default();
+
+        Some((name, addr, data))
+    };
+
+    let (_, _, names) = section(names_index).ok_or_else(invalid)?;
+
+    let mut debug_line = None;
+    let mut strings = Strings::default();
+    let mut text_start = 0;
+
+    for no in 0..count {
+        let (name, addr, data) = section(no).ok_or_else(invalid)?;
+
+        match read_str(names, name as usize).ok_or_else(invalid)? {
+            ".text" => text_start = addr,
+            ".debug_line" => debug_line = Some(data),
+            ".debug_str" => strings.debug_str = data,
+            ".debug_line_str" => strings.debug_line_str = data,
+            _ => (),
+        }
+    }
+
+    let code = Code {
+        address: text_start,
+        insn_size: 8,
+        offset: 0,
+    };
+
+    Ok((debug_line, strings, code))
+}
+
+/// A row of the line table
+#[derive(Debug, PartialEq)]
+struct Row {
+    address: u64,
+    file: String,
+    line: u32,
+    column: u32,
+}
+
+/// Run the line number programs in the `.debug_line` section, and return all the rows. The end
+/// of a sequence is returned as a row with line 0.
+fn parse_line_table(section: &[u8], strings: &Strings) -> Result<Vec<Row>, String> {
+    let mut rows = Vec::new();
+    let mut reader = Reader {
+        data: section,
+        pos: 0,
+    };
+
+    while reader.pos < section.len() {
+        parse_unit(&mut reader, strings, &mut rows)
+            .ok_or_else(|| format!("invalid line table at offset {}", reader.pos))?;
+    }
+
+    Ok(rows)
+}
+
+fn parse_unit(reader: &mut Reader, strings: &Strings, rows: &mut Vec<Row>) -> Option<()> {
+    let (unit_length, offset_size) = match reader.u32()? {
+        0xffff_ffff => (reader.u64()? as usize, 8),
+        length => (length as usize, 4),
+    };
+
+    let unit_end = reader.pos.checked_add(unit_length)?;
+    let version = reader.u16()?;
+
+    if !(2..=5).contains(&version) {
+        return None;
+    }
+
+    if version >= 5 {
+        // DW_LNE_set_address gives the size of the address, so this is not needed
+        let _address_size = reader.u8()?;
+        let _segment_selector_size = reader.u8()?;
+    }
+
+    let header_length = reader.offset(offset_size)? as usize;
+    let program_start = reader.pos.checked_add(header_length)?;
+
+    let min_inst_length = reader.u8()? as u64;
+    if version >= 4 {
+        let _max_ops_per_inst = reader.u8()?;
+    }
+    let _default_is_stmt = reader.u8()?;
+    let line_base = reader.u8()? as i8 as i64;
+    let line_range = reader.u8()?;
+    let opcode_base = reader.u8()?;
+
+    if line_range == 0 {
+        return None;
+    }
+
+    let mut opcode_lengths = Vec::new();
+    for _ in 1..opcode_base {
+        opcode_lengths.push(reader.u8()?);
+    }
+
+    let mut directories = Vec::new();
+    let mut files = Vec::new();
+
+    if version >= 5 {
+        for entry in reader.entries(offset_size, strings)? {
+            directories.push(entry.0);
+        }
+        for (name, dir) in reader.entries(offset_size, strings)? {
+            files.push(join(&directories, dir, name));
+        }
+    } else {
+        // before version 5, the compilation directory is not in the list, and indexes start at 1
+        directories.push(String::new());
+        loop {
+            let dir = reader.str()?;
+            if dir.is_empty() {
+                break;
+            }
+            directories.push(dir);
+        }
+        files.push(String::new());
+        loop {
+            let name = reader.str()?;
+            if name.is_empty() {
+                break;
+            }
+            let dir = reader.uleb()?;
+            let _mtime = reader.uleb()?;
+            let _length = reader.uleb()?;
+            files.push(join(&directories, dir, name));
+        }
+    }
+
+    reader.pos = program_start;
+
+    let mut state = State::new();
+
+    let mut emit = |state: &State, files: &Vec<String>| {
+        rows.push(Row {
+            address: state.address,
+            file: files.get(state.file as usize).cloned().unwrap_or_default(),
+            line: if state.end_sequence { 0 } else { state.line },
+            column: state.column,
+        });
+    };
+
+    while reader.pos < unit_end {
+        let opcode = reader.u8()?;
+
+        if opcode >= opcode_base {
+            // special opcode
+            let adjusted = opcode - opcode_base;
+            state.address += (adjusted / line_range) as u64 * min_inst_length;
+            state.advance_line(line_base + (adjusted % line_range) as i64);
+            emit(&state, &files);
+            continue;
+        }
+
+        match opcode {
+            0 => {
+                let length = reader.uleb()? as usize;
+                let end = reader.pos.checked_add(length)?;
+                match reader.u8()? {
+                    // DW_LNE_end_sequence
+                    1 => {
+                        state.end_sequence = true;
+                        emit(&state, &files);
+                        state = State::new();
+                    }
+                    // DW_LNE_set_address
+                    2 => {
+                        state.address = reader.offset(length as u8 - 1)?;
+                    }
+                    // DW_LNE_define_file
+                    3 if version < 5 => {
+                        let name = reader.str()?;
+                        let dir = reader.uleb()?;
+                        files.push(join(&directories, dir, name));
+                    }
+                    _ => (),
+                }
+                reader.pos = end;
+            }
+            // DW_LNS_copy
+            1 => emit(&state, &files),
+            // DW_LNS_advance_pc
+            2 => state.address += reader.uleb()? * min_inst_length,
+            // DW_LNS_advance_line
+            3 => state.advance_line(reader.sleb()?),
+            // DW_LNS_set_file
+            4 => state.file = reader.uleb()?,
+            // DW_LNS_set_column
+            5 => state.column = reader.uleb()? as u32,
+            // DW_LNS_const_add_pc
+            8 => state.address += ((255 - opcode_base) / line_range) as u64 * min_inst_length,
+            // DW_LNS_fixed_advance_pc
+            9 => state.address += reader.u16()? as u64,
+            // opcodes without arguments, or with arguments which are not needed
+            _ => {
+                for _ in 0..opcode_lengths[opcode as usize - 1] {
+                    reader.uleb()?;
+                }
+            }
+        }
+    }
+
+    reader.pos = unit_end;
+
+    Some(())
+}
+
+/// The state machine of the line number program. Only the registers needed for a source map
+/// are kept.
+struct State {
+    address: u64,
+    file: u64,
+    line: u32,
+    column: u32,
+    end_sequence: bool,
+}
+
+impl State {
+    fn new() -> Self {
+        State {
+            address: 0,
+            file: 1,
+            line: 1,
+            column: 0,
+            end_sequence: false,
+        }
+    }
+
+    fn advance_line(&mut self, delta: i64) {
+        self.line = (self.line as i64 + delta) as u32;
+    }
+}
+
+/// Join a file name with its directory, unless the name is already an absolute path
+fn join(directories: &[String], dir: u64, name: String) -> String {
+    match directories.get(dir as usize) {
+        Some(dir) if !dir.is_empty() && dir != "." && !Path::new(&name).is_absolute() => {
+            Path::new(dir).join(name).display().to_string()
+        }
+        _ => name,
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(self.bytes(2)?.try_into().ok()?))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.bytes(4)?.try_into().ok()?))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.bytes(8)?.try_into().ok()?))
+    }
+
+    /// A little endian value of the given size
+    fn offset(&mut self, size: u8) -> Option<u64> {
+        match size {
+            1 => self.u8().map(u64::from),
+            2 => self.u16().map(u64::from),
+            4 => self.u32().map(u64::from),
+            8 => self.u64(),
+            _ => None,
+        }
+    }
+
+    fn uleb(&mut self) -> Option<u64> {
+        let mut value = 0u64;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.u8()?;
+            if shift < 64 {
+                value |= ((byte & 0x7f) as u64) << shift;
+            }
+            shift += 7;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+    }
+
+    fn sleb(&mut self) -> Option<i64> {
+        let mut value = 0i64;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.u8()?;
+            if shift < 64 {
+                value |= ((byte & 0x7f) as i64) << shift;
+            }
+            shift += 7;
+            if byte & 0x80 == 0 {
+                if shift < 64 && byte & 0x40 != 0 {
+                    value |= -1 << shift;
+                }
+                return Some(value);
+            }
+        }
+    }
+
+    /// A nul terminated string
+    fn str(&mut self) -> Option<String> {
+        let s = read_str(self.data, self.pos)?;
+        self.pos += s.len() + 1;
+        Some(s.to_owned())
+    }
+
+    /// The directory or file entries of a version 5 line table header, as a list of paths and
+    /// directory indexes
+    fn entries(&mut self, offset_size: u8, strings: &Strings) -> Option<Vec<(String, u64)>> {
+        let format_count = self.u8()?;
+        let mut format = Vec::new();
+
+        for _ in 0..format_count {
+            format.push((self.uleb()?, self.uleb()?));
+        }
+
+        let count = self.uleb()?;
+        let mut entries = Vec::new();
+
+        for _ in 0..count {
+            let mut path = String::new();
+            let mut dir = 0;
+
+            for (content_type, form) in &format {
+                let value = self.form(*form, offset_size, strings)?;
+
+                match (content_type, value) {
+                    // DW_LNCT_path
+                    (1, Value::Str(s)) => path = s,
+                    // DW_LNCT_directory_index
+                    (2, Value::Int(n)) => dir = n,
+                    _ => (),
+                }
+            }
+
+            entries.push((path, dir));
+        }
+
+        Some(entries)
+    }
+
+    fn form(&mut self, form: u64, offset_size: u8, strings: &Strings) -> Option<Value> {
+        Some(match form {
+            // DW_FORM_string
+            0x08 => Value::Str(self.str()?),
+            // DW_FORM_line_strp
+            0x1f => {
+                let offset = self.offset(offset_size)? as usize;
+                Value::Str(read_str(strings.debug_line_str, offset)?.to_owned())
+            }
+            // DW_FORM_strp
+            0x0e => {
+                let offset = self.offset(offset_size)? as usize;
+                Value::Str(read_str(strings.debug_str, offset)?.to_owned())
+            }
+            // DW_FORM_udata
+            0x0f => Value::Int(self.uleb()?),
+            // DW_FORM_sdata
+            0x0d => Value::Int(self.sleb()? as u64),
+            // DW_FORM_data1, DW_FORM_data2, DW_FORM_data4, DW_FORM_data8
+            0x0b => Value::Int(self.offset(1)?),
+            0x05 => Value::Int(self.offset(2)?),
+            0x06 => Value::Int(self.offset(4)?),
+            0x07 => Value::Int(self.offset(8)?),
+            // DW_FORM_data16, used for MD5 checksums
+            0x1e => {
+                self.bytes(16)?;
+                Value::None
+            }
+            // DW_FORM_block
+            0x09 => {
+                let len = self.uleb()? as usize;
+                self.bytes(len)?;
+                Value::None
+            }
+            _ => return None,
+        })
+    }
+}
+
+enum Value {
+    Str(String),
+    Int(u64),
+    None,
+}
+
+fn read_str(data: &[u8], offset: usize) -> Option<&str> {
+    let data = data.get(offset..)?;
+    let end = data.iter().position(|b| *b == 0)?;
+    std::str::from_utf8(&data[..end]).ok()
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        data.get(offset..offset.checked_add(2)?)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        data.get(offset..offset.checked_add(4)?)?.try_into().ok()?,
+    ))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(
+        data.get(offset..offset.checked_add(8)?)?.try_into().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A version 4 line table for `dir/a.sol`, with rows at lines 3, 4 and 10
+    fn line_table_v4() -> Vec<u8> {
+        let mut header = vec![
+            1,    // minimum_instruction_length
+            1,    // maximum_operations_per_instruction
+            1,    // default_is_stmt
+            0xfb, // line_base -5
+            14,   // line_range
+            13,   // opcode_base
+            0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1, // standard_opcode_lengths
+        ];
+        header.extend(b"dir\0\0");
+        header.extend(b"a.sol\0\x01\0\0\0");
+
+        let program = vec![
+            0,
+            9,
+            2,
+            0x10,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0,
+            0, // DW_LNE_set_address 0x10
+            3,
+            2, // DW_LNS_advance_line 3
+            5,
+            7, // DW_LNS_set_column 7
+            1, // DW_LNS_copy
+            // special opcode: address += 2, line += 1
+            13 + (1 + 5) + 2 * 14,
+            3,
+            6, // DW_LNS_advance_line 10
+            2,
+            4, // DW_LNS_advance_pc 4
+            1, // DW_LNS_copy
+            2,
+            3, // DW_LNS_advance_pc 3
+            0,
+            1,
+            1, // DW_LNE_end_sequence
+        ];
+
+        let mut unit = Vec::new();
+        unit.extend(4u16.to_le_bytes());
+        unit.extend((header.len() as u32).to_le_bytes());
+        unit.extend(header);
+        unit.extend(program);
+
+        let mut section = (unit.len() as u32).to_le_bytes().to_vec();
+        section.extend(unit);
+        section
+    }
+
+    #[test]
+    fn line_program() {
+        let rows = parse_line_table(&line_table_v4(), &Strings::default()).unwrap();
+
+        let row = |address, line, column| Row {
+            address,
+            file: "dir/a.sol".into(),
+            line,
+            column,
+        };
+
+        assert_eq!(
+            rows,
+            vec![
+                row(0x10, 3, 7),
+                row(0x12, 4, 7),
+                row(0x16, 10, 7),
+                row(0x19, 0, 7)
+            ]
+        );
+    }
+
+    #[test]
+    fn line_program_v5() {
+        let line_str = b"/src\0a.sol\0";
+
+        let mut header = vec![1, 1, 1, 0xfb, 14, 13, 0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1];
+        // directories: DW_LNCT_path as DW_FORM_line_strp
+        header.extend([1, 1, 0x1f, 1, 0, 0, 0, 0]);
+        // files: DW_LNCT_path as DW_FORM_line_strp, DW_LNCT_directory_index as DW_FORM_udata,
+        // DW_LNCT_MD5 as DW_FORM_data16
+        header.extend([3, 1, 0x1f, 2, 0x0b, 5, 0x1e, 1, 5, 0, 0, 0, 0]);
+        header.extend([0; 16]);
+
+        let program = vec![
+            0, 5, 2, 0x20, 0, 0, 0, // DW_LNE_set_address 0x20, 32 bit
+            4, 0, // DW_LNS_set_file 0
+            3, 1, // DW_LNS_advance_line 2
+            1, // DW_LNS_copy
+        ];
+
+        let mut unit = Vec::new();
+        unit.extend(5u16.to_le_bytes());
+        unit.extend([4, 0]);
+        unit.extend((header.len() as u32).to_le_bytes());
+        unit.extend(header);
+        unit.extend(program);
+
+        let mut section = (unit.len() as u32).to_le_bytes().to_vec();
+        section.extend(unit);
+
+        let strings = Strings {
+            debug_str: &[],
+            debug_line_str: line_str,
+        };
+
+        assert_eq!(
+            parse_line_table(&section, &strings).unwrap(),
+            vec![Row {
+                address: 0x20,
+                file: "/src/a.sol".into(),
+                line: 2,
+                column: 0
+            }]
+        );
+    }
+
+    #[test]
+    fn lookup() {
+        let map = SourceMap {
+            version: VERSION,
+            files: vec!["a.sol".into()],
+            entries: vec![
+                SourceMapEntry(4, 0, 3, 7),
+                SourceMapEntry(10, 0, 4, 1),
+                SourceMapEntry(20, 0, 0, 0),
+            ],
+        };
+
+        assert_eq!(map.lookup(3), None);
+        assert_eq!(
+            map.lookup(4),
+            Some(SourceLocation {
+                file: "a.sol",
+                line: 3,
+                column: 7
+            })
+        );
+        assert_eq!(map.lookup(19).map(|loc| loc.line), Some(4));
+        assert_eq!(map.lookup(25), None);
+
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(
+            json,
+            r#"{"version":1,"files":["a.sol"],"entries":[[4,0,3,7],[10,0,4,1],[20,0,0,0]]}"#
+        );
+        assert_eq!(serde_json::from_str::<SourceMap>(&json).unwrap(), map);
+    }
+
+    #[test]
+    fn wasm() {
+        let debug_line = line_table_v4();
+
+        // a module with an empty code section, which starts at offset 10
+        let mut module = b"\0asm\x01\0\0\0\x0a\x01\0".to_vec();
+        module.push(0);
+        module.push((debug_line.len() + 12) as u8);
+        module.push(11);
+        module.extend(b".debug_line");
+        module.extend(debug_line);
+
+        let map = SourceMap::from_binary(&module).unwrap();
+
+        assert_eq!(map.files, vec!["dir/a.sol".to_string()]);
+        assert_eq!(
+            map.entries,
+            vec![
+                SourceMapEntry(0x1a, 0, 3, 7),
+                SourceMapEntry(0x1c, 0, 4, 7),
+                SourceMapEntry(0x20, 0, 10, 7),
+                SourceMapEntry(0x23, 0, 0, 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn elf() {
+        let debug_line = line_table_v4();
+        let names = b"\0.shstrtab\0.text\0.debug_line\0";
+
+        let mut elf = b"\x7fELF\x02\x01\x01".to_vec();
+        elf.resize(64, 0);
+
+        let names_offset = elf.len() as u64;
+        elf.extend(names);
+        let debug_line_offset = elf.len() as u64;
+        elf.extend(&debug_line);
+
+        let section_headers = elf.len() as u64;
+        elf[0x28..0x30].copy_from_slice(&section_headers.to_le_bytes());
+        elf[0x3a..0x3c].copy_from_slice(&64u16.to_le_bytes());
+        elf[0x3c..0x3e].copy_from_slice(&4u16.to_le_bytes());
+        elf[0x3e..0x40].copy_from_slice(&1u16.to_le_bytes());
+
+        // name, address, offset and size of the section; .text is not in the file
+        let sections: [(u32, u64, u64, u64); 4] = [
+            (0, 0, 0, 0),
+            (1, 0, names_offset, names.len() as u64),
+            (11, 0x10, 0x1000, 0x100),
+            (17, 0, debug_line_offset, debug_line.len() as u64),
+        ];
+
+        for (name, addr, offset, size) in sections {
+            let mut header = [0; 64];
+            header[0..4].copy_from_slice(&name.to_le_bytes());
+            header[16..24].copy_from_slice(&addr.to_le_bytes());
+            header[24..32].copy_from_slice(&offset.to_le_bytes());
+            header[32..40].copy_from_slice(&size.to_le_bytes());
+            elf.extend(header);
+        }
+
+        let map = SourceMap::from_binary(&elf).unwrap();
+
+        // instructions are 8 bytes, so several rows end up at the same instruction
+        assert_eq!(
+            map.entries,
+            vec![SourceMapEntry(0, 0, 10, 7), SourceMapEntry(1, 0, 0, 7)]
+        );
+        assert_eq!(map.lookup(0).unwrap().line, 10);
+    }
+
+    #[test]
+    fn not_a_binary() {
+        assert!(SourceMap::from_binary(b"hello").is_err());
+    }
+}
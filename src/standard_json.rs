@@ -44,6 +44,7 @@ pub struct OutputJson {
     pub ty: String,
     pub component: String,
     pub severity: String,
+    pub errorCode: String,
     pub message: String,
     pub formattedMessage: String,
 }
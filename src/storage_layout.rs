@@ -0,0 +1,589 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! The storage layout of a contract, as written by `solang compile --emit storage-layout`, and
+//! the compatibility check between two layouts done by `solang layout-diff`.
+//!
+//! Contracts which are upgraded with `set_code_hash` on Polkadot, or by upgrading the program on
+//! Solana, keep their storage. The new code must find existing state variables where the old code
+//! put them.
+
+use crate::sema::ast::{Layout, Namespace, StructType, Type};
+use crate::Target;
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct StorageLayout {
+    pub contract: String,
+    pub storage: Vec<StorageEntry>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StorageEntry {
+    /// Name of the state variable
+    pub label: String,
+    /// The contract which declares the variable; this may be a base contract
+    pub contract: String,
+    /// The storage slot. On Solana, all variables live in the data account, and this is the byte
+    /// offset into the data account
+    pub slot: String,
+    /// The byte offset into the slot. Variables are not packed into slots, so this is always 0
+    pub offset: u64,
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The number of storage slots used on Polkadot, or the number of bytes on Solana
+    pub size: String,
+    /// Where the variable is stored. Layouts which do not have this predate namespaced and
+    /// transient storage, so the variable is in regular storage
+    #[serde(default)]
+    pub kind: StorageKind,
+    /// If the variable is a struct, or an array or mapping of structs, the members of the struct
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<StorageMember>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub struct StorageMember {
+    /// Name of the struct member
+    pub label: String,
+    /// The storage slot relative to the start of the struct. On Solana, this is the byte offset
+    pub slot: String,
+    /// The byte offset into the slot. Members are not packed into slots, so this is always 0
+    pub offset: u64,
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// The number of storage slots used on Polkadot, or the number of bytes on Solana
+    pub size: String,
+    /// The members of the struct, if this member is one itself
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<StorageMember>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageKind {
+    /// Regular contract storage, where variables are stored in declaration order
+    #[default]
+    Storage,
+    /// The storage slot given by an ERC-7201 namespace
+    Namespace(String),
+    /// Transient storage, where transient variables are stored in declaration order
+    Transient,
+}
+
+impl fmt::Display for StorageKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StorageKind::Storage => write!(f, "storage"),
+            StorageKind::Namespace(namespace) => write!(f, "storage namespace '{namespace}'"),
+            StorageKind::Transient => write!(f, "transient storage"),
+        }
+    }
+}
+
+impl StorageLayout {
+    /// Describe the storage layout of the given contract
+    pub fn new(contract_no: usize, ns: &Namespace) -> Self {
+        let contract = &ns.contracts[contract_no];
+
+        let entry = |layout: &Layout, kind: StorageKind| StorageEntry {
+            label: ns.contracts[layout.contract_no].variables[layout.var_no]
+                .name
+                .clone(),
+            contract: ns.contracts[layout.contract_no].id.name.clone(),
+            slot: layout.slot.to_string(),
+            offset: 0,
+            ty: layout.ty.to_string(ns),
+            size: layout.ty.storage_slots(ns).to_string(),
+            kind,
+            members: struct_members(&layout.ty, ns),
+        };
+
+        let storage = contract
+            .layout
+            .iter()
+            .map(|layout| {
+                let kind = match &layout.ty {
+                    Type::Struct(StructType::UserDefined(struct_no)) => ns.structs[*struct_no]
+                        .storage_namespace()
+                        .map(|namespace| StorageKind::Namespace(namespace.to_owned())),
+                    _ => None,
+                };
+
+                entry(layout, kind.unwrap_or_default())
+            })
+            .chain(
+                contract
+                    .transient_layout
+                    .iter()
+                    .map(|layout| entry(layout, StorageKind::Transient)),
+            )
+            .collect();
+
+        StorageLayout {
+            contract: contract.id.name.clone(),
+            storage,
+        }
+    }
+}
+
+/// The members of the struct stored in a variable of type `ty`. For arrays and mappings, this is
+/// the struct of the elements or values.
+fn struct_members(ty: &Type, ns: &Namespace) -> Vec<StorageMember> {
+    match ty {
+        Type::Struct(StructType::UserDefined(struct_no)) => {
+            let def = &ns.structs[*struct_no];
+            let mut slot = BigInt::from(0);
+
+            def.fields
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| !field.infinite_size)
+                .map(|(field_no, field)| {
+                    // this must match how codegen finds the struct member in storage
+                    let member_slot = if ns.target == Target::Solana {
+                        def.storage_offsets[field_no].clone()
+                    } else {
+                        slot.clone()
+                    };
+                    slot += field.ty.storage_slots(ns);
+
+                    StorageMember {
+                        label: field.name_as_str().to_owned(),
+                        slot: member_slot.to_string(),
+                        offset: 0,
+                        ty: field.ty.to_string(ns),
+                        size: field.ty.storage_slots(ns).to_string(),
+                        members: struct_members(&field.ty, ns),
+                    }
+                })
+                .collect()
+        }
+        Type::Array(elem_ty, _) => struct_members(elem_ty, ns),
+        Type::Mapping(mapping) => struct_members(&mapping.value, ns),
+        _ => Vec::new(),
+    }
+}
+
+/// A change in the storage layout which breaks existing contract storage
+#[derive(Debug, PartialEq)]
+pub enum Incompatibility {
+    /// A variable was removed
+    Removed { var: String },
+    /// A variable moved between regular storage, a storage namespace, or transient storage
+    KindChanged {
+        var: String,
+        old_kind: StorageKind,
+        new_kind: StorageKind,
+    },
+    /// The type of a variable changed
+    TypeChanged {
+        var: String,
+        old_ty: String,
+        new_ty: String,
+    },
+    /// A variable now comes before a variable which used to precede it
+    Reordered { var: String, before: String },
+    /// A new variable was inserted before an existing one
+    Inserted { var: String, before: String },
+    /// A variable moved to another location, for example because the size of a struct changed
+    Moved {
+        var: String,
+        old_slot: String,
+        old_offset: u64,
+        new_slot: String,
+        new_offset: u64,
+    },
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Incompatibility::Removed { var } => write!(f, "variable '{var}' was removed"),
+            Incompatibility::KindChanged {
+                var,
+                old_kind,
+                new_kind,
+            } => write!(f, "variable '{var}' moved from {old_kind} to {new_kind}"),
+            Incompatibility::TypeChanged {
+                var,
+                old_ty,
+                new_ty,
+            } => write!(
+                f,
+                "variable '{var}' changed type from '{old_ty}' to '{new_ty}'"
+            ),
+            Incompatibility::Reordered { var, before } => {
+                write!(f, "variable '{var}' was moved before '{before}'")
+            }
+            Incompatibility::Inserted { var, before } => write!(
+                f,
+                "variable '{var}' was inserted before existing variable '{before}'"
+            ),
+            Incompatibility::Moved {
+                var,
+                old_slot,
+                old_offset,
+                new_slot,
+                new_offset,
+            } => write!(
+                f,
+                "variable '{var}' moved from slot {old_slot} offset {old_offset} to slot {new_slot} offset {new_offset}"
+            ),
+        }
+    }
+}
+
+/// A variable or struct member, as compared by [`compare`]
+struct Item<'a> {
+    /// The name used for reporting; struct members are prefixed with their variable
+    name: String,
+    label: &'a str,
+    contract: &'a str,
+    kind: StorageKind,
+    ty: &'a str,
+    slot: &'a str,
+    offset: u64,
+    members: &'a [StorageMember],
+}
+
+impl<'a> Item<'a> {
+    fn entry(entry: &'a StorageEntry) -> Self {
+        Item {
+            name: format!("{}.{}", entry.contract, entry.label),
+            label: &entry.label,
+            contract: &entry.contract,
+            kind: entry.kind.clone(),
+            ty: &entry.ty,
+            slot: &entry.slot,
+            offset: entry.offset,
+            members: &entry.members,
+        }
+    }
+
+    fn members(&self) -> Vec<Item<'a>> {
+        self.members
+            .iter()
+            .map(|member| Item {
+                name: format!("{}.{}", self.name, member.label),
+                label: &member.label,
+                contract: self.contract,
+                kind: StorageKind::Storage,
+                ty: &member.ty,
+                slot: &member.slot,
+                offset: member.offset,
+                members: &member.members,
+            })
+            .collect()
+    }
+}
+
+/// Compare two storage layouts of the same contract. Variables are identified by their name and
+/// the contract which declares them. Adding variables after all the existing ones is compatible.
+/// The order of variables only matters within regular storage and within transient storage;
+/// variables in a storage namespace have their own slot. The members of structs are compared in
+/// the same way, including the structs in a storage namespace.
+pub fn compare(old: &StorageLayout, new: &StorageLayout) -> Vec<Incompatibility> {
+    compare_items(
+        &old.storage.iter().map(Item::entry).collect::<Vec<_>>(),
+        &new.storage.iter().map(Item::entry).collect::<Vec<_>>(),
+    )
+}
+
+fn compare_items(old: &[Item], new: &[Item]) -> Vec<Incompatibility> {
+    let mut incompatibilities = Vec::new();
+
+    // for each old item, its position in the new layout
+    let positions: Vec<Option<usize>> = old
+        .iter()
+        .map(|item| {
+            new.iter()
+                .position(|e| e.label == item.label && e.contract == item.contract)
+        })
+        .collect();
+
+    // pairs of structs which should have the same members
+    let mut structs = Vec::new();
+
+    for (old_no, item) in old.iter().enumerate() {
+        let Some(new_no) = positions[old_no] else {
+            incompatibilities.push(Incompatibility::Removed {
+                var: item.name.clone(),
+            });
+            continue;
+        };
+
+        let new_item = &new[new_no];
+
+        if item.kind != new_item.kind {
+            incompatibilities.push(Incompatibility::KindChanged {
+                var: item.name.clone(),
+                old_kind: item.kind.clone(),
+                new_kind: new_item.kind.clone(),
+            });
+            continue;
+        }
+
+        if item.ty != new_item.ty {
+            incompatibilities.push(Incompatibility::TypeChanged {
+                var: item.name.clone(),
+                old_ty: item.ty.to_owned(),
+                new_ty: new_item.ty.to_owned(),
+            });
+        } else {
+            structs.push((item, new_item));
+        }
+
+        if matches!(item.kind, StorageKind::Namespace(_)) {
+            continue;
+        }
+
+        if let Some(earlier) = (0..old_no).find(|no| {
+            old[*no].kind == item.kind && matches!(positions[*no], Some(pos) if pos > new_no)
+        }) {
+            incompatibilities.push(Incompatibility::Reordered {
+                var: item.name.clone(),
+                before: old[earlier].name.clone(),
+            });
+        }
+    }
+
+    for (new_no, item) in new.iter().enumerate() {
+        if positions.contains(&Some(new_no)) || matches!(item.kind, StorageKind::Namespace(_)) {
+            continue;
+        }
+
+        if let Some(before) = (new_no + 1..new.len())
+            .find(|no| new[*no].kind == item.kind && positions.contains(&Some(*no)))
+        {
+            incompatibilities.push(Incompatibility::Inserted {
+                var: item.name.clone(),
+                before: new[before].name.clone(),
+            });
+        }
+    }
+
+    // If none of the above explains why an item is stored elsewhere, then report that it moved
+    if incompatibilities.is_empty() {
+        for (old_no, item) in old.iter().enumerate() {
+            let new_item = &new[positions[old_no].unwrap()];
+
+            if item.slot != new_item.slot || item.offset != new_item.offset {
+                incompatibilities.push(Incompatibility::Moved {
+                    var: item.name.clone(),
+                    old_slot: item.slot.to_owned(),
+                    old_offset: item.offset,
+                    new_slot: new_item.slot.to_owned(),
+                    new_offset: new_item.offset,
+                });
+            }
+        }
+    }
+
+    for (old_item, new_item) in structs {
+        incompatibilities.extend(compare_items(&old_item.members(), &new_item.members()));
+    }
+
+    incompatibilities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout(vars: &[(&str, &str, u64)]) -> StorageLayout {
+        StorageLayout {
+            contract: "c".into(),
+            storage: vars
+                .iter()
+                .map(|(label, ty, slot)| StorageEntry {
+                    label: label.to_string(),
+                    contract: "c".into(),
+                    slot: slot.to_string(),
+                    offset: 0,
+                    ty: ty.to_string(),
+                    size: "1".into(),
+                    kind: StorageKind::Storage,
+                    members: Vec::new(),
+                })
+                .collect(),
+        }
+    }
+
+    fn members(fields: &[(&str, &str, u64)]) -> Vec<StorageMember> {
+        fields
+            .iter()
+            .map(|(label, ty, slot)| StorageMember {
+                label: label.to_string(),
+                slot: slot.to_string(),
+                offset: 0,
+                ty: ty.to_string(),
+                size: "1".into(),
+                members: Vec::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn append_is_compatible() {
+        let old = layout(&[("a", "uint64", 0), ("b", "bool", 1)]);
+        let new = layout(&[("a", "uint64", 0), ("b", "bool", 1), ("c", "address", 2)]);
+
+        assert_eq!(compare(&old, &new), vec![]);
+    }
+
+    #[test]
+    fn incompatible_changes() {
+        let old = layout(&[("a", "uint64", 0), ("b", "bool", 1)]);
+
+        let new = layout(&[("b", "bool", 0), ("a", "uint64", 1)]);
+        assert_eq!(
+            compare(&old, &new),
+            vec![Incompatibility::Reordered {
+                var: "c.b".into(),
+                before: "c.a".into()
+            }]
+        );
+
+        let new = layout(&[("a", "uint32", 0), ("b", "bool", 1)]);
+        assert_eq!(
+            compare(&old, &new),
+            vec![Incompatibility::TypeChanged {
+                var: "c.a".into(),
+                old_ty: "uint64".into(),
+                new_ty: "uint32".into()
+            }]
+        );
+
+        let new = layout(&[("a", "uint64", 0), ("x", "bytes", 1), ("b", "bool", 2)]);
+        assert_eq!(
+            compare(&old, &new),
+            vec![Incompatibility::Inserted {
+                var: "c.x".into(),
+                before: "c.b".into()
+            }]
+        );
+
+        let new = layout(&[("a", "uint64", 0)]);
+        assert_eq!(
+            compare(&old, &new),
+            vec![Incompatibility::Removed { var: "c.b".into() }]
+        );
+
+        let new = layout(&[("a", "uint64", 0), ("b", "bool", 2)]);
+        assert_eq!(
+            compare(&old, &new)
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>(),
+            vec!["variable 'c.b' moved from slot 1 offset 0 to slot 2 offset 0"]
+        );
+    }
+
+    #[test]
+    fn storage_kinds() {
+        let mut old = layout(&[("a", "uint64", 0), ("b", "bool", 1), ("t", "bool", 0)]);
+        let mut new = layout(&[("a", "uint64", 0), ("b", "bool", 1), ("t", "bool", 0)]);
+        old.storage[2].kind = StorageKind::Transient;
+        new.storage[2].kind = StorageKind::Transient;
+
+        assert_eq!(compare(&old, &new), vec![]);
+
+        // moving into a namespace changes the slot, but that is not the problem
+        new.storage[1].kind = StorageKind::Namespace("example.main".into());
+        new.storage[1].slot = "123".into();
+        assert_eq!(
+            compare(&old, &new)
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>(),
+            vec!["variable 'c.b' moved from storage to storage namespace 'example.main'"]
+        );
+
+        // and out again
+        assert_eq!(
+            compare(&new, &old)
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>(),
+            vec!["variable 'c.b' moved from storage namespace 'example.main' to storage"]
+        );
+
+        // variables in a namespace may be added anywhere, and transient storage has its own order
+        let mut new = layout(&[
+            ("t2", "bool", 0),
+            ("a", "uint64", 0),
+            ("n", "bool", 123),
+            ("b", "bool", 1),
+            ("t", "bool", 1),
+        ]);
+        new.storage[0].kind = StorageKind::Transient;
+        new.storage[2].kind = StorageKind::Namespace("example.main".into());
+        new.storage[4].kind = StorageKind::Transient;
+        assert_eq!(
+            compare(&old, &new),
+            vec![Incompatibility::Inserted {
+                var: "c.t2".into(),
+                before: "c.t".into()
+            }]
+        );
+
+        new.storage.remove(0);
+        new.storage[3].slot = "0".into();
+        assert_eq!(compare(&old, &new), vec![]);
+
+        // layouts written before the kind was recorded are in regular storage
+        let entry: StorageEntry = serde_json::from_str(
+            r#"{"label":"a","contract":"c","slot":"0","offset":0,"type":"uint64","size":"1"}"#,
+        )
+        .unwrap();
+        assert_eq!(entry.kind, StorageKind::Storage);
+    }
+
+    #[test]
+    fn struct_members() {
+        let mut old = layout(&[("s", "struct S", 0), ("n", "struct N", 123)]);
+        old.storage[0].members = members(&[("a", "uint64", 0), ("b", "bool", 1)]);
+        old.storage[1].kind = StorageKind::Namespace("example.main".into());
+        old.storage[1].members = members(&[("x", "uint64", 0), ("y", "address", 1)]);
+
+        let mut new = layout(&[("s", "struct S", 0), ("n", "struct N", 123)]);
+        new.storage[0].members = members(&[("b", "bool", 0), ("a", "uint64", 1)]);
+        new.storage[1].kind = StorageKind::Namespace("example.main".into());
+        new.storage[1].members =
+            members(&[("z", "bool", 0), ("x", "uint32", 1), ("y", "address", 2)]);
+
+        assert_eq!(
+            compare(&old, &new)
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>(),
+            vec![
+                "variable 'c.s.b' was moved before 'c.s.a'",
+                "variable 'c.n.x' changed type from 'uint64' to 'uint32'",
+                "variable 'c.n.z' was inserted before existing variable 'c.n.x'",
+            ]
+        );
+
+        // appending to a namespaced struct is fine
+        new.storage[0].members = members(&[("a", "uint64", 0), ("b", "bool", 1)]);
+        new.storage[1].members =
+            members(&[("x", "uint64", 0), ("y", "address", 1), ("z", "bool", 2)]);
+        assert_eq!(compare(&old, &new), vec![]);
+
+        // a struct member which moved, because a struct it uses grew
+        old.storage[0].members[0].ty = "struct P".into();
+        old.storage[0].members[0].members = members(&[("p", "bool", 0)]);
+        new.storage[0].members[0].ty = "struct P".into();
+        new.storage[0].members[0].members = members(&[("p", "bool", 0), ("q", "bool", 1)]);
+        new.storage[0].members[1].slot = "2".into();
+        assert_eq!(
+            compare(&old, &new)
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>(),
+            vec!["variable 'c.s.b' moved from slot 1 offset 0 to slot 2 offset 0"]
+        );
+    }
+}
@@ -5,7 +5,7 @@
 #include <stdbool.h>
 #include "stdlib.h"
 
-#ifndef __wasm__
+#if !defined(__wasm__) && !defined(__riscv)
 #include "solana_sdk.h"
 #endif
 
@@ -35,6 +35,19 @@ void __init_heap()
     first->allocated = false;
     first->length = (uint32_t)(__builtin_wasm_memory_size(0) * 0x10000 - (size_t)first - sizeof(struct chunk));
 }
+#elif defined(__riscv)
+// PolkaVM memory cannot grow, so the heap is a static buffer
+static uint8_t heap[128 * 1024] __attribute__((aligned(8)));
+
+#define HEAP_START ((struct chunk *)heap)
+
+void __init_heap()
+{
+    struct chunk *first = HEAP_START;
+    first->next = first->prev = NULL;
+    first->allocated = false;
+    first->length = sizeof(heap) - sizeof(struct chunk);
+}
 #else
 #define HEAP_START ((struct chunk *)0x300000000)
 
@@ -111,7 +124,7 @@ void *__attribute__((noinline)) __malloc(uint32_t size)
     else
     {
         // go bang
-#ifdef __wasm__
+#if defined(__wasm__) || defined(__riscv)
         __builtin_unreachable();
 #else
         sol_log("out of heap memory");
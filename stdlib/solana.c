@@ -7,6 +7,8 @@
 
 extern uint64_t solang_dispatch(SolParameters *param);
 extern void __init_heap();
+// Size of the transient storage of the contract, set by the compiler
+extern const uint32_t solang_transient_size;
 
 // The address 'SysvarC1ock11111111111111111111111111111111' base58 decoded
 static const SolPubkey clock_address = {0x06, 0xa7, 0xd5, 0x17, 0x18, 0xc7, 0x74, 0xc9, 0x28, 0x56, 0x63,
@@ -20,6 +22,13 @@ static const SolPubkey instructions_address = {0x06, 0xa7, 0xd5, 0x17, 0x18, 0x7
 static const SolPubkey ed25519_address = {0x03, 0x7d, 0x46, 0xd6, 0x7c, 0x93, 0xfb, 0xbe, 0x12, 0xf9, 0x42,
                                           0x8f, 0x83, 0x8d, 0x40, 0xff, 0x05, 0x70, 0x74, 0x49, 0x27, 0xf4,
                                           0x8a, 0x64, 0xfc, 0xca, 0x70, 0x44, 0x80, 0x00, 0x00, 0x00};
+// The address '11111111111111111111111111111111' base58 decoded
+static const SolPubkey system_address = {0};
+
+// Returned by a contract which has called selfdestruct(), so that the calls
+// on the stack return without executing any further. The entrypoint turns
+// this into success.
+#define TERMINATED TO_BUILTIN(0x8000)
 
 #ifndef TEST
 
@@ -52,7 +61,23 @@ uint64_t entrypoint(const uint8_t *input)
 
     __init_heap();
 
-    return solang_dispatch(&params);
+    // Transient storage lives on the heap, so it is gone once this instruction completes
+    params.transient = NULL;
+
+    if (solang_transient_size)
+    {
+        params.transient = __malloc(solang_transient_size);
+        __memset(params.transient, 0, solang_transient_size);
+    }
+
+    ret = solang_dispatch(&params);
+
+    if (ret == TERMINATED)
+    {
+        return SUCCESS;
+    }
+
+    return ret;
 }
 
 #endif
@@ -195,6 +220,89 @@ struct clock_layout *sol_clock(SolParameters *params)
     return clock_data;
 }
 
+// Find the account with the given key in the accounts passed to the instruction
+static const SolAccountInfo *find_account(const SolPubkey *key, SolParameters *params)
+{
+    for (int account_no = 0; account_no < params->ka_num; account_no++)
+    {
+        const SolAccountInfo *acc = &params->ka[account_no];
+
+        if (SolPubkey_same(key, acc->key))
+        {
+            return acc;
+        }
+    }
+
+    return NULL;
+}
+
+// Transfer lamports from the data account to another account, which must be
+// passed to the instruction. If the data account is owned by the program, the
+// lamports can be moved directly. Otherwise, the data account must be a signer
+// and the system program does the transfer.
+uint64_t sol_transfer(SolPubkey *to, uint64_t lamports, SolParameters *params)
+{
+    const SolAccountInfo *from = &params->ka[0];
+    const SolAccountInfo *dest = find_account(to, params);
+
+    if (!dest)
+    {
+        sol_log("recipient account missing from transaction");
+        return ERROR_NOT_ENOUGH_ACCOUNT_KEYS;
+    }
+
+    if (SolPubkey_same(from->owner, params->program_id))
+    {
+        if (*from->lamports < lamports)
+        {
+            return ERROR_INSUFFICIENT_FUNDS;
+        }
+
+        *from->lamports -= lamports;
+        *dest->lamports += lamports;
+
+        return SUCCESS;
+    }
+
+    // SystemInstruction::Transfer is a 4 byte instruction index followed by the lamports
+    uint8_t data[12];
+
+    *(uint32_t *)data = 2;
+    *(uint64_t *)(data + 4) = lamports;
+
+    SolAccountMeta metas[2] = {{from->key, true, true}, {dest->key, true, false}};
+
+    const SolInstruction instruction = {(SolPubkey *)&system_address, metas, 2, data, sizeof(data)};
+
+    return sol_invoke(&instruction, params->ka, params->ka_num);
+}
+
+// Close the data account: all its lamports go to the recipient, which must be
+// passed to the instruction, and the data is zeroed and the account is given
+// back to the system program. Returns the code which ends execution.
+uint64_t sol_selfdestruct(SolPubkey *recipient, SolParameters *params)
+{
+    const SolAccountInfo *data_account = &params->ka[0];
+    const SolAccountInfo *dest = find_account(recipient, params);
+
+    if (!dest)
+    {
+        sol_log("recipient account missing from transaction");
+        sol_panic();
+    }
+
+    if (dest != data_account)
+    {
+        *dest->lamports += *data_account->lamports;
+        *data_account->lamports = 0;
+    }
+
+    sol_memset(data_account->data, 0, data_account->data_len);
+    sol_memcpy(data_account->owner, &system_address, sizeof(SolPubkey));
+
+    return TERMINATED;
+}
+
 struct account_data_header
 {
     uint32_t magic;
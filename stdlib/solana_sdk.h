@@ -217,6 +217,7 @@ typedef struct
     SolPubkey *program_id; /** program_id of the currently executing program */
     const SolAccountInfo *ka_clock;
     const SolAccountInfo *ka_instructions;
+    uint8_t *transient;    /** transient storage, zeroed for each instruction */
 } SolParameters;
 
 /**
@@ -157,7 +157,7 @@ bool __memcmp(uint8_t *left, uint32_t left_len, uint8_t *right, uint32_t right_l
 
 #ifndef TEST
 
-#ifdef __wasm__
+#if defined(__wasm__) || defined(__riscv)
 #define VECTOR_EMPTY ((uint8_t *)~0l)
 #else
 #define VECTOR_EMPTY ((uint8_t *)0l)
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use assert_cmd::Command;
-use std::fs::File;
+use std::fs::{self, File};
 use tempfile::TempDir;
 
 #[test]
@@ -130,3 +130,677 @@ fn basic_compilation_from_toml() {
 
     compile_cmd.current_dir(polkadot_test).assert().success();
 }
+
+#[test]
+fn sarif_diagnostics() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let source = tmp.path().join("warning.sol");
+
+    fs::write(
+        &source,
+        r#"contract c {
+    function f(int64 a) public pure returns (int64) {
+        int64 b = 1;
+        return 2;
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+
+    let assert = cmd
+        .args([
+            "compile",
+            "--target",
+            "polkadot",
+            "--diagnostics-format",
+            "sarif",
+        ])
+        .arg(&source)
+        .arg("--output")
+        .arg(tmp.path())
+        .assert()
+        .success();
+
+    let output = assert.get_output();
+
+    let log: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(log["version"], "2.1.0");
+
+    let run = &log["runs"][0];
+
+    assert_eq!(run["tool"]["driver"]["name"], "solang");
+    assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "S5001");
+    assert_eq!(run["tool"]["driver"]["rules"][1]["id"], "S5003");
+
+    let results = run["results"].as_array().unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["ruleId"], "S5001");
+    assert_eq!(results[0]["ruleIndex"], 0);
+    assert_eq!(results[0]["level"], "warning");
+    assert_eq!(
+        results[0]["message"]["text"],
+        "function parameter 'a' is unused"
+    );
+
+    let region = &results[1]["locations"][0]["physicalLocation"]["region"];
+
+    assert_eq!(results[1]["ruleId"], "S5003");
+    assert_eq!(region["startLine"], 3);
+    assert_eq!(region["startColumn"], 15);
+    assert_eq!(region["endColumn"], 16);
+    assert_eq!(region["snippet"]["text"], "b");
+
+    let context_region = &results[1]["locations"][0]["physicalLocation"]["contextRegion"];
+
+    assert_eq!(context_region["startLine"], 3);
+    assert_eq!(context_region["endLine"], 3);
+    assert_eq!(context_region["snippet"]["text"], "        int64 b = 1;");
+
+    // columns are in code points, and the uri is percent-encoded
+    let source = tmp.path().join("my contract.sol");
+
+    fs::write(
+        &source,
+        r#"contract c {
+    function f() public pure returns (int64) {
+        /* é */ int64 b = 1;
+        return 2;
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+
+    let assert = cmd
+        .args([
+            "compile",
+            "--target",
+            "polkadot",
+            "--diagnostics-format",
+            "sarif",
+        ])
+        .arg(&source)
+        .arg("--output")
+        .arg(tmp.path())
+        .assert()
+        .success();
+
+    let log: serde_json::Value = serde_json::from_slice(&assert.get_output().stdout).unwrap();
+
+    let run = &log["runs"][0];
+
+    assert_eq!(run["columnKind"], "unicodeCodePoints");
+
+    let location = &run["results"][0]["locations"][0]["physicalLocation"];
+    let uri = location["artifactLocation"]["uri"].as_str().unwrap();
+
+    assert!(uri.starts_with("tests/"));
+    assert!(uri.ends_with("/my%20contract.sol"));
+    assert_eq!(location["region"]["startColumn"], 23);
+    assert_eq!(location["region"]["endColumn"], 24);
+}
+
+#[test]
+fn check_lints() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let source = tmp.path().join("lint.sol");
+
+    fs::write(
+        &source,
+        r#"contract c {
+    function f(uint64 a, uint64 b) public pure returns (uint64) {
+        return a / b * 2;
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+
+    let assert = cmd
+        .args(["check", "--target", "polkadot"])
+        .arg(&source)
+        .assert()
+        .success();
+
+    let output = assert.get_output();
+
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("warning: multiplication of the result of a division loses precision"));
+
+    // the lint can be denied like any other warning
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+
+    cmd.args(["check", "--target", "polkadot", "--deny", "S6004"])
+        .arg(&source)
+        .assert()
+        .failure();
+}
+
+#[test]
+fn storage_layout_diff() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let v1 = tmp.path().join("v1");
+    let v2 = tmp.path().join("v2");
+    let v3 = tmp.path().join("v3");
+
+    for (dir, source) in [
+        (
+            &v1,
+            "contract b { bool paused; } contract c is b { uint64 count; address owner; }",
+        ),
+        (
+            &v2,
+            "contract b { bool paused; } contract c is b { uint64 count; address owner; bytes data; }",
+        ),
+        (
+            &v3,
+            "contract b { bool paused; } contract c is b { address owner; uint32 count; }",
+        ),
+    ] {
+        fs::create_dir(dir).unwrap();
+        fs::write(dir.join("c.sol"), source).unwrap();
+
+        let mut cmd = Command::cargo_bin("solang").unwrap();
+        cmd.args(["compile", "--target", "polkadot", "--emit", "storage-layout"])
+            .arg(dir.join("c.sol"))
+            .arg("--output")
+            .arg(dir)
+            .assert()
+            .success();
+    }
+
+    let layout: serde_json::Value =
+        serde_json::from_slice(&fs::read(v1.join("c.layout.json")).unwrap()).unwrap();
+
+    assert_eq!(layout["contract"], "c");
+    assert_eq!(layout["storage"][0]["label"], "paused");
+    assert_eq!(layout["storage"][0]["contract"], "b");
+    assert_eq!(layout["storage"][1]["label"], "count");
+    assert_eq!(layout["storage"][1]["slot"], "1");
+    assert_eq!(layout["storage"][1]["type"], "uint64");
+    assert_eq!(layout["storage"][2]["slot"], "2");
+
+    // appending a variable is fine
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    cmd.arg("layout-diff")
+        .arg(v1.join("c.layout.json"))
+        .arg(v2.join("c.layout.json"))
+        .assert()
+        .success();
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    let assert = cmd
+        .arg("layout-diff")
+        .arg(v1.join("c.layout.json"))
+        .arg(v3.join("c.layout.json"))
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).to_string();
+
+    assert!(stderr.contains("error: variable 'c.count' changed type from 'uint64' to 'uint32'"));
+    assert!(stderr.contains("error: variable 'c.owner' was moved before 'c.count'"));
+
+    // namespaced and transient variables are listed with their kind
+    let v4 = tmp.path().join("v4");
+    fs::create_dir(&v4).unwrap();
+    fs::write(
+        v4.join("c.sol"),
+        r#"
+        /// @custom:storage-location erc7201:example.main
+        struct Main { uint64 count; }
+        contract b { bool paused; }
+        contract c is b { Main count; address owner; bool transient locked; }"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    cmd.args([
+        "compile",
+        "--target",
+        "polkadot",
+        "--emit",
+        "storage-layout",
+    ])
+    .arg(v4.join("c.sol"))
+    .arg("--output")
+    .arg(&v4)
+    .assert()
+    .success();
+
+    let layout: serde_json::Value =
+        serde_json::from_slice(&fs::read(v4.join("c.layout.json")).unwrap()).unwrap();
+
+    assert_eq!(layout["storage"][0]["kind"], "storage");
+    assert_eq!(layout["storage"][1]["label"], "count");
+    assert_eq!(layout["storage"][1]["kind"]["namespace"], "example.main");
+    assert_eq!(layout["storage"][2]["slot"], "1");
+    assert_eq!(layout["storage"][3]["label"], "locked");
+    assert_eq!(layout["storage"][3]["kind"], "transient");
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    let assert = cmd
+        .arg("layout-diff")
+        .arg(v1.join("c.layout.json"))
+        .arg(v4.join("c.layout.json"))
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).to_string();
+
+    assert!(stderr.contains(
+        "error: variable 'c.count' moved from storage to storage namespace 'example.main'"
+    ));
+}
+
+#[test]
+fn bindgen() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+
+    cmd.args([
+        "bindgen",
+        "examples/polkadot/flipper.sol",
+        "--target",
+        "polkadot",
+        "--lang",
+        "rust",
+        "--output",
+    ])
+    .arg(tmp.path())
+    .assert()
+    .success();
+
+    let rust = fs::read_to_string(tmp.path().join("flipper.rs")).unwrap();
+
+    assert!(rust.contains("use parity_scale_codec::{Decode, Encode};"));
+    assert!(rust.contains("pub fn new(initvalue: bool) -> Vec<u8> {"));
+    assert!(rust.contains("pub const FLIP_SELECTOR: [u8; 4] = [0xcd, 0xe4, 0xef, 0xa9];"));
+    assert!(rust.contains("pub const GET_SELECTOR: [u8; 4] = [0x6d, 0x4c, 0xe6, 0x3c];"));
+    assert!(rust.contains(
+        "pub fn decode_get(mut output: &[u8]) -> Result<bool, parity_scale_codec::Error> {"
+    ));
+    assert!(rust.contains("[0x08, 0xc3, 0x79, 0xa0] => Some(Error::Error("));
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+
+    cmd.args([
+        "bindgen",
+        "examples/solana/flipper.sol",
+        "--target",
+        "solana",
+        "--lang",
+        "typescript",
+        "--output",
+    ])
+    .arg(tmp.path())
+    .assert()
+    .success();
+
+    let ts = fs::read_to_string(tmp.path().join("flipper.ts")).unwrap();
+
+    assert!(ts.contains("import { PublicKey, TransactionInstruction } from '@solana/web3.js';"));
+    assert!(ts.contains(
+        "export const FLIP_DISCRIMINATOR = Uint8Array.from([0x18, 0xf3, 0x4e, 0xa1, 0xc0, 0xf6, 0x66, 0x67]);"
+    ));
+    assert!(ts.contains(
+        "export function new_(programId: PublicKey, accounts: NewAccounts, initvalue: boolean): TransactionInstruction {"
+    ));
+    assert!(ts.contains("{ pubkey: accounts.payer, isSigner: true, isWritable: true },"));
+    assert!(ts.contains("export function decodeGet(output: Uint8Array): boolean {"));
+    assert!(ts.contains(
+        "/**\n * Simply returns the current value of our `bool`.\n */\nexport function decodeGet("
+    ));
+    // revert data is not returned on Solana
+    assert!(!ts.contains("decodeError"));
+
+    // events, custom errors and user types
+    let source = tmp.path().join("bindgen.sol");
+
+    fs::write(
+        &source,
+        r#"contract c {
+    enum Color { Red, Green }
+    struct Point { int64 x; int64 y; Color color; }
+
+    event Moved(address indexed who, Point to);
+    error OutOfBounds(Point p, uint256 max);
+
+    function move(Point[] memory path) public returns (Point) {
+        Point memory p = path[path.length - 1];
+        if (p.x > 100) {
+            revert OutOfBounds(p, 100);
+        }
+        emit Moved(msg.sender, p);
+        return p;
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+
+    cmd.args([
+        "bindgen", "--target", "polkadot", "--lang", "rust", "--output",
+    ])
+    .arg(tmp.path())
+    .arg(&source)
+    .assert()
+    .success();
+
+    let rust = fs::read_to_string(tmp.path().join("c.rs")).unwrap();
+
+    assert!(rust.contains("pub enum Color {\n    Red,\n    Green,\n}"));
+    assert!(rust.contains(
+        "pub struct Point {\n    pub x: i64,\n    pub y: i64,\n    pub color: Color,\n}"
+    ));
+    assert!(rust.contains("pub fn move_(path: Vec<Point>) -> Vec<u8> {"));
+    assert!(rust
+        .contains("pub struct Moved {\n        pub who: [u8; 32],\n        pub to: Point,\n    }"));
+    assert!(rust
+        .contains("OutOfBounds {\n            p: Point,\n            max: [u8; 32],\n        },"));
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+
+    cmd.args([
+        "bindgen",
+        "--target",
+        "polkadot",
+        "--lang",
+        "typescript",
+        "--output",
+    ])
+    .arg(tmp.path())
+    .arg(&source)
+    .assert()
+    .success();
+
+    let ts = fs::read_to_string(tmp.path().join("c.ts")).unwrap();
+
+    assert!(ts.contains("export interface Point {\n  x: bigint;\n  y: bigint;\n  color: Color;\n}"));
+    assert!(ts.contains("export function encodeMove(path: Point[]): Uint8Array {"));
+    assert!(ts.contains("  w.vec(path, (e0) => writePoint(w, e0));"));
+    assert!(ts.contains("  | { name: 'Moved'; args: MovedEvent }"));
+    assert!(ts.contains("  | { name: 'OutOfBounds'; args: { p: Point; max: bigint } }"));
+
+    // only solana and polkadot are supported
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+
+    cmd.args(["bindgen", "--target", "evm", "--lang", "rust"])
+        .arg(&source)
+        .assert()
+        .failure();
+}
+
+#[test]
+fn bindgen_rust_parses() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let source = tmp.path().join("c.sol");
+
+    fs::write(
+        &source,
+        r#"contract c {
+    enum Color { Red, Green }
+    struct Point { int64 x; int64 y; Color color; bytes name; }
+
+    /// A point was moved
+    event Moved(address indexed who, Point to, string[] tags, bytes32 key);
+    error OutOfBounds(Point p, uint256 max);
+
+    function move(Point[] memory path, bytes32 key) public returns (Point, int8[4]) {
+        Point memory p = path[path.length - 1];
+        require(p.x <= 100, "out of bounds");
+        emit Moved(address(this), p, new string[](0), key);
+        return (p, [int8(1), 2, 3, 4]);
+    }
+
+    function loop() public pure returns (bool) {
+        return true;
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    for target in ["polkadot", "solana"] {
+        let output = tmp.path().join(target);
+
+        let mut cmd = Command::cargo_bin("solang").unwrap();
+
+        cmd.args(["bindgen", "--target", target, "--lang", "rust", "--output"])
+            .arg(&output)
+            .arg(format!("examples/{target}/flipper.sol"))
+            .arg(&source)
+            .assert()
+            .success();
+
+        for file in ["flipper.rs", "c.rs"] {
+            let rust = fs::read_to_string(output.join(file)).unwrap();
+
+            if let Err(err) = syn::parse_file(&rust) {
+                panic!("{target} {file} is not valid rust: {err}\n{rust}");
+            }
+        }
+    }
+}
+
+#[test]
+fn symbolize() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let source = tmp.path().join("c.sol");
+
+    fs::write(
+        &source,
+        r#"contract c {
+    function f(uint64 a) public pure returns (uint64) {
+        return a / 2;
+    }
+}"#,
+    )
+    .unwrap();
+
+    for (target, ext) in [("polkadot", "wasm"), ("solana", "so")] {
+        let out = tmp.path().join(target);
+
+        let mut cmd = Command::cargo_bin("solang").unwrap();
+        cmd.args(["compile", "-g", "--target", target])
+            .arg(&source)
+            .arg("--output")
+            .arg(&out)
+            .assert()
+            .success();
+
+        let source_map: serde_json::Value =
+            serde_json::from_slice(&fs::read(out.join("c.sourcemap.json")).unwrap()).unwrap();
+
+        assert_eq!(source_map["version"], 1);
+        assert!(source_map["files"][0].as_str().unwrap().ends_with("c.sol"));
+
+        let entry = source_map["entries"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|entry| entry[2] == 3)
+            .unwrap();
+        let offset = format!("{:#x}", entry[0].as_u64().unwrap());
+
+        // the source map and the binary give the same answer
+        for artifact in [out.join("c.sourcemap.json"), out.join(format!("c.{ext}"))] {
+            let mut cmd = Command::cargo_bin("solang").unwrap();
+            let assert = cmd
+                .arg("symbolize")
+                .arg(artifact)
+                .arg(&offset)
+                .assert()
+                .success();
+
+            let stdout = String::from_utf8_lossy(&assert.get_output().stdout).to_string();
+
+            assert!(stdout.starts_with(&format!("{offset}: ")));
+            assert!(stdout.contains("c.sol:3:"));
+        }
+
+        let mut cmd = Command::cargo_bin("solang").unwrap();
+        cmd.arg("symbolize")
+            .arg(out.join("c.sourcemap.json"))
+            .arg("1x")
+            .assert()
+            .failure();
+
+        // without -g there is no debug information
+        let out = tmp.path().join(format!("{target}-release"));
+
+        let mut cmd = Command::cargo_bin("solang").unwrap();
+        cmd.args(["compile", "--target", target])
+            .arg(&source)
+            .arg("--output")
+            .arg(&out)
+            .assert()
+            .success();
+
+        assert!(!out.join("c.sourcemap.json").exists());
+
+        let mut cmd = Command::cargo_bin("solang").unwrap();
+        let assert = cmd
+            .arg("symbolize")
+            .arg(out.join(format!("c.{ext}")))
+            .arg("0")
+            .assert()
+            .failure();
+
+        let stderr = String::from_utf8_lossy(&assert.get_output().stderr).to_string();
+
+        assert!(stderr.contains("no debug information found"));
+    }
+}
+
+#[cfg(feature = "fmt")]
+#[test]
+fn fmt() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let contracts = tmp.path().join("contracts");
+    fs::create_dir(&contracts).unwrap();
+
+    let source = contracts.join("c.sol");
+    let unformatted = "contract c{uint64 x;function f()public{x=1;}}";
+
+    fs::write(&source, unformatted).unwrap();
+
+    // the file is found using the import path
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    let assert = cmd
+        .args(["fmt", "--check", "-I"])
+        .arg(&contracts)
+        .arg("c.sol")
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).to_string();
+    assert!(stderr.contains("c.sol: error: file is not formatted"));
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    let assert = cmd.args(["fmt", "--diff"]).arg(&source).assert().failure();
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout).to_string();
+    assert!(stdout.contains(&format!("--- a/{}", source.display())));
+    assert!(stdout.contains(&format!("-{unformatted}")));
+    assert!(stdout.contains("+        x = 1;"));
+
+    // check and diff do not change the file
+    assert_eq!(fs::read_to_string(&source).unwrap(), unformatted);
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    cmd.arg("fmt").arg(&source).assert().success();
+
+    let formatted = fs::read_to_string(&source).unwrap();
+    assert!(formatted.contains("\n        x = 1;\n"));
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    cmd.args(["fmt", "--check"]).arg(&source).assert().success();
+
+    // options from the configuration file
+    let config = tmp.path().join("solang.toml");
+    fs::write(&config, "[fmt]\ntab_width = 2\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    cmd.args(["fmt", "--config-file"])
+        .arg(&config)
+        .arg(&source)
+        .assert()
+        .success();
+
+    let formatted = fs::read_to_string(&source).unwrap();
+    assert!(formatted.contains("\n    x = 1;\n"));
+
+    fs::write(&config, "[fmt]\ntab_size = 2\n").unwrap();
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    let assert = cmd
+        .args(["fmt", "--config-file"])
+        .arg(&config)
+        .arg(&source)
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr).to_string();
+    assert!(stderr.contains("unknown formatting option 'tab_size'"));
+
+    // syntax errors are reported
+    fs::write(&source, "contract c {").unwrap();
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    cmd.args(["fmt", "--check"]).arg(&source).assert().failure();
+}
+
+#[test]
+fn ast_json() {
+    let tmp = TempDir::new_in("tests").unwrap();
+    let source = tmp.path().join("c.sol");
+
+    fs::write(
+        &source,
+        "contract c { uint64 count; function inc() public { count += 1; } }",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    cmd.args(["compile", "--target", "polkadot", "--emit", "ast-json"])
+        .arg(&source)
+        .arg("--output")
+        .arg(tmp.path())
+        .assert()
+        .success();
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&fs::read(tmp.path().join("c.ast.json")).unwrap()).unwrap();
+
+    let sources = json["sources"].as_object().unwrap();
+
+    assert_eq!(sources.len(), 1);
+
+    let ast = &sources.values().next().unwrap()["ast"];
+
+    assert_eq!(ast["nodeType"], "SourceUnit");
+    assert_eq!(ast["nodes"][0]["nodeType"], "ContractDefinition");
+    assert_eq!(ast["nodes"][0]["nodes"][1]["name"], "inc");
+
+    // no contract is compiled
+    assert!(!tmp.path().join("c.contract").exists());
+}
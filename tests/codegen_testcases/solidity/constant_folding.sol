@@ -24,15 +24,13 @@ contract Enum {
         lenders[Lender.USDC] = usdc;
         lenders[Lender.DAI] = dai;
 
-        // TODO / FIXME:
-        // We need an unused variable detection pass in codegen, and run all optimization until the CFG converges.
-        // This will get rid of the unused temp variable assignments below.
-
-        // CHECK: ty:address %temp.17 = (arg #0)
+        // The temporary variables for the mapping values are never read, so dead code elimination
+        // removes them.
+        // NOT-CHECK: ty:address %temp.17 = (arg #0)
         // CHECK: store storage slot(hex"f31349e4056d5e5c8ce6d8359404f2ca89b2a6884691bff0f55ce7629f869af3") ty:address = (arg #0)
-        // CHECK: ty:address %temp.18 = (arg #1)
+        // NOT-CHECK: ty:address %temp.18 = (arg #1)
         // CHECK: store storage slot(hex"e062efc721ea447b5e3918617d57f26130f3d8bc01b883eed1efcb4864d73ac1") ty:address = (arg #1)
-        // CHECK: ty:address %temp.19 = (arg #2)
+        // NOT-CHECK: ty:address %temp.19 = (arg #2)
         // CHECK: store storage slot(hex"b2573af2738ebd4810a3198e92bab190f29b8718f1d5ed1b83e468f2bb322d10") ty:address = (arg #2)
     }
 
@@ -0,0 +1,33 @@
+// RUN: --target polkadot --emit cfg --no-inline
+
+contract c {
+    // BEGIN-CHECK: c::c::function::get__uint64
+    function get(uint64 x) public pure returns (uint64) {
+        bool debug = false;
+
+        // CHECK: ty:uint64 %x = (arg #0)
+        // NOT-CHECK: ty:bool %debug = false
+        // CHECK: branch block1
+        // CHECK: block1: # endif
+        if (debug) {
+            x += 1;
+        }
+
+        return x;
+    }
+
+    // BEGIN-CHECK: c::c::function::loop__uint64
+    function loop(uint64 x) public pure returns (uint64) {
+        bool enabled = false;
+
+        // CHECK: branch block1
+        // CHECK: block1: # cond
+        // CHECK: branch block2
+        // CHECK: block2: # endwhile
+        while (enabled) {
+            x *= 2;
+        }
+
+        return x;
+    }
+}
@@ -0,0 +1,27 @@
+// RUN: --target polkadot-revive --emit cfg
+
+contract EthAbi {
+    // BEGIN-CHECK: EthAbi::EthAbi::function::encode_static
+    function encode_static(uint64 a, address b) public pure returns (bytes) {
+        // CHECK: (alloc bytes len uint32 64)
+        // CHECK: offset:uint32 0 value:bytes32((zext uint256 %a))
+        // CHECK: offset:uint32 44 value:%b
+        return abi.encode(a, b);
+    }
+
+    // BEGIN-CHECK: EthAbi::EthAbi::function::encode_packed
+    function encode_packed(uint64 a, bool b) public pure returns (bytes) {
+        // CHECK: (alloc bytes len uint32 9)
+        // CHECK: offset:uint32 0 value:bytes8(%a)
+        // CHECK: offset:uint32 8 value:%b
+        return abi.encodePacked(a, b);
+    }
+
+    // BEGIN-CHECK: EthAbi::EthAbi::function::decode_address
+    function decode_address(bytes enc) public pure returns (address) {
+        // The 12 leading bytes of the word must be zero
+        // CHECK: branchcond (unsigned less %temp.3 < uint256 1461501637330902918203684832716283019655932542976)
+        // CHECK: ty:address %temp.4 = (builtin ReadFromBuffer ((arg #0), uint32 12))
+        return abi.decodeEthereum(enc, (address));
+    }
+}
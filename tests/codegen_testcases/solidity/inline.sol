@@ -0,0 +1,27 @@
+// RUN: --target polkadot --emit cfg
+contract c {
+    function add(uint64 a, uint64 b) private pure returns (uint64) {
+        return a + b;
+    }
+
+    function fib(uint64 n) private pure returns (uint64) {
+        if (n < 2) {
+            return n;
+        }
+        return fib(n - 1) + fib(n - 2);
+    }
+
+    // BEGIN-CHECK: c::c::function::sum__uint64
+    function sum(uint64 x) public pure returns (uint64) {
+        // CHECK: # c::c::function::add__uint64_uint64::entry
+        // CHECK: # c::c::function::add__uint64_uint64::return
+        return add(x, 1);
+    }
+
+    // BEGIN-CHECK: c::c::function::sum_fib__uint64
+    function sum_fib(uint64 x) public pure returns (uint64) {
+        // fib calls a function, so it is not inlined
+        // CHECK: = call c::c::function::fib__uint64 %x
+        return fib(x);
+    }
+}
@@ -1,4 +1,6 @@
-// RUN: --target polkadot --emit cfg
+// RUN: --target polkadot --emit cfg --no-inline
+
+// Inlining would remove the call to foo(), which test3 relies on to keep its argument a vector
 contract c {
 // BEGIN-CHECK: c::function::test1
 	function test1() public pure{
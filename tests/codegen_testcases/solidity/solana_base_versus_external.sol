@@ -1,4 +1,4 @@
-// RUN: --target solana --emit cfg
+// RUN: --target solana --emit cfg --no-inline
 
 import 'solana';
 
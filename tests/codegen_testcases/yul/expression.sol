@@ -1,4 +1,4 @@
-// RUN: --target solana --emit cfg -Onone --no-cse
+// RUN: --target solana --emit cfg -Onone --no-cse --no-inline
 
 uint128 constant global_cte = 5;
 contract testing {
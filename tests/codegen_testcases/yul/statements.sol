@@ -1,5 +1,7 @@
-// RUN: --target solana --emit cfg -Onone --no-cse
+// RUN: --target solana --emit cfg -Onone --no-cse --no-inline --no-dead-code
 
+// Dead code elimination would remove the unreachable blocks of the loops in for_statement(),
+// which are checked below
 contract testing {
 
     // BEGIN-CHECK: testing::testing::function::calls_and_unreachable__uint64_uint64
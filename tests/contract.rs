@@ -31,6 +31,36 @@ fn polkadot_contracts() -> io::Result<()> {
     )
 }
 
+#[test]
+fn polkadot_revive_contracts() -> io::Result<()> {
+    contract_tests(
+        "tests/contract_testcases/polkadot_revive",
+        Target::PolkadotRevive,
+    )
+}
+
+#[test]
+#[cfg(not(feature = "polkavm"))]
+fn polkadot_revive_without_polkavm() {
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", "contract c { function f() public {} }".into());
+
+    let (blobs, ns) = solang::compile(
+        OsStr::new("test.sol"),
+        &mut cache,
+        Target::PolkadotRevive,
+        &codegen::Options::default(),
+        vec!["unknown".into()],
+        "0.1.0",
+    );
+
+    assert!(blobs.is_empty());
+    assert_eq!(
+        ns.diagnostics.first_error(),
+        "polkadot-revive target requires solang to be built with the polkavm feature"
+    );
+}
+
 #[test]
 fn evm_contracts() -> io::Result<()> {
     contract_tests("tests/contract_testcases/evm", Target::EVM)
@@ -98,7 +128,7 @@ fn parse_file(path: PathBuf, target: Target) -> io::Result<()> {
 
             if contract.instantiable {
                 let code = match ns.target {
-                    Target::Solana | Target::Polkadot { .. } => {
+                    Target::Solana | Target::Polkadot { .. } | Target::PolkadotRevive => {
                         contract.emit(&ns, &Default::default(), contract_no)
                     }
                     Target::EVM => b"beep".to_vec(),
@@ -134,7 +164,7 @@ fn add_file(cache: &mut FileResolver, path: &Path, target: Target) -> io::Result
         if line.starts_with("import") {
             if let (Some(start), Some(end)) = (line.find('"'), line.rfind('"')) {
                 let file = &line[start + 1..end];
-                if !file.is_empty() && file != "solana" {
+                if !file.is_empty() && file != "solana" && file != "polkadot" {
                     let mut import_path = path.parent().unwrap().to_path_buf();
                     import_path.push(file);
                     println!("adding import {}", import_path.display());
@@ -0,0 +1,17 @@
+contract c {
+    struct S {
+        function() external f;
+    }
+
+    function f() external pure {}
+
+    function encode() public view returns (bytes) {
+        return abi.encodeEthereum(this.f);
+    }
+
+    function decode(bytes enc) public pure returns (S) {
+        return abi.decodeEthereum(enc, (S));
+    }
+}
+
+// ---- Expect: diagnostics ----
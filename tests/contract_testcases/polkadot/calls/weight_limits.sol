@@ -0,0 +1,27 @@
+contract c {
+    function f(other o) public {
+        o.g{refTime: 1, proofSize: 2, storageDepositLimit: 3}();
+        new other{refTime: 1, proofSize: 2, storageDepositLimit: 3}();
+    }
+
+    function g(other o) public {
+        o.g{gas: 1, refTime: 2}();
+    }
+
+    function h(other o) public {
+        o.g{proofSize: true}();
+    }
+
+    function d(address a) public {
+        a.delegatecall{proofSize: 2, storageDepositLimit: 3}("");
+    }
+}
+
+contract other {
+    function g() public {}
+}
+// ---- Expect: diagnostics ----
+// error: 8:21-31: 'gas' and 'refTime' cannot both be specified
+// error: 12:24-28: conversion from bool to uint64 not possible
+// warning: 16:9-65: 'proofSize' specified on 'delegatecall' will be ignored
+// warning: 16:9-65: 'storageDepositLimit' specified on 'delegatecall' will be ignored
@@ -0,0 +1,25 @@
+contract c {
+    function get(uint64 x) public pure returns (uint64) {
+        bool debug = false;
+        if (debug) {
+            x += 1;
+            x *= 2;
+        }
+        return x;
+    }
+
+    function set(uint64 x) public pure returns (uint64) {
+        bool enabled = true;
+        if (enabled) {
+            x += 1;
+        } else {
+            x -= 1;
+        }
+        return x;
+    }
+}
+// ---- Expect: diagnostics ----
+// warning: 4:13-18: condition is always false
+// warning: 5:13-6:19: unreachable code
+// warning: 13:13-20: condition is always true
+// warning: 16:13-19: unreachable code
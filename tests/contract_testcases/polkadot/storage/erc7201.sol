@@ -0,0 +1,26 @@
+/// @custom:storage-location erc7201:
+struct S1 {
+    uint64 a;
+}
+
+/// @custom:storage-location example.main
+struct S2 {
+    uint64 a;
+}
+
+interface I layout at 10 {
+    function f() external;
+}
+
+contract B layout at 5 {
+    function f() public {}
+}
+
+contract C is B {
+    function g() public {}
+}
+// ---- Expect: diagnostics ----
+// error: 1:5-38: storage location 'erc7201:' should be of the form 'erc7201:<namespace>'
+// error: 6:5-42: storage location 'example.main' should be of the form 'erc7201:<namespace>'
+// error: 11:23-25: storage layout base can only be specified for contracts, not interface 'I'
+// error: 19:15-16: base contract 'B' specifies a storage layout base, which can only be specified in the most derived contract
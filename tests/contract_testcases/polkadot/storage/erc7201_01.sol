@@ -0,0 +1,18 @@
+/// @custom:storage-location erc7201:example.main
+struct Main {
+    uint64 x;
+}
+
+contract A layout at 0x1000 {
+    Main a;
+    Main b;
+
+    function f() public returns (uint64) {
+        a.x = 1;
+        b.x = a.x;
+        return b.x;
+    }
+}
+// ---- Expect: diagnostics ----
+// error: 8:5-11: storage namespace 'example.main' is already in use
+// 	note 7:5-11: previous use of namespace
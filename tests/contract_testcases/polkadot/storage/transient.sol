@@ -0,0 +1,20 @@
+contract T {
+    uint64 transient constant a = 1;
+    int32 immutable transient b;
+    uint64 transient c = 2;
+    string transient d;
+    mapping(uint64 => bool) transient e;
+    uint64 transient transient f;
+
+    constructor() {
+        b = 1;
+    }
+}
+// ---- Expect: diagnostics ----
+// error: 2:12-21: variable cannot be declared both 'transient' and 'constant'
+// error: 3:21-30: variable cannot be declared both 'transient' and 'immutable'
+// error: 4:26-27: transient variable cannot have an initializer
+// error: 5:5-11: transient storage is not supported for type 'string', only for value types
+// error: 6:5-28: transient storage is not supported for type 'mapping(uint64 => bool)', only for value types
+// error: 7:22-31: duplicate 'transient' attribute
+// 	note 7:12-21: previous 'transient' attribute
@@ -0,0 +1,11 @@
+contract c {
+    function f() public pure returns (address) {
+        return 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed;
+    }
+
+    function g() public pure returns (address) {
+        return 0x5aaeb6053F3E94C9b9A09f33669435E7Ef1BeAed;
+    }
+}
+// ---- Expect: diagnostics ----
+// error: 7:16-58: address literal has incorrect checksum, expected '0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed'
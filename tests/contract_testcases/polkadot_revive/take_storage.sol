@@ -0,0 +1,9 @@
+import "polkadot";
+
+contract c {
+    function f() public {
+        take_storage(new bytes(32));
+    }
+}
+// ---- Expect: diagnostics ----
+// error: 5:9-21: unknown function or type 'take_storage'
@@ -0,0 +1,16 @@
+contract c {
+    function f() public {
+        other.g{refTime: 1}();
+    }
+
+    function h() public {
+        other.g{storageDepositLimit: 3}();
+    }
+}
+
+contract other {
+    function g() public {}
+}
+// ---- Expect: diagnostics ----
+// error: 3:17-27: 'refTime' not permitted for external calls or constructors on Solana
+// error: 7:17-39: 'storageDepositLimit' not permitted for external calls or constructors on Solana
@@ -0,0 +1,19 @@
+contract c {
+    struct S {
+        function() external f;
+    }
+
+    function f() external pure {}
+
+    function encode() public view returns (bytes) {
+        return abi.encodeEthereum(this.f);
+    }
+
+    function decode(bytes enc) public pure returns (S) {
+        return abi.decodeEthereum(enc, (S));
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 9:35-41: type 'function() external pure' cannot be Ethereum ABI encoded on Solana, since the address and selector of an external function do not fit in 24 bytes
+// error: 13:41-42: type 'struct c.S' cannot be Ethereum ABI encoded on Solana, since the address and selector of an external function do not fit in 24 bytes
@@ -18,5 +18,3 @@ contract c1 {
 
 // ---- Expect: diagnostics ----
 // error: 3:16-20: balance is not available on Solana. Use tx.accounts.account_name.lamports to fetch the balance.
-// error: 9:16-33: method 'send' not available on Solana. Use the lamports field from the AccountInfo struct directly to operate on balances.
-// error: 15:9-30: method 'transfer' not available on Solana. Use the lamports field from the AccountInfo struct directly to operate on balances.
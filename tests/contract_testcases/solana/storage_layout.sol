@@ -0,0 +1,11 @@
+/// @custom:storage-location erc7201:example.main
+struct Main {
+    uint64 x;
+}
+
+contract A layout at 100 {
+    function f() public {}
+}
+// ---- Expect: diagnostics ----
+// error: 1:5-50: namespaced storage is not supported on Solana
+// error: 6:22-25: storage layout base is not supported on Solana
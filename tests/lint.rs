@@ -0,0 +1,252 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use solang::codegen::{codegen, Options};
+use solang::file_resolver::FileResolver;
+use solang::sema::ast::{Code, Diagnostic, Namespace};
+use solang::sema::diagnostics::codes;
+use solang::{lint, parse_and_resolve, Target};
+use std::ffi::OsStr;
+
+fn check(src: &'static str, target: Target) -> Namespace {
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+
+    let mut ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, target);
+    codegen(&mut ns, &Options::default());
+    lint::check(&mut ns);
+
+    ns
+}
+
+fn lints(ns: &Namespace, code: Code) -> Vec<&Diagnostic> {
+    ns.diagnostics.iter().filter(|d| d.code == code).collect()
+}
+
+#[test]
+fn reentrancy() {
+    let ns = check(
+        r#"
+        contract Bank {
+            mapping(address => uint256) balances;
+
+            function withdraw() public {
+                uint256 amount = balances[msg.sender];
+                (bool ok, ) = msg.sender.call{value: amount}("");
+                require(ok);
+                balances[msg.sender] = 0;
+            }
+
+            function withdrawSafe() public {
+                uint256 amount = balances[msg.sender];
+                balances[msg.sender] = 0;
+                (bool ok, ) = msg.sender.call{value: amount}("");
+                require(ok);
+            }
+        }"#,
+        Target::default_polkadot(),
+    );
+
+    let warnings = lints(&ns, codes::REENTRANCY);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "storage modified after external call, which may allow reentrancy"
+    );
+    assert_eq!(warnings[0].notes.len(), 1);
+    assert_eq!(warnings[0].notes[0].message, "external call");
+}
+
+#[test]
+fn reentrancy_in_loop() {
+    let ns = check(
+        r#"
+        contract C {
+            uint256 count;
+
+            function f(address[] memory targets) public {
+                for (uint256 i = 0; i < targets.length; i++) {
+                    count += 1;
+                    (bool ok, ) = targets[i].call("");
+                    require(ok);
+                }
+            }
+        }"#,
+        Target::default_polkadot(),
+    );
+
+    // the write at the start of the next iteration happens after the call
+    assert_eq!(lints(&ns, codes::REENTRANCY).len(), 1);
+}
+
+#[test]
+fn unchecked_call() {
+    let ns = check(
+        r#"
+        contract C {
+            function f(address payable a) public {
+                a.call("");
+                a.send(1);
+                (, bytes memory data) = a.call("");
+                (bool ok, ) = a.call("");
+                require(ok);
+                require(a.send(1));
+            }
+        }"#,
+        Target::default_polkadot(),
+    );
+
+    let warnings = lints(&ns, codes::UNCHECKED_CALL);
+    assert_eq!(warnings.len(), 3);
+    assert!(ns
+        .diagnostics
+        .contains_message("return value of low-level call is not checked"));
+    assert!(ns
+        .diagnostics
+        .contains_message("return value of 'send' is not checked"));
+    assert!(ns
+        .diagnostics
+        .contains_message("success of low-level call is discarded"));
+}
+
+#[test]
+fn tx_origin() {
+    let ns = check(
+        r#"
+        contract C {
+            address owner;
+
+            function f() public view {
+                require(tx.origin == owner);
+            }
+
+            function g() public view {
+                // the caller is not a contract
+                require(msg.sender == tx.origin);
+            }
+        }"#,
+        Target::EVM,
+    );
+
+    let warnings = lints(&ns, codes::TX_ORIGIN);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "'tx.origin' used for authorization; use 'msg.sender' instead"
+    );
+}
+
+#[test]
+fn uninitialized_storage_pointer() {
+    let ns = check(
+        r#"
+        contract C {
+            struct S { uint64 a; }
+            S s1;
+            S s2;
+
+            function f(bool c) public {
+                S storage p;
+                if (c) {
+                    p = s1;
+                }
+                p.a = 1;
+            }
+        }"#,
+        Target::default_polkadot(),
+    );
+
+    // reported by codegen, the lints are not run
+    assert!(ns.diagnostics.any_errors());
+    assert!(ns.diagnostics.contains_message("Variable 'p' is undefined"));
+
+    let ns = check(
+        r#"
+        contract C {
+            struct S { uint64 a; }
+            S s1;
+            S s2;
+
+            function f(bool c) public {
+                S storage p;
+                if (c) {
+                    p = s1;
+                } else {
+                    p = s2;
+                }
+                p.a = 1;
+            }
+
+            function g() public {
+                S storage p = s1;
+                p.a = 2;
+            }
+        }"#,
+        Target::default_polkadot(),
+    );
+
+    assert!(!ns.diagnostics.any_errors());
+
+    let warnings = lints(&ns, codes::UNINITIALIZED_STORAGE_POINTER);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "storage reference 'p' is declared without a value; assign it in the declaration"
+    );
+}
+
+#[test]
+fn divide_before_multiply() {
+    let ns = check(
+        r#"
+        contract C {
+            function f(uint64 a, uint64 b, uint64 c) public pure returns (uint64, uint64) {
+                return (a / b * c, a * c / b);
+            }
+        }"#,
+        Target::default_polkadot(),
+    );
+
+    let warnings = lints(&ns, codes::DIVIDE_BEFORE_MULTIPLY);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "multiplication of the result of a division loses precision"
+    );
+}
+
+#[test]
+fn missing_signer() {
+    let ns = check(
+        r#"
+        contract C {
+            address authority;
+            uint64 value;
+
+            @account(auth)
+            function set(uint64 v) external {
+                require(tx.accounts.auth.key == authority);
+                value = v;
+            }
+
+            @account(auth)
+            function setChecked(uint64 v) external {
+                require(tx.accounts.auth.key == authority && tx.accounts.auth.is_signer);
+                value = v;
+            }
+
+            @signer(auth)
+            function setSigner(uint64 v) external {
+                require(tx.accounts.auth.key == authority);
+                value = v;
+            }
+        }"#,
+        Target::Solana,
+    );
+
+    let warnings = lints(&ns, codes::MISSING_SIGNER_CHECK);
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "account is compared with an authority, but the function does not check that any account signed the transaction"
+    );
+}
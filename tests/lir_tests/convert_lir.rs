@@ -140,7 +140,6 @@ contract dynamicarray {
 block#0 entry:
     uint32 %array_length.temp.1 = 3;
     ptr<int64[]> %a = alloc ptr<int64[]>[uint32(3)];
-    uint32 %index.temp.3 = 0;
     bool %temp.ssa_ir.9 = uint32(0) (u)>= uint32(3);
     cbr bool(%temp.ssa_ir.9) block#1 else block#2;
 
@@ -148,10 +147,8 @@ block#1 out_of_bounds:
     assert_failure;
 
 block#2 in_bounds:
-    int64 %temp.2 = 1;
     ptr<int64> %temp.ssa_ir.10 = ptr<int64[]>(%a)[uint32(0)];
     store int64(1) to ptr<int64>(%temp.ssa_ir.10);
-    uint32 %index.temp.5 = 1;
     bool %temp.ssa_ir.11 = uint32(1) (u)>= uint32(3);
     cbr bool(%temp.ssa_ir.11) block#3 else block#4;
 
@@ -159,10 +156,8 @@ block#3 out_of_bounds:
     assert_failure;
 
 block#4 in_bounds:
-    int64 %temp.4 = 2;
     ptr<int64> %temp.ssa_ir.12 = ptr<int64[]>(%a)[uint32(1)];
     store int64(2) to ptr<int64>(%temp.ssa_ir.12);
-    uint32 %index.temp.7 = 2;
     bool %temp.ssa_ir.13 = uint32(2) (u)>= uint32(3);
     cbr bool(%temp.ssa_ir.13) block#5 else block#6;
 
@@ -170,7 +165,6 @@ block#5 out_of_bounds:
     assert_failure;
 
 block#6 in_bounds:
-    int64 %temp.6 = 3;
     ptr<int64> %temp.ssa_ir.14 = ptr<int64[]>(%a)[uint32(2)];
     store int64(3) to ptr<int64>(%temp.ssa_ir.14);
     int64 %temp.8 = push_mem ptr<int64[]>(%a) int64(4);
@@ -273,8 +267,8 @@ fn test_arithmetic_exprs() {
         cfg_no,
         r#"public function sol#2 test::test::function::celcius2fahrenheit__int32 (int32) returns (int32):
 block#0 entry:
-    int32 %celcius = int32(arg#0);
-    int32 %temp.ssa_ir.4 = int32(%celcius) * int32(9);
+    int32 %temp.ssa_ir.5 = int32(arg#0);
+    int32 %temp.ssa_ir.4 = int32(%temp.ssa_ir.5) * int32(9);
     int32 %temp.ssa_ir.3 = int32(%temp.ssa_ir.4) / int32(5);
     int32 %fahrenheit = int32(%temp.ssa_ir.3) + int32(32);
     return int32(%fahrenheit);"#,
@@ -305,39 +299,39 @@ fn test_arithmetic_exprs_1() {
         cfg_no,
         r#"public function sol#2 test::test::function::byte8reverse__bytes8 (bytes8) returns (bytes8):
 block#0 entry:
-    bytes8 %input = bytes8(arg#0);
     bytes8 %out = bytes8 hex"00_00_00_00_00_00_00_00";
-    bytes8 %temp.ssa_ir.9 = bytes8(%input) << bytes8(56);
-    bytes8 %temp.ssa_ir.10 = bytes8 hex"ff_00_00_00_00_00_00_00";
-    bytes8 %temp.ssa_ir.8 = bytes8(%temp.ssa_ir.9) & bytes8(%temp.ssa_ir.10);
-    bytes8 %temp.ssa_ir.12 = bytes8(%input) << bytes8(40);
-    bytes8 %temp.ssa_ir.13 = bytes8 hex"00_ff_00_00_00_00_00_00";
-    bytes8 %temp.ssa_ir.11 = bytes8(%temp.ssa_ir.12) & bytes8(%temp.ssa_ir.13);
-    bytes8 %temp.ssa_ir.7 = bytes8(%temp.ssa_ir.8) | bytes8(%temp.ssa_ir.11);
-    bytes8 %temp.ssa_ir.15 = bytes8(%input) << bytes8(24);
-    bytes8 %temp.ssa_ir.16 = bytes8 hex"00_00_ff_00_00_00_00_00";
-    bytes8 %temp.ssa_ir.14 = bytes8(%temp.ssa_ir.15) & bytes8(%temp.ssa_ir.16);
-    bytes8 %temp.ssa_ir.6 = bytes8(%temp.ssa_ir.7) | bytes8(%temp.ssa_ir.14);
-    bytes8 %temp.ssa_ir.18 = bytes8(%input) << bytes8(8);
-    bytes8 %temp.ssa_ir.19 = bytes8 hex"00_00_00_ff_00_00_00_00";
-    bytes8 %temp.ssa_ir.17 = bytes8(%temp.ssa_ir.18) & bytes8(%temp.ssa_ir.19);
-    bytes8 %temp.ssa_ir.5 = bytes8(%temp.ssa_ir.6) | bytes8(%temp.ssa_ir.17);
-    bytes8 %temp.ssa_ir.21 = bytes8(%input) (u)>> bytes8(8);
-    bytes8 %temp.ssa_ir.22 = bytes8 hex"00_00_00_00_ff_00_00_00";
-    bytes8 %temp.ssa_ir.20 = bytes8(%temp.ssa_ir.21) & bytes8(%temp.ssa_ir.22);
-    bytes8 %temp.ssa_ir.4 = bytes8(%temp.ssa_ir.5) | bytes8(%temp.ssa_ir.20);
-    bytes8 %temp.ssa_ir.24 = bytes8(%input) (u)>> bytes8(24);
-    bytes8 %temp.ssa_ir.25 = bytes8 hex"00_00_00_00_00_ff_00_00";
-    bytes8 %temp.ssa_ir.23 = bytes8(%temp.ssa_ir.24) & bytes8(%temp.ssa_ir.25);
-    bytes8 %temp.ssa_ir.3 = bytes8(%temp.ssa_ir.4) | bytes8(%temp.ssa_ir.23);
-    bytes8 %temp.ssa_ir.27 = bytes8(%input) (u)>> bytes8(40);
-    bytes8 %temp.ssa_ir.28 = bytes8 hex"00_00_00_00_00_00_ff_00";
-    bytes8 %temp.ssa_ir.26 = bytes8(%temp.ssa_ir.27) & bytes8(%temp.ssa_ir.28);
-    bytes8 %temp.ssa_ir.2 = bytes8(%temp.ssa_ir.3) | bytes8(%temp.ssa_ir.26);
-    bytes8 %temp.ssa_ir.30 = bytes8(%input) (u)>> bytes8(56);
-    bytes8 %temp.ssa_ir.31 = bytes8 hex"00_00_00_00_00_00_00_ff";
-    bytes8 %temp.ssa_ir.29 = bytes8(%temp.ssa_ir.30) & bytes8(%temp.ssa_ir.31);
-    bytes8 %out = bytes8(%temp.ssa_ir.2) | bytes8(%temp.ssa_ir.29);
+    bytes8 %temp.ssa_ir.10 = bytes8(arg#0);
+    bytes8 %temp.ssa_ir.9 = bytes8(%temp.ssa_ir.10) << bytes8(56);
+    bytes8 %temp.ssa_ir.11 = bytes8 hex"ff_00_00_00_00_00_00_00";
+    bytes8 %temp.ssa_ir.8 = bytes8(%temp.ssa_ir.9) & bytes8(%temp.ssa_ir.11);
+    bytes8 %temp.ssa_ir.13 = bytes8(%temp.ssa_ir.10) << bytes8(40);
+    bytes8 %temp.ssa_ir.14 = bytes8 hex"00_ff_00_00_00_00_00_00";
+    bytes8 %temp.ssa_ir.12 = bytes8(%temp.ssa_ir.13) & bytes8(%temp.ssa_ir.14);
+    bytes8 %temp.ssa_ir.7 = bytes8(%temp.ssa_ir.8) | bytes8(%temp.ssa_ir.12);
+    bytes8 %temp.ssa_ir.16 = bytes8(%temp.ssa_ir.10) << bytes8(24);
+    bytes8 %temp.ssa_ir.17 = bytes8 hex"00_00_ff_00_00_00_00_00";
+    bytes8 %temp.ssa_ir.15 = bytes8(%temp.ssa_ir.16) & bytes8(%temp.ssa_ir.17);
+    bytes8 %temp.ssa_ir.6 = bytes8(%temp.ssa_ir.7) | bytes8(%temp.ssa_ir.15);
+    bytes8 %temp.ssa_ir.19 = bytes8(%temp.ssa_ir.10) << bytes8(8);
+    bytes8 %temp.ssa_ir.20 = bytes8 hex"00_00_00_ff_00_00_00_00";
+    bytes8 %temp.ssa_ir.18 = bytes8(%temp.ssa_ir.19) & bytes8(%temp.ssa_ir.20);
+    bytes8 %temp.ssa_ir.5 = bytes8(%temp.ssa_ir.6) | bytes8(%temp.ssa_ir.18);
+    bytes8 %temp.ssa_ir.22 = bytes8(%temp.ssa_ir.10) (u)>> bytes8(8);
+    bytes8 %temp.ssa_ir.23 = bytes8 hex"00_00_00_00_ff_00_00_00";
+    bytes8 %temp.ssa_ir.21 = bytes8(%temp.ssa_ir.22) & bytes8(%temp.ssa_ir.23);
+    bytes8 %temp.ssa_ir.4 = bytes8(%temp.ssa_ir.5) | bytes8(%temp.ssa_ir.21);
+    bytes8 %temp.ssa_ir.25 = bytes8(%temp.ssa_ir.10) (u)>> bytes8(24);
+    bytes8 %temp.ssa_ir.26 = bytes8 hex"00_00_00_00_00_ff_00_00";
+    bytes8 %temp.ssa_ir.24 = bytes8(%temp.ssa_ir.25) & bytes8(%temp.ssa_ir.26);
+    bytes8 %temp.ssa_ir.3 = bytes8(%temp.ssa_ir.4) | bytes8(%temp.ssa_ir.24);
+    bytes8 %temp.ssa_ir.28 = bytes8(%temp.ssa_ir.10) (u)>> bytes8(40);
+    bytes8 %temp.ssa_ir.29 = bytes8 hex"00_00_00_00_00_00_ff_00";
+    bytes8 %temp.ssa_ir.27 = bytes8(%temp.ssa_ir.28) & bytes8(%temp.ssa_ir.29);
+    bytes8 %temp.ssa_ir.2 = bytes8(%temp.ssa_ir.3) | bytes8(%temp.ssa_ir.27);
+    bytes8 %temp.ssa_ir.31 = bytes8(%temp.ssa_ir.10) (u)>> bytes8(56);
+    bytes8 %temp.ssa_ir.32 = bytes8 hex"00_00_00_00_00_00_00_ff";
+    bytes8 %temp.ssa_ir.30 = bytes8(%temp.ssa_ir.31) & bytes8(%temp.ssa_ir.32);
+    bytes8 %out = bytes8(%temp.ssa_ir.2) | bytes8(%temp.ssa_ir.30);
     return bytes8(%out);"#,
     )
 }
@@ -376,7 +370,6 @@ fn test_for_loop() {
         cfg_no,
         r#"private function sol#2 test::test::function::get_pid_state__uint64 (uint64) returns (uint8):
 block#0 entry:
-    uint64 %_pid = uint64(arg#0);
     uint64 %n = 8;
     uint16 %i = 1;
     br block#2;
@@ -401,8 +394,9 @@ block#4 endfor:
     return uint8(%temp.ssa_ir.8);
 
 block#5 then:
-    uint64 %temp.ssa_ir.11 = (zext uint16(%i) to uint64);
-    uint64 %temp.ssa_ir.10 = uint64(%_pid) (u)/ uint64(%temp.ssa_ir.11);
+    uint64 %temp.ssa_ir.11 = uint64(arg#0);
+    uint64 %temp.ssa_ir.12 = (zext uint16(%i) to uint64);
+    uint64 %temp.ssa_ir.10 = uint64(%temp.ssa_ir.11) (u)/ uint64(%temp.ssa_ir.12);
     uint64 %n = uint64(%n) * uint64(%temp.ssa_ir.10);
     br block#7;
 
@@ -452,18 +446,18 @@ fn test_nested_if_blocks() {
         cfg_no,
         r#"public function sol#2 test::test::function::score_card__test.card (ptr<struct.0>) returns (uint32):
 block#0 entry:
-    ptr<struct.0> %c = ptr<struct.0>(arg#0);
     uint32 %score = 0;
-    ptr<uint8> %temp.ssa_ir.4 = access ptr<struct.0>(%c) member 1;
+    ptr<struct.0> %temp.ssa_ir.5 = ptr<struct.0>(arg#0);
+    ptr<uint8> %temp.ssa_ir.4 = access ptr<struct.0>(%temp.ssa_ir.5) member 1;
     uint8 %temp.ssa_ir.3 = *ptr<uint8>(%temp.ssa_ir.4);
     bool %temp.ssa_ir.2 = uint8(%temp.ssa_ir.3) == uint8(2);
     cbr bool(%temp.ssa_ir.2) block#1 else block#2;
 
 block#1 then:
-    ptr<uint8> %temp.ssa_ir.7 = access ptr<struct.0>(%c) member 0;
-    uint8 %temp.ssa_ir.6 = *ptr<uint8>(%temp.ssa_ir.7);
-    bool %temp.ssa_ir.5 = uint8(%temp.ssa_ir.6) == uint8(12);
-    cbr bool(%temp.ssa_ir.5) block#3 else block#4;
+    ptr<uint8> %temp.ssa_ir.8 = access ptr<struct.0>(%temp.ssa_ir.5) member 0;
+    uint8 %temp.ssa_ir.7 = *ptr<uint8>(%temp.ssa_ir.8);
+    bool %temp.ssa_ir.6 = uint8(%temp.ssa_ir.7) == uint8(12);
+    cbr bool(%temp.ssa_ir.6) block#3 else block#4;
 
 block#2 endif:
     return uint32(%score);
@@ -473,30 +467,30 @@ block#3 then:
     br block#4;
 
 block#4 endif:
-    ptr<uint8> %temp.ssa_ir.10 = access ptr<struct.0>(%c) member 0;
-    uint8 %temp.ssa_ir.9 = *ptr<uint8>(%temp.ssa_ir.10);
-    bool %temp.ssa_ir.8 = uint8(%temp.ssa_ir.9) == uint8(11);
-    cbr bool(%temp.ssa_ir.8) block#5 else block#6;
+    ptr<uint8> %temp.ssa_ir.11 = access ptr<struct.0>(%temp.ssa_ir.5) member 0;
+    uint8 %temp.ssa_ir.10 = *ptr<uint8>(%temp.ssa_ir.11);
+    bool %temp.ssa_ir.9 = uint8(%temp.ssa_ir.10) == uint8(11);
+    cbr bool(%temp.ssa_ir.9) block#5 else block#6;
 
 block#5 then:
     uint32 %score = 13;
     br block#6;
 
 block#6 endif:
-    ptr<uint8> %temp.ssa_ir.13 = access ptr<struct.0>(%c) member 0;
-    uint8 %temp.ssa_ir.12 = *ptr<uint8>(%temp.ssa_ir.13);
-    bool %temp.ssa_ir.11 = uint8(%temp.ssa_ir.12) == uint8(10);
-    cbr bool(%temp.ssa_ir.11) block#7 else block#8;
+    ptr<uint8> %temp.ssa_ir.14 = access ptr<struct.0>(%temp.ssa_ir.5) member 0;
+    uint8 %temp.ssa_ir.13 = *ptr<uint8>(%temp.ssa_ir.14);
+    bool %temp.ssa_ir.12 = uint8(%temp.ssa_ir.13) == uint8(10);
+    cbr bool(%temp.ssa_ir.12) block#7 else block#8;
 
 block#7 then:
     uint32 %score = 12;
     br block#8;
 
 block#8 endif:
-    ptr<uint8> %temp.ssa_ir.16 = access ptr<struct.0>(%c) member 0;
-    uint8 %temp.ssa_ir.15 = *ptr<uint8>(%temp.ssa_ir.16);
-    bool %temp.ssa_ir.14 = uint8(%temp.ssa_ir.15) == uint8(9);
-    cbr bool(%temp.ssa_ir.14) block#9 else block#10;
+    ptr<uint8> %temp.ssa_ir.17 = access ptr<struct.0>(%temp.ssa_ir.5) member 0;
+    uint8 %temp.ssa_ir.16 = *ptr<uint8>(%temp.ssa_ir.17);
+    bool %temp.ssa_ir.15 = uint8(%temp.ssa_ir.16) == uint8(9);
+    cbr bool(%temp.ssa_ir.15) block#9 else block#10;
 
 block#9 then:
     uint32 %score = 11;
@@ -617,7 +611,6 @@ block#9 out_of_bounds:
     assert_failure;
 
 block#10 in_bounds:
-    bytes1 %temp.5 = 202;
     ptr<slice<bytes1>> %temp.ssa_ir.31 = access ptr<struct.SolAccountInfo>(%temp.6) member 2;
     ptr<slice<bytes1>> %temp.ssa_ir.30 = *ptr<slice<bytes1>>(%temp.ssa_ir.31);
     ptr<bytes1> %temp.ssa_ir.29 = ptr<slice<bytes1>>(%temp.ssa_ir.30)[uint32(%index.temp.7)];
@@ -635,7 +628,6 @@ block#11 out_of_bounds:
     assert_failure;
 
 block#12 in_bounds:
-    bytes1 %temp.8 = 254;
     ptr<slice<bytes1>> %temp.ssa_ir.39 = access ptr<struct.SolAccountInfo>(%temp.9) member 2;
     ptr<slice<bytes1>> %temp.ssa_ir.38 = *ptr<slice<bytes1>>(%temp.ssa_ir.39);
     ptr<bytes1> %temp.ssa_ir.37 = ptr<slice<bytes1>>(%temp.ssa_ir.38)[uint32(%index.temp.10)];
@@ -660,18 +652,18 @@ fn test_assertion_using_require() {
     assert_polkadot_lir_str_eq(
         src,
         0,
-        r#"public function sol#4 Test::Test::function::test__int32 (int32):
+        r#"public function sol#13 Test::Test::function::test__int32 (int32):
 block#0 entry:
-    int32 %num = int32(arg#0);
-    bool %temp.ssa_ir.1 = int32(%num) > int32(10);
+    int32 %temp.ssa_ir.2 = int32(arg#0);
+    bool %temp.ssa_ir.1 = int32(%temp.ssa_ir.2) > int32(10);
     cbr bool(%temp.ssa_ir.1) block#1 else block#2;
 
 block#1 noassert:
     return;
 
 block#2 doassert:
-    ptr<slice<bytes1>> %temp.ssa_ir.2 = alloc ptr<slice<bytes1>>[uint32(9)] {08, c3, 79, a0, 10, 73, 65, 73, 61};
-    assert_failure ptr<slice<bytes1>>(%temp.ssa_ir.2);"#,
+    ptr<slice<bytes1>> %temp.ssa_ir.3 = alloc ptr<slice<bytes1>>[uint32(9)] {08, c3, 79, a0, 10, 73, 65, 73, 61};
+    assert_failure ptr<slice<bytes1>>(%temp.ssa_ir.3);"#,
     );
 }
 
@@ -690,10 +682,10 @@ fn test_call_1() {
     assert_polkadot_lir_str_eq(
         src,
         0,
-        r#"public function sol#4 Test::Test::function::test__int32 (int32):
+        r#"public function sol#13 Test::Test::function::test__int32 (int32):
 block#0 entry:
-    int32 %num = int32(arg#0);
-     = call function#1(int32(%num));
+    int32 %temp.ssa_ir.1 = int32(arg#0);
+     = call function#1(int32(%temp.ssa_ir.1));
     return;"#,
     )
 }
@@ -710,16 +702,15 @@ fn test_return_data_and_return_code() {
         "polkadot_call_dispatch",
         r#"private function none polkadot_call_dispatch (ptr<uint8>, uint32, uint128, ptr<uint32>):
 block#0 entry:
-    uint32 %input_len.temp.4 = uint32(arg#1);
-    uint128 %value.temp.5 = uint128(arg#2);
-    ptr<uint8> %input_ptr.temp.6 = ptr<uint8>(arg#0);
-    bool %temp.ssa_ir.8 = uint32(%input_len.temp.4) (u)< uint32(4);
+    uint32 %temp.ssa_ir.9 = uint32(arg#1);
+    bool %temp.ssa_ir.8 = uint32(%temp.ssa_ir.9) (u)< uint32(4);
     cbr bool(%temp.ssa_ir.8) block#2 else block#1;
 
 block#1 start_dispatch:
-    uint32 %selector.temp.7 = builtin: ReadFromBuffer(ptr<uint8>(%input_ptr.temp.6), uint32(0));
-    uint32 %temp.ssa_ir.9 = uint32(arg#3);
-    store uint32(%selector.temp.7) to uint32(%temp.ssa_ir.9);
+    ptr<uint8> %temp.ssa_ir.10 = ptr<uint8>(arg#0);
+    uint32 %selector.temp.7 = builtin: ReadFromBuffer(ptr<uint8>(%temp.ssa_ir.10), uint32(0));
+    uint32 %temp.ssa_ir.11 = uint32(arg#3);
+    store uint32(%selector.temp.7) to uint32(%temp.ssa_ir.11);
     switch uint32(%selector.temp.7):
     case:    uint32(1845340408) => block#3
     default: block#2;
@@ -728,16 +719,17 @@ block#2 fb_or_recv:
     return_code "function selector invalid";
 
 block#3 func_0_dispatch:
-    bool %temp.ssa_ir.10 = uint128(%value.temp.5) (u)> uint128(0);
-    cbr bool(%temp.ssa_ir.10) block#4 else block#5;
+    uint128 %temp.ssa_ir.13 = uint128(arg#2);
+    bool %temp.ssa_ir.12 = uint128(%temp.ssa_ir.13) (u)> uint128(0);
+    cbr bool(%temp.ssa_ir.12) block#4 else block#5;
 
 block#4 func_0_got_value:
     assert_failure;
 
 block#5 func_0_no_value:
      = call function#0();
-    ptr<struct.vector<uint8>> %temp.ssa_ir.11 = alloc ptr<struct.vector<uint8>>[uint32(0)];
-    return_data ptr<struct.vector<uint8>>(%temp.ssa_ir.11) of length uint32(0);"#,
+    ptr<struct.vector<uint8>> %temp.ssa_ir.14 = alloc ptr<struct.vector<uint8>>[uint32(0)];
+    return_data ptr<struct.vector<uint8>>(%temp.ssa_ir.14) of length uint32(0);"#,
         Target::default_polkadot(),
     )
 }
@@ -754,7 +746,7 @@ fn test_value_transfer() {
     assert_polkadot_lir_str_eq(
         src,
         0,
-        r#"public function sol#4 Test::Test::function::transfer__address_uint128 (uint8[32], uint128):
+        r#"public function sol#13 Test::Test::function::transfer__address_uint128 (uint8[32], uint128):
 block#0 entry:
     uint8[32] %addr = uint8[32](arg#0);
     uint128 %amount = uint128(arg#1);
@@ -880,9 +872,8 @@ fn test_switch() {
         0,
         r#"public function sol#2 foo::foo::function::test__uint256 (uint256):
 block#0 entry:
-    uint256 %x = uint256(arg#0);
-    uint256 %y = 5;
-    uint256 %temp.ssa_ir.3 = uint256(%x) & uint256(3);
+    uint256 %temp.ssa_ir.4 = uint256(arg#0);
+    uint256 %temp.ssa_ir.3 = uint256(%temp.ssa_ir.4) & uint256(3);
     switch uint256(%temp.ssa_ir.3):
     case:    uint256(0) => block#2, 
     case:    uint256(1) => block#3, 
@@ -893,18 +884,12 @@ block#1 end_switch:
     return;
 
 block#2 case_0:
-    uint256 %y = 5;
-    uint256 %x = 5;
     br block#1;
 
 block#3 case_1:
-    uint256 %y = 7;
-    uint256 %x = 9;
     br block#1;
 
 block#4 case_2:
-    uint256 %y = 10;
-    uint256 %x = 80;
     br block#1;"#,
     )
 }
@@ -928,18 +913,16 @@ fn test_keccak256() {
     assert_polkadot_lir_str_eq(
         src,
         0,
-        r#"public function sol#4 b::b::function::add__string_address (ptr<struct.vector<uint8>>, uint8[32]):
+        r#"public function sol#13 b::b::function::add__string_address (ptr<struct.vector<uint8>>, uint8[32]):
 block#0 entry:
-    ptr<struct.vector<uint8>> %name = ptr<struct.vector<uint8>>(arg#0);
-    uint8[32] %addr = uint8[32](arg#1);
-    bool %temp.2 = true;
-    storage_ptr<mapping(struct.vector<uint8> => struct.0)> %temp.ssa_ir.5 = keccak256(uint256(0), ptr<struct.vector<uint8>>(%name));
+    ptr<struct.vector<uint8>> %temp.ssa_ir.6 = ptr<struct.vector<uint8>>(arg#0);
+    storage_ptr<mapping(struct.vector<uint8> => struct.0)> %temp.ssa_ir.5 = keccak256(uint256(0), ptr<struct.vector<uint8>>(%temp.ssa_ir.6));
     uint256 %temp.ssa_ir.4 = storage_ptr<mapping(struct.vector<uint8> => struct.0)>(%temp.ssa_ir.5) (of)+ uint256(0);
     set_storage uint256(%temp.ssa_ir.4) true;
-    uint8[32] %temp.3 = uint8[32](arg#1);
-    storage_ptr<mapping(struct.vector<uint8> => struct.0)> %temp.ssa_ir.7 = keccak256(uint256(0), ptr<struct.vector<uint8>>(%name));
-    uint256 %temp.ssa_ir.6 = storage_ptr<mapping(struct.vector<uint8> => struct.0)>(%temp.ssa_ir.7) (of)+ uint256(1);
-    set_storage uint256(%temp.ssa_ir.6) uint8[32](%temp.3);
+    storage_ptr<mapping(struct.vector<uint8> => struct.0)> %temp.ssa_ir.8 = keccak256(uint256(0), ptr<struct.vector<uint8>>(%temp.ssa_ir.6));
+    uint256 %temp.ssa_ir.7 = storage_ptr<mapping(struct.vector<uint8> => struct.0)>(%temp.ssa_ir.8) (of)+ uint256(1);
+    uint8[32] %temp.ssa_ir.9 = uint8[32](arg#1);
+    set_storage uint256(%temp.ssa_ir.7) uint8[32](%temp.ssa_ir.9);
     return;"#,
     )
 }
@@ -960,12 +943,12 @@ fn test_internal_function_cfg() {
     assert_polkadot_lir_str_eq(
         src,
         1,
-        r#"public function sol#5 A::A::function::bar__uint256 (uint256) returns (uint256):
+        r#"public function sol#14 A::A::function::bar__uint256 (uint256) returns (uint256):
 block#0 entry:
-    uint256 %b = uint256(arg#0);
     ptr<function (uint256) returns (uint256)> %temp.ssa_ir.6 = function#0;
     ptr<function (uint256) returns (uint256)> %fPtr = (cast ptr<function (uint256) returns (uint256)>(%temp.ssa_ir.6) to ptr<function (uint256) returns (uint256)>);
-    uint256 %.temp.5 = call ptr<function (uint256) returns (uint256)>(%fPtr)(uint256(%b));
+    uint256 %temp.ssa_ir.7 = uint256(arg#0);
+    uint256 %.temp.5 = call ptr<function (uint256) returns (uint256)>(%fPtr)(uint256(%temp.ssa_ir.7));
     return uint256(%.temp.5);"#,
     )
 }
@@ -983,8 +966,8 @@ fn test_sign_ext() {
         0,
         r#"public function sol#2 Test::Test::function::test__int32 (int32) returns (int128):
 block#0 entry:
-    int32 %a = int32(arg#0);
-    int128 %temp.ssa_ir.2 = (sext int32(%a) to int128);
+    int32 %temp.ssa_ir.3 = int32(arg#0);
+    int128 %temp.ssa_ir.2 = (sext int32(%temp.ssa_ir.3) to int128);
     return int128(%temp.ssa_ir.2);"#,
     )
 }
@@ -1002,9 +985,9 @@ fn test_string_compare() {
         0,
         r#"public function sol#2 Test::Test::function::test__string_string (ptr<struct.vector<uint8>>, ptr<struct.vector<uint8>>) returns (bool):
 block#0 entry:
-    ptr<struct.vector<uint8>> %a = ptr<struct.vector<uint8>>(arg#0);
-    ptr<struct.vector<uint8>> %b = ptr<struct.vector<uint8>>(arg#1);
-    bool %temp.ssa_ir.3 = strcmp(ptr<struct.vector<uint8>>(%a), ptr<struct.vector<uint8>>(%b));
+    ptr<struct.vector<uint8>> %temp.ssa_ir.4 = ptr<struct.vector<uint8>>(arg#0);
+    ptr<struct.vector<uint8>> %temp.ssa_ir.5 = ptr<struct.vector<uint8>>(arg#1);
+    bool %temp.ssa_ir.3 = strcmp(ptr<struct.vector<uint8>>(%temp.ssa_ir.4), ptr<struct.vector<uint8>>(%temp.ssa_ir.5));
     return bool(%temp.ssa_ir.3);"#,
     )
 }
@@ -1023,7 +1006,6 @@ fn test_const_array() {
         0,
         r#"public function sol#2 Test::Test::function::test () returns (uint32):
 block#0 entry:
-    uint32 %index.temp.1 = 0;
     bool %temp.ssa_ir.2 = uint32(0) (u)>= uint32(5);
     cbr bool(%temp.ssa_ir.2) block#1 else block#2;
 
@@ -1093,13 +1075,13 @@ block#0 entry:
     ptr<struct.SolAccountMeta> %temp.ssa_ir.22 = struct { ptr<uint8[32]>(%temp.ssa_ir.23), false, false };
     ptr<struct.SolAccountMeta[3]> %metas = ptr<struct.SolAccountMeta[3]> [ptr<struct.SolAccountMeta>(%temp.ssa_ir.16), ptr<struct.SolAccountMeta>(%temp.ssa_ir.19), ptr<struct.SolAccountMeta>(%temp.ssa_ir.22)];
     ptr<struct.vector<uint8>> %abi_encoded.temp.12 = alloc ptr<struct.vector<uint8>>[uint32(8)];
-    bytes8 %temp.ssa_ir.24 = bytes8 hex"87_2c_cd_c6_19_01_48_bc";
-    write_buf ptr<struct.vector<uint8>>(%abi_encoded.temp.12) offset:uint32(0) value:bytes8(%temp.ssa_ir.24);
-    _ = call_ext [regular] address:uint8[32](78642644713358252795404932596995255556623171005675782810573618728006773308276) payload:ptr<struct.vector<uint8>>(%abi_encoded.temp.12) value:uint64(0) gas:uint64(0) accounts:ptr<struct.SolAccountMeta[3]>(%metas) seeds:_ contract_no:1, function_no:3 flags:_;
+    uint64 %temp.ssa_ir.24 = uint64 hex"87_2c_cd_c6_19_01_48_bc";
+    write_buf ptr<struct.vector<uint8>>(%abi_encoded.temp.12) offset:uint32(0) value:uint64(%temp.ssa_ir.24);
+    _ = call_ext [regular] address:uint8[32](78642644713358252795404932596995255556623171005675782810573618728006773308276) payload:ptr<struct.vector<uint8>>(%abi_encoded.temp.12) value:uint64(0) gas:uint64(0) proof_size:_ storage_deposit_limit:_ accounts:ptr<struct.SolAccountMeta[3]>(%metas) seeds:_ contract_no:1, function_no:3 flags:_;
     ptr<struct.vector<uint8>> %abi_encoded.temp.13 = alloc ptr<struct.vector<uint8>>[uint32(8)];
-    bytes8 %temp.ssa_ir.25 = bytes8 hex"97_f8_3c_a2_18_9f_26_9d";
-    write_buf ptr<struct.vector<uint8>>(%abi_encoded.temp.13) offset:uint32(0) value:bytes8(%temp.ssa_ir.25);
-    _ = call_ext [regular] address:uint8[32](78642644713358252795404932596995255556623171005675782810573618728006773308276) payload:ptr<struct.vector<uint8>>(%abi_encoded.temp.13) value:uint64(0) gas:uint64(0) accounts:none seeds:_ contract_no:1, function_no:4 flags:_;
+    uint64 %temp.ssa_ir.25 = uint64 hex"97_f8_3c_a2_18_9f_26_9d";
+    write_buf ptr<struct.vector<uint8>>(%abi_encoded.temp.13) offset:uint32(0) value:uint64(%temp.ssa_ir.25);
+    _ = call_ext [regular] address:uint8[32](78642644713358252795404932596995255556623171005675782810573618728006773308276) payload:ptr<struct.vector<uint8>>(%abi_encoded.temp.13) value:uint64(0) gas:uint64(0) proof_size:_ storage_deposit_limit:_ accounts:none seeds:_ contract_no:1, function_no:4 flags:_;
     return;"#,
     )
 }
@@ -1124,14 +1106,14 @@ fn test_constructor() {
     assert_polkadot_lir_str_eq(
         src,
         0,
-        r#"public function sol#4 B::B::function::test__uint256 (uint256):
+        r#"public function sol#13 B::B::function::test__uint256 (uint256):
 block#0 entry:
-    uint256 %a = uint256(arg#0);
     ptr<struct.vector<uint8>> %abi_encoded.temp.18 = alloc ptr<struct.vector<uint8>>[uint32(36)];
     uint32 %temp.ssa_ir.20 = uint32 hex"58_16_c4_25";
     write_buf ptr<struct.vector<uint8>>(%abi_encoded.temp.18) offset:uint32(0) value:uint32(%temp.ssa_ir.20);
-    write_buf ptr<struct.vector<uint8>>(%abi_encoded.temp.18) offset:uint32(4) value:uint256(%a);
-    uint32 %success.temp.17, uint8[32] %temp.16 = constructor(no: 6, contract_no:1) salt:_ value:_ gas:uint64(0) address:_ seeds:_ encoded-buffer:ptr<struct.vector<uint8>>(%abi_encoded.temp.18) accounts:absent
+    uint256 %temp.ssa_ir.21 = uint256(arg#0);
+    write_buf ptr<struct.vector<uint8>>(%abi_encoded.temp.18) offset:uint32(4) value:uint256(%temp.ssa_ir.21);
+    uint32 %success.temp.17, uint8[32] %temp.16 = constructor(no: 15, contract_no:1) salt:_ value:_ gas:uint64(0) address:_ seeds:_ encoded-buffer:ptr<struct.vector<uint8>>(%abi_encoded.temp.18) accounts:absent
     switch uint32(%success.temp.17):
     case:    uint32(0) => block#1, 
     case:    uint32(2) => block#2
@@ -1143,8 +1125,8 @@ block#1 ret_success:
     return;
 
 block#2 ret_bubble:
-    ptr<struct.vector<uint8>> %temp.ssa_ir.21 = (extern_call_ret_data);
-    assert_failure ptr<struct.vector<uint8>>(%temp.ssa_ir.21);
+    ptr<struct.vector<uint8>> %temp.ssa_ir.22 = (extern_call_ret_data);
+    assert_failure ptr<struct.vector<uint8>>(%temp.ssa_ir.22);
 
 block#3 ret_no_data:
     assert_failure;"#,
@@ -1171,14 +1153,14 @@ fn test_external_fn() {
         0,
         r#"public function sol#2 Testing::Testing::function::testExternalFunction__bytes (ptr<struct.vector<uint8>>) returns (bytes8, uint8[32]):
 block#0 entry:
-    ptr<struct.vector<uint8>> %buffer = ptr<struct.vector<uint8>>(arg#0);
-    uint32 %temp.4 = builtin: ArrayLength(ptr<struct.vector<uint8>>(%buffer));
-    bool %temp.ssa_ir.5 = uint32(40) (u)< uint32(%temp.4);
-    cbr bool(%temp.ssa_ir.5) block#1 else block#2;
+    ptr<struct.vector<uint8>> %temp.ssa_ir.5 = ptr<struct.vector<uint8>>(arg#0);
+    uint32 %temp.4 = builtin: ArrayLength(ptr<struct.vector<uint8>>(%temp.ssa_ir.5));
+    bool %temp.ssa_ir.6 = uint32(40) (u)< uint32(%temp.4);
+    cbr bool(%temp.ssa_ir.6) block#1 else block#2;
 
 block#1 inbounds:
-    bool %temp.ssa_ir.6 = uint32(40) (u)< uint32(%temp.4);
-    cbr bool(%temp.ssa_ir.6) block#3 else block#4;
+    bool %temp.ssa_ir.7 = uint32(40) (u)< uint32(%temp.4);
+    cbr bool(%temp.ssa_ir.7) block#3 else block#4;
 
 block#2 out_of_bounds:
     assert_failure;
@@ -1187,17 +1169,17 @@ block#3 not_all_bytes_read:
     assert_failure;
 
 block#4 buffer_read:
-    uint64 %temp.ssa_ir.9 = builtin: ReadFromBuffer(ptr<struct.vector<uint8>>(%buffer), uint32(0));
-    uint8[32] %temp.ssa_ir.10 = builtin: ReadFromBuffer(ptr<struct.vector<uint8>>(%buffer), uint32(8));
-    ptr<struct.ExternalFunction> %temp.ssa_ir.8 = struct { uint64(%temp.ssa_ir.9), uint8[32](%temp.ssa_ir.10) };
-    ptr<struct.ExternalFunction> %temp.ssa_ir.7 = (cast ptr<struct.ExternalFunction>(%temp.ssa_ir.8) to ptr<struct.ExternalFunction>);
-    ptr<struct.ExternalFunction> %fPtr = (cast ptr<struct.ExternalFunction>(%temp.ssa_ir.7) to ptr<struct.ExternalFunction>);
-    ptr<uint64> %temp.ssa_ir.13 = access ptr<struct.ExternalFunction>(%fPtr) member 0;
-    uint64 %temp.ssa_ir.12 = *ptr<uint64>(%temp.ssa_ir.13);
-    bytes8 %temp.ssa_ir.11 = (cast uint64(%temp.ssa_ir.12) to bytes8);
-    ptr<uint8[32]> %temp.ssa_ir.15 = access ptr<struct.ExternalFunction>(%fPtr) member 1;
-    uint8[32] %temp.ssa_ir.14 = *ptr<uint8[32]>(%temp.ssa_ir.15);
-    return bytes8(%temp.ssa_ir.11), uint8[32](%temp.ssa_ir.14);"#,
+    uint64 %temp.ssa_ir.10 = builtin: ReadFromBuffer(ptr<struct.vector<uint8>>(%temp.ssa_ir.5), uint32(0));
+    uint8[32] %temp.ssa_ir.11 = builtin: ReadFromBuffer(ptr<struct.vector<uint8>>(%temp.ssa_ir.5), uint32(8));
+    ptr<struct.ExternalFunction> %temp.ssa_ir.9 = struct { uint64(%temp.ssa_ir.10), uint8[32](%temp.ssa_ir.11) };
+    ptr<struct.ExternalFunction> %temp.ssa_ir.8 = (cast ptr<struct.ExternalFunction>(%temp.ssa_ir.9) to ptr<struct.ExternalFunction>);
+    ptr<struct.ExternalFunction> %fPtr = (cast ptr<struct.ExternalFunction>(%temp.ssa_ir.8) to ptr<struct.ExternalFunction>);
+    ptr<uint64> %temp.ssa_ir.14 = access ptr<struct.ExternalFunction>(%fPtr) member 0;
+    uint64 %temp.ssa_ir.13 = *ptr<uint64>(%temp.ssa_ir.14);
+    bytes8 %temp.ssa_ir.12 = (cast uint64(%temp.ssa_ir.13) to bytes8);
+    ptr<uint8[32]> %temp.ssa_ir.16 = access ptr<struct.ExternalFunction>(%fPtr) member 1;
+    uint8[32] %temp.ssa_ir.15 = *ptr<uint8[32]>(%temp.ssa_ir.16);
+    return bytes8(%temp.ssa_ir.12), uint8[32](%temp.ssa_ir.15);"#,
     )
 }
 
@@ -1288,12 +1270,10 @@ block#0 entry:
     set_storage uint32(64) uint256(%temp.9);
     ptr<struct.vector<uint8>> %temp.10 = alloc ptr<slice<bytes1>>[uint32(0)] {};
     set_storage uint32(96) ptr<struct.vector<uint8>>(%temp.10);
-    bytes1 %temp.11 = 255;
     set_storage_bytes uint32(100) offset:uint32(1) value:bytes1(255);
     bytes4 %temp.12 = load_storage uint32(104);
     bytes4 %temp.13 = bytes4(%temp.12) | bytes4(538976288);
     set_storage uint32(104) bytes4(%temp.13);
-    uint8 %temp.14 = 3;
     set_storage uint32(108) uint8(3);
     return;"#,
     )
@@ -1314,20 +1294,19 @@ fn test_byte_cast() {
         0,
         r#"public function sol#2 Cast::Cast::function::test__uint256 (uint256) returns (ptr<struct.vector<uint8>>):
 block#0 entry:
-    uint256 %num = uint256(arg#0);
-    uint256 %value.temp.4 = uint256(arg#0);
-    bool %temp.ssa_ir.5 = uint256(%value.temp.4) (u)>= uint256(4294967296);
+    uint256 %temp.ssa_ir.6 = uint256(arg#0);
+    bool %temp.ssa_ir.5 = uint256(%temp.ssa_ir.6) (u)>= uint256(4294967296);
     cbr bool(%temp.ssa_ir.5) block#1 else block#2;
 
 block#1 out_of_bounds:
     assert_failure;
 
 block#2 in_bounds:
-    uint32 %temp.ssa_ir.6 = (trunc uint256(%value.temp.4) to uint32);
-    ptr<struct.vector<uint8>> %smol_buf = alloc ptr<struct.vector<uint8>>[uint32(%temp.ssa_ir.6)];
+    uint32 %temp.ssa_ir.7 = (trunc uint256(%temp.ssa_ir.6) to uint32);
+    ptr<struct.vector<uint8>> %smol_buf = alloc ptr<struct.vector<uint8>>[uint32(%temp.ssa_ir.7)];
     bytes32 %b32 = (cast ptr<struct.vector<uint8>>(%smol_buf) to bytes32);
-    ptr<struct.vector<uint8>> %temp.ssa_ir.7 = (cast bytes32(%b32) to ptr<struct.vector<uint8>>);
-    return ptr<struct.vector<uint8>>(%temp.ssa_ir.7);"#,
+    ptr<struct.vector<uint8>> %temp.ssa_ir.8 = (cast bytes32(%b32) to ptr<struct.vector<uint8>>);
+    return ptr<struct.vector<uint8>>(%temp.ssa_ir.8);"#,
     )
 }
 
@@ -1345,9 +1324,9 @@ fn test_signed_modulo() {
         0,
         r#"public function sol#2 SignedModulo::SignedModulo::function::test__int256_int256 (int256, int256) returns (int256):
 block#0 entry:
-    int256 %a = int256(arg#0);
-    int256 %b = int256(arg#1);
-    int256 %c = int256(%a) % int256(%b);
+    int256 %temp.ssa_ir.4 = int256(arg#0);
+    int256 %temp.ssa_ir.5 = int256(arg#1);
+    int256 %c = int256(%temp.ssa_ir.4) % int256(%temp.ssa_ir.5);
     return int256(%c);"#,
     )
 }
@@ -1372,13 +1351,13 @@ fn test_compare() {
         0,
         r#"public function sol#2 example::example::function::func__int256 (int256):
 block#0 entry:
-    int256 %x = int256(arg#0);
     bool %or.temp.1 = true;
-    bool %temp.ssa_ir.3 = int256(%x) < int256(-32768);
+    int256 %temp.ssa_ir.4 = int256(arg#0);
+    bool %temp.ssa_ir.3 = int256(%temp.ssa_ir.4) < int256(-32768);
     cbr bool(%temp.ssa_ir.3) block#2 else block#1;
 
 block#1 or_right_side:
-    bool %or.temp.1 = int256(%x) > int256(32767);
+    bool %or.temp.1 = int256(%temp.ssa_ir.4) > int256(32767);
     br block#2;
 
 block#2 or_end:
@@ -1388,7 +1367,7 @@ block#3 then:
     assert_failure;
 
 block#4 endif:
-    int16 %temp.2 = (trunc int256(%x) to int16);
+    int16 %temp.2 = (trunc int256(%temp.ssa_ir.4) to int16);
     set_storage uint32(16) int16(%temp.2);
     return;"#,
     )
@@ -1459,27 +1438,27 @@ fn test_call_ext() {
         0,
         r#"public function sol#2 adult::adult::function::test__address (uint8[32]):
 block#0 entry:
-    uint8[32] %id = uint8[32](arg#0);
     ptr<struct.vector<uint8>> %temp.ssa_ir.15 = alloc ptr<struct.vector<uint8>>[uint32(4)] {6c, 75, 6e, 61};
     uint32 %temp.ssa_ir.14 = builtin: ArrayLength(ptr<struct.vector<uint8>>(%temp.ssa_ir.15));
     uint32 %temp.ssa_ir.13 = uint32(%temp.ssa_ir.14) + uint32(4);
     uint32 %temp.ssa_ir.12 = uint32(8) + uint32(%temp.ssa_ir.13);
     ptr<struct.vector<uint8>> %abi_encoded.temp.10 = alloc ptr<struct.vector<uint8>>[uint32(%temp.ssa_ir.12)];
-    bytes8 %temp.ssa_ir.16 = bytes8 hex"87_2c_cd_c6_19_01_48_bc";
-    write_buf ptr<struct.vector<uint8>>(%abi_encoded.temp.10) offset:uint32(0) value:bytes8(%temp.ssa_ir.16);
+    uint64 %temp.ssa_ir.16 = uint64 hex"87_2c_cd_c6_19_01_48_bc";
+    write_buf ptr<struct.vector<uint8>>(%abi_encoded.temp.10) offset:uint32(0) value:uint64(%temp.ssa_ir.16);
     ptr<struct.vector<uint8>> %temp.ssa_ir.17 = alloc ptr<struct.vector<uint8>>[uint32(4)] {6c, 75, 6e, 61};
     uint32 %temp.11 = builtin: ArrayLength(ptr<struct.vector<uint8>>(%temp.ssa_ir.17));
     write_buf ptr<struct.vector<uint8>>(%abi_encoded.temp.10) offset:uint32(8) value:uint32(%temp.11);
     ptr<struct.vector<uint8>> %temp.ssa_ir.18 = alloc ptr<struct.vector<uint8>>[uint32(4)] {6c, 75, 6e, 61};
     ptr<uint8> %temp.ssa_ir.19 = ptr_add(ptr<struct.vector<uint8>>(%abi_encoded.temp.10), uint32(12));
     memcopy ptr<struct.vector<uint8>>(%temp.ssa_ir.18) to ptr<uint8>(%temp.ssa_ir.19) for uint32(%temp.11) bytes;
-    ptr<struct.SolAccountInfo[]> %temp.ssa_ir.25 = builtin: Accounts();
-    ptr<struct.SolAccountInfo> %temp.ssa_ir.24 = ptr<struct.SolAccountInfo[]>(%temp.ssa_ir.25)[uint32(1)];
-    ptr<ptr<uint8[32]>> %temp.ssa_ir.23 = access ptr<struct.SolAccountInfo>(%temp.ssa_ir.24) member 0;
-    ptr<uint8[32]> %temp.ssa_ir.22 = *ptr<ptr<uint8[32]>>(%temp.ssa_ir.23);
-    ptr<struct.SolAccountMeta> %temp.ssa_ir.21 = struct { ptr<uint8[32]>(%temp.ssa_ir.22), true, false };
-    ptr<struct.SolAccountMeta[1]> %temp.ssa_ir.20 = ptr<struct.SolAccountMeta[1]> [ptr<struct.SolAccountMeta>(%temp.ssa_ir.21)];
-    _ = call_ext [regular] address:uint8[32](%id) payload:ptr<struct.vector<uint8>>(%abi_encoded.temp.10) value:uint64(0) gas:uint64(0) accounts:ptr<struct.SolAccountMeta[1]>(%temp.ssa_ir.20) seeds:_ contract_no:1, function_no:3 flags:_;
+    uint8[32] %temp.ssa_ir.20 = uint8[32](arg#0);
+    ptr<struct.SolAccountInfo[]> %temp.ssa_ir.26 = builtin: Accounts();
+    ptr<struct.SolAccountInfo> %temp.ssa_ir.25 = ptr<struct.SolAccountInfo[]>(%temp.ssa_ir.26)[uint32(1)];
+    ptr<ptr<uint8[32]>> %temp.ssa_ir.24 = access ptr<struct.SolAccountInfo>(%temp.ssa_ir.25) member 0;
+    ptr<uint8[32]> %temp.ssa_ir.23 = *ptr<ptr<uint8[32]>>(%temp.ssa_ir.24);
+    ptr<struct.SolAccountMeta> %temp.ssa_ir.22 = struct { ptr<uint8[32]>(%temp.ssa_ir.23), true, false };
+    ptr<struct.SolAccountMeta[1]> %temp.ssa_ir.21 = ptr<struct.SolAccountMeta[1]> [ptr<struct.SolAccountMeta>(%temp.ssa_ir.22)];
+    _ = call_ext [regular] address:uint8[32](%temp.ssa_ir.20) payload:ptr<struct.vector<uint8>>(%abi_encoded.temp.10) value:uint64(0) gas:uint64(0) proof_size:_ storage_deposit_limit:_ accounts:ptr<struct.SolAccountMeta[1]>(%temp.ssa_ir.21) seeds:_ contract_no:1, function_no:3 flags:_;
     return;"#,
     )
 }
@@ -1542,8 +1521,8 @@ fn test_bitewise_not() {
         0,
         r#"public function sol#2 Test::Test::function::byte_wise_not__bytes14 (bytes14) returns (bytes14):
 block#0 entry:
-    bytes14 %a = bytes14(arg#0);
-    bytes14 %temp.ssa_ir.2 = ~bytes14(%a);
+    bytes14 %temp.ssa_ir.3 = bytes14(arg#0);
+    bytes14 %temp.ssa_ir.2 = ~bytes14(%temp.ssa_ir.3);
     return bytes14(%temp.ssa_ir.2);"#,
     )
 }
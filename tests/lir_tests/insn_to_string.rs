@@ -411,6 +411,8 @@ fn test_stringfy_external_call_insn() {
     set_tmp(&mut v, 4, Type::Uint(64));
     // gas
     set_tmp(&mut v, 7, Type::Uint(64));
+    // proof_size
+    set_tmp(&mut v, 8, Type::Uint(64));
     let printer = new_printer(&v);
     assert_eq!(
         stringfy_insn!(
@@ -426,10 +428,12 @@ fn test_stringfy_external_call_insn() {
                 callty: CallTy::Regular,
                 contract_function_no: None,
                 flags: None,
+                proof_size: Some(identifier(8)),
+                storage_deposit_limit: None,
                 loc: Loc::Codegen,
             }
         ),
-        "bool %temp.ssa_ir.1 = call_ext [regular] address:_ payload:bytes32(%temp.ssa_ir.3) value:uint64(%temp.ssa_ir.4) gas:uint64(%temp.ssa_ir.7) accounts:absent seeds:_ contract_no:_, function_no:_ flags:_;"
+        "bool %temp.ssa_ir.1 = call_ext [regular] address:_ payload:bytes32(%temp.ssa_ir.3) value:uint64(%temp.ssa_ir.4) gas:uint64(%temp.ssa_ir.7) proof_size:uint64(%temp.ssa_ir.8) storage_deposit_limit:_ accounts:absent seeds:_ contract_no:_, function_no:_ flags:_;"
     );
 }
 
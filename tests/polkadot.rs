@@ -229,6 +229,12 @@ struct Runtime {
     events: Vec<Event>,
     /// The set of called events, needed for reentrancy protection.
     called_accounts: HashSet<usize>,
+    /// The ref time limit of the current call, if it has one.
+    ref_time_limit: Option<u64>,
+    /// The accounts on the call stack, including the one that is being executed.
+    call_stack: Vec<usize>,
+    /// Transient storage of all accounts, which only lives for the duration of a transaction.
+    transient_storage: HashMap<(usize, StorageKey), Vec<u8>>,
 }
 
 impl Runtime {
@@ -250,8 +256,13 @@ impl Runtime {
     /// * switch out the caller and callee account
     /// * populate the input and the transferred balance
     /// * clear the output
-    fn new_context(&self, callee: usize, input: Vec<u8>, value: u128) -> Self {
+    /// * limit the ref time, if a limit is given
+    fn new_context(&self, callee: usize, input: Vec<u8>, value: u128, ref_time_limit: u64) -> Self {
         let mut runtime = self.clone();
+        if ref_time_limit != 0 {
+            runtime.ref_time_limit =
+                Some(ref_time_limit.min(self.ref_time_limit.unwrap_or(u64::MAX)));
+        }
         runtime.caller_account = self.account;
         runtime.account = callee;
         runtime.transferred_value = value;
@@ -259,17 +270,19 @@ impl Runtime {
         runtime.input = Some(input);
         runtime.output = Default::default();
         runtime.called_accounts.insert(self.caller_account);
+        runtime.call_stack.push(callee);
         runtime
     }
 
     /// After a succesfull contract execution, merge the runtime context of the callee back.
     ///
-    /// We take over accounts (the callee might deploy new ones), debug buffer and emitted events.
-    /// The transferred balance will now be deducted from the caller.
+    /// We take over accounts (the callee might deploy new ones), debug buffer, emitted events
+    /// and transient storage. The transferred balance will now be deducted from the caller.
     fn accept_state(&mut self, callee_state: Self, transferred_value: u128) {
         self.debug_buffer = callee_state.debug_buffer;
         self.events = callee_state.events;
         self.accounts = callee_state.accounts;
+        self.transient_storage = callee_state.transient_storage;
         self.accounts[self.caller_account].value -= transferred_value;
     }
 
@@ -287,6 +300,7 @@ impl Runtime {
         callee: usize,
         input: Vec<u8>,
         value: u128,
+        ref_time_limit: u64,
     ) -> Option<Result<Store<Runtime>, Error>> {
         println!(
             "{export}: account={} input={} value={value}",
@@ -297,7 +311,10 @@ impl Runtime {
         self.accounts[callee]
             .contract
             .as_ref()?
-            .execute(export, self.new_context(callee, input, value))
+            .execute(
+                export,
+                self.new_context(callee, input, value, ref_time_limit),
+            )
             .map_err(|(err, debug_buffer)| {
                 self.debug_buffer = debug_buffer;
                 err
@@ -314,6 +331,7 @@ impl Runtime {
         value: u128,
         salt: &[u8],
         input: Vec<u8>,
+        ref_time_limit: u64,
     ) -> Option<Result<Store<Runtime>, Error>> {
         let account = self
             .blobs
@@ -326,7 +344,13 @@ impl Runtime {
         }
 
         self.accounts.push(account);
-        self.call("deploy", self.accounts.len() - 1, input, value)
+        self.call(
+            "deploy",
+            self.accounts.len() - 1,
+            input,
+            value,
+            ref_time_limit,
+        )
     }
 }
 
@@ -354,6 +378,11 @@ fn read_hash(mem: &[u8], ptr: u32) -> Hash {
     Hash::try_from(&mem[ptr as usize..(ptr + 32) as usize]).unwrap()
 }
 
+/// The storage deposit limit, or `None` if the "none sentinel" `u32::MAX` is passed
+fn read_deposit_limit(mem: &[u8], ptr: u32) -> Option<u128> {
+    (ptr != u32::MAX).then(|| read_value(mem, ptr))
+}
+
 /// Host functions mock the original implementation, refer to the [pallet docs][1] for more information.
 ///
 /// [1]: https://docs.rs/pallet-contracts/latest/pallet_contracts/api_doc/index.html
@@ -452,6 +481,65 @@ impl Runtime {
         }
     }
 
+    #[seal(0)]
+    fn get_transient_storage(
+        key_ptr: u32,
+        key_len: u32,
+        out_ptr: u32,
+        out_len_ptr: u32,
+    ) -> Result<u32, Trap> {
+        let key = StorageKey::try_from(read_buf(mem, key_ptr, key_len))
+            .expect("storage key size must be 32 bytes");
+        let value = match vm.transient_storage.get(&(vm.account, key)) {
+            Some(value) => value,
+            _ => return Ok(3), // In pallet-contracts, ReturnCode::KeyNotFound == 3
+        };
+        println!(
+            "get_transient_storage: {}={}",
+            hex::encode(key),
+            hex::encode(value)
+        );
+
+        write_buf(mem, out_ptr, value);
+        write_buf(mem, out_len_ptr, &(value.len() as u32).to_le_bytes());
+
+        Ok(0)
+    }
+
+    #[seal(0)]
+    fn set_transient_storage(
+        key_ptr: u32,
+        key_len: u32,
+        value_ptr: u32,
+        value_len: u32,
+    ) -> Result<u32, Trap> {
+        let key = StorageKey::try_from(read_buf(mem, key_ptr, key_len))
+            .expect("storage key size must be 32 bytes");
+        let value = mem[value_ptr as usize..(value_ptr + value_len) as usize].to_vec();
+        println!(
+            "set_transient_storage: {}={}",
+            hex::encode(key),
+            hex::encode(&value)
+        );
+
+        match vm.transient_storage.insert((vm.account, key), value) {
+            Some(value) => Ok(value.len() as u32),
+            _ => Ok(u32::MAX), // In pallets contract, u32::MAX is the "none sentinel"
+        }
+    }
+
+    #[seal(0)]
+    fn clear_transient_storage(key_ptr: u32, key_len: u32) -> Result<u32, Trap> {
+        let key = StorageKey::try_from(read_buf(mem, key_ptr, key_len))
+            .expect("storage key size must be 32 bytes");
+        println!("clear_transient_storage: {}", hex::encode(key));
+
+        match vm.transient_storage.remove(&(vm.account, key)) {
+            Some(value) => Ok(value.len() as u32),
+            _ => Ok(u32::MAX), // In pallets contract, u32::MAX is the "none sentinel"
+        }
+    }
+
     #[seal(0)]
     fn hash_keccak_256(input_ptr: u32, input_len: u32, output_ptr: u32) -> Result<(), Trap> {
         let mut hasher = Keccak::v256();
@@ -482,11 +570,13 @@ impl Runtime {
         Ok(())
     }
 
-    #[seal(1)]
-    fn seal_call(
+    #[seal(2)]
+    fn call(
         flags: u32,
         callee_ptr: u32,
-        _gas: u64,
+        ref_time_limit: u64,
+        proof_size_limit: u64,
+        deposit_ptr: u32,
         value_ptr: u32,
         input_ptr: u32,
         input_len: u32,
@@ -530,7 +620,12 @@ impl Runtime {
             return Ok(5); // ReturnCode::TransferFailed
         }
 
-        let ((ret, data), state) = match vm.call("call", callee, input, value) {
+        println!(
+            "call: ref_time_limit={ref_time_limit} proof_size_limit={proof_size_limit} deposit_limit={:?}",
+            read_deposit_limit(mem, deposit_ptr)
+        );
+
+        let ((ret, data), state) = match vm.call("call", callee, input, value, ref_time_limit) {
             Some(Ok(state)) => ((state.data().output.as_data()), state),
             Some(Err(_)) => return Ok(1), // ReturnCode::CalleeTrapped
             None => return Ok(8),
@@ -565,10 +660,12 @@ impl Runtime {
         Ok(())
     }
 
-    #[seal(1)]
+    #[seal(2)]
     fn instantiate(
         code_hash_ptr: u32,
-        _gas: u64,
+        ref_time_limit: u64,
+        proof_size_limit: u64,
+        deposit_ptr: u32,
         value_ptr: u32,
         input_data_ptr: u32,
         input_data_len: u32,
@@ -588,7 +685,13 @@ impl Runtime {
             return Ok(5); // ReturnCode::TransferFailed
         }
 
-        let ((flags, data), state) = match vm.deploy(code_hash, value, &salt, input) {
+        println!(
+            "instantiate: ref_time_limit={ref_time_limit} proof_size_limit={proof_size_limit} deposit_limit={:?}",
+            read_deposit_limit(mem, deposit_ptr)
+        );
+
+        let ((flags, data), state) = match vm.deploy(code_hash, value, &salt, input, ref_time_limit)
+        {
             Some(Ok(state)) => ((state.data().output.as_data()), state),
             Some(Err(_)) => return Ok(1), // ReturnCode::CalleeTrapped
             None => return Ok(7),         // ReturnCode::CodeNotFound
@@ -697,7 +800,7 @@ impl Runtime {
 
     #[seal(0)]
     fn gas_left(out_ptr: u32, out_len_ptr: u32) -> Result<(), Trap> {
-        let gas = 2_224_097_461u64.to_le_bytes();
+        let gas = vm.ref_time_limit.unwrap_or(2_224_097_461).to_le_bytes();
         let out_len = read_len(mem, out_len_ptr);
         assert!(out_len >= gas.len());
 
@@ -803,6 +906,144 @@ impl Runtime {
         }
         Ok(7) // ReturnCode::CodeNoteFound
     }
+
+    /// Mock signature verification: A signature is considered valid if its first 32 bytes
+    /// are the blake2_256 hash of the public key concatenated with the message.
+    #[seal(0)]
+    fn sr25519_verify(
+        signature_ptr: u32,
+        pub_key_ptr: u32,
+        message_len: u32,
+        message_ptr: u32,
+    ) -> Result<u32, Trap> {
+        let signature = read_buf(mem, signature_ptr, 64);
+        let mut data = read_buf(mem, pub_key_ptr, 32);
+        data.extend_from_slice(&read_buf(mem, message_ptr, message_len));
+
+        if signature[..32] == *blake2b(32, &[], &data).as_bytes() {
+            return Ok(0);
+        }
+        Ok(12) // ReturnCode::Sr25519VerifyFailed
+    }
+
+    /// Mock address recovery: Instead of decompressing the public key, the Ethereum address
+    /// is derived from the keccak hash of the compressed public key.
+    #[seal(0)]
+    fn ecdsa_to_eth_address(key_ptr: u32, out_ptr: u32) -> Result<u32, Trap> {
+        let key = read_buf(mem, key_ptr, 33);
+        if key[0] != 2 && key[0] != 3 {
+            return Ok(11); // ReturnCode::EcdsaRecoveryFailed
+        }
+
+        let mut hash = [0; 32];
+        let mut hasher = Keccak::v256();
+        hasher.update(&key);
+        hasher.finalize(&mut hash);
+        write_buf(mem, out_ptr, &hash[12..]);
+
+        Ok(0)
+    }
+
+    #[seal(0)]
+    fn take_storage(
+        key_ptr: u32,
+        key_len: u32,
+        out_ptr: u32,
+        out_len_ptr: u32,
+    ) -> Result<u32, Trap> {
+        let key = StorageKey::try_from(read_buf(mem, key_ptr, key_len))
+            .expect("storage key size must be 32 bytes");
+        let out_len = u32::from_le_bytes(read_buf(mem, out_len_ptr, 4).try_into().unwrap());
+        match vm.contract().storage.get(&key) {
+            // In pallet-contracts, this is Error::OutputBufferTooSmall
+            Some(value) if value.len() > out_len as usize => {
+                return Err(TrapCode::UnreachableCodeReached.into())
+            }
+            Some(_) => (),
+            _ => return Ok(3), // ReturnCode::KeyNotFound
+        }
+        let value = vm.contract().storage.remove(&key).unwrap();
+        println!("take_storage: {}={}", hex::encode(key), hex::encode(&value));
+
+        write_buf(mem, out_ptr, &value);
+        write_buf(mem, out_len_ptr, &(value.len() as u32).to_le_bytes());
+
+        Ok(0)
+    }
+
+    #[seal(1)]
+    fn contains_storage(key_ptr: u32, key_len: u32) -> Result<u32, Trap> {
+        let key = StorageKey::try_from(read_buf(mem, key_ptr, key_len))
+            .expect("storage key size must be 32 bytes");
+
+        match vm.contract().storage.get(&key) {
+            Some(value) => Ok(value.len() as u32),
+            _ => Ok(u32::MAX), // In pallets contract, u32::MAX is the "none sentinel"
+        }
+    }
+
+    #[seal(0)]
+    fn reentrance_count() -> Result<u32, Trap> {
+        let count = vm.call_stack.iter().filter(|a| **a == vm.account).count();
+        Ok(count.saturating_sub(1) as u32)
+    }
+
+    #[seal(0)]
+    fn account_reentrance_count(account_ptr: u32) -> Result<u32, Trap> {
+        let address = read_account(mem, account_ptr);
+        let count = vm
+            .call_stack
+            .iter()
+            .filter(|a| vm.accounts[**a].address == address)
+            .count();
+        Ok(count as u32)
+    }
+
+    #[seal(0)]
+    fn lock_delegate_dependency(code_hash_ptr: u32) -> Result<(), Trap> {
+        let hash = read_hash(mem, code_hash_ptr);
+        if !vm.blobs.iter().any(|code| code.hash == hash) {
+            return Err(TrapCode::UnreachableCodeReached.into()); // Error::CodeNotFound
+        }
+        println!("lock_delegate_dependency: {}", hex::encode(hash));
+        Ok(())
+    }
+
+    /// Mock XCM: The message is not executed. The message id is the blake2_256 hash of the message.
+    #[seal(0)]
+    fn xcm_send(
+        dest_ptr: u32,
+        dest_len: u32,
+        msg_ptr: u32,
+        msg_len: u32,
+        output_ptr: u32,
+    ) -> Result<u32, Trap> {
+        let dest = read_buf(mem, dest_ptr, dest_len);
+        let msg = read_buf(mem, msg_ptr, msg_len);
+        println!(
+            "xcm_send: dest={} msg={}",
+            hex::encode(&dest),
+            hex::encode(&msg)
+        );
+
+        if msg.is_empty() {
+            return Ok(14); // ReturnCode::XcmSendFailed
+        }
+        write_buf(mem, output_ptr, blake2b(32, &[], &msg).as_bytes());
+
+        Ok(0)
+    }
+
+    #[seal(0)]
+    fn xcm_execute(msg_ptr: u32, msg_len: u32) -> Result<u32, Trap> {
+        let msg = read_buf(mem, msg_ptr, msg_len);
+        println!("xcm_execute: msg={}", hex::encode(&msg));
+
+        if msg.is_empty() {
+            return Ok(13); // ReturnCode::XcmExecutionFailed
+        }
+        Ok(0)
+    }
 }
 
 /// Provides a mock implementation of substrates [contracts pallet][1]
@@ -819,7 +1060,9 @@ impl MockSubstrate {
         runtime.debug_buffer.clear();
         runtime.events.clear();
         runtime.called_accounts.clear();
-        self.0 = runtime.call(export, callee, input, value).unwrap()?;
+        runtime.call_stack.clear();
+        runtime.transient_storage.clear();
+        self.0 = runtime.call(export, callee, input, value, 0).unwrap()?;
         self.0.data_mut().transferred_value = 0;
 
         Ok(())
@@ -0,0 +1,232 @@
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "polkavm")]
+
+use solang::{codegen::Options, compile, file_resolver::FileResolver, Target};
+use std::ffi::OsStr;
+
+fn build_polkavm(src: &str) -> Vec<(Vec<u8>, String)> {
+    let tmp_file = OsStr::new("test.sol");
+    let mut cache = FileResolver::default();
+    cache.set_file_contents(tmp_file.to_str().unwrap(), src.to_string());
+    let (blobs, ns) = compile(
+        tmp_file,
+        &mut cache,
+        Target::PolkadotRevive,
+        &Options::default(),
+        vec!["unknown".to_string()],
+        "0.0.1",
+    );
+    ns.print_diagnostics_in_plain(&cache, false);
+    assert!(!ns.diagnostics.any_errors());
+    blobs
+}
+
+/// The imports and exports of a PolkaVM program blob, see `ProgramParts::from_bytes` in
+/// polkavm-common
+fn imports_and_exports(blob: &[u8]) -> (Vec<String>, Vec<String>) {
+    const SECTION_MEMORY_CONFIG: u8 = 1;
+    const SECTION_IMPORTS: u8 = 4;
+    const SECTION_EXPORTS: u8 = 5;
+    const SECTION_END_OF_FILE: u8 = 0;
+
+    assert_eq!(&blob[..4], b"PVM\0");
+    assert_eq!(blob[4], 1, "blob version");
+
+    let mut pos = 5;
+    let mut imports = Vec::new();
+    let mut exports = Vec::new();
+
+    loop {
+        let section = blob[pos];
+        pos += 1;
+
+        if section == SECTION_END_OF_FILE {
+            break;
+        }
+
+        let length = read_varint(blob, &mut pos) as usize;
+        let data = &blob[pos..pos + length];
+        pos += length;
+
+        match section {
+            SECTION_MEMORY_CONFIG => (),
+            SECTION_IMPORTS => {
+                let mut p = 0;
+                let count = read_varint(data, &mut p) as usize;
+                let offsets: Vec<usize> = data[p..p + count * 4]
+                    .chunks(4)
+                    .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+                    .collect();
+                let symbols = &data[p + count * 4..];
+
+                for (no, offset) in offsets.iter().enumerate() {
+                    let end = offsets.get(no + 1).copied().unwrap_or(symbols.len());
+
+                    imports.push(String::from_utf8(symbols[*offset..end].to_vec()).unwrap());
+                }
+            }
+            SECTION_EXPORTS => {
+                let mut p = 0;
+                let count = read_varint(data, &mut p);
+
+                for _ in 0..count {
+                    let _code_offset = read_varint(data, &mut p);
+                    let len = read_varint(data, &mut p) as usize;
+
+                    exports.push(String::from_utf8(data[p..p + len].to_vec()).unwrap());
+                    p += len;
+                }
+
+                assert_eq!(p, data.len());
+            }
+            _ => (),
+        }
+    }
+
+    assert_eq!(pos, blob.len());
+
+    (imports, exports)
+}
+
+/// Read a PolkaVM varint: the number of leading one bits of the first byte is the number of
+/// little endian bytes which follow, and the remaining bits are the upper bits of the value
+fn read_varint(data: &[u8], pos: &mut usize) -> u32 {
+    let first = data[*pos];
+    *pos += 1;
+
+    let length = (!first).leading_zeros();
+    let upper = (u32::from(first) & (0xff >> length))
+        .checked_shl(length * 8)
+        .unwrap_or(0);
+    let mut bytes = [0u8; 4];
+
+    bytes[..length as usize].copy_from_slice(&data[*pos..*pos + length as usize]);
+    *pos += length as usize;
+
+    upper | u32::from_le_bytes(bytes)
+}
+
+/// The host functions of pallet-revive which Solang can import
+const REVIVE_HOST_FUNCTIONS: &[&str] = &[
+    "account_reentrance_count",
+    "address",
+    "balance",
+    "block_number",
+    "call",
+    "call_chain_extension",
+    "caller",
+    "caller_is_root",
+    "clear_storage",
+    "code_hash",
+    "contains_storage",
+    "debug_message",
+    "delegate_call",
+    "deposit_event",
+    "ecdsa_to_eth_address",
+    "get_storage",
+    "hash_blake2_128",
+    "hash_blake2_256",
+    "hash_keccak_256",
+    "hash_sha2_256",
+    "input",
+    "instantiate",
+    "instantiation_nonce",
+    "is_contract",
+    "lock_delegate_dependency",
+    "minimum_balance",
+    "now",
+    "reentrance_count",
+    "seal_return",
+    "set_code_hash",
+    "set_storage",
+    "sr25519_verify",
+    "take_storage",
+    "terminate",
+    "transfer",
+    "value_transferred",
+    "weight_left",
+    "weight_to_fee",
+    "xcm_execute",
+    "xcm_send",
+];
+
+#[test]
+fn emit_and_link() {
+    let blobs = build_polkavm(
+        r#"
+        contract c {
+            event Stored(address indexed from, uint256 value);
+
+            uint256 value;
+            uint64 transient counter;
+
+            function store(uint256 v) public payable {
+                counter += 1;
+                value = v + msg.value + address(this).balance;
+                emit Stored(msg.sender, v);
+            }
+
+            function get() public view returns (uint256) {
+                return value + block.number + block.timestamp + gasleft();
+            }
+        }"#,
+    );
+
+    assert_eq!(blobs.len(), 1);
+
+    let (blob, _) = &blobs[0];
+
+    let (imports, mut exports) = imports_and_exports(blob);
+
+    exports.sort();
+
+    assert_eq!(exports, ["call", "deploy"]);
+
+    for name in &imports {
+        assert!(
+            REVIVE_HOST_FUNCTIONS.contains(&name.as_str()),
+            "{name} is not a pallet-revive host function"
+        );
+        assert_eq!(
+            imports.iter().filter(|n| *n == name).count(),
+            1,
+            "{name} imported more than once"
+        );
+    }
+
+    // the transient storage and weight functions are adapted to the pallet-revive names
+    for name in [
+        "input",
+        "seal_return",
+        "set_storage",
+        "get_storage",
+        "deposit_event",
+        "value_transferred",
+        "weight_left",
+        "caller",
+    ] {
+        assert!(imports.iter().any(|n| n == name), "{name} missing");
+    }
+}
+
+#[test]
+fn ethereum_abi_external_function() {
+    // addresses are 20 bytes, so the address and selector fit in the 24 bytes of the ABI
+    let blobs = build_polkavm(
+        r#"
+        contract c {
+            function f() external pure {}
+
+            function encode() public view returns (bytes) {
+                return abi.encodeEthereum(this.f);
+            }
+
+            function decode(bytes enc) public pure returns (function() external pure) {
+                return abi.decodeEthereum(enc, (function() external pure));
+            }
+        }"#,
+    );
+
+    assert_eq!(blobs.len(), 1);
+}
@@ -182,6 +182,168 @@ fn abi_encode_with_signature() {
     runtime.function("test2", Vec::new());
 }
 
+#[test]
+fn abi_encode_ethereum() {
+    let mut runtime = build_solidity(
+        r#"
+        contract bar {
+            struct S {
+                uint16 a;
+                string b;
+            }
+
+            function test1() public {
+                uint16 a = 0xfd01;
+                assert(abi.encodeEthereum(a) == hex"000000000000000000000000000000000000000000000000000000000000fd01");
+                assert(abi.encodeEthereum(true, int8(-2)) == hex"0000000000000000000000000000000000000000000000000000000000000001fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe");
+            }
+
+            function test2() public {
+                bytes enc = abi.encodeEthereum(uint32(7), "foobar");
+                assert(enc == hex"000000000000000000000000000000000000000000000000000000000000000700000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000006666f6f6261720000000000000000000000000000000000000000000000000000");
+
+                (uint32 x, string s) = abi.decodeEthereum(enc, (uint32, string));
+                assert(x == 7);
+                assert(s == "foobar");
+            }
+
+            function test3() public {
+                uint64[] arr = new uint64[](2);
+                arr[0] = 1;
+                arr[1] = 0xffff;
+                S s = S({ a: 5, b: "hi" });
+
+                bytes enc = abi.encodeEthereum(arr, s, true);
+
+                (uint64[] arr2, S s2, bool t) = abi.decodeEthereum(enc, (uint64[], S, bool));
+                assert(arr2.length == 2);
+                assert(arr2[0] == 1);
+                assert(arr2[1] == 0xffff);
+                assert(s2.a == 5);
+                assert(s2.b == "hi");
+                assert(t);
+            }
+        }"#,
+    );
+
+    runtime.function("test1", Vec::new());
+    runtime.heap_verify();
+
+    runtime.function("test2", Vec::new());
+    runtime.heap_verify();
+
+    runtime.function("test3", Vec::new());
+    runtime.heap_verify();
+}
+
+#[test]
+fn abi_decode_ethereum_invalid() {
+    let mut runtime = build_solidity(
+        r#"
+        contract bar {
+            function test(bytes enc) public pure returns (string) {
+                return abi.decodeEthereum(enc, (string));
+            }
+        }"#,
+    );
+
+    // the offset of the string points past the end of the data
+    let mut enc = vec![0u8; 32];
+    enc[31] = 0x40;
+
+    runtime.function_expect_failure("test", enc.encode());
+}
+
+#[test]
+fn abi_decode_ethereum_non_canonical() {
+    let mut runtime = build_solidity(
+        r#"
+        contract bar {
+            enum E { A, B, C }
+
+            function dec_uint8(bytes enc) public pure returns (uint8) {
+                return abi.decodeEthereum(enc, (uint8));
+            }
+
+            function dec_int8(bytes enc) public pure returns (int8) {
+                return abi.decodeEthereum(enc, (int8));
+            }
+
+            function dec_bool(bytes enc) public pure returns (bool) {
+                return abi.decodeEthereum(enc, (bool));
+            }
+
+            function dec_address(bytes enc) public pure returns (address) {
+                return abi.decodeEthereum(enc, (address));
+            }
+
+            function dec_bytes4(bytes enc) public pure returns (bytes4) {
+                return abi.decodeEthereum(enc, (bytes4));
+            }
+
+            function dec_enum(bytes enc) public pure returns (E) {
+                return abi.decodeEthereum(enc, (E));
+            }
+
+            function dec_string(bytes enc) public pure returns (string) {
+                return abi.decodeEthereum(enc, (string));
+            }
+        }"#,
+    );
+
+    let word = |value: u8| {
+        let mut word = vec![0u8; 32];
+        word[31] = value;
+        word
+    };
+
+    runtime.function("dec_uint8", word(0xff).encode());
+    assert_eq!(runtime.output(), 0xffu8.encode());
+    let mut enc = word(0xff);
+    enc[30] = 1;
+    runtime.function_expect_failure("dec_uint8", enc.encode());
+
+    runtime.function("dec_int8", vec![0xffu8; 32].encode());
+    assert_eq!(runtime.output(), (-1i8).encode());
+    runtime.function_expect_failure("dec_int8", word(0xff).encode());
+
+    runtime.function("dec_bool", word(1).encode());
+    assert_eq!(runtime.output(), true.encode());
+    runtime.function_expect_failure("dec_bool", word(2).encode());
+
+    // addresses are 32 bytes on Polkadot, so every word is a valid address
+    let mut enc = word(0);
+    enc[12..].copy_from_slice(&[0x11; 20]);
+    runtime.function("dec_address", enc.encode());
+    assert_eq!(runtime.output(), enc);
+    enc[11] = 1;
+    runtime.function("dec_address", enc.encode());
+    assert_eq!(runtime.output(), enc);
+
+    let mut enc = vec![0u8; 32];
+    enc[..4].copy_from_slice(b"abcd");
+    runtime.function("dec_bytes4", enc.encode());
+    assert_eq!(runtime.output(), b"abcd".encode());
+    enc[4] = 1;
+    runtime.function_expect_failure("dec_bytes4", enc.encode());
+
+    runtime.function("dec_enum", word(2).encode());
+    assert_eq!(runtime.output(), 2u8.encode());
+    runtime.function_expect_failure("dec_enum", word(3).encode());
+
+    // the offset would wrap around to 0x20 if it were truncated to 32 bits
+    let mut enc = word(0x20);
+    enc[27] = 1;
+    enc.extend(word(0));
+    runtime.function_expect_failure("dec_string", enc.encode());
+
+    // the same goes for the length of the string
+    let mut enc = word(0x20);
+    enc.extend(word(0));
+    enc[59] = 1;
+    runtime.function_expect_failure("dec_string", enc.encode());
+}
+
 #[test]
 fn call() {
     let mut runtime = build_solidity(
@@ -866,3 +1028,205 @@ fn caller_is_root() {
     runtime.function("test", [0; 32].to_vec());
     assert_eq!(runtime.output(), true.encode());
 }
+
+#[test]
+fn sr25519_verify() {
+    let mut runtime = build_solidity(
+        r#"
+        import { sr25519_verify } from "polkadot";
+        contract Test {
+            function verify(uint8[64] signature, uint8[32] public_key, bytes message) public view returns (bool) {
+                return sr25519_verify(signature, public_key, message);
+            }
+        }"#,
+    );
+
+    let public_key = [7u8; 32];
+    let message = b"polkadot".to_vec();
+
+    // The mock runtime accepts signatures starting with blake2_256(public_key ++ message)
+    let mut signature = [0u8; 64];
+    let data = [&public_key[..], &message[..]].concat();
+    signature[..32].copy_from_slice(blake2_rfc::blake2b::blake2b(32, &[], &data).as_bytes());
+
+    runtime.function("verify", (signature, public_key, message).encode());
+    assert_eq!(runtime.output(), true.encode());
+
+    runtime.function(
+        "verify",
+        (signature, public_key, b"kusama".to_vec()).encode(),
+    );
+    assert_eq!(runtime.output(), false.encode());
+}
+
+#[test]
+fn ecdsa_to_eth_address() {
+    let mut runtime = build_solidity(
+        r#"
+        import { ecdsa_to_eth_address } from "polkadot";
+        contract Test {
+            function to_eth_address(uint8[33] public_key) public view returns (uint32, uint8[20]) {
+                return ecdsa_to_eth_address(public_key);
+            }
+        }"#,
+    );
+
+    let public_key = [2u8; 33];
+    let mut hash = [0u8; 32];
+    let mut hasher = tiny_keccak::Keccak::v256();
+    tiny_keccak::Hasher::update(&mut hasher, &public_key);
+    tiny_keccak::Hasher::finalize(hasher, &mut hash);
+
+    runtime.function("to_eth_address", public_key.encode());
+    let (ret, address) = <(u32, [u8; 20])>::decode(&mut &runtime.output()[..]).unwrap();
+    assert_eq!(ret, 0);
+    assert_eq!(address[..], hash[12..]);
+
+    runtime.function("to_eth_address", [0u8; 33].encode());
+    let (ret, _) = <(u32, [u8; 20])>::decode(&mut &runtime.output()[..]).unwrap();
+    assert_eq!(ret, 11);
+}
+
+#[test]
+fn take_and_contains_storage() {
+    let mut runtime = build_solidity(
+        r#"
+        import "polkadot";
+        contract Test {
+            uint64 public x = 42;
+
+            function contains() public view returns (bool, uint32) {
+                return contains_storage(new bytes(32));
+            }
+
+            function take() public returns (bool, bytes) {
+                return take_storage(new bytes(32));
+            }
+        }"#,
+    );
+
+    runtime.constructor(0, vec![]);
+
+    runtime.function("contains", vec![]);
+    assert_eq!(runtime.output(), (true, 8u32).encode());
+
+    runtime.function("take", vec![]);
+    assert_eq!(
+        runtime.output(),
+        (true, 42u64.to_le_bytes().to_vec()).encode()
+    );
+
+    runtime.function("contains", vec![]);
+    assert_eq!(runtime.output(), (false, 0u32).encode());
+
+    runtime.function("take", vec![]);
+    assert_eq!(runtime.output(), (false, Vec::<u8>::new()).encode());
+
+    runtime.function("x", vec![]);
+    assert_eq!(runtime.output(), 0u64.encode());
+}
+
+#[test]
+fn take_storage_oversized() {
+    let mut runtime = build_solidity(
+        r#"
+        import "polkadot";
+        contract Test {
+            bytes b;
+
+            function fill(uint32 size) public {
+                b = new bytes(size);
+            }
+
+            function take() public returns (bool, bytes) {
+                return take_storage(new bytes(32));
+            }
+        }"#,
+    );
+
+    runtime.constructor(0, vec![]);
+
+    // the value does not fit into the scratch buffer, which must not look like a missing key
+    runtime.function("fill", 40_000u32.encode());
+    runtime.function_expect_failure("take", vec![]);
+    assert_eq!(runtime.storage()[&[0u8; 32]].len(), 40_000);
+
+    runtime.function("fill", 100u32.encode());
+    runtime.function("take", vec![]);
+    assert_eq!(runtime.output(), (true, vec![0u8; 100]).encode());
+}
+
+#[test]
+fn reentrance_count() {
+    let mut runtime = build_solidity(
+        r#"
+        import "polkadot";
+        contract Test {
+            function count() public view returns (uint32, uint32, uint32) {
+                return (
+                    reentrance_count(),
+                    account_reentrance_count(address(this)),
+                    account_reentrance_count(address(0))
+                );
+            }
+        }"#,
+    );
+
+    runtime.function("count", vec![]);
+    assert_eq!(runtime.output(), (0u32, 1u32, 0u32).encode());
+}
+
+#[test]
+fn lock_delegate_dependency() {
+    let mut runtime = build_solidity(
+        r#"
+        import { lock_delegate_dependency } from "polkadot";
+        contract Test {
+            function lock(uint8[32] code_hash) public {
+                lock_delegate_dependency(code_hash);
+            }
+        }"#,
+    );
+
+    let code_hash = runtime.blobs()[0].hash;
+    runtime.function("lock", code_hash.as_ref().to_vec());
+
+    runtime.function_expect_failure("lock", [0u8; 32].to_vec());
+}
+
+#[test]
+fn xcm_send_and_execute() {
+    let mut runtime = build_solidity(
+        r#"
+        import { xcm_send, xcm_execute } from "polkadot";
+        contract Test {
+            function send(bytes destination, bytes message) public returns (uint32, uint8[32]) {
+                return xcm_send(destination, message);
+            }
+
+            function execute(bytes message) public returns (uint32) {
+                return xcm_execute(message);
+            }
+        }"#,
+    );
+
+    let destination = vec![3u8, 1, 0];
+    let message = vec![4u8, 0, 1, 2, 3];
+    let message_id: [u8; 32] = blake2_rfc::blake2b::blake2b(32, &[], &message)
+        .as_bytes()
+        .try_into()
+        .unwrap();
+
+    runtime.function("send", (destination.clone(), message.clone()).encode());
+    assert_eq!(runtime.output(), (0u32, message_id).encode());
+
+    runtime.function("send", (destination, Vec::<u8>::new()).encode());
+    let (ret, _) = <(u32, [u8; 32])>::decode(&mut &runtime.output()[..]).unwrap();
+    assert_eq!(ret, 14);
+
+    runtime.function("execute", message.encode());
+    assert_eq!(runtime.output(), 0u32.encode());
+
+    runtime.function("execute", Vec::<u8>::new().encode());
+    assert_eq!(runtime.output(), 13u32.encode());
+}
@@ -1311,3 +1311,43 @@ fn try_catch_multiple_error_args() {
     runtime.function_expect_failure("a", Vec::new());
     assert_eq!(runtime.output(), expected_output);
 }
+
+#[test]
+fn weight_limits() {
+    let mut runtime = build_solidity(
+        r##"
+        contract Caller {
+            function test() public returns (uint64, uint64, uint64) {
+                Callee c = new Callee{refTime: 700, storageDepositLimit: 5}();
+
+                return (
+                    c.constructed(),
+                    c.left{refTime: 1000, proofSize: 50, storageDepositLimit: 100}(),
+                    c.left()
+                );
+            }
+        }
+
+        contract Callee {
+            uint64 public constructed;
+
+            constructor() {
+                constructed = gasleft();
+            }
+
+            function left() public view returns (uint64) {
+                return gasleft();
+            }
+        }
+        "##,
+    );
+
+    runtime.constructor(0, Vec::new());
+    runtime.function("test", Vec::new());
+
+    // the mock runtime reports the ref time limit as the gas left
+    assert_eq!(
+        runtime.output(),
+        (700u64, 1000u64, 2_224_097_461u64).encode()
+    );
+}
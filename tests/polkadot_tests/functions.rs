@@ -631,3 +631,47 @@ fn virtual_function_member_access() {
     runtime.function("create", vec![]);
     assert_eq!(runtime.output(), vec![1, 2, 3, 4]);
 }
+
+#[test]
+fn inlined_functions() {
+    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+    struct Val(u64, u64);
+
+    let mut runtime = build_solidity(
+        r##"
+        contract c {
+            function min_max(uint64 a, uint64 b) private pure returns (uint64, uint64) {
+                if (a < b) {
+                    return (a, b);
+                }
+                return (b, a);
+            }
+
+            function clamp(uint64 v) private pure returns (uint64 r) {
+                r = v;
+                if (r > 100) {
+                    r = 100;
+                }
+            }
+
+            function test(uint64 a, uint64 b) public pure returns (uint64, uint64) {
+                (uint64 lo, uint64 hi) = min_max(a, b);
+                uint64 total = 0;
+
+                for (uint64 i = 0; i < 3; i++) {
+                    total += clamp(hi + i);
+                }
+
+                (uint64 m, ) = min_max(lo, 7);
+
+                return (m + clamp(lo), total);
+            }
+        }"##,
+    );
+
+    runtime.function("test", Val(5, 50).encode());
+    assert_eq!(runtime.output(), Val(10, 153).encode());
+
+    runtime.function("test", Val(99, 3).encode());
+    assert_eq!(runtime.output(), Val(6, 299).encode());
+}
@@ -2,6 +2,7 @@
 
 use crate::build_solidity;
 use parity_scale_codec::Encode;
+use primitive_types::U256;
 
 #[test]
 fn global_constants() {
@@ -93,3 +94,111 @@ fn ext_fn_call_is_reading_variable() {
     runtime.function("ext_func_call", 0xdeadbeefu32.encode());
     assert_eq!(runtime.output(), 0xdeadbeefu32.encode())
 }
+
+#[test]
+fn namespaced_storage_and_layout_base() {
+    let mut runtime = build_solidity(
+        r#"
+        /// @custom:storage-location erc7201:example.main
+        struct MainStorage {
+            uint64 x;
+        }
+
+        contract C layout at 0x1000 {
+            uint64 a;
+            MainStorage main;
+            uint64 b;
+
+            function set() public {
+                a = 1;
+                main.x = 2;
+                b = 3;
+            }
+        }"#,
+    );
+
+    runtime.function("set", Vec::new());
+
+    let slot = |n: u16| {
+        let mut key = [0u8; 32];
+        key[..2].copy_from_slice(&n.to_le_bytes());
+        key
+    };
+
+    // erc7201:example.main, little endian
+    let mut main =
+        hex::decode("183a6125c38840424c4a85fa12bab2ab606c4b6d0e7cc73c0c06ba5300eab500").unwrap();
+    main.reverse();
+    let main: [u8; 32] = main.try_into().unwrap();
+
+    assert_eq!(runtime.storage()[&slot(0x1000)], 1u64.encode());
+    assert_eq!(runtime.storage()[&main], 2u64.encode());
+    assert_eq!(runtime.storage()[&slot(0x1001)], 3u64.encode());
+}
+
+#[test]
+fn transient_storage() {
+    let mut runtime = build_solidity(
+        r#"
+        contract C {
+            uint64 persistent;
+            uint64 transient counter;
+            bool transient locked;
+
+            function bump() public returns (uint64) {
+                counter += 1;
+                return counter;
+            }
+
+            function twice() public returns (uint64) {
+                bump();
+                return bump();
+            }
+
+            function lock() public returns (bool) {
+                require(!locked);
+                locked = true;
+                counter = 7;
+                persistent = counter;
+                delete counter;
+                return locked && counter == 0;
+            }
+
+            function yul() public returns (uint256 r) {
+                assembly {
+                    tstore(counter.slot, 42)
+                    r := tload(counter.slot)
+                }
+            }
+
+            function get() public view returns (uint64, bool) {
+                return (counter, locked);
+            }
+        }"#,
+    );
+
+    runtime.constructor(0, Vec::new());
+
+    runtime.function("bump", Vec::new());
+    assert_eq!(runtime.output(), 1u64.encode());
+
+    // transient storage does not outlive the call
+    runtime.function("bump", Vec::new());
+    assert_eq!(runtime.output(), 1u64.encode());
+
+    runtime.function("twice", Vec::new());
+    assert_eq!(runtime.output(), 2u64.encode());
+
+    runtime.function("lock", Vec::new());
+    assert_eq!(runtime.output(), true.encode());
+
+    runtime.function("yul", Vec::new());
+    assert_eq!(runtime.output(), U256::from(42).encode());
+
+    runtime.function("get", Vec::new());
+    assert_eq!(runtime.output(), (0u64, false).encode());
+
+    // only the persistent variable ended up in contract storage
+    assert_eq!(runtime.storage().len(), 1);
+    assert_eq!(runtime.storage()[&[0u8; 32]], 7u64.encode());
+}
@@ -389,6 +389,15 @@ fn deserialize_parameters(
                     .try_into()
                     .unwrap(),
             );
+
+            // a program may assign an account it owns to another program
+            let owner: Account = input[r.owner_offset..r.owner_offset + 32]
+                .try_into()
+                .unwrap();
+
+            if entry.owner.unwrap_or_default() != owner {
+                entry.owner = Some(owner);
+            }
         }
     }
 }
@@ -1086,3 +1086,66 @@ contract Testing {
     assert_eq!(decoded.item_2[1][1], 89);
     assert_eq!(decoded.item_3, -190);
 }
+
+#[test]
+fn ethereum_abi() {
+    let mut vm = build_solidity(
+        r#"
+contract Testing {
+    function encode(address addr) public pure returns (bytes) {
+        return abi.encodeEthereum(uint16(0xfd01), addr, "foobar");
+    }
+
+    function roundTrip(bytes enc) public pure returns (uint16, address, string) {
+        return abi.decodeEthereum(enc, (uint16, address, string));
+    }
+}
+        "#,
+    );
+
+    let data_account = vm.initialize_data_account();
+    vm.function("new")
+        .accounts(vec![("dataAccount", data_account)])
+        .call();
+
+    let addr = [0x55u8; 32];
+
+    let returns = vm
+        .function("encode")
+        .arguments(&[BorshToken::Address(addr)])
+        .call()
+        .unwrap();
+    let encoded = returns.into_bytes().unwrap();
+
+    // Addresses are 32 bytes on Solana, so they take up a whole word
+    let mut expected = vec![0u8; 30];
+    expected.extend_from_slice(&[0xfd, 0x01]);
+    expected.extend_from_slice(&addr);
+    expected.extend_from_slice(&[0u8; 31]);
+    expected.push(0x60);
+    expected.extend_from_slice(&[0u8; 31]);
+    expected.push(6);
+    expected.extend_from_slice(b"foobar");
+    expected.extend_from_slice(&[0u8; 26]);
+
+    assert_eq!(encoded, expected);
+
+    let returns = vm
+        .function("roundTrip")
+        .arguments(&[BorshToken::Bytes(encoded)])
+        .call()
+        .unwrap()
+        .unwrap_tuple();
+
+    assert_eq!(
+        returns,
+        vec![
+            BorshToken::Uint {
+                width: 16,
+                value: BigInt::from(0xfd01u16)
+            },
+            BorshToken::Address(addr),
+            BorshToken::String(String::from("foobar")),
+        ]
+    );
+}
@@ -1,7 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::build_solidity;
+use crate::{account_new, build_solidity, AccountState, BorshToken};
 use anchor_syn::idl::types::IdlInstruction;
+use num_bigint::BigInt;
 
 #[test]
 fn fallback() {
@@ -37,3 +38,147 @@ fn fallback() {
 
     assert_eq!(vm.logs, "fallback");
 }
+
+#[test]
+fn transfer_and_send() {
+    let mut vm = build_solidity(
+        r#"
+        contract c {
+            @mutableAccount(recipient)
+            function pay(uint64 amount) external {
+                payable(tx.accounts.recipient.key).transfer(amount);
+            }
+
+            @mutableAccount(recipient)
+            function trySend(uint64 amount) external returns (bool) {
+                return payable(tx.accounts.recipient.key).send(amount);
+            }
+        }"#,
+    );
+
+    let data_account = vm.initialize_data_account();
+    vm.function("new")
+        .accounts(vec![("dataAccount", data_account)])
+        .call();
+
+    vm.account_data.get_mut(&data_account).unwrap().lamports = 1000;
+
+    let recipient = account_new();
+    vm.account_data.insert(
+        recipient,
+        AccountState {
+            data: vec![],
+            owner: None,
+            lamports: 5,
+        },
+    );
+
+    let amount = |value: u64| {
+        [BorshToken::Uint {
+            width: 64,
+            value: BigInt::from(value),
+        }]
+    };
+
+    vm.function("pay")
+        .arguments(&amount(300))
+        .accounts(vec![
+            ("dataAccount", data_account),
+            ("recipient", recipient),
+            ("systemProgram", [0; 32]),
+        ])
+        .call();
+
+    assert_eq!(vm.account_data[&data_account].lamports, 700);
+    assert_eq!(vm.account_data[&recipient].lamports, 305);
+
+    let returns = vm
+        .function("trySend")
+        .arguments(&amount(701))
+        .accounts(vec![
+            ("dataAccount", data_account),
+            ("recipient", recipient),
+            ("systemProgram", [0; 32]),
+        ])
+        .call()
+        .unwrap();
+
+    assert_eq!(returns, BorshToken::Bool(false));
+    assert_eq!(vm.account_data[&data_account].lamports, 700);
+
+    let returns = vm
+        .function("trySend")
+        .arguments(&amount(700))
+        .accounts(vec![
+            ("dataAccount", data_account),
+            ("recipient", recipient),
+            ("systemProgram", [0; 32]),
+        ])
+        .call()
+        .unwrap();
+
+    assert_eq!(returns, BorshToken::Bool(true));
+    assert_eq!(vm.account_data[&data_account].lamports, 0);
+    assert_eq!(vm.account_data[&recipient].lamports, 1005);
+
+    vm.function("pay")
+        .arguments(&amount(1))
+        .accounts(vec![
+            ("dataAccount", data_account),
+            ("recipient", recipient),
+            ("systemProgram", [0; 32]),
+        ])
+        .must_fail();
+}
+
+#[test]
+fn selfdestruct() {
+    let mut vm = build_solidity(
+        r#"
+        contract c {
+            uint64 x = 102;
+
+            @mutableAccount(recipient)
+            function close() external {
+                destroy(tx.accounts.recipient.key);
+                x = 1;
+            }
+
+            function destroy(address recipient) internal {
+                selfdestruct(payable(recipient));
+            }
+        }"#,
+    );
+
+    let data_account = vm.initialize_data_account();
+    vm.function("new")
+        .accounts(vec![("dataAccount", data_account)])
+        .call();
+
+    vm.account_data.get_mut(&data_account).unwrap().lamports = 1000;
+
+    let recipient = account_new();
+    vm.account_data.insert(
+        recipient,
+        AccountState {
+            data: vec![],
+            owner: None,
+            lamports: 0,
+        },
+    );
+
+    vm.function("close")
+        .accounts(vec![
+            ("dataAccount", data_account),
+            ("recipient", recipient),
+        ])
+        .call();
+
+    let account = &vm.account_data[&data_account];
+
+    // the account is closed, and the code after selfdestruct is not executed
+    assert_eq!(account.lamports, 0);
+    assert!(account.data.iter().all(|b| *b == 0));
+    assert_eq!(account.owner, Some([0; 32]));
+    assert_eq!(vm.account_data[&recipient].lamports, 1000);
+}
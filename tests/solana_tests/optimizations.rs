@@ -29,6 +29,8 @@ static NO_OPTIMIZATIONS: Lazy<Options> = Lazy::new(|| Options {
     strength_reduce: false,
     vector_to_slice: false,
     common_subexpression_elimination: false,
+    inline: false,
+    dead_code: false,
     ..Default::default()
 });
 
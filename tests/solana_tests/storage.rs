@@ -3,6 +3,7 @@
 use crate::{build_solidity, BorshToken};
 use num_bigint::BigInt;
 use num_traits::{One, Zero};
+use solang::abi::anchor::function_discriminator;
 
 #[test]
 fn simple() {
@@ -1050,3 +1051,157 @@ fn complex_struct() {
 }
 
 // dereference struct storage member (read/write)
+
+#[test]
+fn external_function() {
+    let mut vm = build_solidity(
+        r#"
+        contract c {
+            struct S {
+                uint8 a;
+                function(uint64) external returns (uint64) f;
+            }
+
+            function(uint64) external returns (uint64) f;
+            S s;
+
+            function set() public {
+                f = this.double;
+                s = S({a: 7, f: this.triple});
+            }
+
+            function get() public view returns (bytes8, address, bytes8, address) {
+                return (f.selector, f.address, s.f.selector, s.f.address);
+            }
+
+            function call() public returns (uint64) {
+                return f{accounts: []}(2) + s.f{accounts: []}(5);
+            }
+
+            function clear() public {
+                delete f;
+                delete s;
+            }
+
+            function double(uint64 x) public pure returns (uint64) {
+                return x * 2;
+            }
+
+            function triple(uint64 x) public pure returns (uint64) {
+                return x * 3;
+            }
+        }"#,
+    );
+
+    let data_account = vm.initialize_data_account();
+    vm.function("new")
+        .accounts(vec![("dataAccount", data_account)])
+        .call();
+
+    vm.function("set")
+        .accounts(vec![("dataAccount", data_account)])
+        .call();
+
+    let returns = vm
+        .function("get")
+        .accounts(vec![("dataAccount", data_account)])
+        .call()
+        .unwrap()
+        .unwrap_tuple();
+
+    let program_id = vm.stack[0].id;
+
+    assert_eq!(
+        returns,
+        vec![
+            BorshToken::FixedBytes(function_discriminator("double")),
+            BorshToken::Address(program_id),
+            BorshToken::FixedBytes(function_discriminator("triple")),
+            BorshToken::Address(program_id),
+        ]
+    );
+
+    let returns = vm
+        .function("call")
+        .accounts(vec![
+            ("dataAccount", data_account),
+            ("systemProgram", [0; 32]),
+        ])
+        .call()
+        .unwrap();
+
+    assert_eq!(
+        returns,
+        BorshToken::Uint {
+            width: 64,
+            value: BigInt::from(19u8),
+        }
+    );
+
+    vm.function("clear")
+        .accounts(vec![("dataAccount", data_account)])
+        .call();
+
+    let returns = vm
+        .function("get")
+        .accounts(vec![("dataAccount", data_account)])
+        .call()
+        .unwrap()
+        .unwrap_tuple();
+
+    assert_eq!(
+        returns,
+        vec![
+            BorshToken::FixedBytes(vec![0; 8]),
+            BorshToken::Address([0; 32]),
+            BorshToken::FixedBytes(vec![0; 8]),
+            BorshToken::Address([0; 32]),
+        ]
+    );
+}
+
+#[test]
+fn transient_storage() {
+    let mut vm = build_solidity(
+        r#"
+        contract c {
+            uint64 transient counter;
+
+            function bump() public returns (uint64) {
+                counter += 1;
+                return counter;
+            }
+
+            function twice() public returns (uint64) {
+                bump();
+                return bump();
+            }
+        }"#,
+    );
+
+    let data_account = vm.initialize_data_account();
+    vm.function("new")
+        .accounts(vec![("dataAccount", data_account)])
+        .call();
+
+    // transient storage does not need the data account, and is reset for each instruction
+    for _ in 0..2 {
+        let returns = vm.function("bump").call().unwrap();
+        assert_eq!(
+            returns,
+            BorshToken::Uint {
+                width: 64,
+                value: BigInt::one(),
+            }
+        );
+    }
+
+    let returns = vm.function("twice").call().unwrap();
+    assert_eq!(
+        returns,
+        BorshToken::Uint {
+            width: 64,
+            value: BigInt::from(2u8),
+        }
+    );
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use solang::file_resolver::FileResolver;
+use solang::Target;
+use std::ffi::OsStr;
+
+#[test]
+fn transient_not_supported() {
+    let mut cache = FileResolver::default();
+    cache.set_file_contents(
+        "test.sol",
+        r#"contract locked {
+            bool transient entered;
+
+            function get() public view returns (bool) {
+                return entered;
+            }
+        }"#
+        .to_string(),
+    );
+
+    let ns = solang::parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::Soroban);
+
+    assert_eq!(
+        ns.diagnostics.first_error(),
+        "transient storage is not supported on Soroban"
+    );
+}
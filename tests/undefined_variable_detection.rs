@@ -21,6 +21,8 @@ fn parse_and_codegen(src: &'static str) -> Namespace {
         strength_reduce: false,
         vector_to_slice: false,
         common_subexpression_elimination: false,
+        inline: false,
+        dead_code: false,
         opt_level: OptimizationLevel::Default,
         generate_debug_information: false,
         log_runtime_errors: false,
@@ -2,6 +2,7 @@
 
 use solang::file_resolver::FileResolver;
 use solang::sema::ast;
+use solang::sema::diagnostics::{codes, CodeLevel, DiagnosticsConfig};
 use solang::{parse_and_resolve, Target};
 use std::ffi::OsStr;
 
@@ -1232,3 +1233,94 @@ fn balance() {
     let ns = parse(file);
     assert_eq!(ns.diagnostics.count_warnings(), 0);
 }
+
+#[test]
+fn suppress_warnings() {
+    let file = r#"
+    contract Test {
+        function get(int64 a, int64 b) public pure returns (int64) {
+            // solang-disable-next-line S5003
+            int64 c = 2;
+            // solang-disable-next-line S5001
+            int64 d = 3;
+            // solang-disable-next-line
+            int64 e = 4;
+            return a;
+        }
+    }
+    "#;
+
+    let mut ns = parse(file);
+    let warnings = ns.diagnostics.warnings();
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].message, "function parameter 'b' is unused");
+    assert_eq!(warnings[0].code, codes::UNUSED_PARAMETER);
+    assert_eq!(
+        warnings[1].message,
+        "local variable 'd' has been assigned, but never read"
+    );
+    assert_eq!(warnings[1].code, codes::UNUSED_VARIABLE);
+
+    let mut config = DiagnosticsConfig::default();
+    config.set(codes::UNUSED_PARAMETER, CodeLevel::Allow);
+    config.set(codes::UNUSED_VARIABLE, CodeLevel::Deny);
+
+    ns.diagnostics.apply_config(&config);
+
+    assert_eq!(ns.diagnostics.count_warnings(), 0);
+    assert!(ns.diagnostics.any_errors());
+    assert_eq!(
+        ns.diagnostics.first_error(),
+        "local variable 'd' has been assigned, but never read"
+    );
+
+    let file = r#"
+    contract Test {
+        // solang-disable-next-line S5001, X
+        function get(int64 a) public pure {}
+    }
+    "#;
+
+    let ns = parse(file);
+    let warnings = ns.diagnostics.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "'X' is not a diagnostic code, expected e.g. 'S5001'"
+    );
+}
+
+#[test]
+fn warning_codes() {
+    let file = r#"
+    interface I {
+        function f() external virtual;
+    }
+
+    contract Test {
+        constructor() public {}
+
+        function g() public pure {
+            assembly ("foo") {}
+        }
+    }
+    "#;
+
+    let ns = parse(file);
+    let mut found: Vec<_> = ns
+        .diagnostics
+        .warnings()
+        .iter()
+        .map(|diag| diag.code)
+        .collect();
+    found.sort();
+
+    assert_eq!(
+        found,
+        vec![
+            codes::ASSEMBLY_FLAG,
+            codes::CONSTRUCTOR_VISIBILITY,
+            codes::INTERFACE_VIRTUAL
+        ]
+    );
+}